@@ -55,7 +55,7 @@ impl From<RequestBuilderError> for ParseError {
     }
 }
 
-pub fn parse(input: &str) -> Result<Request, ParseError> {
+pub fn parse(input: &str) -> Result<Request<'_>, ParseError> {
     let mut split = input.split(' ');
 
     let cmd_name = match split.next() {
@@ -104,13 +104,13 @@ fn input_args<'a>(
     match cmd_id.key_notation() {
         KeyNotation::None => {}
         KeyNotation::One => {
-            let arg = iter.next().ok_or_else(|| ParseError::KeyUnspecified)?;
+            let arg = iter.next().ok_or(ParseError::KeyUnspecified)?;
 
             args.push(arg.as_bytes().to_vec());
         }
         KeyNotation::Two => {
             for _ in 0..2 {
-                let arg = iter.next().ok_or_else(|| ParseError::KeyUnspecified)?;
+                let arg = iter.next().ok_or(ParseError::KeyUnspecified)?;
 
                 args.push(arg.as_bytes().to_vec());
             }
@@ -122,7 +122,12 @@ fn input_args<'a>(
         }
     }
 
+    let mut idx = 0;
+
     while let Some(arg) = iter.next() {
+        let key_type = argument_type(cmd_id, idx).unwrap_or(key_type);
+        idx += 1;
+
         match key_type {
             KeyType::Boolean => {
                 let boolean = arg
@@ -134,7 +139,7 @@ fn input_args<'a>(
 
                 args.push(vec![boolean as u8]);
             }
-            KeyType::Bytes => args.push(arg.as_bytes().to_vec()),
+            KeyType::Bytes | KeyType::Document => args.push(arg.as_bytes().to_vec()),
             KeyType::Float => {
                 let float = arg
                     .parse::<f64>()
@@ -145,6 +150,7 @@ fn input_args<'a>(
 
                 args.push(float.to_be_bytes().to_vec());
             }
+            KeyType::HyperLogLog => args.push(arg.as_bytes().to_vec()),
             KeyType::Integer => {
                 let int = arg
                     .parse::<i64>()
@@ -168,6 +174,7 @@ fn input_args<'a>(
             KeyType::Set => {
                 args.push(arg.as_bytes().to_vec());
             }
+            KeyType::Stream => args.push(arg.as_bytes().to_vec()),
             KeyType::String => {
                 args.push(arg.as_bytes().to_vec());
             }
@@ -177,6 +184,36 @@ fn input_args<'a>(
     Ok(args)
 }
 
+/// Retrieve the type of a non-key argument of a command if it's fixed
+/// regardless of the key type, such as the offsets of the string commands.
+fn argument_type(cmd_id: CommandId, idx: usize) -> Option<KeyType> {
+    match (cmd_id, idx) {
+        (CommandId::BitCount, _)
+        | (CommandId::BitGet, 0)
+        | (CommandId::BitSet, 0)
+        | (CommandId::SetRange, 0)
+        | (CommandId::Scan, 1)
+        | (CommandId::Select, 0)
+        | (CommandId::Slice, _)
+        | (CommandId::StreamRange, 2)
+        | (CommandId::StreamRead, 1)
+        | (CommandId::StreamRead, 2)
+        | (CommandId::StreamTrim, 0)
+        | (CommandId::StreamTrimAge, 0)
+        | (CommandId::Substring, _) => Some(KeyType::Integer),
+        (CommandId::BitPosition, 0)
+        | (CommandId::BitSet, 1)
+        | (CommandId::Copy, 0)
+        | (CommandId::Flush, 0)
+        | (CommandId::FlushAll, 0)
+        | (CommandId::FlushPrefix, 1)
+        | (CommandId::Rename, 0)
+        | (CommandId::Restore, 1) => Some(KeyType::Boolean),
+        (CommandId::FlushPrefix, 0) | (CommandId::Scan, 0) => Some(KeyType::Bytes),
+        _ => None,
+    }
+}
+
 fn command(name: &str) -> Option<(CommandId, Option<KeyType>)> {
     if let Ok(cmd_id) = CommandId::from_str(name) {
         return Some((cmd_id, None));
@@ -195,11 +232,14 @@ fn key_type(key_type: &str) -> Option<KeyType> {
     Some(match key_type {
         "boolean" | "bool" => KeyType::Boolean,
         "bytes" => KeyType::Bytes,
+        "document" | "doc" => KeyType::Document,
         "float" => KeyType::Float,
+        "hyperloglog" | "hll" => KeyType::HyperLogLog,
         "integer" | "int" => KeyType::Integer,
         "list" => KeyType::List,
         "map" => KeyType::Map,
         "set" => KeyType::Set,
+        "stream" => KeyType::Stream,
         "string" | "str" => KeyType::String,
         _ => return None,
     })
@@ -209,11 +249,14 @@ fn key_type_name(key_type: KeyType) -> &'static str {
     match key_type {
         KeyType::Boolean => "bool",
         KeyType::Bytes => "bytes",
+        KeyType::Document => "doc",
         KeyType::Float => "float",
+        KeyType::HyperLogLog => "hll",
         KeyType::Integer => "int",
         KeyType::List => "list",
         KeyType::Map => "map",
         KeyType::Set => "set",
+        KeyType::Stream => "stream",
         KeyType::String => "str",
     }
 }
@@ -230,6 +273,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_args_fixed_type() {
+        assert_eq!(
+            vec![
+                b"foo".to_vec(),
+                1i64.to_be_bytes().to_vec(),
+                b"bar".to_vec()
+            ],
+            super::input_args("foo 1 bar".split(' '), CommandId::SetRange, KeyType::String)
+                .unwrap()
+        );
+        assert!(
+            super::input_args("foo bar".split(' '), CommandId::Substring, KeyType::String).is_err()
+        );
+    }
+
+    #[test]
+    fn test_args_bit_set() {
+        assert_eq!(
+            vec![b"foo".to_vec(), 7i64.to_be_bytes().to_vec(), vec![1]],
+            super::input_args("foo 7 true".split(' '), CommandId::BitSet, KeyType::Bytes).unwrap()
+        );
+    }
+
     #[test]
     fn test_command() {
         assert_eq!(Some((CommandId::Echo, None)), super::command("echo"));
@@ -253,6 +320,67 @@ mod tests {
         assert!(req.arg(2).is_none());
     }
 
+    #[test]
+    fn test_command_scan_int() {
+        let req = super::parse("scan:int user:* 100").unwrap();
+        assert_eq!(CommandId::Scan, req.command_id());
+        assert_eq!(Some(KeyType::Integer), req.key_type());
+        assert_eq!(Some(b"user:*".as_ref()), req.arg(0));
+        assert_eq!(Some(100), req.typed_arg::<i64>(1));
+    }
+
+    #[test]
+    fn test_command_select() {
+        let req = super::parse("select 3").unwrap();
+        assert_eq!(CommandId::Select, req.command_id());
+        assert_eq!(Some(3), req.typed_arg::<i64>(0));
+    }
+
+    #[test]
+    fn test_command_substring_str() {
+        let req = super::parse("substring:str foo 0 -3").unwrap();
+        assert_eq!(CommandId::Substring, req.command_id());
+        assert_eq!(Some(KeyType::String), req.key_type());
+        assert_eq!(Some(b"foo".as_ref()), req.arg(0));
+        assert_eq!(Some(0), req.typed_arg::<i64>(1));
+        assert_eq!(Some(-3), req.typed_arg::<i64>(2));
+    }
+
+    #[test]
+    fn test_command_stream_read() {
+        let req = super::parse("stream:read foo 1-0 10").unwrap();
+        assert_eq!(CommandId::StreamRead, req.command_id());
+        assert_eq!(Some(b"foo".as_ref()), req.arg(0));
+        assert_eq!(Some(b"1-0".as_ref()), req.arg(1));
+        assert_eq!(Some(10), req.typed_arg::<i64>(2));
+    }
+
+    #[test]
+    fn test_command_copy_replace() {
+        let req = super::parse("copy foo bar true").unwrap();
+        assert_eq!(CommandId::Copy, req.command_id());
+        assert_eq!(Some(b"foo".as_ref()), req.arg(0));
+        assert_eq!(Some(b"bar".as_ref()), req.arg(1));
+        assert_eq!(Some(true), req.typed_arg::<bool>(2));
+    }
+
+    #[test]
+    fn test_command_restore_replace() {
+        let req = super::parse("restore foo 484f50 true").unwrap();
+        assert_eq!(CommandId::Restore, req.command_id());
+        assert_eq!(Some(b"foo".as_ref()), req.arg(0));
+        assert_eq!(Some(b"484f50".as_ref()), req.arg(1));
+        assert_eq!(Some(true), req.typed_arg::<bool>(2));
+    }
+
+    #[test]
+    fn test_command_flush_prefix() {
+        let req = super::parse("flush:prefix user: true").unwrap();
+        assert_eq!(CommandId::FlushPrefix, req.command_id());
+        assert_eq!(Some(b"user:".as_ref()), req.arg(0));
+        assert_eq!(Some(true), req.typed_arg::<bool>(1));
+    }
+
     #[test]
     fn test_command_invalid_key_type() {
         assert!(super::command("increment:floatt").is_none());
@@ -264,12 +392,17 @@ mod tests {
         assert_eq!(Some(KeyType::Boolean), super::key_type("boolean"));
         assert_eq!(Some(KeyType::Boolean), super::key_type("bool"));
         assert_eq!(Some(KeyType::Bytes), super::key_type("bytes"));
+        assert_eq!(Some(KeyType::Document), super::key_type("document"));
+        assert_eq!(Some(KeyType::Document), super::key_type("doc"));
         assert_eq!(Some(KeyType::Float), super::key_type("float"));
+        assert_eq!(Some(KeyType::HyperLogLog), super::key_type("hyperloglog"));
+        assert_eq!(Some(KeyType::HyperLogLog), super::key_type("hll"));
         assert_eq!(Some(KeyType::Integer), super::key_type("integer"));
         assert_eq!(Some(KeyType::Integer), super::key_type("int"));
         assert_eq!(Some(KeyType::List), super::key_type("list"));
         assert_eq!(Some(KeyType::Map), super::key_type("map"));
         assert_eq!(Some(KeyType::Set), super::key_type("set"));
+        assert_eq!(Some(KeyType::Stream), super::key_type("stream"));
         assert_eq!(Some(KeyType::String), super::key_type("string"));
         assert_eq!(Some(KeyType::String), super::key_type("str"));
     }
@@ -278,11 +411,14 @@ mod tests {
     fn test_key_type_name() {
         assert_eq!(super::key_type_name(KeyType::Boolean), "bool");
         assert_eq!(super::key_type_name(KeyType::Bytes), "bytes");
+        assert_eq!(super::key_type_name(KeyType::Document), "doc");
         assert_eq!(super::key_type_name(KeyType::Float), "float");
+        assert_eq!(super::key_type_name(KeyType::HyperLogLog), "hll");
         assert_eq!(super::key_type_name(KeyType::Integer), "int");
         assert_eq!(super::key_type_name(KeyType::List), "list");
         assert_eq!(super::key_type_name(KeyType::Map), "map");
         assert_eq!(super::key_type_name(KeyType::Set), "set");
+        assert_eq!(super::key_type_name(KeyType::Stream), "stream");
         assert_eq!(super::key_type_name(KeyType::String), "str");
     }
 
@@ -1,18 +1,26 @@
-use hop_engine::state::{KeyType, Value};
+use hop_engine::state::{KeyType, Stream, Value};
 
 pub fn key_type_name(key_type: KeyType) -> &'static str {
     match key_type {
         KeyType::Boolean => "bool",
         KeyType::Bytes => "bytes",
+        KeyType::Document => "doc",
         KeyType::Float => "float",
+        KeyType::HyperLogLog => "hll",
         KeyType::Integer => "int",
         KeyType::List => "list",
         KeyType::Map => "map",
         KeyType::Set => "set",
+        KeyType::Stream => "stream",
         KeyType::String => "str",
     }
 }
 
+/// Format bytes as lowercase hexadecimal digits.
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 pub fn list<T: IntoIterator<Item = U>, U: AsRef<[u8]>>(items: T) -> String {
     let mut output = String::new();
 
@@ -21,7 +29,7 @@ pub fn list<T: IntoIterator<Item = U>, U: AsRef<[u8]>>(items: T) -> String {
         output.push('\n');
     }
 
-    output.truncate(output.len() - 2);
+    output.pop();
 
     output
 }
@@ -41,15 +49,39 @@ pub fn map<T: IntoIterator<Item = (U, V)>, U: AsRef<[u8]>, V: AsRef<[u8]>>(pairs
     output
 }
 
+pub fn stream(stream: &Stream) -> String {
+    let mut output = String::new();
+
+    for entry in stream.entries() {
+        output.push_str(&entry.id().to_string());
+
+        for (field, value) in entry.fields() {
+            output.push(' ');
+            output.push_str(&String::from_utf8_lossy(field));
+            output.push('=');
+            output.push_str(&String::from_utf8_lossy(value));
+        }
+
+        output.push('\n');
+    }
+
+    output.pop();
+
+    output
+}
+
 pub fn value(value: Value) -> String {
     match value {
         Value::Boolean(boolean) => boolean.to_string(),
         Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        Value::Document(document) => document.to_string(),
         Value::Float(float) => float.to_string(),
+        Value::HyperLogLog(hll) => format!("~{} unique items", hll.count()),
         Value::Integer(int) => int.to_string(),
         Value::List(value_list) => list(value_list),
         Value::Map(value_map) => map(value_map),
         Value::Set(set) => list(set),
+        Value::Stream(stream_value) => stream(&stream_value),
         Value::String(string) => string,
     }
 }
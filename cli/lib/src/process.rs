@@ -3,13 +3,24 @@ use crate::{
     print,
 };
 use alloc::borrow::Cow;
-use core::fmt::{Debug, Display, Error as FmtError, Formatter, Result as FmtResult, Write};
+use core::{
+    convert::TryFrom,
+    fmt::{Debug, Display, Error as FmtError, Formatter, Result as FmtResult, Write},
+    future,
+    pin::Pin,
+    str,
+    time::Duration,
+};
+use futures_core::Stream;
 use hop::{
     backend::{memory::Error as MemoryError, Backend},
     request::CommandConfigurationError,
     Client,
 };
-use hop_engine::command::{CommandId, DispatchError, Request};
+use hop_engine::{
+    command::{CommandId, DispatchError, Request},
+    state::{Document, KeyType, StreamId, Value},
+};
 use std::error::Error;
 
 #[derive(Debug)]
@@ -73,10 +84,17 @@ where
 }
 
 enum InnerProcessError<B: Backend> {
+    AclRuleInvalid,
+    ArithmeticOverflow,
     Backend { source: <B as Backend>::Error },
     BadRequest,
     BuildingRequest,
+    CharBoundaryInvalid,
+    ConfigInvalid,
     Dispatching,
+    DivisionByZero,
+    DocumentInvalid,
+    DumpInvalid,
     KeyDestinationRequired,
     KeyNonexistent,
     KeyRequiredMinimum,
@@ -86,9 +104,14 @@ enum InnerProcessError<B: Backend> {
     KeyTypeRequired,
     KeyTypeUnexpected,
     KeyUnspecified,
+    MemoryLimitReached,
+    PermissionDenied,
     PreconditionFailed,
+    RateLimited,
+    SizeLimitExceeded,
     TooFewArguments,
     TooManyArguments,
+    Unauthenticated,
     WritingOutput { source: FmtError },
 }
 
@@ -118,6 +141,10 @@ where
 
     Ok(match process_inner(client, req).await {
         Ok(output) => output,
+        Err(InnerProcessError::AclRuleInvalid) => "A rule configuring the user is invalid.".into(),
+        Err(InnerProcessError::ArithmeticOverflow) => {
+            "The arithmetic operation overflowed, so the key wasn't modified.".into()
+        }
         Err(InnerProcessError::Backend { source }) => return Err(ProcessError::Backend { source }),
         Err(InnerProcessError::BadRequest) => {
             "The server rejected the request due to being invalid.".into()
@@ -125,9 +152,23 @@ where
         Err(InnerProcessError::BuildingRequest) => {
             "Building the request failed, such as due to too many arguments.".into()
         }
+        Err(InnerProcessError::CharBoundaryInvalid) => {
+            "The offset doesn't lie on a UTF-8 character boundary.".into()
+        }
+        Err(InnerProcessError::ConfigInvalid) => {
+            "The configuration parameter is unknown, can't be changed, or the value is invalid."
+                .into()
+        }
         Err(InnerProcessError::Dispatching) => {
             "The engine failed to properly run the request.".into()
         }
+        Err(InnerProcessError::DivisionByZero) => "Dividing by zero isn't possible.".into(),
+        Err(InnerProcessError::DocumentInvalid) => {
+            "The value isn't a valid JSON document; it can't contain spaces.".into()
+        }
+        Err(InnerProcessError::DumpInvalid) => {
+            "The dump is invalid, corrupted or from another version.".into()
+        }
         Err(InnerProcessError::KeyDestinationRequired) => {
             "The destination key name is required.".into()
         }
@@ -149,15 +190,30 @@ where
             "A key type was specified when the command can't be given one.".into()
         }
         Err(InnerProcessError::KeyUnspecified) => "Specifying a key is required.".into(),
+        Err(InnerProcessError::MemoryLimitReached) => {
+            "The memory limit was reached and no keys can be evicted.".into()
+        }
+        Err(InnerProcessError::PermissionDenied) => {
+            "The user isn't permitted to run the command on the keys.".into()
+        }
         Err(InnerProcessError::PreconditionFailed) => {
             "A precondition failed, such as the key not existing.".into()
         }
+        Err(InnerProcessError::RateLimited) => {
+            "Requests are being sent too quickly; wait before trying again.".into()
+        }
+        Err(InnerProcessError::SizeLimitExceeded) => {
+            "A key or value would be larger than the configured limit.".into()
+        }
         Err(InnerProcessError::TooFewArguments) => {
             "Too few arguments were provided for this command.".into()
         }
         Err(InnerProcessError::TooManyArguments) => {
             "You may only provide at most 255 arguments.".into()
         }
+        Err(InnerProcessError::Unauthenticated) => {
+            "Authenticating with the auth command is required first.".into()
+        }
         Err(InnerProcessError::WritingOutput { source }) => {
             format!("Failed to write the response: {}", source).into()
         }
@@ -179,14 +235,25 @@ where
                 MemoryError::KeyTypeInvalid { .. } => InnerProcessError::KeyTypeInvalid,
                 MemoryError::KeyTypeUnsupported { .. } => InnerProcessError::KeyTypeInvalid,
                 MemoryError::RunningCommand { source } => match source {
+                    DispatchError::AclRuleInvalid => InnerProcessError::AclRuleInvalid,
                     DispatchError::ArgumentRetrieval => InnerProcessError::TooFewArguments,
+                    DispatchError::ArithmeticOverflow => InnerProcessError::ArithmeticOverflow,
+                    DispatchError::CharBoundaryInvalid => InnerProcessError::CharBoundaryInvalid,
+                    DispatchError::ConfigInvalid => InnerProcessError::ConfigInvalid,
+                    DispatchError::DivisionByZero => InnerProcessError::DivisionByZero,
+                    DispatchError::DumpInvalid => InnerProcessError::DumpInvalid,
                     DispatchError::KeyNonexistent => InnerProcessError::KeyNonexistent,
                     DispatchError::KeyTypeDifferent => InnerProcessError::KeyTypeDifferent,
                     DispatchError::KeyTypeInvalid => InnerProcessError::KeyTypeInvalid,
                     DispatchError::KeyTypeRequired => InnerProcessError::KeyTypeRequired,
                     DispatchError::KeyTypeUnexpected => InnerProcessError::KeyTypeUnexpected,
                     DispatchError::KeyUnspecified => InnerProcessError::KeyUnspecified,
+                    DispatchError::MemoryLimitReached => InnerProcessError::MemoryLimitReached,
+                    DispatchError::PermissionDenied => InnerProcessError::PermissionDenied,
                     DispatchError::PreconditionFailed => InnerProcessError::PreconditionFailed,
+                    DispatchError::RateLimited => InnerProcessError::RateLimited,
+                    DispatchError::SizeLimitExceeded => InnerProcessError::SizeLimitExceeded,
+                    DispatchError::Unauthenticated => InnerProcessError::Unauthenticated,
                 },
             }
         }
@@ -208,6 +275,203 @@ where
     B::Error: Error,
 {
     match req.command_id() {
+        CommandId::AclDelete => {
+            let name = req.arg(0).ok_or(InnerProcessError::TooFewArguments)?;
+
+            if client.acl_delete(name).await.map_err(backend_err)? {
+                Ok("Deleted.".into())
+            } else {
+                Ok("No such user.".into())
+            }
+        }
+        CommandId::AclList => Ok(client
+            .acl_list()
+            .await
+            .map_err(backend_err)?
+            .join("\n")
+            .into()),
+        CommandId::AclSet => {
+            let name = req.arg(0).ok_or(InnerProcessError::TooFewArguments)?;
+            let rules = req.args(1..).into_iter().flatten();
+
+            Ok(client
+                .acl_set(name, rules)
+                .await
+                .map_err(backend_err)?
+                .into())
+        }
+        CommandId::Auth => {
+            let auth = match (req.arg(0), req.arg(1)) {
+                (Some(username), Some(password)) => client.auth(password).username(username),
+                (Some(password), None) => client.auth(password),
+                _ => return Err(InnerProcessError::TooFewArguments),
+            };
+
+            auth.await.map_err(backend_err)?;
+
+            Ok("Authenticated.".into())
+        }
+        CommandId::BitAnd | CommandId::BitOr | CommandId::BitXor => {
+            let destination = req.key().ok_or(InnerProcessError::KeyDestinationRequired)?;
+            let sources = req
+                .args(1..)
+                .ok_or(InnerProcessError::KeySourceRequired)?
+                .collect::<Vec<_>>();
+
+            let v = match req.command_id() {
+                CommandId::BitAnd => client.bit_and(destination, sources).await,
+                CommandId::BitOr => client.bit_or(destination, sources).await,
+                _ => client.bit_xor(destination, sources).await,
+            }
+            .map_err(backend_err)?;
+
+            Ok(print::value(Value::Bytes(v)).into())
+        }
+        CommandId::BitCount => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let mut count = client.bit_count(key);
+
+            if let Some(start) = req.typed_arg::<i64>(1) {
+                count = count.start(start);
+            }
+
+            if let Some(end) = req.typed_arg::<i64>(2) {
+                count = count.end(end);
+            }
+
+            Ok(count.await.map_err(backend_err)?.to_string().into())
+        }
+        CommandId::BitGet => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let offset = req
+                .typed_arg::<i64>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+
+            let v = client.bit_get(key, offset).await.map_err(backend_err)?;
+
+            Ok(v.to_string().into())
+        }
+        CommandId::BitNot => {
+            let destination = req.key().ok_or(InnerProcessError::KeyDestinationRequired)?;
+            let source = req.arg(1).ok_or(InnerProcessError::KeySourceRequired)?;
+
+            let v = client
+                .bit_not(destination, source)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(print::value(Value::Bytes(v)).into())
+        }
+        CommandId::BitPosition => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let bit = req
+                .typed_arg::<bool>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+
+            let v = client.bit_position(key, bit).await.map_err(backend_err)?;
+
+            Ok(v.to_string().into())
+        }
+        CommandId::BitSet => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let offset = req
+                .typed_arg::<i64>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+            let bit = req
+                .typed_arg::<bool>(2)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+
+            let v = client
+                .bit_set(key, offset, bit)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(v.to_string().into())
+        }
+        CommandId::Clamp => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let min = numeric_arg(&req, 1)?;
+            let max = numeric_arg(&req, 2)?;
+
+            let v = client.clamp(key, min, max).await.map_err(backend_err)?;
+
+            Ok(print::value(v).into())
+        }
+        CommandId::ClientKill => {
+            let id = req
+                .typed_arg::<i64>(0)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+
+            // Session IDs are never negative or too large, so such IDs can't
+            // match a session.
+            let killed = match u32::try_from(id) {
+                Ok(id) => client.client_kill(id).await.map_err(backend_err)?,
+                Err(_) => false,
+            };
+
+            if killed {
+                Ok("Killed.".into())
+            } else {
+                Ok("No such session.".into())
+            }
+        }
+        CommandId::ClientList => {
+            let sessions = client.client_list().await.map_err(backend_err)?;
+
+            if sessions.is_empty() {
+                return Ok("No sessions.".into());
+            }
+
+            Ok(sessions
+                .iter()
+                .map(|session| {
+                    format!(
+                        "id={} peer={} connected_at={} idle={} subscriptions={}",
+                        session.id(),
+                        session.peer().unwrap_or("-"),
+                        session.connected_at(),
+                        session.idle(),
+                        session.subscriptions(),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+                .into())
+        }
+        CommandId::ConfigGet => {
+            let names = req.args(..).into_iter().flatten();
+            let config = client.config_get(names).await.map_err(backend_err)?;
+            let mut parameters = config.into_iter().collect::<Vec<_>>();
+            parameters.sort();
+
+            Ok(print::map(parameters).into())
+        }
+        CommandId::ConfigSet => {
+            let name = req.arg(0).ok_or(InnerProcessError::TooFewArguments)?;
+            let value = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+
+            Ok(client
+                .config_set(name, value)
+                .await
+                .map_err(backend_err)?
+                .into())
+        }
+        CommandId::Copy => {
+            let from = req.key().ok_or(InnerProcessError::KeySourceRequired)?;
+            let to = req
+                .arg(1)
+                .ok_or(InnerProcessError::KeyDestinationRequired)?;
+            let copy = client.copy(from, to);
+
+            let v = if req.typed_arg::<bool>(2).unwrap_or(false) {
+                copy.replace().await
+            } else {
+                copy.await
+            }
+            .map_err(backend_err)?;
+
+            Ok(String::from_utf8_lossy(&v).into_owned().into())
+        }
         CommandId::Decrement => {
             let key = req.key().ok_or_else(|| InnerProcessError::KeyUnspecified)?;
 
@@ -222,6 +486,95 @@ where
 
             Ok(String::from_utf8_lossy(&v).into_owned().into())
         }
+        CommandId::Divide
+        | CommandId::Modulo
+        | CommandId::Multiply
+        | CommandId::SetIfGreater
+        | CommandId::SetIfLess => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let value = numeric_arg(&req, 1)?;
+
+            let v = match req.command_id() {
+                CommandId::Divide => client.divide(key, value).await,
+                CommandId::Modulo => client.modulo(key, value).await,
+                CommandId::Multiply => client.multiply(key, value).await,
+                CommandId::SetIfGreater => client.set_if_greater(key, value).await,
+                _ => client.set_if_less(key, value).await,
+            }
+            .map_err(backend_err)?;
+
+            Ok(print::value(v).into())
+        }
+        CommandId::DocumentAppend => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let path = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+            let values = req
+                .args(2..)
+                .ok_or(InnerProcessError::TooFewArguments)?
+                .map(|value| Document::from_bytes(value).ok_or(InnerProcessError::DocumentInvalid))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let len = client
+                .document_append(key, path, values)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(len.to_string().into())
+        }
+        CommandId::DocumentDelete => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let path = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+
+            let removed = client
+                .document_delete(key, path)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(removed.to_string().into())
+        }
+        CommandId::DocumentGet => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let path = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+
+            let document = client.document_get(key, path).await.map_err(backend_err)?;
+
+            Ok(document.to_string().into())
+        }
+        CommandId::DocumentIncrement => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let path = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+            let amount = req
+                .typed_arg::<Document>(2)
+                .ok_or(InnerProcessError::DocumentInvalid)?;
+
+            let number = client
+                .document_increment(key, path, amount)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(number.to_string().into())
+        }
+        CommandId::DocumentSet => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let path = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+            let value = req
+                .typed_arg::<Document>(2)
+                .ok_or(InnerProcessError::DocumentInvalid)?;
+
+            let document = client
+                .document_set(key, path, value)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(document.to_string().into())
+        }
+        CommandId::Dump => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+
+            let v = client.dump(key).await.map_err(backend_err)?;
+
+            Ok(print::hex(&v).into())
+        }
         CommandId::Echo => {
             if let Some(req_args) = req.args(..) {
                 let req_args = req_args.collect::<Vec<_>>().join(b" ".as_ref());
@@ -238,6 +591,18 @@ where
                 Ok("".into())
             }
         }
+        CommandId::EndsWith => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let suffix = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+
+            let v = match req.key_type() {
+                Some(KeyType::String) => client.ends_with(key, suffix).str().await,
+                _ => client.ends_with(key, suffix).bytes().await,
+            }
+            .map_err(backend_err)?;
+
+            Ok(v.to_string().into())
+        }
         CommandId::Exists => {
             let args = req
                 .args(..)
@@ -257,6 +622,40 @@ where
 
             Ok(exists.to_string().into())
         }
+        CommandId::Flush | CommandId::FlushAll => {
+            let background = req.typed_arg::<bool>(0).unwrap_or(false);
+            let removed = if req.command_id() == CommandId::Flush {
+                let flush = client.flush();
+
+                if background {
+                    flush.background().await
+                } else {
+                    flush.await
+                }
+            } else {
+                let flush = client.flush_all();
+
+                if background {
+                    flush.background().await
+                } else {
+                    flush.await
+                }
+            };
+
+            Ok(print::value(Value::Integer(removed.map_err(backend_err)?)).into())
+        }
+        CommandId::FlushPrefix => {
+            let prefix = req.arg(0).ok_or(InnerProcessError::TooFewArguments)?;
+            let flush = client.flush_prefix(prefix);
+
+            let removed = if req.typed_arg::<bool>(1).unwrap_or(false) {
+                flush.background().await
+            } else {
+                flush.await
+            };
+
+            Ok(print::value(Value::Integer(removed.map_err(backend_err)?)).into())
+        }
         CommandId::Get => {
             let key = req.key().ok_or_else(|| InnerProcessError::KeyUnspecified)?;
 
@@ -264,6 +663,47 @@ where
 
             Ok(print::value(value).into())
         }
+        CommandId::HyperLogLogAdd => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let items = req
+                .args(1..)
+                .ok_or(InnerProcessError::TooFewArguments)?
+                .collect::<Vec<_>>();
+
+            let changed = client
+                .hyper_log_log_add(key, items)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(changed.to_string().into())
+        }
+        CommandId::HyperLogLogCount => {
+            let keys = req
+                .args(..)
+                .ok_or(InnerProcessError::KeyUnspecified)?
+                .collect::<Vec<_>>();
+
+            let count = client
+                .hyper_log_log_count(keys)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(count.to_string().into())
+        }
+        CommandId::HyperLogLogMerge => {
+            let destination = req.key().ok_or(InnerProcessError::KeyDestinationRequired)?;
+            let sources = req
+                .args(1..)
+                .ok_or(InnerProcessError::KeySourceRequired)?
+                .collect::<Vec<_>>();
+
+            let count = client
+                .hyper_log_log_merge(destination, sources)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(count.to_string().into())
+        }
         CommandId::Increment => {
             let key = req.key().ok_or_else(|| InnerProcessError::KeyUnspecified)?;
 
@@ -291,6 +731,31 @@ where
 
             Ok(print_list(v).into())
         }
+        CommandId::Lowercase => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+
+            Ok(client.lowercase(key).await.map_err(backend_err)?.into())
+        }
+        CommandId::MemoryUsage => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let bytes = client.memory_usage(key).await.map_err(backend_err)?;
+
+            Ok(format!("{} bytes", bytes).into())
+        }
+        CommandId::ObjectInfo => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let info = client.object_info(key).await.map_err(backend_err)?;
+
+            let mut output = String::new();
+            writeln!(output, "Type: {}", print::key_type_name(info.key_type()))?;
+            writeln!(output, "Memory usage: {} bytes", info.memory_usage())?;
+            writeln!(output, "Created at: {}", info.created_at())?;
+            writeln!(output, "Last accessed at: {}", info.accessed_at())?;
+            writeln!(output, "Idle: {} ms", info.idle())?;
+            write!(output, "Accesses: {}", info.frequency())?;
+
+            Ok(output.into())
+        }
         CommandId::Rename => {
             let from = req
                 .key()
@@ -299,10 +764,149 @@ where
                 .arg(1)
                 .ok_or_else(|| InnerProcessError::KeySourceRequired)?;
 
-            let v = client.rename(from, to).await.map_err(backend_err)?;
+            let rename = client.rename(from, to);
+
+            let v = if req.typed_arg::<bool>(2).unwrap_or(false) {
+                rename.replace().await
+            } else {
+                rename.await
+            }
+            .map_err(backend_err)?;
+
+            Ok(String::from_utf8_lossy(&v).into_owned().into())
+        }
+        CommandId::Restore => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let dump = req
+                .arg(1)
+                .ok_or(InnerProcessError::TooFewArguments)
+                .and_then(hex_arg)?;
+
+            let restore = client.restore(key, dump);
+
+            let v = if req.typed_arg::<bool>(2).unwrap_or(false) {
+                restore.replace().await
+            } else {
+                restore.await
+            }
+            .map_err(backend_err)?;
 
             Ok(String::from_utf8_lossy(&v).into_owned().into())
         }
+        CommandId::Scan => {
+            let mut scan = client.scan();
+
+            if let Some(key_type) = req.key_type() {
+                scan = scan.key_type(key_type);
+            }
+
+            if let Some(pattern) = req.arg(0) {
+                scan = scan.pattern(pattern);
+            }
+
+            if let Some(count) = req.typed_arg::<i64>(1) {
+                scan = scan.count(count);
+            }
+
+            let mut keys = Vec::new();
+
+            while let Some(key) = future::poll_fn(|cx| Pin::new(&mut scan).poll_next(cx)).await {
+                keys.push(key.map_err(backend_err)?);
+            }
+
+            // Keys are scanned in no particular order.
+            keys.sort();
+
+            Ok(print_list(keys).into())
+        }
+        CommandId::Select => {
+            let namespace = req
+                .typed_arg::<i64>(0)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+
+            let namespace = client.select(namespace).await.map_err(backend_err)?;
+
+            Ok(format!("Selected namespace {}", namespace).into())
+        }
+        CommandId::SetRange => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let offset = req
+                .typed_arg::<i64>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+            let value = req.arg(2).ok_or(InnerProcessError::TooFewArguments)?;
+
+            let value = match req.key_type() {
+                Some(KeyType::String) => Value::String(String::from_utf8_lossy(value).into_owned()),
+                _ => Value::Bytes(value.to_vec()),
+            };
+
+            let v = client
+                .set_range(key, offset, value)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(print::value(v).into())
+        }
+        CommandId::Slice | CommandId::Substring => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let start = req
+                .typed_arg::<i64>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+            let end = req.typed_arg::<i64>(2);
+            // Commands without a key type are parsed as bytes, so only send the
+            // type for strings and otherwise let the server use the key's, so
+            // that both work on string keys without the `:str` suffix.
+            let is_str = req.key_type() == Some(KeyType::String);
+
+            let v = if req.command_id() == CommandId::Slice {
+                let mut slice = client.slice(key, start);
+
+                if let Some(end) = end {
+                    slice = slice.end(end);
+                }
+
+                if is_str {
+                    slice.str().await
+                } else {
+                    slice.await
+                }
+            } else {
+                let mut substring = client.substring(key, start);
+
+                if let Some(end) = end {
+                    substring = substring.end(end);
+                }
+
+                if is_str {
+                    substring.str().await
+                } else {
+                    substring.await
+                }
+            }
+            .map_err(backend_err)?;
+
+            Ok(print::value(v).into())
+        }
+        CommandId::Split => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let separator = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+
+            let v = client.split(key, separator).await.map_err(backend_err)?;
+
+            Ok(print_list(v).into())
+        }
+        CommandId::StartsWith => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let prefix = req.arg(1).ok_or(InnerProcessError::TooFewArguments)?;
+
+            let v = match req.key_type() {
+                Some(KeyType::String) => client.starts_with(key, prefix).str().await,
+                _ => client.starts_with(key, prefix).bytes().await,
+            }
+            .map_err(backend_err)?;
+
+            Ok(v.to_string().into())
+        }
         CommandId::Stats => {
             let stats = client.stats().await.map_err(backend_err)?;
 
@@ -317,11 +921,106 @@ where
                 .map_err(|source| InnerProcessError::WritingOutput { source })?;
             writeln!(output, "Sessions started: {}", stats.sessions_started())
                 .map_err(|source| InnerProcessError::WritingOutput { source })?;
-            write!(output, "Sessions ended: {}", stats.sessions_ended())
+            writeln!(output, "Sessions ended: {}", stats.sessions_ended())
+                .map_err(|source| InnerProcessError::WritingOutput { source })?;
+            writeln!(output, "Sessions timed out: {}", stats.sessions_timed_out())
                 .map_err(|source| InnerProcessError::WritingOutput { source })?;
+            writeln!(output, "Memory used: {} bytes", stats.memory_used())
+                .map_err(|source| InnerProcessError::WritingOutput { source })?;
+            write!(output, "Keys evicted: {}", stats.keys_evicted())
+                .map_err(|source| InnerProcessError::WritingOutput { source })?;
+
+            for (namespace, keys) in stats.namespaces_keys() {
+                write!(output, "\nKeys in namespace {}: {}", namespace, keys)
+                    .map_err(|source| InnerProcessError::WritingOutput { source })?;
+            }
 
             Ok(output.into())
         }
+        CommandId::StreamAppend => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let args = req
+                .args(2..)
+                .ok_or(InnerProcessError::TooFewArguments)?
+                .collect::<Vec<_>>();
+
+            if !args.len().is_multiple_of(2) {
+                return Err(InnerProcessError::TooFewArguments);
+            }
+
+            let fields = args.chunks(2).map(|pair| (pair[0], pair[1]));
+            let mut append = client.stream_append(key, fields);
+
+            if req.arg(1) != Some(b"*") {
+                let id = req
+                    .typed_arg::<StreamId>(1)
+                    .ok_or(InnerProcessError::TooFewArguments)?;
+                append = append.id(id);
+            }
+
+            Ok(append.await.map_err(backend_err)?.to_string().into())
+        }
+        CommandId::StreamRange => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let mut range = client.stream_range(key);
+
+            if let Some(start) = req.typed_arg::<StreamId>(1) {
+                range = range.start(start);
+            }
+
+            if let Some(end) = req.typed_arg::<StreamId>(2) {
+                range = range.end(end);
+            }
+
+            if let Some(count) = req.typed_arg::<i64>(3) {
+                range = range.count(count);
+            }
+
+            Ok(print::value(range.await.map_err(backend_err)?.into()).into())
+        }
+        CommandId::StreamRead => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let after = req
+                .typed_arg::<StreamId>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+            let mut read = client.stream_read(key, after);
+
+            if let Some(count) = req.typed_arg::<i64>(2) {
+                read = read.count(count);
+            }
+
+            if let Some(block) = req.typed_arg::<i64>(3) {
+                read = read.block(Duration::from_millis(block.max(0) as u64));
+            }
+
+            Ok(print::value(read.await.map_err(backend_err)?.into()).into())
+        }
+        CommandId::StreamTrim => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let max_length = req
+                .typed_arg::<i64>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+
+            let removed = client
+                .stream_trim(key, max_length)
+                .await
+                .map_err(backend_err)?;
+
+            Ok(removed.to_string().into())
+        }
+        CommandId::StreamTrimAge => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+            let max_age = req
+                .typed_arg::<i64>(1)
+                .ok_or(InnerProcessError::TooFewArguments)?;
+
+            let removed = client
+                .stream_trim_age(key, Duration::from_millis(max_age.max(0) as u64))
+                .await
+                .map_err(backend_err)?;
+
+            Ok(removed.to_string().into())
+        }
         CommandId::Type => {
             let key = req.key().ok_or_else(|| InnerProcessError::KeyUnspecified)?;
 
@@ -329,13 +1028,78 @@ where
 
             Ok(print::key_type_name(key_type).into())
         }
+        CommandId::Uppercase => {
+            let key = req.key().ok_or(InnerProcessError::KeyUnspecified)?;
+
+            Ok(client.uppercase(key).await.map_err(backend_err)?.into())
+        }
         _ => panic!(),
     }
 }
 
+/// Decode an argument made of hexadecimal digits, such as a dump printed by the
+/// dump command.
+fn hex_arg<B: Backend>(arg: &[u8]) -> Result<Vec<u8>, InnerProcessError<B>> {
+    if !arg.len().is_multiple_of(2) {
+        return Err(InnerProcessError::DumpInvalid);
+    }
+
+    arg.chunks(2)
+        .map(|pair| {
+            str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or(InnerProcessError::DumpInvalid)
+        })
+        .collect()
+}
+
+/// Retrieve a numeric argument as a float if the key type of the request is a
+/// float, or an integer otherwise.
+fn numeric_arg<B: Backend>(req: &Request<'_>, idx: usize) -> Result<Value, InnerProcessError<B>> {
+    let value = if req.key_type() == Some(KeyType::Float) {
+        req.typed_arg::<f64>(idx).map(Value::Float)
+    } else {
+        req.typed_arg::<i64>(idx).map(Value::Integer)
+    };
+
+    value.ok_or(InnerProcessError::TooFewArguments)
+}
+
 fn print_list(list: Vec<Vec<u8>>) -> String {
     list.into_iter()
         .map(|item| String::from_utf8_lossy(&item).into_owned())
         .collect::<Vec<_>>()
         .join("\n")
 }
+
+#[cfg(test)]
+mod tests {
+    use hop::Client;
+
+    #[tokio::test]
+    async fn test_slice() {
+        let client = Client::memory();
+        client.set("foo").string("hopdb").await.unwrap();
+        client.set("bar").bytes(b"hopdb".as_ref()).await.unwrap();
+
+        let process = |input| super::process(&client, input);
+        assert_eq!("op", process("slice foo 1 3").await.unwrap());
+        assert_eq!("op", process("slice:str foo 1 3").await.unwrap());
+        assert_eq!("db", process("slice bar -2").await.unwrap());
+        assert_eq!("db", process("slice:bytes bar -2").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_substring() {
+        let client = Client::memory();
+        client.set("foo").string("h🤠pdb").await.unwrap();
+        client.set("bar").bytes(b"hopdb".as_ref()).await.unwrap();
+
+        let process = |input| super::process(&client, input);
+        assert_eq!("🤠p", process("substring foo 1 3").await.unwrap());
+        assert_eq!("🤠p", process("substring:str foo 1 3").await.unwrap());
+        assert_eq!("op", process("substring bar 1 3").await.unwrap());
+        assert_eq!("op", process("substring:bytes bar 1 3").await.unwrap());
+    }
+}
@@ -1,6 +1,10 @@
 use super::Backend;
-use crate::model::StatsData;
-use alloc::{boxed::Box, vec::Vec};
+use crate::model::{ObjectInfoData, SessionData, StatsData};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 use async_trait::async_trait;
 use core::{
     convert::TryInto,
@@ -12,7 +16,9 @@ use hop_engine::{
         response::{Context, Instruction, Response},
         CommandId, DispatchError, Request,
     },
-    state::{KeyType, Value},
+    dashmap::DashMap,
+    hop::Builder,
+    state::{Document, KeyType, Stream, StreamId, Value},
     Hop,
 };
 
@@ -51,7 +57,7 @@ impl Display for Error {
     }
 }
 
-#[cfg(all(not(no_std), feature = "std"))]
+#[cfg(feature = "std")]
 mod if_std {
     use super::Error;
     use std::error::Error as StdError;
@@ -82,7 +88,7 @@ impl From<RequestBuilderError> for Error {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct MemoryBackend {
     hop: Hop,
 }
@@ -92,6 +98,27 @@ impl MemoryBackend {
         Default::default()
     }
 
+    /// Create a backend using an engine configured by a builder.
+    ///
+    /// Unlike the default backend, the engine uses the builder's clock as-is.
+    pub fn with_builder(builder: Builder) -> Self {
+        Self {
+            hop: builder.build(),
+        }
+    }
+
+    /// Retrieve the current Unix timestamp in milliseconds for generating
+    /// stream entry IDs.
+    #[cfg(feature = "std")]
+    fn clock() -> u64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis().try_into().unwrap_or(u64::MAX))
+            .unwrap_or(0)
+    }
+
     fn send<'a>(&self, req: impl Into<Request<'a>>) -> Result<Value, Error> {
         let mut resp = Vec::new();
 
@@ -112,10 +139,58 @@ impl MemoryBackend {
     }
 }
 
+impl Default for MemoryBackend {
+    fn default() -> Self {
+        #[allow(unused_mut)]
+        let mut builder = Builder::new();
+
+        #[cfg(feature = "std")]
+        builder.clock(Self::clock);
+
+        Self::with_builder(builder)
+    }
+}
+
 #[async_trait]
 impl Backend for MemoryBackend {
     type Error = Error;
 
+    async fn acl_delete(&self, name: &[u8]) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::AclDelete);
+        builder.bytes(name)?;
+
+        match self.send(builder)? {
+            Value::Boolean(deleted) => Ok(deleted),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn acl_list(&self) -> Result<Vec<String>, Self::Error> {
+        let builder = RequestBuilder::new(CommandId::AclList);
+
+        match self.send(builder)? {
+            Value::List(users) => Ok(users
+                .into_iter()
+                .map(|user| String::from_utf8(user).expect("users are valid utf-8"))
+                .collect()),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn acl_set(&self, name: &[u8], rules: Vec<Vec<u8>>) -> Result<String, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::AclSet);
+        builder.bytes(name)?;
+
+        for rule in rules {
+            builder.bytes(rule)?;
+        }
+
+        match self.send(builder)? {
+            Value::String(rules) => Ok(rules),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
     async fn append<T: Into<Value> + Send>(
         &self,
         key: &[u8],
@@ -145,44 +220,136 @@ impl Backend for MemoryBackend {
         self.send(builder)
     }
 
-    async fn decrement_by<T: Into<Value> + Send>(
+    async fn auth(&self, username: Option<&[u8]>, password: &[u8]) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Auth);
+
+        if let Some(username) = username {
+            builder.bytes(username)?;
+        }
+
+        builder.bytes(password)?;
+
+        match self.send(builder)? {
+            Value::Boolean(authenticated) => Ok(authenticated),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn bit_and(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitAnd);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(bytes),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn bit_count(
         &self,
         key: &[u8],
-        value: T,
-    ) -> Result<Value, Self::Error> {
-        let value = value.into();
-        let key_type = value.kind();
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitCount);
+        builder.bytes(key)?;
 
-        let mut builder = RequestBuilder::new_with_key_type(CommandId::DecrementBy, key_type);
+        if start.is_some() || end.is_some() {
+            builder.value(Value::Integer(start.unwrap_or(0)))?;
+        }
+
+        if let Some(end) = end {
+            builder.value(Value::Integer(end))?;
+        }
+
+        match self.send(builder)? {
+            Value::Integer(count) => Ok(count),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn bit_get(&self, key: &[u8], offset: i64) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitGet);
         builder.bytes(key)?;
+        builder.value(Value::Integer(offset))?;
 
-        if key_type != KeyType::Float && key_type != KeyType::Integer {
-            return Err(Error::KeyTypeUnsupported { key_type, value });
+        match self.send(builder)? {
+            Value::Boolean(bit) => Ok(bit),
+            other => panic!("Other response: {:?}", other),
         }
+    }
 
-        builder.value(value)?;
+    async fn bit_not(&self, destination: &[u8], source: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitNot);
+        builder.bytes(destination)?;
+        builder.bytes(source)?;
 
         match self.send(builder)? {
-            Value::Float(float) => Ok(Value::Float(float)),
-            Value::Integer(int) => Ok(Value::Integer(int)),
+            Value::Bytes(bytes) => Ok(bytes),
             other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn decrement(&self, key: &[u8], key_type: Option<KeyType>) -> Result<Value, Self::Error> {
-        let mut builder = RequestBuilder::new_with_key_type(CommandId::Decrement, key_type);
+    async fn bit_or(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitOr);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(bytes),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn bit_position(&self, key: &[u8], bit: bool) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitPosition);
         builder.bytes(key)?;
+        builder.value(Value::Boolean(bit))?;
 
         match self.send(builder)? {
-            Value::Float(float) => Ok(Value::Float(float)),
-            Value::Integer(int) => Ok(Value::Integer(int)),
+            Value::Integer(position) => Ok(position),
             other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn delete(&self, key: &[u8]) -> Result<Vec<u8>, Self::Error> {
-        let mut builder = RequestBuilder::new(CommandId::Delete);
+    async fn bit_set(&self, key: &[u8], offset: i64, bit: bool) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitSet);
         builder.bytes(key)?;
+        builder.value(Value::Integer(offset))?;
+        builder.value(Value::Boolean(bit))?;
+
+        match self.send(builder)? {
+            Value::Boolean(previous) => Ok(previous),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn bit_xor(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::BitXor);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
 
         match self.send(builder)? {
             Value::Bytes(bytes) => Ok(bytes),
@@ -190,40 +357,98 @@ impl Backend for MemoryBackend {
         }
     }
 
-    async fn echo(&self, content: &[u8]) -> Result<Vec<Vec<u8>>, Self::Error> {
-        let mut builder = RequestBuilder::new(CommandId::Echo);
-        builder.bytes(content)?;
+    async fn clamp<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        min: T,
+        max: T,
+    ) -> Result<Value, Self::Error> {
+        let min = min.into();
+        let max = max.into();
+        let key_type = min.kind();
+
+        if max.kind() != key_type || (key_type != KeyType::Float && key_type != KeyType::Integer) {
+            let value = max;
+
+            return Err(Error::KeyTypeUnsupported { key_type, value });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Clamp, key_type);
+        builder.bytes(key)?;
+        builder.value(min)?;
+        builder.value(max)?;
 
         match self.send(builder)? {
-            Value::List(list) => Ok(list),
-            _ => panic!(),
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn exists<T: IntoIterator<Item = U> + Send, U: AsRef<[u8]> + Send>(
+    async fn client_kill(&self, id: u32) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::ClientKill);
+        builder.value(Value::Integer(id.into()))?;
+
+        match self.send(builder)? {
+            Value::Boolean(killed) => Ok(killed),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn client_list(&self) -> Result<Vec<SessionData>, Self::Error> {
+        let builder = RequestBuilder::new(CommandId::ClientList);
+
+        let fields = match self.send(builder)? {
+            Value::List(fields) => fields,
+            other => panic!("Other response: {:?}", other),
+        };
+
+        Ok(SessionData::from_fields(&fields).expect("valid sessions"))
+    }
+
+    async fn config_get(
         &self,
-        keys: T,
-    ) -> Result<bool, Self::Error> {
-        let mut builder = RequestBuilder::new(CommandId::Exists);
+        names: Vec<Vec<u8>>,
+    ) -> Result<DashMap<Vec<u8>, Vec<u8>>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::ConfigGet);
 
-        for key in keys {
-            builder.bytes(key.as_ref())?;
+        for name in names {
+            builder.bytes(name)?;
         }
 
         match self.send(builder)? {
-            Value::Boolean(exists) => Ok(exists),
-            _ => panic!(),
+            Value::Map(parameters) => Ok(parameters),
+            other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn get(&self, key: &[u8]) -> Result<Value, Self::Error> {
-        let mut builder = RequestBuilder::new(CommandId::Get);
-        builder.bytes(key)?;
+    async fn config_set(&self, name: &[u8], value: &[u8]) -> Result<String, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::ConfigSet);
+        builder.bytes(name)?;
+        builder.bytes(value)?;
 
-        self.send(builder)
+        match self.send(builder)? {
+            Value::String(value) => Ok(value),
+            other => panic!("Other response: {:?}", other),
+        }
     }
 
-    async fn increment_by<T: Into<Value> + Send>(
+    async fn copy(&self, from: &[u8], to: &[u8], replace: bool) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Copy);
+        builder.bytes(from)?;
+        builder.bytes(to)?;
+
+        if replace {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(bytes),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn decrement_by<T: Into<Value> + Send>(
         &self,
         key: &[u8],
         value: T,
@@ -231,7 +456,7 @@ impl Backend for MemoryBackend {
         let value = value.into();
         let key_type = value.kind();
 
-        let mut builder = RequestBuilder::new_with_key_type(CommandId::IncrementBy, key_type);
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::DecrementBy, key_type);
         builder.bytes(key)?;
 
         if key_type != KeyType::Float && key_type != KeyType::Integer {
@@ -247,110 +472,802 @@ impl Backend for MemoryBackend {
         }
     }
 
-    async fn increment(&self, key: &[u8], key_type: Option<KeyType>) -> Result<Value, Self::Error> {
-        let mut builder = RequestBuilder::new_with_key_type(CommandId::Increment, key_type);
+    async fn decrement(&self, key: &[u8], key_type: Option<KeyType>) -> Result<Value, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Decrement, key_type);
         builder.bytes(key)?;
 
         match self.send(builder)? {
             Value::Float(float) => Ok(Value::Float(float)),
             Value::Integer(int) => Ok(Value::Integer(int)),
-            _ => panic!(),
+            other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn is<T: IntoIterator<Item = U> + Send, U: AsRef<[u8]> + Send>(
+    async fn delete(&self, key: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Delete);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(bytes),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn divide<T: Into<Value> + Send>(
         &self,
-        key_type: KeyType,
-        keys: T,
-    ) -> Result<bool, Self::Error> {
-        let mut builder = RequestBuilder::new_with_key_type(CommandId::Is, key_type);
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
 
-        for key in keys {
-            builder.bytes(key.as_ref())?;
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type, value });
         }
 
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Divide, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
         match self.send(builder)? {
-            Value::Boolean(exists) => Ok(exists),
-            _ => panic!(),
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn key_type(&self, key: &[u8]) -> Result<KeyType, Self::Error> {
-        let mut builder = RequestBuilder::new(CommandId::Type);
+    async fn document_append(
+        &self,
+        key: &[u8],
+        path: &[u8],
+        values: Vec<Document>,
+    ) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentAppend);
         builder.bytes(key)?;
+        builder.bytes(path)?;
 
-        match self.send(builder)? {
-            Value::Integer(int) => {
-                let number = int as u8;
+        for value in values {
+            builder.value(value)?;
+        }
 
-                number
-                    .try_into()
-                    .map_err(|_| Error::KeyTypeInvalid { number })
-            }
-            _ => panic!(),
+        match self.send(builder)? {
+            Value::Integer(len) => Ok(len),
+            other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn keys(&self, key: &[u8]) -> Result<Vec<Vec<u8>>, Self::Error> {
-        let mut builder = RequestBuilder::new(CommandId::Keys);
+    async fn document_delete(&self, key: &[u8], path: &[u8]) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentDelete);
         builder.bytes(key)?;
+        builder.bytes(path)?;
 
         match self.send(builder)? {
-            Value::List(list) => Ok(list),
-            _ => panic!(),
+            Value::Integer(removed) => Ok(removed),
+            other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn length(&self, key: &[u8], key_type: Option<KeyType>) -> Result<i64, Self::Error> {
-        let mut builder = RequestBuilder::new_with_key_type(CommandId::Length, key_type);
+    async fn document_get(&self, key: &[u8], path: &[u8]) -> Result<Document, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentGet);
         builder.bytes(key)?;
+        builder.bytes(path)?;
 
         match self.send(builder)? {
-            Value::Integer(int) => Ok(int),
+            Value::Document(document) => Ok(document),
             other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn rename(&self, from: &[u8], to: &[u8]) -> Result<Vec<u8>, Self::Error> {
-        let mut builder = RequestBuilder::new(CommandId::Rename);
-        builder.bytes(from)?;
-        builder.bytes(to)?;
+    async fn document_increment(
+        &self,
+        key: &[u8],
+        path: &[u8],
+        amount: Document,
+    ) -> Result<Document, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentIncrement);
+        builder.bytes(key)?;
+        builder.bytes(path)?;
+        builder.value(amount)?;
 
         match self.send(builder)? {
-            Value::Bytes(bytes) => Ok(bytes),
-            _ => panic!(),
+            Value::Float(float) => Ok(Document::Float(float)),
+            Value::Integer(int) => Ok(Document::Integer(int)),
+            other => panic!("Other response: {:?}", other),
         }
     }
 
-    async fn set<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value, Self::Error> {
-        let value = value.into();
-        let key_type = value.kind();
-
-        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, key_type);
+    async fn document_set(
+        &self,
+        key: &[u8],
+        path: &[u8],
+        value: Document,
+    ) -> Result<Document, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentSet);
         builder.bytes(key)?;
+        builder.bytes(path)?;
         builder.value(value)?;
 
-        self.send(builder)
+        match self.send(builder)? {
+            Value::Document(document) => Ok(document),
+            other => panic!("Other response: {:?}", other),
+        }
     }
 
-    async fn stats(&self) -> Result<StatsData, Self::Error> {
-        let builder = RequestBuilder::new(CommandId::Stats);
-
-        let stats = match self.send(builder)? {
-            Value::Map(stats) => stats,
-            _ => panic!(),
-        };
+    async fn dump(&self, key: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Dump);
+        builder.bytes(key)?;
 
-        Ok(StatsData::new(stats.into_iter().collect()))
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(bytes),
+            other => panic!("Other response: {:?}", other),
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
+    async fn echo(&self, content: &[u8]) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Echo);
+        builder.bytes(content)?;
+
+        match self.send(builder)? {
+            Value::List(list) => Ok(list),
+            _ => panic!(),
+        }
+    }
+
+    async fn ends_with(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        suffix: &[u8],
+    ) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::EndsWith, key_type);
+        builder.bytes(key)?;
+        builder.bytes(suffix)?;
+
+        match self.send(builder)? {
+            Value::Boolean(matches) => Ok(matches),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn exists<T: IntoIterator<Item = U> + Send, U: AsRef<[u8]> + Send>(
+        &self,
+        keys: T,
+    ) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Exists);
+
+        for key in keys {
+            builder.bytes(key.as_ref())?;
+        }
+
+        match self.send(builder)? {
+            Value::Boolean(exists) => Ok(exists),
+            _ => panic!(),
+        }
+    }
+
+    async fn flush(&self, background: bool) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Flush);
+
+        if background {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send(builder)? {
+            Value::Integer(int) => Ok(int),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn flush_all(&self, background: bool) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::FlushAll);
+
+        if background {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send(builder)? {
+            Value::Integer(int) => Ok(int),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn flush_prefix(&self, prefix: &[u8], background: bool) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::FlushPrefix);
+        builder.bytes(prefix)?;
+
+        if background {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send(builder)? {
+            Value::Integer(int) => Ok(int),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn get(&self, key: &[u8]) -> Result<Value, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Get);
+        builder.bytes(key)?;
+
+        self.send(builder)
+    }
+
+    async fn hyper_log_log_add(
+        &self,
+        key: &[u8],
+        items: Vec<Vec<u8>>,
+    ) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogAdd);
+        builder.bytes(key)?;
+
+        for item in items {
+            builder.bytes(item)?;
+        }
+
+        match self.send(builder)? {
+            Value::Boolean(changed) => Ok(changed),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn hyper_log_log_count(&self, keys: Vec<Vec<u8>>) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogCount);
+
+        for key in keys {
+            builder.bytes(key)?;
+        }
+
+        match self.send(builder)? {
+            Value::Integer(count) => Ok(count),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn hyper_log_log_merge(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogMerge);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
+
+        match self.send(builder)? {
+            Value::Integer(count) => Ok(count),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn increment_by<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::IncrementBy, key_type);
+        builder.bytes(key)?;
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type, value });
+        }
+
+        builder.value(value)?;
+
+        match self.send(builder)? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn increment(&self, key: &[u8], key_type: Option<KeyType>) -> Result<Value, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Increment, key_type);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            _ => panic!(),
+        }
+    }
+
+    async fn is<T: IntoIterator<Item = U> + Send, U: AsRef<[u8]> + Send>(
+        &self,
+        key_type: KeyType,
+        keys: T,
+    ) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Is, key_type);
+
+        for key in keys {
+            builder.bytes(key.as_ref())?;
+        }
+
+        match self.send(builder)? {
+            Value::Boolean(exists) => Ok(exists),
+            _ => panic!(),
+        }
+    }
+
+    async fn key_type(&self, key: &[u8]) -> Result<KeyType, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Type);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::Integer(int) => {
+                let number = int as u8;
+
+                number
+                    .try_into()
+                    .map_err(|_| Error::KeyTypeInvalid { number })
+            }
+            _ => panic!(),
+        }
+    }
+
+    async fn keys(&self, key: &[u8]) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Keys);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::List(list) => Ok(list),
+            _ => panic!(),
+        }
+    }
+
+    async fn length(&self, key: &[u8], key_type: Option<KeyType>) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Length, key_type);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::Integer(int) => Ok(int),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn lowercase(&self, key: &[u8]) -> Result<String, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Lowercase);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::String(string) => Ok(string),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn memory_usage(&self, key: &[u8]) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::MemoryUsage);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::Integer(bytes) => Ok(bytes),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn modulo<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type, value });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Modulo, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send(builder)? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn multiply<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type, value });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Multiply, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send(builder)? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn object_info(&self, key: &[u8]) -> Result<ObjectInfoData, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::ObjectInfo);
+        builder.bytes(key)?;
+
+        let fields = match self.send(builder)? {
+            Value::List(fields) => fields,
+            other => panic!("Other response: {:?}", other),
+        };
+
+        Ok(ObjectInfoData::from_fields(&fields).expect("valid object info"))
+    }
+
+    async fn rename(&self, from: &[u8], to: &[u8], replace: bool) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Rename);
+        builder.bytes(from)?;
+        builder.bytes(to)?;
+
+        if replace {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => panic!(),
+        }
+    }
+
+    async fn restore(
+        &self,
+        key: &[u8],
+        dump: &[u8],
+        replace: bool,
+    ) -> Result<Vec<u8>, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Restore);
+        builder.bytes(key)?;
+        builder.bytes(dump)?;
+
+        if replace {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(bytes),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn scan(
+        &self,
+        cursor: &[u8],
+        pattern: Option<&[u8]>,
+        key_type: Option<KeyType>,
+        count: Option<i64>,
+    ) -> Result<(Vec<u8>, Vec<Vec<u8>>), Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Scan, key_type);
+        builder.bytes(cursor)?;
+
+        if pattern.is_some() || count.is_some() {
+            builder.bytes(pattern.unwrap_or(b"*"))?;
+        }
+
+        if let Some(count) = count {
+            builder.value(Value::Integer(count))?;
+        }
+
+        match self.send(builder)? {
+            Value::List(mut list) if !list.is_empty() => {
+                let cursor = list.remove(0);
+
+                Ok((cursor, list))
+            }
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn select(&self, namespace: i64) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Select);
+        builder.value(Value::Integer(namespace))?;
+
+        match self.send(builder)? {
+            Value::Integer(int) => Ok(int),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn set<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        self.send(builder)
+    }
+
+    async fn set_if_greater<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type, value });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::SetIfGreater, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send(builder)? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn set_if_less<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type, value });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::SetIfLess, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send(builder)? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn set_range<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        offset: i64,
+        value: T,
+    ) -> Result<Value, Self::Error> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::SetRange, key_type);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(offset))?;
+
+        match value {
+            Value::Bytes(bytes) => {
+                builder.bytes(bytes)?;
+            }
+            Value::String(string) => {
+                builder.bytes(string.into_bytes())?;
+            }
+            value => return Err(Error::KeyTypeUnsupported { key_type, value }),
+        }
+
+        self.send(builder)
+    }
+
+    async fn slice(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        start: i64,
+        end: Option<i64>,
+    ) -> Result<Value, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Slice, key_type);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(start))?;
+
+        if let Some(end) = end {
+            builder.value(Value::Integer(end))?;
+        }
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(Value::Bytes(bytes)),
+            Value::String(string) => Ok(Value::String(string)),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn split(&self, key: &[u8], separator: &[u8]) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Split, KeyType::String);
+        builder.bytes(key)?;
+        builder.bytes(separator)?;
+
+        match self.send(builder)? {
+            Value::List(list) => Ok(list),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn starts_with(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        prefix: &[u8],
+    ) -> Result<bool, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::StartsWith, key_type);
+        builder.bytes(key)?;
+        builder.bytes(prefix)?;
+
+        match self.send(builder)? {
+            Value::Boolean(matches) => Ok(matches),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn stats(&self) -> Result<StatsData, Self::Error> {
+        let builder = RequestBuilder::new(CommandId::Stats);
+
+        let stats = match self.send(builder)? {
+            Value::Map(stats) => stats,
+            _ => panic!(),
+        };
+
+        Ok(StatsData::new(stats.into_iter().collect()))
+    }
+
+    async fn stream_append(
+        &self,
+        key: &[u8],
+        id: Option<StreamId>,
+        fields: Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> Result<StreamId, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::StreamAppend);
+        builder.bytes(key)?;
+
+        match id {
+            Some(id) => builder.bytes(id.to_string())?,
+            None => builder.bytes(b"*".as_ref())?,
+        };
+
+        for (field, value) in fields {
+            builder.bytes(field)?;
+            builder.bytes(value)?;
+        }
+
+        match self.send(builder)? {
+            Value::String(id) => Ok(id
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid stream ID: {}", id))),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn stream_range(
+        &self,
+        key: &[u8],
+        start: Option<StreamId>,
+        end: Option<StreamId>,
+        count: Option<i64>,
+    ) -> Result<Stream, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::StreamRange);
+        builder.bytes(key)?;
+
+        match start {
+            Some(start) => builder.bytes(start.to_string())?,
+            None => builder.bytes(b"-".as_ref())?,
+        };
+
+        match end {
+            Some(end) => builder.bytes(end.to_string())?,
+            None => builder.bytes(b"+".as_ref())?,
+        };
+
+        if let Some(count) = count {
+            builder.value(Value::Integer(count))?;
+        }
+
+        match self.send(builder)? {
+            Value::Stream(stream) => Ok(stream),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn stream_read(
+        &self,
+        key: &[u8],
+        after: StreamId,
+        count: Option<i64>,
+        block: Option<u64>,
+    ) -> Result<Stream, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::StreamRead);
+        builder.bytes(key)?;
+        builder.bytes(after.to_string())?;
+
+        if count.is_some() || block.is_some() {
+            builder.value(Value::Integer(count.unwrap_or(i64::MAX)))?;
+        }
+
+        if let Some(block) = block {
+            builder.value(Value::Integer(block.try_into().unwrap_or(i64::MAX)))?;
+        }
+
+        match self.send(builder)? {
+            Value::Stream(stream) => Ok(stream),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn stream_trim(&self, key: &[u8], max_length: i64) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::StreamTrim);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(max_length))?;
+
+        match self.send(builder)? {
+            Value::Integer(removed) => Ok(removed),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn stream_trim_age(&self, key: &[u8], max_age: u64) -> Result<i64, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::StreamTrimAge);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(max_age.try_into().unwrap_or(i64::MAX)))?;
+
+        match self.send(builder)? {
+            Value::Integer(removed) => Ok(removed),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn substring(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        start: i64,
+        end: Option<i64>,
+    ) -> Result<Value, Self::Error> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Substring, key_type);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(start))?;
+
+        if let Some(end) = end {
+            builder.value(Value::Integer(end))?;
+        }
+
+        match self.send(builder)? {
+            Value::Bytes(bytes) => Ok(Value::Bytes(bytes)),
+            Value::String(string) => Ok(Value::String(string)),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+
+    async fn uppercase(&self, key: &[u8]) -> Result<String, Self::Error> {
+        let mut builder = RequestBuilder::new(CommandId::Uppercase);
+        builder.bytes(key)?;
+
+        match self.send(builder)? {
+            Value::String(string) => Ok(string),
+            other => panic!("Other response: {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
     use super::{Backend, Error, MemoryBackend};
     use hop_engine::{
+        command::DispatchError,
         dashmap::{DashMap, DashSet},
-        state::{KeyType, Value},
+        hop::{Builder, EvictionPolicy},
+        state::{Document, KeyType, StreamEntry, StreamId, Value},
     };
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
@@ -372,6 +1289,156 @@ mod tests {
             .is_ok());
     }
 
+    #[tokio::test]
+    async fn test_bit_operations() {
+        let backend = MemoryBackend::new();
+        assert!(backend.set(b"a", [0b1100u8].to_vec()).await.is_ok());
+        assert!(backend.set(b"b", [0b1010u8].to_vec()).await.is_ok());
+        let sources = vec![b"a".to_vec(), b"b".to_vec()];
+
+        assert_eq!(
+            vec![0b1000],
+            backend.bit_and(b"c", sources.clone()).await.unwrap()
+        );
+        assert_eq!(
+            vec![0b1110],
+            backend.bit_or(b"c", sources.clone()).await.unwrap()
+        );
+        assert_eq!(vec![0b0110], backend.bit_xor(b"c", sources).await.unwrap());
+        assert_eq!(
+            vec![0b1111_1001],
+            backend.bit_not(b"c", b"c").await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bit_set_get_count() {
+        let backend = MemoryBackend::new();
+        assert!(!backend.bit_set(b"foo", 12, true).await.unwrap());
+        assert!(backend.bit_get(b"foo", 12).await.unwrap());
+        assert_eq!(1, backend.bit_count(b"foo", None, None).await.unwrap());
+        assert_eq!(0, backend.bit_count(b"foo", None, Some(1)).await.unwrap());
+        assert_eq!(12, backend.bit_position(b"foo", true).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_checked_arithmetic() {
+        let backend = MemoryBackend::new();
+        assert!(backend.set(b"foo", i64::MAX).await.is_ok());
+        assert!(matches!(
+            backend.increment(b"foo", None).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::ArithmeticOverflow
+            })
+        ));
+        assert!(matches!(
+            backend.multiply(b"foo", 2).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::ArithmeticOverflow
+            })
+        ));
+        assert!(matches!(
+            backend.divide(b"foo", 0).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::DivisionByZero
+            })
+        ));
+        assert!(matches!(
+            backend.modulo(b"foo", 10).await,
+            Ok(Value::Integer(7))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_clamp_set_if() {
+        let backend = MemoryBackend::new();
+        assert!(matches!(
+            backend.set_if_less(b"foo", 2.5).await,
+            Ok(Value::Float(_))
+        ));
+        assert!(matches!(
+            backend.set_if_greater(b"foo", 7.5).await,
+            Ok(Value::Float(float)) if (float - 7.5).abs() < f64::EPSILON
+        ));
+        assert!(matches!(
+            backend.clamp(b"foo", 0.0, 5.0).await,
+            Ok(Value::Float(float)) if (float - 5.0).abs() < f64::EPSILON
+        ));
+        assert!(backend.clamp(b"foo", 0, 5).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_list_kill() {
+        let backend = MemoryBackend::new();
+        assert!(backend.client_list().await.unwrap().is_empty());
+        assert!(!backend.client_kill(0).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_config() {
+        let backend = MemoryBackend::new();
+        assert_eq!(
+            "1024",
+            backend
+                .config_set(b"max_value_size", b"1024")
+                .await
+                .unwrap()
+        );
+
+        let config = backend
+            .config_get(vec![b"max_value_size".to_vec()])
+            .await
+            .unwrap();
+        assert_eq!(1, config.len());
+        assert_eq!(
+            Some(b"1024".to_vec()),
+            config.get(b"max_value_size".as_ref()).map(|v| v.clone())
+        );
+        assert!(backend
+            .config_get(Vec::new())
+            .await
+            .unwrap()
+            .contains_key(b"eviction_policy".as_ref()));
+
+        assert!(matches!(
+            backend.config_set(b"namespaces", b"4").await,
+            Err(Error::RunningCommand {
+                source: DispatchError::ConfigInvalid
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_copy_rename_replace() {
+        let backend = MemoryBackend::new();
+        let set = DashSet::new();
+        set.insert(b"a".to_vec());
+        backend.set(b"foo", set).await.unwrap();
+        backend.set(b"bar", 1).await.unwrap();
+
+        assert!(matches!(
+            backend.copy(b"foo", b"bar", false).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::PreconditionFailed
+            })
+        ));
+        assert_eq!(
+            b"bar".to_vec(),
+            backend.copy(b"foo", b"bar", true).await.unwrap()
+        );
+        assert_eq!(1, backend.length(b"bar", None).await.unwrap());
+        assert!(backend.exists([b"foo"]).await.unwrap());
+
+        backend.set(b"baz", 2).await.unwrap();
+        assert!(backend.rename(b"baz", b"bar", false).await.is_err());
+        assert_eq!(
+            b"bar".to_vec(),
+            backend.rename(b"baz", b"bar", true).await.unwrap()
+        );
+        assert!(!backend.exists([b"baz"]).await.unwrap());
+        assert!(matches!(backend.get(b"bar").await, Ok(Value::Integer(2))));
+    }
+
     #[tokio::test]
     async fn test_decrement() {
         let backend = MemoryBackend::new();
@@ -382,19 +1449,159 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_document() {
+        let backend = MemoryBackend::new();
+        let document = r#"{"a":{"b":[1]},"c":1.5}"#.parse::<Document>().unwrap();
+        assert_eq!(
+            document,
+            backend
+                .document_set(b"foo", b"$", document.clone())
+                .await
+                .unwrap()
+        );
+        assert!(backend
+            .document_set(b"foo", b"a.d", Document::from("e"))
+            .await
+            .is_ok());
+        assert_eq!(
+            3,
+            backend
+                .document_append(b"foo", b"a.b", vec![Document::Integer(2), Document::Null])
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Document::Integer(3),
+            backend
+                .document_increment(b"foo", b"a.b[1]", Document::Integer(1))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Document::Float(2.0),
+            backend
+                .document_increment(b"foo", b"c", Document::Float(0.5))
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            1,
+            backend.document_delete(b"foo", b"a.b[-1]").await.unwrap()
+        );
+        assert_eq!(
+            r#"{"b":[1,3],"d":"e"}"#,
+            backend
+                .document_get(b"foo", b"a")
+                .await
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(1, backend.document_delete(b"foo", b"$").await.unwrap());
+        assert!(backend.document_get(b"foo", b"$").await.is_err());
+    }
+
     #[tokio::test]
     async fn test_echo() {
         let backend = MemoryBackend::new();
         assert!(matches!(backend.echo(b"test").await, Ok(vec) if vec == vec![b"test"]));
     }
 
+    #[tokio::test]
+    async fn test_flush() {
+        let backend = MemoryBackend::new();
+        backend.set(b"user:1", 1).await.unwrap();
+        backend.set(b"user:2", 2).await.unwrap();
+        backend.set(b"post:1", 3).await.unwrap();
+
+        assert_eq!(2, backend.flush_prefix(b"user:", false).await.unwrap());
+        assert_eq!(1, backend.flush(true).await.unwrap());
+        assert_eq!(0, backend.flush(false).await.unwrap());
+
+        backend.set(b"foo", 1).await.unwrap();
+        backend.select(1).await.unwrap();
+        backend.set(b"foo", 2).await.unwrap();
+        assert_eq!(2, backend.flush_all(false).await.unwrap());
+        assert!(backend
+            .hop
+            .namespaces()
+            .all(|state| !state.contains_key(b"foo")));
+    }
+
+    #[tokio::test]
+    async fn test_hyper_log_log() {
+        let backend = MemoryBackend::new();
+        let items = vec![b"alice".to_vec(), b"bob".to_vec()];
+        assert!(backend.hyper_log_log_add(b"a", items).await.unwrap());
+        let items = vec![b"bob".to_vec(), b"carol".to_vec()];
+        assert!(backend.hyper_log_log_add(b"b", items).await.unwrap());
+
+        let keys = vec![b"a".to_vec(), b"b".to_vec()];
+        assert_eq!(3, backend.hyper_log_log_count(keys.clone()).await.unwrap());
+        assert_eq!(3, backend.hyper_log_log_merge(b"c", keys).await.unwrap());
+
+        let hll = match backend.get(b"c").await {
+            Ok(Value::HyperLogLog(hll)) => hll,
+            other => panic!("unexpected response: {:?}", other),
+        };
+        assert!(backend.set(b"d", hll).await.is_ok());
+        assert_eq!(
+            3,
+            backend
+                .hyper_log_log_count(vec![b"d".to_vec()])
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        let backend = MemoryBackend::new();
+        let fields = vec![(b"user".to_vec(), b"alice".to_vec())];
+        let first = backend
+            .stream_append(b"foo", Some(StreamId::new(1, 0)), fields.clone())
+            .await
+            .unwrap();
+        assert_eq!(StreamId::new(1, 0), first);
+        let second = backend.stream_append(b"foo", None, fields).await.unwrap();
+        assert!(second > first);
+
+        let stream = backend
+            .stream_range(b"foo", None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(2, stream.len());
+        let stream = backend
+            .stream_range(b"foo", Some(second), None, None)
+            .await
+            .unwrap();
+        assert_eq!(1, stream.len());
+        let stream = backend
+            .stream_read(b"foo", first, None, Some(100))
+            .await
+            .unwrap();
+        assert_eq!(Some(second), stream.entries().next().map(StreamEntry::id));
+
+        assert_eq!(0, backend.stream_trim_age(b"foo", u64::MAX).await.unwrap());
+        assert_eq!(1, backend.stream_trim_age(b"foo", 60_000).await.unwrap());
+        assert_eq!(1, backend.stream_trim(b"foo", 0).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_is() {
         let backend = MemoryBackend::new();
         backend.set(b"foo", Value::Boolean(true)).await.unwrap();
 
-        assert!(backend.is(KeyType::Boolean, &["foo"]).await.unwrap());
-        assert!(!backend.is(KeyType::Integer, &["foo"]).await.unwrap());
+        assert!(backend.is(KeyType::Boolean, ["foo"]).await.unwrap());
+        assert!(!backend.is(KeyType::Integer, ["foo"]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_lowercase_uppercase() {
+        let backend = MemoryBackend::new();
+        assert!(backend.set(b"foo", "HopDB".to_owned()).await.is_ok());
+        assert_eq!("hopdb", backend.lowercase(b"foo").await.unwrap());
+        assert_eq!("HOPDB", backend.uppercase(b"foo").await.unwrap());
     }
 
     #[tokio::test]
@@ -411,9 +1618,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_length_list() {
-        let mut list = Vec::new();
-        list.push(b"foo".to_vec());
-        list.push(b"bar".to_vec());
+        let list = vec![b"foo".to_vec(), b"bar".to_vec()];
         let backend = MemoryBackend::new();
         assert!(backend.set(b"foo", Value::List(list)).await.is_ok());
         assert_eq!(
@@ -455,6 +1660,272 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_scan() {
+        let backend = MemoryBackend::new();
+        backend.set(b"user:1", 1).await.unwrap();
+        backend.set(b"user:2", true).await.unwrap();
+        backend.set(b"user:3", 3).await.unwrap();
+
+        let (cursor, mut keys) = backend
+            .scan(b"0", Some(b"user:*"), None, Some(2))
+            .await
+            .unwrap();
+        assert_eq!(2, keys.len());
+
+        let (cursor, rest) = backend
+            .scan(&cursor, Some(b"user:*"), None, Some(2))
+            .await
+            .unwrap();
+        assert_eq!(b"0".as_ref(), cursor.as_slice());
+        keys.extend(rest);
+        keys.sort();
+        assert_eq!(
+            [b"user:1".to_vec(), b"user:2".to_vec(), b"user:3".to_vec()].to_vec(),
+            keys
+        );
+
+        let (_, keys) = backend
+            .scan(b"0", None, Some(KeyType::Boolean), None)
+            .await
+            .unwrap();
+        assert_eq!([b"user:2".to_vec()].to_vec(), keys);
+
+        assert!(matches!(
+            backend.scan(b"0", None, None, Some(0)).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::PreconditionFailed
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_max_memory() {
+        let mut builder = Builder::new();
+        builder
+            .eviction_policy(EvictionPolicy::NoEviction)
+            .max_memory(1024);
+        let backend = MemoryBackend::with_builder(builder);
+
+        backend.set(b"foo", [0; 2048].to_vec()).await.unwrap();
+        assert!(matches!(
+            backend.set(b"bar", 1).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::MemoryLimitReached
+            })
+        ));
+
+        let mut builder = Builder::new();
+        builder
+            .eviction_policy(EvictionPolicy::LeastRecentlyUsed)
+            .max_memory(1024);
+        let backend = MemoryBackend::with_builder(builder);
+
+        backend.set(b"foo", [0; 2048].to_vec()).await.unwrap();
+        backend.set(b"bar", 1).await.unwrap();
+        assert!(!backend.exists([b"foo"]).await.unwrap());
+        assert_eq!(1, backend.stats().await.unwrap().keys_evicted());
+    }
+
+    #[tokio::test]
+    async fn test_size_limits() {
+        let mut builder = Builder::new();
+        builder.max_key_length(3).max_value_size(4);
+        let backend = MemoryBackend::with_builder(builder);
+
+        assert!(matches!(
+            backend.set(b"foobar", 1).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::SizeLimitExceeded
+            })
+        ));
+        backend.set(b"foo", b"bar".to_vec()).await.unwrap();
+        assert!(matches!(
+            backend.append(b"foo", b"baz".to_vec()).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::SizeLimitExceeded
+            })
+        ));
+        assert_eq!(
+            Some(b"bar".as_ref()),
+            backend.get(b"foo").await.unwrap().as_bytes_ref()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_auth() {
+        let mut builder = Builder::new();
+        builder.password("hunter2");
+        let backend = MemoryBackend::with_builder(builder);
+
+        assert!(matches!(
+            backend.set(b"foo", 1).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::Unauthenticated
+            })
+        ));
+        assert!(matches!(
+            backend.auth(None, b"hunter3").await,
+            Err(Error::RunningCommand {
+                source: DispatchError::Unauthenticated
+            })
+        ));
+        assert!(backend.auth(None, b"hunter2").await.unwrap());
+        assert!(backend.set(b"foo", 1).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_acl() {
+        let backend = MemoryBackend::new();
+        assert_eq!(
+            "+get ~a:*",
+            backend
+                .acl_set(
+                    b"foo",
+                    vec![b">bar".to_vec(), b"+get".to_vec(), b"~a:*".to_vec()]
+                )
+                .await
+                .unwrap()
+        );
+        assert!(matches!(
+            backend.acl_set(b"foo", vec![b"+baz".to_vec()]).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::AclRuleInvalid
+            })
+        ));
+        assert_eq!(2, backend.acl_list().await.unwrap().len());
+
+        assert!(backend.auth(Some(b"foo"), b"bar").await.unwrap());
+        assert!(matches!(
+            backend.set(b"a:1", 1).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::PermissionDenied
+            })
+        ));
+        assert!(matches!(
+            backend.get(b"a:1").await,
+            Err(Error::RunningCommand {
+                source: DispatchError::KeyNonexistent
+            })
+        ));
+        assert!(matches!(
+            backend.get(b"b:1").await,
+            Err(Error::RunningCommand {
+                source: DispatchError::PermissionDenied
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_dump_restore() {
+        let backend = MemoryBackend::new();
+        backend
+            .set(b"foo", vec![b"a".to_vec(), b"b".to_vec()])
+            .await
+            .unwrap();
+        let dump = backend.dump(b"foo").await.unwrap();
+
+        let other = MemoryBackend::new();
+        assert_eq!(
+            b"bar".to_vec(),
+            other.restore(b"bar", &dump, false).await.unwrap()
+        );
+        assert!(matches!(
+            other.restore(b"bar", &dump, false).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::PreconditionFailed
+            })
+        ));
+        assert!(other.restore(b"bar", &dump, true).await.is_ok());
+        assert_eq!(
+            Some([b"a".to_vec(), b"b".to_vec()].as_ref()),
+            other.get(b"bar").await.unwrap().as_list_ref()
+        );
+        assert!(matches!(
+            other.restore(b"baz", &dump[1..], false).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::DumpInvalid
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_memory_usage() {
+        let backend = MemoryBackend::new();
+        backend.set(b"foo", b"bar".to_vec()).await.unwrap();
+        let before = backend.memory_usage(b"foo").await.unwrap();
+
+        backend.append(b"foo", [0; 1024].to_vec()).await.unwrap();
+        let after = backend.memory_usage(b"foo").await.unwrap();
+        assert!(after >= before + 1024);
+        assert_eq!(after, backend.stats().await.unwrap().memory_used());
+
+        assert!(matches!(
+            backend.memory_usage(b"bar").await,
+            Err(Error::RunningCommand {
+                source: DispatchError::KeyNonexistent
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_object_info() {
+        let backend = MemoryBackend::new();
+        backend.set(b"foo", 1).await.unwrap();
+        backend.get(b"foo").await.unwrap();
+
+        let info = backend.object_info(b"foo").await.unwrap();
+        assert_eq!(KeyType::Integer, info.key_type());
+        assert_eq!(2, info.frequency());
+        assert!(info.created_at() > 0);
+        assert!(info.accessed_at() >= info.created_at());
+        assert!(info.idle() >= 0);
+        assert_eq!(
+            backend.memory_usage(b"foo").await.unwrap(),
+            info.memory_usage()
+        );
+
+        assert!(matches!(
+            backend.object_info(b"bar").await,
+            Err(Error::RunningCommand {
+                source: DispatchError::KeyNonexistent
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_select() {
+        let backend = MemoryBackend::new();
+        backend.set(b"foo", 1).await.unwrap();
+
+        assert_eq!(2, backend.select(2).await.unwrap());
+        assert!(!backend.exists([b"foo"]).await.unwrap());
+        backend.set(b"foo", 2).await.unwrap();
+        backend.set(b"bar", 3).await.unwrap();
+
+        let stats = backend.stats().await.unwrap();
+        assert_eq!(1, stats.namespace_keys(0));
+        assert_eq!(0, stats.namespace_keys(1));
+        assert_eq!(2, stats.namespace_keys(2));
+        assert_eq!([(0, 1), (2, 2)].to_vec(), stats.namespaces_keys());
+
+        assert!(matches!(
+            backend.select(16).await,
+            Err(Error::RunningCommand {
+                source: DispatchError::PreconditionFailed
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_set_range() {
+        let backend = MemoryBackend::new();
+        assert!(backend.set(b"foo", "hello".to_owned()).await.is_ok());
+        assert!(
+            matches!(backend.set_range(b"foo", 1, "ey".to_owned()).await, Ok(Value::String(str)) if str == "heylo")
+        );
+    }
+
     #[tokio::test]
     async fn test_set_bool() {
         let backend = MemoryBackend::new();
@@ -553,4 +2024,35 @@ mod tests {
                 .and_then(Value::as_string_ref),
         );
     }
+
+    #[tokio::test]
+    async fn test_slice_substring() {
+        let backend = MemoryBackend::new();
+        assert!(backend.set(b"foo", "h🤠pdb".to_owned()).await.is_ok());
+        assert!(
+            matches!(backend.substring(b"foo", None, 1, Some(2)).await, Ok(Value::String(str)) if str == "🤠")
+        );
+        assert!(
+            matches!(backend.slice(b"foo", None, 1, Some(5)).await, Ok(Value::String(str)) if str == "🤠")
+        );
+        assert!(backend.slice(b"foo", None, 2, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_split() {
+        let backend = MemoryBackend::new();
+        assert!(backend.set(b"foo", "a b".to_owned()).await.is_ok());
+        assert_eq!(
+            vec![b"a".to_vec(), b"b".to_vec()],
+            backend.split(b"foo", b" ").await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_starts_with_ends_with() {
+        let backend = MemoryBackend::new();
+        assert!(backend.set(b"foo", [1u8, 2, 3].to_vec()).await.is_ok());
+        assert!(backend.starts_with(b"foo", None, &[1, 2]).await.unwrap());
+        assert!(!backend.ends_with(b"foo", None, &[1, 2]).await.unwrap());
+    }
 }
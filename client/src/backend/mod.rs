@@ -3,20 +3,41 @@ pub mod memory;
 #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
 pub mod server;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "tls"))]
+pub mod tls;
+
 pub use self::memory::MemoryBackend;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
-pub use self::server::ServerBackend;
+pub use self::server::{ConnectOptions, ServerBackend};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "tls"))]
+pub use self::tls::{TlsError, TlsOptions};
 
-use crate::model::StatsData;
-use alloc::{boxed::Box, vec::Vec};
+use crate::model::{ObjectInfoData, SessionData, StatsData};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use async_trait::async_trait;
-use hop_engine::state::{KeyType, Value};
+use hop_engine::{
+    dashmap::DashMap,
+    state::{Document, KeyType, Stream, StreamId, Value},
+};
 
 #[async_trait]
 pub trait Backend: Send + Sync {
     type Error;
 
+    async fn acl_delete(&self, name: &[u8]) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
+    async fn acl_list(&self) -> Result<Vec<String>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn acl_set(&self, name: &[u8], rules: Vec<Vec<u8>>) -> Result<String, Self::Error>
+    where
+        Self: Sized;
+
     async fn append<T: Into<Value> + Send>(
         &self,
         key: &[u8],
@@ -25,6 +46,91 @@ pub trait Backend: Send + Sync {
     where
         Self: Sized;
 
+    async fn auth(&self, username: Option<&[u8]>, password: &[u8]) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_and(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_count(
+        &self,
+        key: &[u8],
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_get(&self, key: &[u8], offset: i64) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_not(&self, destination: &[u8], source: &[u8]) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_or(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_position(&self, key: &[u8], bit: bool) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_set(&self, key: &[u8], offset: i64, bit: bool) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
+    async fn bit_xor(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn clamp<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        min: T,
+        max: T,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn client_kill(&self, id: u32) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
+    async fn client_list(&self) -> Result<Vec<SessionData>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn config_get(
+        &self,
+        names: Vec<Vec<u8>>,
+    ) -> Result<DashMap<Vec<u8>, Vec<u8>>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn config_set(&self, name: &[u8], value: &[u8]) -> Result<String, Self::Error>
+    where
+        Self: Sized;
+
+    async fn copy(&self, from: &[u8], to: &[u8], replace: bool) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
     async fn decrement_by<T: Into<Value> + Send>(
         &self,
         key: &[u8],
@@ -41,10 +147,66 @@ pub trait Backend: Send + Sync {
     where
         Self: Sized;
 
+    async fn divide<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn document_append(
+        &self,
+        key: &[u8],
+        path: &[u8],
+        values: Vec<Document>,
+    ) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn document_delete(&self, key: &[u8], path: &[u8]) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn document_get(&self, key: &[u8], path: &[u8]) -> Result<Document, Self::Error>
+    where
+        Self: Sized;
+
+    async fn document_increment(
+        &self,
+        key: &[u8],
+        path: &[u8],
+        amount: Document,
+    ) -> Result<Document, Self::Error>
+    where
+        Self: Sized;
+
+    async fn document_set(
+        &self,
+        key: &[u8],
+        path: &[u8],
+        value: Document,
+    ) -> Result<Document, Self::Error>
+    where
+        Self: Sized;
+
+    async fn dump(&self, key: &[u8]) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
     async fn echo(&self, content: &[u8]) -> Result<Vec<Vec<u8>>, Self::Error>
     where
         Self: Sized;
 
+    async fn ends_with(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        suffix: &[u8],
+    ) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
     async fn exists<T: IntoIterator<Item = U> + Send, U: AsRef<[u8]> + Send>(
         &self,
         keys: T,
@@ -52,10 +214,38 @@ pub trait Backend: Send + Sync {
     where
         Self: Sized;
 
+    async fn flush(&self, background: bool) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn flush_all(&self, background: bool) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn flush_prefix(&self, prefix: &[u8], background: bool) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
     async fn get(&self, key: &[u8]) -> Result<Value, Self::Error>
     where
         Self: Sized;
 
+    async fn hyper_log_log_add(&self, key: &[u8], items: Vec<Vec<u8>>) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
+    async fn hyper_log_log_count(&self, keys: Vec<Vec<u8>>) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn hyper_log_log_merge(
+        &self,
+        destination: &[u8],
+        sources: Vec<Vec<u8>>,
+    ) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
     async fn increment_by<T: Into<Value> + Send>(
         &self,
         key: &[u8],
@@ -88,7 +278,53 @@ pub trait Backend: Send + Sync {
     where
         Self: Sized;
 
-    async fn rename(&self, from: &[u8], to: &[u8]) -> Result<Vec<u8>, Self::Error>
+    async fn lowercase(&self, key: &[u8]) -> Result<String, Self::Error>
+    where
+        Self: Sized;
+
+    async fn memory_usage(&self, key: &[u8]) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn modulo<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn multiply<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn object_info(&self, key: &[u8]) -> Result<ObjectInfoData, Self::Error>
+    where
+        Self: Sized;
+
+    async fn rename(&self, from: &[u8], to: &[u8], replace: bool) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn restore(&self, key: &[u8], dump: &[u8], replace: bool) -> Result<Vec<u8>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn scan(
+        &self,
+        cursor: &[u8],
+        pattern: Option<&[u8]>,
+        key_type: Option<KeyType>,
+        count: Option<i64>,
+    ) -> Result<(Vec<u8>, Vec<Vec<u8>>), Self::Error>
+    where
+        Self: Sized;
+
+    async fn select(&self, namespace: i64) -> Result<i64, Self::Error>
     where
         Self: Sized;
 
@@ -96,7 +332,106 @@ pub trait Backend: Send + Sync {
     where
         Self: Sized;
 
+    async fn set_if_greater<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn set_if_less<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        value: T,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn set_range<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        offset: i64,
+        value: T,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn slice(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        start: i64,
+        end: Option<i64>,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn split(&self, key: &[u8], separator: &[u8]) -> Result<Vec<Vec<u8>>, Self::Error>
+    where
+        Self: Sized;
+
+    async fn starts_with(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        prefix: &[u8],
+    ) -> Result<bool, Self::Error>
+    where
+        Self: Sized;
+
     async fn stats(&self) -> Result<StatsData, Self::Error>
     where
         Self: Sized;
+
+    async fn stream_append(
+        &self,
+        key: &[u8],
+        id: Option<StreamId>,
+        fields: Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> Result<StreamId, Self::Error>
+    where
+        Self: Sized;
+
+    async fn stream_range(
+        &self,
+        key: &[u8],
+        start: Option<StreamId>,
+        end: Option<StreamId>,
+        count: Option<i64>,
+    ) -> Result<Stream, Self::Error>
+    where
+        Self: Sized;
+
+    async fn stream_read(
+        &self,
+        key: &[u8],
+        after: StreamId,
+        count: Option<i64>,
+        block: Option<u64>,
+    ) -> Result<Stream, Self::Error>
+    where
+        Self: Sized;
+
+    async fn stream_trim(&self, key: &[u8], max_length: i64) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn stream_trim_age(&self, key: &[u8], max_age: u64) -> Result<i64, Self::Error>
+    where
+        Self: Sized;
+
+    async fn substring(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        start: i64,
+        end: Option<i64>,
+    ) -> Result<Value, Self::Error>
+    where
+        Self: Sized;
+
+    async fn uppercase(&self, key: &[u8]) -> Result<String, Self::Error>
+    where
+        Self: Sized;
 }
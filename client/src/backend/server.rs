@@ -1,10 +1,14 @@
 use super::Backend;
-use crate::model::StatsData;
-use alloc::{boxed::Box, vec::Vec};
+use crate::model::{ObjectInfoData, SessionData, StatsData};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 use async_trait::async_trait;
 use core::{
     convert::TryInto,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     result::Result as StdResult,
 };
 use hop_engine::{
@@ -13,18 +17,33 @@ use hop_engine::{
         response::{Context, Instruction, Response},
         CommandId, DispatchError,
     },
-    state::{KeyType, Value},
+    dashmap::DashMap,
+    state::{Document, KeyType, Stream, StreamId, Value},
+};
+use std::{
+    error::Error as StdError,
+    io::{Error as IoError, ErrorKind},
 };
-use std::{error::Error as StdError, io::Error as IoError};
 use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt, BufReader},
-    net::{
-        tcp::{OwnedReadHalf, OwnedWriteHalf},
-        TcpStream, ToSocketAddrs,
-    },
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    net::{TcpStream, ToSocketAddrs},
     sync::Mutex,
 };
 
+#[cfg(feature = "tls")]
+use super::TlsOptions;
+
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+
+/// The reading half of a connection, which may be encrypted.
+type Reader = Box<dyn AsyncRead + Send + Sync + Unpin>;
+
+/// The writing half of a connection, which may be encrypted.
+type Writer = Box<dyn AsyncWrite + Send + Sync + Unpin>;
+
 pub type Result<T> = StdResult<T, Error>;
 
 #[derive(Debug)]
@@ -37,7 +56,9 @@ pub enum Error {
     Dispatching { reason: DispatchError },
     KeyTypeInvalid { number: u8 },
     KeyTypeUnsupported { key_type: KeyType },
+    RateLimited,
     ReadingMessage { source: IoError },
+    RequestTooLarge,
     WritingMessage { source: IoError },
 }
 
@@ -65,7 +86,9 @@ impl Display for Error {
                 "key type {} is not supported by this command",
                 *key_type as u8
             )),
+            Self::RateLimited => f.write_str("the server is rate limiting requests"),
             Self::ReadingMessage { .. } => f.write_str("failed to read a message"),
+            Self::RequestTooLarge => f.write_str("the request is larger than the server accepts"),
             Self::WritingMessage { .. } => f.write_str("failed to write a message"),
         }
     }
@@ -82,7 +105,9 @@ impl StdError for Error {
             Self::Dispatching { .. } => None,
             Self::KeyTypeInvalid { .. } => None,
             Self::KeyTypeUnsupported { .. } => None,
+            Self::RateLimited => None,
             Self::ReadingMessage { source } => Some(source),
+            Self::RequestTooLarge => None,
             Self::WritingMessage { source } => Some(source),
         }
     }
@@ -94,24 +119,159 @@ impl From<RequestBuilderError> for Error {
     }
 }
 
-#[derive(Debug)]
+/// Options for connecting to a server, such as the credentials to
+/// authenticate with.
+#[derive(Clone, Default)]
+pub struct ConnectOptions {
+    password: Option<Vec<u8>>,
+    #[cfg(feature = "tls")]
+    tls: Option<TlsOptions>,
+    username: Option<Vec<u8>>,
+}
+
+impl ConnectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the password or token to authenticate with once connected.
+    ///
+    /// By default the session doesn't authenticate, which is only allowed by
+    /// servers that don't require a password.
+    pub fn password(&mut self, password: impl Into<Vec<u8>>) -> &mut Self {
+        self.password = Some(password.into());
+
+        self
+    }
+
+    /// Encrypt the connection with TLS.
+    ///
+    /// By default the connection isn't encrypted.
+    #[cfg(feature = "tls")]
+    pub fn tls(&mut self, tls: TlsOptions) -> &mut Self {
+        self.tls = Some(tls);
+
+        self
+    }
+
+    /// Set the name of the user to authenticate as once connected.
+    ///
+    /// By default the session authenticates as the default user. This is only
+    /// used if a password is set.
+    pub fn username(&mut self, username: impl Into<Vec<u8>>) -> &mut Self {
+        self.username = Some(username.into());
+
+        self
+    }
+}
+
+impl Debug for ConnectOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // The password is redacted so that it doesn't end up in logs.
+        let mut debug = f.debug_struct("ConnectOptions");
+        debug.field("password", &self.password.as_ref().map(|_| ".."));
+
+        #[cfg(feature = "tls")]
+        debug.field("tls", &self.tls);
+
+        debug
+            .field(
+                "username",
+                &self.username.as_deref().map(String::from_utf8_lossy),
+            )
+            .finish()
+    }
+}
+
 pub struct ServerBackend {
-    reader: Mutex<BufReader<OwnedReadHalf>>,
-    writer: Mutex<OwnedWriteHalf>,
+    reader: Mutex<BufReader<Reader>>,
+    writer: Mutex<Writer>,
+}
+
+impl Debug for ServerBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ServerBackend").finish()
+    }
 }
 
 impl ServerBackend {
     pub async fn connect(addrs: impl ToSocketAddrs) -> Result<Self> {
+        Self::connect_with_options(addrs, &ConnectOptions::new()).await
+    }
+
+    /// Connect to a server, authenticating with the credentials in the options
+    /// if there are any.
+    ///
+    /// Returns [`Error::Dispatching`] if the credentials are rejected.
+    ///
+    /// [`Error::Dispatching`]: enum.Error.html#variant.Dispatching
+    pub async fn connect_with_options(
+        addrs: impl ToSocketAddrs,
+        options: &ConnectOptions,
+    ) -> Result<Self> {
         let stream = TcpStream::connect(addrs)
             .await
             .map_err(|source| Error::Connecting { source })?;
 
+        #[cfg(feature = "tls")]
+        {
+            if let Some(tls) = options.tls.as_ref() {
+                let stream = tls
+                    .connect(stream)
+                    .await
+                    .map_err(|source| Error::Connecting { source })?;
+                let (reader, writer) = tokio::io::split(stream);
+
+                return Self::from_halves(Box::new(reader), Box::new(writer), options).await;
+            }
+        }
+
         let (reader, writer) = stream.into_split();
 
-        Ok(Self {
+        Self::from_halves(Box::new(reader), Box::new(writer), options).await
+    }
+
+    /// Connect to a server listening on a Unix socket at a path.
+    #[cfg(unix)]
+    pub async fn connect_unix(path: impl AsRef<Path>) -> Result<Self> {
+        Self::connect_unix_with_options(path, &ConnectOptions::new()).await
+    }
+
+    /// Connect to a server listening on a Unix socket at a path,
+    /// authenticating with the credentials in the options if there are any.
+    ///
+    /// Connections over Unix sockets stay on the machine, so the TLS options
+    /// are ignored.
+    ///
+    /// Returns [`Error::Dispatching`] if the credentials are rejected.
+    ///
+    /// [`Error::Dispatching`]: enum.Error.html#variant.Dispatching
+    #[cfg(unix)]
+    pub async fn connect_unix_with_options(
+        path: impl AsRef<Path>,
+        options: &ConnectOptions,
+    ) -> Result<Self> {
+        let stream = UnixStream::connect(path)
+            .await
+            .map_err(|source| Error::Connecting { source })?;
+        let (reader, writer) = tokio::io::split(stream);
+
+        Self::from_halves(Box::new(reader), Box::new(writer), options).await
+    }
+
+    /// Create a backend from the halves of a connection, authenticating with
+    /// the credentials in the options if there are any.
+    async fn from_halves(reader: Reader, writer: Writer, options: &ConnectOptions) -> Result<Self> {
+        let backend = Self {
             reader: Mutex::new(BufReader::new(reader)),
             writer: Mutex::new(writer),
-        })
+        };
+
+        if let Some(password) = options.password.as_ref() {
+            backend.auth(options.username.as_deref(), password).await?;
+        }
+
+        Ok(backend)
     }
 
     async fn send_and_wait(&self, request: impl Into<Request<'_>>) -> Result<Value> {
@@ -123,30 +283,38 @@ impl ServerBackend {
             .map_err(|source| Error::WritingMessage { source })?;
 
         let mut ctx = Context::new();
-        let mut resp = Vec::with_capacity(1);
+        let mut resp = Vec::new();
 
         let mut reader = self.reader.lock().await;
 
         loop {
-            let read_amount = reader
-                .read_exact(&mut resp)
-                .await
-                .map_err(|source| Error::ReadingMessage { source })?;
-
-            if read_amount == 0 {
-                return Err(Error::ConnectionClosed);
-            }
-
-            match ctx.feed(&resp).unwrap() {
+            match ctx.feed(&resp).map_err(|_| Error::BadResponse)? {
                 Instruction::Concluded(response) => {
                     return match response {
                         Response::Value(value) => Ok(value),
+                        Response::DispatchError(DispatchError::RateLimited) => {
+                            Err(Error::RateLimited)
+                        }
                         Response::DispatchError(reason) => Err(Error::Dispatching { reason }),
+                        Response::ParseError(ParseError::RequestTooLarge) => {
+                            Err(Error::RequestTooLarge)
+                        }
                         Response::ParseError(reason) => Err(Error::BadRequest { reason }),
                     }
                 }
                 Instruction::ReadBytes(bytes) => {
-                    resp.reserve_exact(bytes);
+                    // The context is fed every byte of the response read so
+                    // far, so read the requested bytes onto the end.
+                    let start = resp.len();
+                    resp.resize(start + bytes, 0);
+
+                    reader
+                        .read_exact(&mut resp[start..])
+                        .await
+                        .map_err(|source| match source.kind() {
+                            ErrorKind::UnexpectedEof => Error::ConnectionClosed,
+                            _ => Error::ReadingMessage { source },
+                        })?;
                 }
             }
         }
@@ -157,6 +325,42 @@ impl ServerBackend {
 impl Backend for ServerBackend {
     type Error = Error;
 
+    async fn acl_delete(&self, name: &[u8]) -> Result<bool> {
+        let mut builder = RequestBuilder::new(CommandId::AclDelete);
+        builder.bytes(name)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(deleted) => Ok(deleted),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn acl_list(&self) -> Result<Vec<String>> {
+        let builder = RequestBuilder::new(CommandId::AclList);
+
+        match self.send_and_wait(builder).await? {
+            Value::List(users) => users
+                .into_iter()
+                .map(|user| String::from_utf8(user).map_err(|_| Error::BadResponse))
+                .collect(),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn acl_set(&self, name: &[u8], rules: Vec<Vec<u8>>) -> Result<String> {
+        let mut builder = RequestBuilder::new(CommandId::AclSet);
+        builder.bytes(name)?;
+
+        for rule in rules {
+            builder.bytes(rule)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::String(rules) => Ok(rules),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
     async fn append<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
         let value = value.into();
         let key_type = value.kind();
@@ -182,6 +386,207 @@ impl Backend for ServerBackend {
         self.send_and_wait(builder).await
     }
 
+    async fn auth(&self, username: Option<&[u8]>, password: &[u8]) -> Result<bool> {
+        let mut builder = RequestBuilder::new(CommandId::Auth);
+
+        if let Some(username) = username {
+            builder.bytes(username)?;
+        }
+
+        builder.bytes(password)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(authenticated) => Ok(authenticated),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_and(&self, destination: &[u8], sources: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+        let mut builder = RequestBuilder::new(CommandId::BitAnd);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_count(&self, key: &[u8], start: Option<i64>, end: Option<i64>) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::BitCount);
+        builder.bytes(key)?;
+
+        if start.is_some() || end.is_some() {
+            builder.value(Value::Integer(start.unwrap_or(0)))?;
+        }
+
+        if let Some(end) = end {
+            builder.value(Value::Integer(end))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(count) => Ok(count),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_get(&self, key: &[u8], offset: i64) -> Result<bool> {
+        let mut builder = RequestBuilder::new(CommandId::BitGet);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(offset))?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(bit) => Ok(bit),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_not(&self, destination: &[u8], source: &[u8]) -> Result<Vec<u8>> {
+        let mut builder = RequestBuilder::new(CommandId::BitNot);
+        builder.bytes(destination)?;
+        builder.bytes(source)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_or(&self, destination: &[u8], sources: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+        let mut builder = RequestBuilder::new(CommandId::BitOr);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_position(&self, key: &[u8], bit: bool) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::BitPosition);
+        builder.bytes(key)?;
+        builder.value(Value::Boolean(bit))?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(position) => Ok(position),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_set(&self, key: &[u8], offset: i64, bit: bool) -> Result<bool> {
+        let mut builder = RequestBuilder::new(CommandId::BitSet);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(offset))?;
+        builder.value(Value::Boolean(bit))?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(previous) => Ok(previous),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn bit_xor(&self, destination: &[u8], sources: Vec<Vec<u8>>) -> Result<Vec<u8>> {
+        let mut builder = RequestBuilder::new(CommandId::BitXor);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn clamp<T: Into<Value> + Send>(&self, key: &[u8], min: T, max: T) -> Result<Value> {
+        let min = min.into();
+        let max = max.into();
+        let key_type = min.kind();
+
+        if max.kind() != key_type || (key_type != KeyType::Float && key_type != KeyType::Integer) {
+            return Err(Error::KeyTypeUnsupported { key_type });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Clamp, key_type);
+        builder.bytes(key)?;
+        builder.value(min)?;
+        builder.value(max)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn client_kill(&self, id: u32) -> Result<bool> {
+        let mut builder = RequestBuilder::new(CommandId::ClientKill);
+        builder.value(Value::Integer(id.into()))?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(killed) => Ok(killed),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn client_list(&self) -> Result<Vec<SessionData>> {
+        let builder = RequestBuilder::new(CommandId::ClientList);
+
+        let fields = match self.send_and_wait(builder).await? {
+            Value::List(fields) => fields,
+            _ => return Err(Error::BadResponse),
+        };
+
+        SessionData::from_fields(&fields).ok_or(Error::BadResponse)
+    }
+
+    async fn config_get(&self, names: Vec<Vec<u8>>) -> Result<DashMap<Vec<u8>, Vec<u8>>> {
+        let mut builder = RequestBuilder::new(CommandId::ConfigGet);
+
+        for name in names {
+            builder.bytes(name)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Map(parameters) => Ok(parameters),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn config_set(&self, name: &[u8], value: &[u8]) -> Result<String> {
+        let mut builder = RequestBuilder::new(CommandId::ConfigSet);
+        builder.bytes(name)?;
+        builder.bytes(value)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::String(value) => Ok(value),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn copy(&self, from: &[u8], to: &[u8], replace: bool) -> Result<Vec<u8>> {
+        let mut builder = RequestBuilder::new(CommandId::Copy);
+        builder.bytes(from)?;
+        builder.bytes(to)?;
+
+        if replace {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
     async fn decrement_by<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
         let value = value.into();
         let key_type = value.kind();
@@ -229,6 +634,102 @@ impl Backend for ServerBackend {
         }
     }
 
+    async fn divide<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Divide, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn document_append(&self, key: &[u8], path: &[u8], values: Vec<Document>) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentAppend);
+        builder.bytes(key)?;
+        builder.bytes(path)?;
+
+        for value in values {
+            builder.value(value)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(len) => Ok(len),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn document_delete(&self, key: &[u8], path: &[u8]) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentDelete);
+        builder.bytes(key)?;
+        builder.bytes(path)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(removed) => Ok(removed),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn document_get(&self, key: &[u8], path: &[u8]) -> Result<Document> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentGet);
+        builder.bytes(key)?;
+        builder.bytes(path)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Document(document) => Ok(document),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn document_increment(
+        &self,
+        key: &[u8],
+        path: &[u8],
+        amount: Document,
+    ) -> Result<Document> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentIncrement);
+        builder.bytes(key)?;
+        builder.bytes(path)?;
+        builder.value(amount)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Float(float) => Ok(Document::Float(float)),
+            Value::Integer(int) => Ok(Document::Integer(int)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn document_set(&self, key: &[u8], path: &[u8], value: Document) -> Result<Document> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentSet);
+        builder.bytes(key)?;
+        builder.bytes(path)?;
+        builder.value(value)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Document(document) => Ok(document),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn dump(&self, key: &[u8]) -> Result<Vec<u8>> {
+        let mut builder = RequestBuilder::new(CommandId::Dump);
+        builder.bytes(key)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
     async fn echo(&self, content: &[u8]) -> Result<Vec<Vec<u8>>> {
         let mut builder = RequestBuilder::new(CommandId::Echo);
         builder.bytes(content)?;
@@ -241,6 +742,22 @@ impl Backend for ServerBackend {
         }
     }
 
+    async fn ends_with(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        suffix: &[u8],
+    ) -> Result<bool> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::EndsWith, key_type);
+        builder.bytes(key)?;
+        builder.bytes(suffix)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(matches) => Ok(matches),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
     async fn exists<T: IntoIterator<Item = U> + Send, U: AsRef<[u8]> + Send>(
         &self,
         keys: T,
@@ -259,6 +776,46 @@ impl Backend for ServerBackend {
         }
     }
 
+    async fn flush(&self, background: bool) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::Flush);
+
+        if background {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(int) => Ok(int),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn flush_all(&self, background: bool) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::FlushAll);
+
+        if background {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(int) => Ok(int),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn flush_prefix(&self, prefix: &[u8], background: bool) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::FlushPrefix);
+        builder.bytes(prefix)?;
+
+        if background {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(int) => Ok(int),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
     async fn get(&self, key: &[u8]) -> Result<Value> {
         let mut builder = RequestBuilder::new(CommandId::Get);
         builder.bytes(key)?;
@@ -266,6 +823,47 @@ impl Backend for ServerBackend {
         self.send_and_wait(builder).await
     }
 
+    async fn hyper_log_log_add(&self, key: &[u8], items: Vec<Vec<u8>>) -> Result<bool> {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogAdd);
+        builder.bytes(key)?;
+
+        for item in items {
+            builder.bytes(item)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(changed) => Ok(changed),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn hyper_log_log_count(&self, keys: Vec<Vec<u8>>) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogCount);
+
+        for key in keys {
+            builder.bytes(key)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(count) => Ok(count),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn hyper_log_log_merge(&self, destination: &[u8], sources: Vec<Vec<u8>>) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogMerge);
+        builder.bytes(destination)?;
+
+        for source in sources {
+            builder.bytes(source)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(count) => Ok(count),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
     async fn increment_by<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
         let value = value.into();
         let key_type = value.kind();
@@ -361,11 +959,85 @@ impl Backend for ServerBackend {
         }
     }
 
-    async fn rename(&self, from: &[u8], to: &[u8]) -> Result<Vec<u8>> {
+    async fn lowercase(&self, key: &[u8]) -> Result<String> {
+        let mut builder = RequestBuilder::new(CommandId::Lowercase);
+        builder.bytes(key)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::String(string) => Ok(string),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn memory_usage(&self, key: &[u8]) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::MemoryUsage);
+        builder.bytes(key)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn modulo<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Modulo, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn multiply<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Multiply, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn object_info(&self, key: &[u8]) -> Result<ObjectInfoData> {
+        let mut builder = RequestBuilder::new(CommandId::ObjectInfo);
+        builder.bytes(key)?;
+
+        let fields = match self.send_and_wait(builder).await? {
+            Value::List(fields) => fields,
+            _ => return Err(Error::BadResponse),
+        };
+
+        ObjectInfoData::from_fields(&fields).ok_or(Error::BadResponse)
+    }
+
+    async fn rename(&self, from: &[u8], to: &[u8], replace: bool) -> Result<Vec<u8>> {
         let mut builder = RequestBuilder::new(CommandId::Rename);
         builder.bytes(from)?;
         builder.bytes(to)?;
 
+        if replace {
+            builder.value(Value::Boolean(true))?;
+        }
+
         let value = self.send_and_wait(builder).await?;
 
         match value {
@@ -374,6 +1046,97 @@ impl Backend for ServerBackend {
         }
     }
 
+    async fn restore(&self, key: &[u8], dump: &[u8], replace: bool) -> Result<Vec<u8>> {
+        let mut builder = RequestBuilder::new(CommandId::Restore);
+        builder.bytes(key)?;
+        builder.bytes(dump)?;
+
+        if replace {
+            builder.value(Value::Boolean(true))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn scan(
+        &self,
+        cursor: &[u8],
+        pattern: Option<&[u8]>,
+        key_type: Option<KeyType>,
+        count: Option<i64>,
+    ) -> Result<(Vec<u8>, Vec<Vec<u8>>)> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Scan, key_type);
+        builder.bytes(cursor)?;
+
+        if pattern.is_some() || count.is_some() {
+            builder.bytes(pattern.unwrap_or(b"*"))?;
+        }
+
+        if let Some(count) = count {
+            builder.value(Value::Integer(count))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::List(mut list) if !list.is_empty() => {
+                let cursor = list.remove(0);
+
+                Ok((cursor, list))
+            }
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn select(&self, namespace: i64) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::Select);
+        builder.value(Value::Integer(namespace))?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(int) => Ok(int),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn set_if_greater<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::SetIfGreater, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn set_if_less<T: Into<Value> + Send>(&self, key: &[u8], value: T) -> Result<Value> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        if key_type != KeyType::Float && key_type != KeyType::Integer {
+            return Err(Error::KeyTypeUnsupported { key_type });
+        }
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::SetIfLess, key_type);
+        builder.bytes(key)?;
+        builder.value(value)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Float(float) => Ok(Value::Float(float)),
+            Value::Integer(int) => Ok(Value::Integer(int)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
     async fn stats(&self) -> Result<StatsData> {
         let builder = RequestBuilder::new(CommandId::Stats);
 
@@ -397,6 +1160,215 @@ impl Backend for ServerBackend {
 
         self.send_and_wait(builder).await
     }
+
+    async fn set_range<T: Into<Value> + Send>(
+        &self,
+        key: &[u8],
+        offset: i64,
+        value: T,
+    ) -> Result<Value> {
+        let value = value.into();
+        let key_type = value.kind();
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::SetRange, key_type);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(offset))?;
+
+        match value {
+            Value::Bytes(bytes) => {
+                builder.bytes(bytes)?;
+            }
+            Value::String(string) => {
+                builder.bytes(string.into_bytes())?;
+            }
+            _ => return Err(Error::KeyTypeUnsupported { key_type }),
+        }
+
+        self.send_and_wait(builder).await
+    }
+
+    async fn slice(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        start: i64,
+        end: Option<i64>,
+    ) -> Result<Value> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Slice, key_type);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(start))?;
+
+        if let Some(end) = end {
+            builder.value(Value::Integer(end))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(Value::Bytes(bytes)),
+            Value::String(string) => Ok(Value::String(string)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn split(&self, key: &[u8], separator: &[u8]) -> Result<Vec<Vec<u8>>> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Split, KeyType::String);
+        builder.bytes(key)?;
+        builder.bytes(separator)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::List(list) => Ok(list),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn starts_with(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        prefix: &[u8],
+    ) -> Result<bool> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::StartsWith, key_type);
+        builder.bytes(key)?;
+        builder.bytes(prefix)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Boolean(matches) => Ok(matches),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn stream_append(
+        &self,
+        key: &[u8],
+        id: Option<StreamId>,
+        fields: Vec<(Vec<u8>, Vec<u8>)>,
+    ) -> Result<StreamId> {
+        let mut builder = RequestBuilder::new(CommandId::StreamAppend);
+        builder.bytes(key)?;
+
+        match id {
+            Some(id) => builder.bytes(id.to_string())?,
+            None => builder.bytes(b"*".as_ref())?,
+        };
+
+        for (field, value) in fields {
+            builder.bytes(field)?;
+            builder.bytes(value)?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::String(id) => id.parse().map_err(|_| Error::BadResponse),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn stream_range(
+        &self,
+        key: &[u8],
+        start: Option<StreamId>,
+        end: Option<StreamId>,
+        count: Option<i64>,
+    ) -> Result<Stream> {
+        let mut builder = RequestBuilder::new(CommandId::StreamRange);
+        builder.bytes(key)?;
+
+        match start {
+            Some(start) => builder.bytes(start.to_string())?,
+            None => builder.bytes(b"-".as_ref())?,
+        };
+
+        match end {
+            Some(end) => builder.bytes(end.to_string())?,
+            None => builder.bytes(b"+".as_ref())?,
+        };
+
+        if let Some(count) = count {
+            builder.value(Value::Integer(count))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Stream(stream) => Ok(stream),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn stream_read(
+        &self,
+        key: &[u8],
+        after: StreamId,
+        count: Option<i64>,
+        block: Option<u64>,
+    ) -> Result<Stream> {
+        let mut builder = RequestBuilder::new(CommandId::StreamRead);
+        builder.bytes(key)?;
+        builder.bytes(after.to_string())?;
+
+        if count.is_some() || block.is_some() {
+            builder.value(Value::Integer(count.unwrap_or(i64::MAX)))?;
+        }
+
+        if let Some(block) = block {
+            builder.value(Value::Integer(block.try_into().unwrap_or(i64::MAX)))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Stream(stream) => Ok(stream),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn stream_trim(&self, key: &[u8], max_length: i64) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::StreamTrim);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(max_length))?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(removed) => Ok(removed),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn stream_trim_age(&self, key: &[u8], max_age: u64) -> Result<i64> {
+        let mut builder = RequestBuilder::new(CommandId::StreamTrimAge);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(max_age.try_into().unwrap_or(i64::MAX)))?;
+
+        match self.send_and_wait(builder).await? {
+            Value::Integer(removed) => Ok(removed),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn substring(
+        &self,
+        key: &[u8],
+        key_type: Option<KeyType>,
+        start: i64,
+        end: Option<i64>,
+    ) -> Result<Value> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Substring, key_type);
+        builder.bytes(key)?;
+        builder.value(Value::Integer(start))?;
+
+        if let Some(end) = end {
+            builder.value(Value::Integer(end))?;
+        }
+
+        match self.send_and_wait(builder).await? {
+            Value::Bytes(bytes) => Ok(Value::Bytes(bytes)),
+            Value::String(string) => Ok(Value::String(string)),
+            _ => Err(Error::BadResponse),
+        }
+    }
+
+    async fn uppercase(&self, key: &[u8]) -> Result<String> {
+        let mut builder = RequestBuilder::new(CommandId::Uppercase);
+        builder.bytes(key)?;
+
+        match self.send_and_wait(builder).await? {
+            Value::String(string) => Ok(string),
+            _ => Err(Error::BadResponse),
+        }
+    }
 }
 
 #[cfg(test)]
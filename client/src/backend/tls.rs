@@ -0,0 +1,147 @@
+//! Options for connecting to servers over TLS.
+//!
+//! Refer to [`TlsOptions`] for more information.
+//!
+//! [`TlsOptions`]: struct.TlsOptions.html
+
+use alloc::sync::Arc;
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::error::Error as StdError;
+use tokio::net::TcpStream;
+use tokio_rustls::{
+    client::TlsStream,
+    rustls::{internal::pemfile, ClientConfig},
+    webpki::{DNSName, DNSNameRef},
+    TlsConnector,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TlsError {
+    /// The PEM-encoded certificates couldn't be parsed or there weren't any.
+    CertificatesInvalid,
+    /// The domain name to verify the server's certificate for isn't a valid
+    /// DNS name.
+    DomainInvalid,
+    /// The PEM-encoded private key couldn't be parsed or doesn't match the
+    /// certificate.
+    PrivateKeyInvalid,
+}
+
+impl Display for TlsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::CertificatesInvalid => f.write_str("the certificates are invalid"),
+            Self::DomainInvalid => f.write_str("the domain name is invalid"),
+            Self::PrivateKeyInvalid => f.write_str("the private key is invalid"),
+        }
+    }
+}
+
+impl StdError for TlsError {}
+
+/// Options for encrypting the connection to a server with TLS.
+///
+/// The server's certificate is verified to be for a domain name and signed by
+/// a trusted certificate authority. No certificate authorities are trusted by
+/// default, so at least one must be added with [`ca_certificates`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use hop::{
+///     backend::{ConnectOptions, TlsOptions},
+///     Client,
+/// };
+///
+/// let mut tls = TlsOptions::new("hop.example.com")?;
+/// tls.ca_certificates(&std::fs::read("ca.pem")?)?;
+///
+/// let mut options = ConnectOptions::new();
+/// options.tls(tls);
+///
+/// let client = Client::connect_with_options("hop.example.com:46733", &options).await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`ca_certificates`]: #method.ca_certificates
+#[derive(Clone)]
+pub struct TlsOptions {
+    config: ClientConfig,
+    domain: DNSName,
+}
+
+impl TlsOptions {
+    /// Create options verifying that the server's certificate is for a domain
+    /// name.
+    ///
+    /// Returns [`TlsError::DomainInvalid`] if the domain name isn't a valid
+    /// DNS name, such as if it's an IP address.
+    ///
+    /// [`TlsError::DomainInvalid`]: enum.TlsError.html#variant.DomainInvalid
+    pub fn new(domain: &str) -> Result<Self, TlsError> {
+        let domain = DNSNameRef::try_from_ascii_str(domain)
+            .map_err(|_| TlsError::DomainInvalid)?
+            .to_owned();
+
+        Ok(Self {
+            config: ClientConfig::new(),
+            domain,
+        })
+    }
+
+    /// Trust the certificate authorities in PEM-encoded certificates to sign
+    /// the server's certificate.
+    ///
+    /// A self-signed server certificate can be trusted by providing the
+    /// certificate itself.
+    pub fn ca_certificates(&mut self, pem: &[u8]) -> Result<&mut Self, TlsError> {
+        match self.config.root_store.add_pem_file(&mut &*pem) {
+            Ok((valid, _)) if valid > 0 => Ok(self),
+            _ => Err(TlsError::CertificatesInvalid),
+        }
+    }
+
+    /// Present a PEM-encoded certificate chain and private key to servers that
+    /// verify their clients.
+    pub fn client_certificate(
+        &mut self,
+        certificates_pem: &[u8],
+        private_key_pem: &[u8],
+    ) -> Result<&mut Self, TlsError> {
+        let certificates = pemfile::certs(&mut &*certificates_pem)
+            .ok()
+            .filter(|certificates| !certificates.is_empty())
+            .ok_or(TlsError::CertificatesInvalid)?;
+
+        // Keys may either be in the PKCS #8 or the older RSA format.
+        let private_key = pemfile::pkcs8_private_keys(&mut &*private_key_pem)
+            .ok()
+            .filter(|keys| !keys.is_empty())
+            .or_else(|| pemfile::rsa_private_keys(&mut &*private_key_pem).ok())
+            .and_then(|mut keys| keys.pop())
+            .ok_or(TlsError::PrivateKeyInvalid)?;
+
+        self.config
+            .set_single_client_cert(certificates, private_key)
+            .map_err(|_| TlsError::PrivateKeyInvalid)?;
+
+        Ok(self)
+    }
+
+    pub(super) async fn connect(&self, stream: TcpStream) -> std::io::Result<TlsStream<TcpStream>> {
+        TlsConnector::from(Arc::new(self.config.clone()))
+            .connect(self.domain.as_ref(), stream)
+            .await
+    }
+}
+
+impl Debug for TlsOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let domain: &str = self.domain.as_ref().into();
+
+        f.debug_struct("TlsOptions")
+            .field("domain", &domain)
+            .finish()
+    }
+}
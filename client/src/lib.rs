@@ -1,6 +1,6 @@
 #![deny(clippy::all, clippy::cargo)]
 #![forbid(unsafe_code)]
-#![allow(clippy::multiple_crate_versions)]
+#![allow(clippy::multiple_bound_locations, clippy::multiple_crate_versions)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(all(feature = "tokio", not(feature = "std")))]
@@ -12,10 +12,14 @@ pub mod backend;
 pub mod model;
 pub mod request;
 
-pub use hop_engine::state::{KeyType, Value};
+pub use hop_engine::{
+    hop::{Builder, EvictionPolicy},
+    state::{Document, DocumentPath, HyperLogLog, KeyType, Stream, StreamEntry, StreamId, Value},
+};
 
 use alloc::sync::Arc;
 use backend::{Backend, MemoryBackend};
+use core::{convert::TryInto, time::Duration};
 use request::{append::AppendUnconfigured, get::GetUnconfigured, set::SetUnconfigured, *};
 
 /// A client for interfacing over Hop instances.
@@ -48,7 +52,72 @@ impl Client<backend::ServerBackend> {
     pub async fn connect(
         addrs: impl tokio::net::ToSocketAddrs,
     ) -> Result<Self, <backend::ServerBackend as Backend>::Error> {
-        let backend = backend::ServerBackend::connect(addrs).await.unwrap();
+        Self::connect_with_options(addrs, &backend::ConnectOptions::new()).await
+    }
+
+    /// Connect to a server instance of Hop by address with options, such as
+    /// the password to authenticate with.
+    ///
+    /// # Examples
+    ///
+    /// Connect to an instance of Hop requiring a password:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use hop::{backend::ConnectOptions, Client};
+    ///
+    /// let mut options = ConnectOptions::new();
+    /// options.password("hunter2");
+    ///
+    /// let client = Client::connect_with_options("localhost:14000", &options).await?;
+    /// println!("Increment value: {}", client.increment("foo").int().await?);
+    /// # Ok(()) }
+    pub async fn connect_with_options(
+        addrs: impl tokio::net::ToSocketAddrs,
+        options: &backend::ConnectOptions,
+    ) -> Result<Self, <backend::ServerBackend as Backend>::Error> {
+        let backend = backend::ServerBackend::connect_with_options(addrs, options).await?;
+
+        Ok(Self {
+            backend: Arc::new(backend),
+        })
+    }
+
+    /// Connect to a server instance of Hop listening on a Unix socket.
+    ///
+    /// This avoids the overhead of TCP for clients on the same machine as the
+    /// server.
+    ///
+    /// # Examples
+    ///
+    /// Connect to an instance of Hop listening on `/tmp/hop.sock`:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use hop::Client;
+    ///
+    /// let client = Client::connect_unix("/tmp/hop.sock").await?;
+    /// println!("Increment value: {}", client.increment("foo").int().await?);
+    /// # Ok(()) }
+    /// ```
+    #[cfg(unix)]
+    pub async fn connect_unix(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, <backend::ServerBackend as Backend>::Error> {
+        Self::connect_unix_with_options(path, &backend::ConnectOptions::new()).await
+    }
+
+    /// Connect to a server instance of Hop listening on a Unix socket with
+    /// options, such as the password to authenticate with.
+    ///
+    /// Connections over Unix sockets stay on the machine, so the TLS options
+    /// are ignored.
+    #[cfg(unix)]
+    pub async fn connect_unix_with_options(
+        path: impl AsRef<std::path::Path>,
+        options: &backend::ConnectOptions,
+    ) -> Result<Self, <backend::ServerBackend as Backend>::Error> {
+        let backend = backend::ServerBackend::connect_unix_with_options(path, options).await?;
 
         Ok(Self {
             backend: Arc::new(backend),
@@ -77,9 +146,111 @@ impl Client<MemoryBackend> {
             backend: Arc::new(MemoryBackend::new()),
         }
     }
+
+    /// Create a local memory-backend Hop instance using an engine configured
+    /// by a builder.
+    ///
+    /// # Examples
+    ///
+    /// Create an instance that acts as a cache of about 64 megabytes, removing
+    /// the least recently used keys once it's full:
+    ///
+    /// ```rust
+    /// use hop::{Builder, Client, EvictionPolicy};
+    ///
+    /// let mut builder = Builder::new();
+    /// builder
+    ///     .eviction_policy(EvictionPolicy::LeastRecentlyUsed)
+    ///     .max_memory(64 * 1024 * 1024);
+    ///
+    /// let client = Client::memory_with_builder(builder);
+    /// ```
+    pub fn memory_with_builder(builder: Builder) -> Self {
+        Self {
+            backend: Arc::new(MemoryBackend::with_builder(builder)),
+        }
+    }
 }
 
 impl<B: Backend> Client<B> {
+    /// Delete a user from the access control list.
+    ///
+    /// Sessions authenticated as the user are no longer authenticated. The
+    /// default user can't be deleted.
+    ///
+    /// Returns whether the user existed on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.acl_set("reader", ["+@read"]).await?;
+    ///
+    /// assert!(client.acl_delete("reader").await?);
+    /// assert!(!client.acl_delete("reader").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn acl_delete<N: AsRef<[u8]> + Send + Unpin>(&self, name: N) -> AclDelete<'_, B, N> {
+        AclDelete::new(self.backend(), name)
+    }
+
+    /// List the users of the access control list, sorted by name.
+    ///
+    /// Each user is described like a line of an ACL file, without passwords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.acl_set("reader", [">secret", "+@read", "~cache:*"]).await?;
+    ///
+    /// assert_eq!(
+    ///     ["user default nopass +@all ~*", "user reader +@read ~cache:*"].as_ref(),
+    ///     client.acl_list().await?.as_slice(),
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn acl_list(&self) -> AclList<'_, B> {
+        AclList::new(self.backend())
+    }
+
+    /// Create a user or apply rules to an existing user.
+    ///
+    /// Rules permit the user to run commands on keys and set its password.
+    /// Refer to the engine's `User` for the rules.
+    ///
+    /// Returns the rules describing the user on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// assert_eq!("+@read ~*", client.acl_set("reader", [">secret", "+@read", "allkeys"]).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn acl_set<N: AsRef<[u8]> + Send + Unpin, T: IntoIterator<Item = U>, U: AsRef<[u8]>>(
+        &self,
+        name: N,
+        rules: T,
+    ) -> AclSet<'_, B, N> {
+        let rules = rules
+            .into_iter()
+            .map(|rule| rule.as_ref().to_vec())
+            .collect();
+
+        AclSet::new(self.backend(), name, rules)
+    }
+
     /// Append to a key's value.
     ///
     /// The struct returned includes methods for appending to each key type.
@@ -87,78 +258,1408 @@ impl<B: Backend> Client<B> {
     ///
     /// If the key does not exist then an error is returned.
     ///
-    /// Returns the new value on success.
+    /// Returns the new value on success.
+    ///
+    /// # Examples
+    ///
+    /// Append to a string:
+    ///
+    /// ```rust
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use hop::Client;
+    ///
+    /// let mut client = Client::memory();
+    ///
+    /// client.set("foo").str("this is").await?;
+    ///
+    /// assert_eq!("this is a string", client.append("foo").str(" a string").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn append<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> AppendUnconfigured<B, K> {
+        AppendUnconfigured::new(self.backend(), key)
+    }
+
+    /// Authenticate the session with the password required by the instance.
+    ///
+    /// Instances configured with a password reject every other command until
+    /// the session has authenticated. Sessions connecting to a server can
+    /// instead provide the password when connecting. Use [`Auth::username`]
+    /// to authenticate as a named user of the access control list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Builder, Client};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut builder = Builder::new();
+    /// builder.password("hunter2");
+    /// let client = Client::memory_with_builder(builder);
+    ///
+    /// assert!(client.auth("hunter2").await?);
+    /// client.set("foo").int(1).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Auth::username`]: request/struct.Auth.html#method.username
+    pub fn auth<P: AsRef<[u8]> + Send + Unpin>(&self, password: P) -> Auth<'_, B, P> {
+        Auth::new(self.backend(), password)
+    }
+
+    /// Combine bytes keys with a bitwise AND, storing the result in a
+    /// destination key.
+    ///
+    /// Shorter sources are padded with zeroes and nonexistent sources are
+    /// treated as empty.
+    ///
+    /// Returns the resulting bytes on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("a").bytes([0b1100].as_ref()).await?;
+    /// client.set("b").bytes([0b1010].as_ref()).await?;
+    ///
+    /// assert_eq!([0b1000].as_ref(), client.bit_and("dest", ["a", "b"]).await?.as_slice());
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_and<D: AsRef<[u8]> + Send + Unpin, T: IntoIterator<Item = U>, U: AsRef<[u8]>>(
+        &self,
+        destination: D,
+        sources: T,
+    ) -> BitAnd<'_, B, D> {
+        let sources = sources
+            .into_iter()
+            .map(|source| source.as_ref().to_vec())
+            .collect();
+
+        BitAnd::new(self.backend(), destination, sources)
+    }
+
+    /// Count the number of set bits of a bytes key, optionally within a range
+    /// of bytes.
+    ///
+    /// Nonexistent keys have no set bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").bytes([0xff, 0b101].as_ref()).await?;
+    ///
+    /// assert_eq!(10, client.bit_count("foo").await?);
+    /// assert_eq!(2, client.bit_count("foo").start(-1).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_count<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> BitCount<'_, B, K> {
+        BitCount::new(self.backend(), key)
+    }
+
+    /// Retrieve a bit of a bytes key at an offset.
+    ///
+    /// Offset 0 is the most significant bit of the first byte. Bits past the
+    /// end of the value and of nonexistent keys are unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.bit_set("foo", 3, true).await?;
+    ///
+    /// assert!(client.bit_get("foo", 3).await?);
+    /// assert!(!client.bit_get("foo", 100).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_get<K: AsRef<[u8]> + Send + Unpin>(&self, key: K, offset: i64) -> BitGet<'_, B, K> {
+        BitGet::new(self.backend(), key, offset)
+    }
+
+    /// Invert the bits of a bytes key, storing the result in a destination key.
+    ///
+    /// Returns the resulting bytes on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").bytes([0x0f].as_ref()).await?;
+    ///
+    /// assert_eq!([0xf0].as_ref(), client.bit_not("bar", "foo").await?.as_slice());
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_not<D: AsRef<[u8]> + Send + Unpin, S: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        destination: D,
+        source: S,
+    ) -> BitNot<'_, B, D, S> {
+        BitNot::new(self.backend(), destination, source)
+    }
+
+    /// Combine bytes keys with a bitwise OR, storing the result in a
+    /// destination key.
+    ///
+    /// Shorter sources are padded with zeroes and nonexistent sources are
+    /// treated as empty.
+    ///
+    /// Returns the resulting bytes on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("a").bytes([0b1100].as_ref()).await?;
+    /// client.set("b").bytes([0b1010].as_ref()).await?;
+    ///
+    /// assert_eq!([0b1110].as_ref(), client.bit_or("dest", ["a", "b"]).await?.as_slice());
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_or<D: AsRef<[u8]> + Send + Unpin, T: IntoIterator<Item = U>, U: AsRef<[u8]>>(
+        &self,
+        destination: D,
+        sources: T,
+    ) -> BitOr<'_, B, D> {
+        let sources = sources
+            .into_iter()
+            .map(|source| source.as_ref().to_vec())
+            .collect();
+
+        BitOr::new(self.backend(), destination, sources)
+    }
+
+    /// Find the offset of the first set or clear bit of a bytes key.
+    ///
+    /// Returns -1 if no bit matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").bytes([0, 0b0010_0000].as_ref()).await?;
+    ///
+    /// assert_eq!(10, client.bit_position("foo", true).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_position<K: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        bit: bool,
+    ) -> BitPosition<'_, B, K> {
+        BitPosition::new(self.backend(), key, bit)
+    }
+
+    /// Set or clear a bit of a bytes key at an offset, creating the key and
+    /// growing the value as needed.
+    ///
+    /// Returns the previous value of the bit on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// assert!(!client.bit_set("foo", 9, true).await?);
+    /// assert_eq!(2, client.length("foo").bytes().await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_set<K: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        offset: i64,
+        bit: bool,
+    ) -> BitSet<'_, B, K> {
+        BitSet::new(self.backend(), key, offset, bit)
+    }
+
+    /// Combine bytes keys with a bitwise XOR, storing the result in a
+    /// destination key.
+    ///
+    /// Shorter sources are padded with zeroes and nonexistent sources are
+    /// treated as empty.
+    ///
+    /// Returns the resulting bytes on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("a").bytes([0b1100].as_ref()).await?;
+    /// client.set("b").bytes([0b1010].as_ref()).await?;
+    ///
+    /// assert_eq!([0b0110].as_ref(), client.bit_xor("dest", ["a", "b"]).await?.as_slice());
+    /// # Ok(()) }
+    /// ```
+    pub fn bit_xor<D: AsRef<[u8]> + Send + Unpin, T: IntoIterator<Item = U>, U: AsRef<[u8]>>(
+        &self,
+        destination: D,
+        sources: T,
+    ) -> BitXor<'_, B, D> {
+        let sources = sources
+            .into_iter()
+            .map(|source| source.as_ref().to_vec())
+            .collect();
+
+        BitXor::new(self.backend(), destination, sources)
+    }
+
+    /// Clamp an existing integer or float key to an inclusive range.
+    ///
+    /// The bounds must be the same type as the key, and the minimum must not
+    /// be greater than the maximum.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(15).await?;
+    ///
+    /// assert_eq!(Some(&10), client.clamp("foo", 0, 10).await?.as_integer_ref());
+    /// # Ok(()) }
+    /// ```
+    pub fn clamp<K: AsRef<[u8]> + Send + Unpin, V: Into<Value>>(
+        &self,
+        key: K,
+        min: V,
+        max: V,
+    ) -> Clamp<'_, B, K> {
+        Clamp::new(self.backend(), key, min.into(), max.into())
+    }
+
+    /// Kill a session connected to the instance by its ID, closing its
+    /// connection and removing its subscriptions.
+    ///
+    /// Returns whether the session existed. Session IDs can be retrieved with
+    /// [`client_list`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// assert!(!client.client_kill(5).await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`client_list`]: #method.client_list
+    pub fn client_kill(&self, id: u32) -> ClientKill<'_, B> {
+        ClientKill::new(self.backend(), id)
+    }
+
+    /// List the sessions connected to the instance, in order of their IDs.
+    ///
+    /// The memory backend isn't connected to, so it has no sessions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// for session in client.client_list().await? {
+    ///     println!("{} connected from {:?}", session.id(), session.peer());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn client_list(&self) -> ClientList<'_, B> {
+        ClientList::new(self.backend())
+    }
+
+    /// Retrieve the values of configuration parameters of the instance by
+    /// name, or of every parameter if no names are given.
+    ///
+    /// Values are formatted as they're accepted by [`config_set`], where
+    /// limits that aren't set are `none`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// let config = client.config_get(["max_memory"]).await?;
+    ///
+    /// assert_eq!(
+    ///     Some(b"none".as_ref()),
+    ///     config.get(b"max_memory".as_ref()).as_deref().map(Vec::as_slice),
+    /// );
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`config_set`]: #method.config_set
+    pub fn config_get<T: IntoIterator<Item = U>, U: AsRef<[u8]>>(
+        &self,
+        names: T,
+    ) -> ConfigGet<'_, B> {
+        let names = names
+            .into_iter()
+            .map(|name| name.as_ref().to_vec())
+            .collect();
+
+        ConfigGet::new(self.backend(), names)
+    }
+
+    /// Change the value of a configuration parameter of the instance while
+    /// it's running.
+    ///
+    /// The limits, including the maximum number of active sessions, eviction
+    /// policy, log level and slow command threshold can be changed. Limits are
+    /// numbers, or `none` to remove the limit.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// assert_eq!("lru", client.config_set("eviction_policy", "lru").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn config_set<N: AsRef<[u8]> + Send + Unpin, V: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        name: N,
+        value: V,
+    ) -> ConfigSet<'_, B, N, V> {
+        ConfigSet::new(self.backend(), name, value)
+    }
+
+    /// Copy the value of a key to another key, if the other key doesn't
+    /// already exist.
+    ///
+    /// The value is deep-cloned, so changes to either key don't affect the
+    /// other. Use [`Copy::replace`] to overwrite the destination key if it
+    /// exists.
+    ///
+    /// Returns the destination key on success as a confirmation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(1).await?;
+    /// client.copy("foo", "bar").await?;
+    /// client.increment("bar").int().await?;
+    ///
+    /// assert_eq!(1, client.get("foo").int().await?);
+    /// assert_eq!(2, client.get("bar").int().await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Copy::replace`]: request/struct.Copy.html#method.replace
+    pub fn copy<F: AsRef<[u8]> + Send + Unpin, T: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        from: F,
+        to: T,
+    ) -> Copy<'_, B, F, T> {
+        Copy::new(self.backend(), from, to)
+    }
+
+    /// Decrements a float or integer key by one.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// If the key does not exist, an integer key is created with a value of 0
+    /// and then decremented by 1, resulting in the value being -1.
+    ///
+    /// This is an `O(1)` time complexity operation.
+    pub fn decrement<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Decrement<'_, B, K> {
+        Decrement::new(self.backend(), key)
+    }
+
+    /// Delete a key by its name if it exists.
+    ///
+    /// Returns the deleted key name on success as a confirmation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// assert_eq!(1, client.increment("foo").int().await?);
+    /// assert!(client.delete("foo").await.is_ok());
+    ///
+    /// // since the key doesn't exist anymore, incrementing it again will
+    /// // result in a value of 1 again
+    /// assert_eq!(1, client.increment("foo").int().await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn delete<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Delete<'_, B, K> {
+        Delete::new(self.backend(), key)
+    }
+
+    /// Divide an integer or float key by an amount, creating the key with a
+    /// value of zero if it doesn't exist.
+    ///
+    /// Dividing by zero results in an error.
+    ///
+    /// Integer overflow results in an error and leaves the key unchanged.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").float(3.0).await?;
+    ///
+    /// assert_eq!(Some(&1.5), client.divide("foo", 2.0).await?.as_float_ref());
+    /// assert!(client.divide("foo", 0.0).await.is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn divide<K: AsRef<[u8]> + Send + Unpin, V: Into<Value>>(
+        &self,
+        key: K,
+        value: V,
+    ) -> Divide<'_, B, K> {
+        Divide::new(self.backend(), key, value.into())
+    }
+
+    /// Append values to the end of an array at a path within a document key.
+    ///
+    /// Resolves to the new length of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, Document};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// let document = r#"{"tags":["a"]}"#.parse::<Document>().unwrap();
+    /// client.document_set("post", "$", document).await?;
+    ///
+    /// assert_eq!(3, client.document_append("post", "tags", vec![Document::from("b"), Document::from("c")]).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn document_append<
+        K: AsRef<[u8]> + Send + Unpin,
+        P: AsRef<[u8]> + Send + Unpin,
+        T: IntoIterator<Item = Document>,
+    >(
+        &self,
+        key: K,
+        path: P,
+        values: T,
+    ) -> DocumentAppend<'_, B, K, P> {
+        DocumentAppend::new(self.backend(), key, path, values.into_iter().collect())
+    }
+
+    /// Delete the part of a document key at a path.
+    ///
+    /// Deleting the root path `$` deletes the whole key. Resolves to the number
+    /// of deleted parts, which is either 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, Document};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// let document = r#"{"name":"alice","age":30}"#.parse::<Document>().unwrap();
+    /// client.document_set("user", "$", document).await?;
+    ///
+    /// assert_eq!(1, client.document_delete("user", "age").await?);
+    /// assert_eq!(0, client.document_delete("user", "age").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn document_delete<K: AsRef<[u8]> + Send + Unpin, P: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        path: P,
+    ) -> DocumentDelete<'_, B, K, P> {
+        DocumentDelete::new(self.backend(), key, path)
+    }
+
+    /// Retrieve the part of a document key at a path.
+    ///
+    /// Paths are fields separated by periods with array indexes in brackets,
+    /// such as `user.visits[0]`; refer to [`DocumentPath`] for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, Document};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// let document = r#"{"user":{"visits":[1,2]}}"#.parse::<Document>().unwrap();
+    /// client.document_set("session", "$", document).await?;
+    ///
+    /// assert_eq!(Document::Integer(2), client.document_get("session", "user.visits[-1]").await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`DocumentPath`]: struct.DocumentPath.html
+    pub fn document_get<K: AsRef<[u8]> + Send + Unpin, P: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        path: P,
+    ) -> DocumentGet<'_, B, K, P> {
+        DocumentGet::new(self.backend(), key, path)
+    }
+
+    /// Increment a number at a path within a document key by an integer or
+    /// float amount.
+    ///
+    /// Resolves to the new number. Incrementing an integer by a float results
+    /// in a float.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, Document};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// let document = r#"{"stats":{"views":1}}"#.parse::<Document>().unwrap();
+    /// client.document_set("page", "$", document).await?;
+    ///
+    /// assert_eq!(Document::Integer(3), client.document_increment("page", "stats.views", 2).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn document_increment<K: AsRef<[u8]> + Send + Unpin, P: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        path: P,
+        amount: impl Into<Document>,
+    ) -> DocumentIncrement<'_, B, K, P> {
+        DocumentIncrement::new(self.backend(), key, path, amount.into())
+    }
+
+    /// Set the part of a document key at a path, replacing anything already
+    /// there.
+    ///
+    /// Setting the root path `$` creates the key if it doesn't exist. Other
+    /// paths require the key and the parent of the path to exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, Document};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.document_set("user", "$", r#"{"name":"alice"}"#.parse::<Document>().unwrap()).await?;
+    /// client.document_set("user", "name", Document::from("bob")).await?;
+    ///
+    /// let user = client.document_get("user", "$").await?;
+    /// assert_eq!(r#"{"name":"bob"}"#, user.to_string());
+    /// # Ok(()) }
+    /// ```
+    pub fn document_set<K: AsRef<[u8]> + Send + Unpin, P: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        path: P,
+        value: Document,
+    ) -> DocumentSet<'_, B, K, P> {
+        DocumentSet::new(self.backend(), key, path, value)
+    }
+
+    /// Serialize the value of a key into a portable form.
+    ///
+    /// The serialized form is versioned and checksummed, and can be restored
+    /// into another instance with [`restore`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(1).await?;
+    ///
+    /// let dump = client.dump("foo").await?;
+    /// let other = Client::memory();
+    /// other.restore("foo", dump).await?;
+    ///
+    /// assert_eq!(1, other.get("foo").int().await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`restore`]: #method.restore
+    pub fn dump<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Dump<'_, B, K> {
+        Dump::new(self.backend(), key)
+    }
+
+    /// Echos the provided content back at you.
+    ///
+    /// Returns the input content.
+    pub fn echo<K: AsRef<[u8]> + Send + Unpin>(&self, content: K) -> Echo<'_, B, K> {
+        Echo::new(self.backend(), content)
+    }
+
+    /// Check whether a bytes or string key ends with a suffix.
+    ///
+    /// Returns whether the value ends with the suffix on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").string("hopdb").await?;
+    ///
+    /// assert!(client.ends_with("foo", "db").str().await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn ends_with<K: AsRef<[u8]> + Send + Unpin, S: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        suffix: S,
+    ) -> EndsWith<'_, B, K, S> {
+        EndsWith::new(self.backend(), key, suffix)
+    }
+
+    /// Check if one or more keys exist.
+    ///
+    /// Returns `true` if all of the keys exist, or `false` if at least one of
+    /// them does not.
+    ///
+    /// Refer to the documentation for the [`Exists`] request for more
+    /// information on how to use the request struct returned by this method.
+    ///
+    /// This is an `O(n)` time complexity operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// // "foo" doesn't exist
+    /// assert!(!client.exists().key("foo").await?);
+    /// client.increment("foo").await?;
+    ///
+    /// // and now it does
+    /// assert!(client.exists().key("foo").await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Exists`]: request/exists/struct.Exists.html
+    pub fn exists(&self) -> Exists<B> {
+        Exists::new(self.backend())
+    }
+
+    /// Remove every key in the selected namespace.
+    ///
+    /// Subscribers of the removed keys are notified of their deletion. Use
+    /// [`Flush::background`] to respond as soon as the keys have been removed
+    /// and drop them in the background.
+    ///
+    /// Returns the number of removed keys on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(1).await?;
+    /// client.set("bar").int(2).await?;
+    ///
+    /// assert_eq!(2, client.flush().await?);
+    /// assert!(!client.exists().key("foo").await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Flush::background`]: request/struct.Flush.html#method.background
+    pub fn flush(&self) -> Flush<'_, B> {
+        Flush::new(self.backend())
+    }
+
+    /// Remove every key in every namespace.
+    ///
+    /// This works like [`flush`], but isn't limited to the selected namespace.
+    ///
+    /// Returns the number of removed keys on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(1).await?;
+    /// client.select(1).await?;
+    /// client.set("foo").int(2).await?;
+    ///
+    /// assert_eq!(2, client.flush_all().background().await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`flush`]: #method.flush
+    pub fn flush_all(&self) -> FlushAll<'_, B> {
+        FlushAll::new(self.backend())
+    }
+
+    /// Remove every key starting with a prefix in the selected namespace.
+    ///
+    /// This works like [`flush`], but only removes the keys with the prefix.
+    ///
+    /// Returns the number of removed keys on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("user:1").int(1).await?;
+    /// client.set("post:1").int(2).await?;
+    ///
+    /// assert_eq!(1, client.flush_prefix("user:").await?);
+    /// assert!(client.exists().key("post:1").await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`flush`]: #method.flush
+    pub fn flush_prefix<P: AsRef<[u8]> + Send + Unpin>(&self, prefix: P) -> FlushPrefix<'_, B, P> {
+        FlushPrefix::new(self.backend(), prefix)
+    }
+
+    /// Get a key's value.
+    ///
+    /// The returned request struct, [`GetUnconfigured`] can be `await`ed or can
+    /// be called with the type-specific methods if you know the type of the
+    /// key. If you call one of these methods, the type that `await`ing the
+    /// struct resolves to will be more fine-grained.
+    ///
+    /// Refer to [`GetUnconfigured`] for more information and available methods.
+    ///
+    /// # Examples
+    ///
+    /// Get the "foo" key as an integer, since we know it is one:
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// client.set("foo").int(123).await?;
+    ///
+    /// assert_eq!(123, client.get("foo").int().await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// If we didn't know the type of the key, we could match on a generic Value
+    /// enum:
+    ///
+    /// ```
+    /// use hop::{Client, Value};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// client.set("foo").string("this is a string").await?;
+    ///
+    /// match client.get("foo").await? {
+    ///     Value::Boolean(boolean) => println!("it's a boolean: {}", boolean),
+    ///     Value::Integer(int) => println!("it's an integer: {}", int),
+    ///     Value::String(string) => println!("it's a string: {}", string),
+    ///     _ => println!("it's something else"),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`GetUnconfigured`]: request/get/struct.GetUnconfigured.html
+    pub fn get<'a, K: AsRef<[u8]> + Send + Unpin + 'a>(&self, key: K) -> GetUnconfigured<'a, B, K> {
+        GetUnconfigured::new(self.backend(), key)
+    }
+
+    /// Add items to a HyperLogLog key, creating it if it doesn't exist.
+    ///
+    /// Resolves to whether the estimated number of unique items may have
+    /// changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// assert!(client.hyper_log_log_add("visitors", &["alice", "bob"]).await?);
+    /// assert!(!client.hyper_log_log_add("visitors", &["alice"]).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn hyper_log_log_add<
+        K: AsRef<[u8]> + Send + Unpin,
+        T: IntoIterator<Item = U>,
+        U: AsRef<[u8]>,
+    >(
+        &self,
+        key: K,
+        items: T,
+    ) -> HyperLogLogAdd<'_, B, K> {
+        let items = items
+            .into_iter()
+            .map(|item| item.as_ref().to_vec())
+            .collect();
+
+        HyperLogLogAdd::new(self.backend(), key, items)
+    }
+
+    /// Estimate the number of unique items added to the union of HyperLogLog
+    /// keys.
+    ///
+    /// Nonexistent keys are treated as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.hyper_log_log_add("monday", &["alice", "bob"]).await?;
+    /// client.hyper_log_log_add("tuesday", &["bob", "carol"]).await?;
+    ///
+    /// assert_eq!(2, client.hyper_log_log_count(&["monday"]).await?);
+    /// assert_eq!(3, client.hyper_log_log_count(&["monday", "tuesday"]).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn hyper_log_log_count<T: IntoIterator<Item = U>, U: AsRef<[u8]>>(
+        &self,
+        keys: T,
+    ) -> HyperLogLogCount<'_, B> {
+        let keys = keys.into_iter().map(|key| key.as_ref().to_vec()).collect();
+
+        HyperLogLogCount::new(self.backend(), keys)
+    }
+
+    /// Merge HyperLogLog source keys into a destination key, keeping the
+    /// destination's existing items.
+    ///
+    /// Resolves to the estimated number of unique items in the destination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.hyper_log_log_add("monday", &["alice", "bob"]).await?;
+    /// client.hyper_log_log_add("tuesday", &["bob", "carol"]).await?;
+    ///
+    /// assert_eq!(3, client.hyper_log_log_merge("week", &["monday", "tuesday"]).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn hyper_log_log_merge<
+        D: AsRef<[u8]> + Send + Unpin,
+        T: IntoIterator<Item = U>,
+        U: AsRef<[u8]>,
+    >(
+        &self,
+        destination: D,
+        sources: T,
+    ) -> HyperLogLogMerge<'_, B, D> {
+        let sources = sources
+            .into_iter()
+            .map(|source| source.as_ref().to_vec())
+            .collect();
+
+        HyperLogLogMerge::new(self.backend(), destination, sources)
+    }
+
+    /// Increments a float or integer key by one.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// If the key does not exist, an integer key is created with a value of 0
+    /// and then incremented by 1, resulting in the value being 1.
+    ///
+    /// This is an `O(1)` time complexity operation.
+    ///
+    /// # Examples
+    ///
+    /// Increment a nonexistent key, which will set it to 0 and then increment
+    /// it:
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// println!("New value: {}", client.increment("foo").int().await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// Increment the key "foo" by the integer 2 and then 4:
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// assert_eq!(2, client.increment("foo").int().by(2).await?);
+    /// assert_eq!(6, client.increment("foo").int().by(4).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn increment<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Increment<'_, B, K> {
+        Increment::new(self.backend(), key)
+    }
+
+    /// Check if one or more keys is a specified key type.
+    ///
+    /// Returns `true` if all of the keys both exist and are the specified key
+    /// type, or `false` if at least one of the keys does not exist or is of a
+    /// different key type.
+    ///
+    /// Refer to the documentation for the [`Is`] request for more
+    /// information on how to use the request struct returned by this method.
+    ///
+    /// This is an `O(n)` time complexity operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, KeyType};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    ///
+    /// // make a key named "foo" that is an integer
+    /// client.set("foo").int(123).await?;
+    ///
+    /// // check if "foo" is an integer, which it is:
+    /// assert!(client.is(KeyType::Integer).key("foo").await?);
+    ///
+    /// // now make a key named "bar" that is a float
+    /// client.set("bar").float(1.23).await?;
+    ///
+    /// // now if we check that both are an integer, we'll get back false, since
+    /// // "foo" is an integer but "bar" is not
+    /// assert!(!client.is(KeyType::Integer).keys(&["foo", "bar"])?.await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Exists`]: request/exists/struct.Exists.html
+    pub fn is(&self, key_type: KeyType) -> Is<B> {
+        Is::new(self.backend(), key_type)
+    }
+
+    /// Retrieve the type of a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, KeyType};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(123).await?;
+    ///
+    /// assert_eq!(KeyType::Integer, client.key_type("foo").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn key_type<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Type<'_, B, K> {
+        Type::new(self.backend(), key)
+    }
+
+    /// Retrieve a list of the keys of a map.
+    ///
+    /// Returns the list of keys on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").map([(b"key".to_vec(), b"value".to_vec())].to_vec()).await?;
+    ///
+    /// assert_eq!([b"key".to_vec()].to_vec(), client.keys("foo").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn keys<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Keys<'_, B, K> {
+        Keys::new(self.backend(), key)
+    }
+
+    /// Retrieve the length of a key's value.
+    ///
+    /// This command only works with bytes, lists, and strings by default. If
+    /// you want to specify to only retrieve the length if the key is of a
+    /// certain type, use the methods on the returned [`Length`] request struct.
+    ///
+    /// Returns the value's length on success.
+    ///
+    /// # Examples
+    ///
+    /// Get the length of a list key:
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").list([b"foo".to_vec(), b"bar".to_vec()].to_vec()).await?;
+    ///
+    /// assert_eq!(2, client.length("foo").await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// Get the length of a key, but *only* if it is a string:
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").string("this is a string").await?;
+    ///
+    /// assert_eq!(16, client.length("foo").str().await?);
+    ///
+    /// // if you try to get the length of a string key and specify list, it
+    /// // will error:
+    /// assert!(client.length("foo").list().await.is_err());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Length`]: request/struct.Length.html
+    pub fn length<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Length<'_, B, K> {
+        Length::new(self.backend(), key)
+    }
+
+    /// Convert a string key to lowercase in place.
+    ///
+    /// Returns the new string on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").string("HopDB").await?;
+    ///
+    /// assert_eq!("hopdb", client.lowercase("foo").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn lowercase<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Lowercase<'_, B, K> {
+        Lowercase::new(self.backend(), key)
+    }
+
+    /// Retrieve the estimated number of bytes of memory used by a key and its
+    /// value.
+    ///
+    /// The estimate includes the key itself and the bookkeeping of its entry.
+    /// Large collections are estimated from a sample of their elements, so the
+    /// result is approximate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").bytes(vec![0; 1024]).await?;
+    ///
+    /// assert!(client.memory_usage("foo").await? > 1024);
+    /// # Ok(()) }
+    /// ```
+    pub fn memory_usage<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> MemoryUsage<'_, B, K> {
+        MemoryUsage::new(self.backend(), key)
+    }
+
+    /// Set an integer or float key to the remainder of dividing it by an
+    /// amount, creating the key with a value of zero if it doesn't exist.
+    ///
+    /// Dividing by zero results in an error.
+    ///
+    /// Integer overflow results in an error and leaves the key unchanged.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(7).await?;
+    ///
+    /// assert_eq!(Some(&3), client.modulo("foo", 4).await?.as_integer_ref());
+    /// # Ok(()) }
+    /// ```
+    pub fn modulo<K: AsRef<[u8]> + Send + Unpin, V: Into<Value>>(
+        &self,
+        key: K,
+        value: V,
+    ) -> Modulo<'_, B, K> {
+        Modulo::new(self.backend(), key, value.into())
+    }
+
+    /// Multiply an integer or float key by an amount, creating the key with
+    /// a value of zero if it doesn't exist.
+    ///
+    /// Integer overflow results in an error and leaves the key unchanged.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(4).await?;
+    ///
+    /// assert_eq!(Some(&12), client.multiply("foo", 3).await?.as_integer_ref());
+    /// # Ok(()) }
+    /// ```
+    pub fn multiply<K: AsRef<[u8]> + Send + Unpin, V: Into<Value>>(
+        &self,
+        key: K,
+        value: V,
+    ) -> Multiply<'_, B, K> {
+        Multiply::new(self.backend(), key, value.into())
+    }
+
+    /// Retrieve information about a key and how it has been accessed, such as
+    /// its type, estimated memory usage, and when it was created and last
+    /// accessed.
+    ///
+    /// Retrieving the information doesn't count as an access of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::{Client, KeyType};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(1).await?;
+    /// client.get("foo").await?;
+    ///
+    /// let info = client.object_info("foo").await?;
+    /// assert_eq!(KeyType::Integer, info.key_type());
+    /// assert_eq!(2, info.frequency());
+    /// # Ok(()) }
+    /// ```
+    pub fn object_info<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> ObjectInfo<'_, B, K> {
+        ObjectInfo::new(self.backend(), key)
+    }
+
+    /// Rename a key to a new key name, if the new key name doesn't already
+    /// exist.
+    ///
+    /// Use [`Rename::replace`] to overwrite the new key name if it exists.
+    ///
+    /// Returns the new key value on success as a confirmation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.increment("foo").int().await?;
+    /// println!("New key name: {:?}", client.rename("foo", "bar").await?);
+    /// println!("New incremented value: {}", client.increment("foo").int().await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Rename::replace`]: request/struct.Rename.html#method.replace
+    pub fn rename<F: AsRef<[u8]> + Send + Unpin, T: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        from: F,
+        to: T,
+    ) -> Rename<'_, B, F, T> {
+        Rename::new(self.backend(), from, to)
+    }
+
+    /// Recreate a key from the serialized form created by [`dump`].
+    ///
+    /// Returns the name of the key on success. By default the key must not
+    /// already exist; use [`Restore::replace`] to overwrite it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(1).await?;
+    /// let dump = client.dump("foo").await?;
+    ///
+    /// client.increment("foo").int().await?;
+    /// client.restore("foo", dump).replace().await?;
+    ///
+    /// assert_eq!(1, client.get("foo").int().await?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Restore::replace`]: request/struct.Restore.html#method.replace
+    /// [`dump`]: #method.dump
+    pub fn restore<K: AsRef<[u8]> + Send + Unpin, D: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        dump: D,
+    ) -> Restore<'_, B, K, D> {
+        Restore::new(self.backend(), key, dump)
+    }
+
+    /// Iterate over the keys in the database.
+    ///
+    /// Returns a stream of keys, retrieved from the backend in batches without
+    /// blocking writers for the duration of the scan. Use the methods on the
+    /// returned [`Scan`] stream to only yield keys matching a glob pattern or
+    /// of a certain type. Only keys that the authenticated user can access are
+    /// yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop::Client;
+    /// use tokio::stream::StreamExt;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("user:1").int(1).await?;
+    /// client.set("user:2").int(2).await?;
+    /// client.set("post:1").int(3).await?;
+    ///
+    /// let mut keys = client
+    ///     .scan()
+    ///     .pattern("user:*")
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .await?;
+    /// keys.sort();
+    ///
+    /// assert_eq!(vec![b"user:1".to_vec(), b"user:2".to_vec()], keys);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Scan`]: request/struct.Scan.html
+    pub fn scan(&self) -> Scan<'_, B> {
+        Scan::new(self.backend())
+    }
+
+    /// Select the namespace that the session works with.
+    ///
+    /// Each namespace has its own independent set of keys, so services sharing
+    /// a Hop instance can use separate namespaces to avoid their keys
+    /// colliding. Namespaces are numbered from 0, which is selected by default.
+    ///
+    /// Returns the number of the selected namespace on success. The request
+    /// fails if the namespace doesn't exist.
     ///
     /// # Examples
     ///
-    /// Append to a string:
-    ///
-    /// ```rust
-    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// ```
     /// use hop::Client;
     ///
-    /// let mut client = Client::memory();
-    ///
-    /// client.set("foo").str("this is").await?;
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.set("foo").int(1).await?;
     ///
-    /// assert_eq!("this is a string", client.append("foo").str(" a string").await?);
+    /// assert_eq!(1, client.select(1).await?);
+    /// assert!(!client.exists().key("foo").await?);
     /// # Ok(()) }
     /// ```
-    pub fn append<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> AppendUnconfigured<B, K> {
-        AppendUnconfigured::new(self.backend(), key)
+    pub fn select(&self, namespace: i64) -> Select<'_, B> {
+        Select::new(self.backend(), namespace)
     }
 
-    /// Decrements a float or integer key by one.
-    ///
-    /// Returns the new value on success.
-    ///
-    /// If the key does not exist, an integer key is created with a value of 0
-    /// and then decremented by 1, resulting in the value being -1.
+    /// Set a key to a new value, overriding it regardless of whether it exists
+    /// and its current key type.
     ///
-    /// This is an `O(1)` time complexity operation.
-    pub fn decrement<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Decrement<'_, B, K> {
-        Decrement::new(self.backend(), key)
-    }
-
-    /// Delete a key by its name if it exists.
+    /// Returns the new value on success as confirmation.
     ///
-    /// Returns the deleted key name on success as a confirmation.
+    /// Refer to [`SetUnconfigured`] for more information and available methods.
     ///
     /// # Examples
     ///
+    /// Set the key "foo" to the integer `123`, and then set "foo" to the string
+    /// "bar".
+    ///
     /// ```
     /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// assert_eq!(1, client.increment("foo").int().await?);
-    /// assert!(client.delete("foo").await.is_ok());
+    /// assert_eq!(123, client.set("foo").int(123).await?);
     ///
-    /// // since the key doesn't exist anymore, incrementing it again will
-    /// // result in a value of 1 again
-    /// assert_eq!(1, client.increment("foo").int().await?);
+    /// assert_eq!("bar", client.set("foo").string("bar").await?);
     /// # Ok(()) }
     /// ```
-    pub fn delete<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Delete<'_, B, K> {
-        Delete::new(self.backend(), key)
-    }
-
-    /// Echos the provided content back at you.
     ///
-    /// Returns the input content.
-    pub fn echo<K: AsRef<[u8]> + Send + Unpin>(&self, content: K) -> Echo<'_, B, K> {
-        Echo::new(self.backend(), content)
+    /// [`SetUnconfigured`]: request/set/struct.SetUnconfigured.html
+    pub fn set<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> SetUnconfigured<B, K> {
+        SetUnconfigured::new(self.backend(), key)
     }
 
-    /// Check if one or more keys exist.
-    ///
-    /// Returns `true` if all of the keys exist, or `false` if at least one of
-    /// them does not.
+    /// Set an integer or float key to a value if the key doesn't exist or if
+    /// the value is greater than the current value.
     ///
-    /// Refer to the documentation for the [`Exists`] request for more
-    /// information on how to use the request struct returned by this method.
+    /// Integer overflow results in an error and leaves the key unchanged.
     ///
-    /// This is an `O(n)` time complexity operation.
+    /// Returns the new value on success.
     ///
     /// # Examples
     ///
@@ -167,287 +1668,361 @@ impl<B: Backend> Client<B> {
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// // "foo" doesn't exist
-    /// assert!(!client.exists().key("foo").await?);
-    /// client.increment("foo").await?;
+    /// client.set("foo").int(5).await?;
     ///
-    /// // and now it does
-    /// assert!(client.exists().key("foo").await?);
+    /// assert_eq!(Some(&5), client.set_if_greater("foo", 3).await?.as_integer_ref());
+    /// assert_eq!(Some(&8), client.set_if_greater("foo", 8).await?.as_integer_ref());
     /// # Ok(()) }
     /// ```
-    ///
-    /// [`Exists`]: request/exists/struct.Exists.html
-    pub fn exists(&self) -> Exists<B> {
-        Exists::new(self.backend())
+    pub fn set_if_greater<K: AsRef<[u8]> + Send + Unpin, V: Into<Value>>(
+        &self,
+        key: K,
+        value: V,
+    ) -> SetIfGreater<'_, B, K> {
+        SetIfGreater::new(self.backend(), key, value.into())
     }
 
-    /// Get a key's value.
+    /// Set an integer or float key to a value if the key doesn't exist or if
+    /// the value is less than the current value.
     ///
-    /// The returned request struct, [`GetUnconfigured`] can be `await`ed or can
-    /// be called with the type-specific methods if you know the type of the
-    /// key. If you call one of these methods, the type that `await`ing the
-    /// struct resolves to will be more fine-grained.
+    /// Integer overflow results in an error and leaves the key unchanged.
     ///
-    /// Refer to [`GetUnconfigured`] for more information and available methods.
+    /// Returns the new value on success.
     ///
     /// # Examples
     ///
-    /// Get the "foo" key as an integer, since we know it is one:
-    ///
     /// ```
     /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
+    /// client.set("foo").int(5).await?;
     ///
-    /// client.set("foo").int(123).await?;
-    ///
-    /// assert_eq!(123, client.get("foo").int().await?);
+    /// assert_eq!(Some(&3), client.set_if_less("foo", 3).await?.as_integer_ref());
     /// # Ok(()) }
     /// ```
+    pub fn set_if_less<K: AsRef<[u8]> + Send + Unpin, V: Into<Value>>(
+        &self,
+        key: K,
+        value: V,
+    ) -> SetIfLess<'_, B, K> {
+        SetIfLess::new(self.backend(), key, value.into())
+    }
+
+    /// Overwrite part of a bytes or string key starting at an offset, creating
+    /// the key if it doesn't exist.
     ///
-    /// If we didn't know the type of the key, we could match on a generic Value
-    /// enum:
+    /// The offset is in bytes for bytes and in characters for strings. If the
+    /// offset is past the end of the value then the value is padded with null
+    /// bytes or characters.
+    ///
+    /// Returns the new value on success.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use hop::{Client, Value};
+    /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
+    /// client.set("foo").string("hello world").await?;
+    /// client.set_range("foo", 6, "there".to_owned()).await?;
     ///
-    /// client.set("foo").string("this is a string").await?;
-    ///
-    /// match client.get("foo").await? {
-    ///     Value::Boolean(boolean) => println!("it's a boolean: {}", boolean),
-    ///     Value::Integer(int) => println!("it's an integer: {}", int),
-    ///     Value::String(string) => println!("it's a string: {}", string),
-    ///     _ => println!("it's something else"),
-    /// }
+    /// assert_eq!("hello there", client.get("foo").string().await?);
     /// # Ok(()) }
     /// ```
-    ///
-    /// [`GetUnconfigured`]: request/get/struct.GetUnconfigured.html
-    pub fn get<'a, K: AsRef<[u8]> + Send + Unpin + 'a>(&self, key: K) -> GetUnconfigured<'a, B, K> {
-        GetUnconfigured::new(self.backend(), key)
+    pub fn set_range<K: AsRef<[u8]> + Send + Unpin, V: Into<Value>>(
+        &self,
+        key: K,
+        offset: i64,
+        value: V,
+    ) -> SetRange<'_, B, K> {
+        SetRange::new(self.backend(), key, offset, value.into())
     }
 
-    /// Increments a float or integer key by one.
+    /// Retrieve part of a bytes or string key by byte offsets.
     ///
-    /// Returns the new value on success.
-    ///
-    /// If the key does not exist, an integer key is created with a value of 0
-    /// and then incremented by 1, resulting in the value being 1.
+    /// The end offset is exclusive, and negative offsets count backwards from
+    /// the end of the value. If the offsets of a string don't lie on character
+    /// boundaries then the request will error.
     ///
-    /// This is an `O(1)` time complexity operation.
+    /// Returns the retrieved part of the value on success.
     ///
     /// # Examples
     ///
-    /// Increment a nonexistent key, which will set it to 0 and then increment
-    /// it:
-    ///
     /// ```
     /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// println!("New value: {}", client.increment("foo").int().await?);
+    /// client.set("foo").bytes([1, 2, 3, 4].as_ref()).await?;
+    ///
+    /// let slice = client.slice("foo", 1).end(3).await?;
+    /// assert_eq!(Some([2, 3].as_ref()), slice.as_bytes_ref());
     /// # Ok(()) }
     /// ```
+    pub fn slice<K: AsRef<[u8]> + Send + Unpin>(&self, key: K, start: i64) -> Slice<'_, B, K> {
+        Slice::new(self.backend(), key, start)
+    }
+
+    /// Split a string key into a list by a separator.
     ///
-    /// Increment the key "foo" by the integer 2 and then 4:
+    /// The key itself isn't modified.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// assert_eq!(2, client.increment("foo").int().by(2).await?);
-    /// assert_eq!(6, client.increment("foo").int().by(4).await?);
+    /// client.set("foo").string("a,b").await?;
+    ///
+    /// assert_eq!([b"a".to_vec(), b"b".to_vec()].to_vec(), client.split("foo", ",").await?);
     /// # Ok(()) }
     /// ```
-    pub fn increment<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Increment<'_, B, K> {
-        Increment::new(self.backend(), key)
+    pub fn split<K: AsRef<[u8]> + Send + Unpin, S: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        separator: S,
+    ) -> Split<'_, B, K, S> {
+        Split::new(self.backend(), key, separator)
     }
 
-    /// Check if one or more keys is a specified key type.
-    ///
-    /// Returns `true` if all of the keys both exist and are the specified key
-    /// type, or `false` if at least one of the keys does not exist or is of a
-    /// different key type.
-    ///
-    /// Refer to the documentation for the [`Is`] request for more
-    /// information on how to use the request struct returned by this method.
+    /// Check whether a bytes or string key starts with a prefix.
     ///
-    /// This is an `O(n)` time complexity operation.
+    /// Returns whether the value starts with the prefix on success.
     ///
     /// # Examples
     ///
     /// ```
-    /// use hop::{Client, KeyType};
+    /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
+    /// client.set("foo").string("hopdb").await?;
     ///
-    /// // make a key named "foo" that is an integer
-    /// client.set("foo").int(123).await?;
+    /// assert!(client.starts_with("foo", "hop").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn starts_with<K: AsRef<[u8]> + Send + Unpin, P: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        prefix: P,
+    ) -> StartsWith<'_, B, K, P> {
+        StartsWith::new(self.backend(), key, prefix)
+    }
+
+    /// Retrieve statistics about the current runtime of Hop.
     ///
-    /// // check if "foo" is an integer, which it is:
-    /// assert!(client.is(KeyType::Integer).key("foo").await?);
+    /// When Hop is restarted, many of the statistics - like commands run - are
+    /// reset.
     ///
-    /// // now make a key named "bar" that is a float
-    /// client.set("bar").float(1.23).await?;
+    /// # Examples
     ///
-    /// // now if we check that both are an integer, we'll get back false, since
-    /// // "foo" is an integer but "bar" is not
-    /// assert!(!client.is(KeyType::Integer).keys(&["foo", "bar"])?.await?);
-    /// # Ok(()) }
     /// ```
+    /// use hop::Client;
     ///
-    /// [`Exists`]: request/exists/struct.Exists.html
-    pub fn is(&self, key_type: KeyType) -> Is<B> {
-        Is::new(self.backend(), key_type)
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// let stats = client.stats().await?;
+    /// println!("Successful commands: {}", stats.commands_successful());
+    /// # Ok(()) }
+    /// ```
+    pub fn stats(&self) -> Stats<'_, B> {
+        Stats::new(self.backend())
     }
 
-    /// Retrieve the type of a key.
+    /// Append an entry of field-value pairs to a stream key, creating the key
+    /// if it doesn't exist.
+    ///
+    /// Resolves to the ID of the new entry. IDs are generated from the
+    /// current time unless one is given via [`StreamAppend::id`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use hop::{Client, KeyType};
+    /// use hop::{Client, StreamId};
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// client.set("foo").int(123).await?;
     ///
-    /// assert_eq!(KeyType::Integer, client.key_type("foo").await?);
+    /// let id = client
+    ///     .stream_append("events", [("user", "alice")])
+    ///     .id(StreamId::new(5, 0))
+    ///     .await?;
+    /// assert_eq!("5-0", id.to_string());
     /// # Ok(()) }
     /// ```
-    pub fn key_type<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Type<'_, B, K> {
-        Type::new(self.backend(), key)
+    ///
+    /// [`StreamAppend::id`]: request/struct.StreamAppend.html#method.id
+    pub fn stream_append<
+        K: AsRef<[u8]> + Send + Unpin,
+        T: IntoIterator<Item = (F, V)>,
+        F: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    >(
+        &self,
+        key: K,
+        fields: T,
+    ) -> StreamAppend<'_, B, K> {
+        let fields = fields
+            .into_iter()
+            .map(|(field, value)| (field.as_ref().to_vec(), value.as_ref().to_vec()))
+            .collect();
+
+        StreamAppend::new(self.backend(), key, fields)
     }
 
-    /// Retrieve a list of the keys of a map.
+    /// Retrieve the entries of a stream key, optionally within an inclusive
+    /// range of IDs.
     ///
-    /// Returns the list of keys on success.
+    /// Nonexistent keys are treated as empty streams.
     ///
     /// # Examples
     ///
     /// ```
-    /// use hop::Client;
+    /// use hop::{Client, StreamId};
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// client.set("foo").map([(b"key".to_vec(), b"value".to_vec())].to_vec()).await?;
     ///
-    /// assert_eq!([b"key".to_vec()].to_vec(), client.keys("foo").await?);
+    /// for millis in 1..=3 {
+    ///     client
+    ///         .stream_append("events", [("n", millis.to_string())])
+    ///         .id(StreamId::new(millis, 0))
+    ///         .await?;
+    /// }
+    ///
+    /// let range = client.stream_range("events").start(StreamId::new(2, 0)).await?;
+    /// assert_eq!(2, range.len());
     /// # Ok(()) }
     /// ```
-    pub fn keys<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Keys<'_, B, K> {
-        Keys::new(self.backend(), key)
+    pub fn stream_range<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> StreamRange<'_, B, K> {
+        StreamRange::new(self.backend(), key)
     }
 
-    /// Retrieve the length of a key's value.
-    ///
-    /// This command only works with bytes, lists, and strings by default. If
-    /// you want to specify to only retrieve the length if the key is of a
-    /// certain type, use the methods on the returned [`Length`] request struct.
+    /// Read the entries of a stream key that come after an ID.
     ///
-    /// Returns the value's length on success.
+    /// Server instances can wait for new entries via [`StreamRead::block`].
     ///
     /// # Examples
     ///
-    /// Get the length of a list key:
-    ///
     /// ```
-    /// use hop::Client;
+    /// use hop::{Client, StreamId};
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// client.set("foo").list([b"foo".to_vec(), b"bar".to_vec()].to_vec()).await?;
+    /// let id = client.stream_append("events", [("user", "alice")]).await?;
     ///
-    /// assert_eq!(2, client.length("foo").await?);
+    /// assert_eq!(1, client.stream_read("events", StreamId::MIN).await?.len());
+    /// assert!(client.stream_read("events", id).await?.is_empty());
     /// # Ok(()) }
     /// ```
     ///
-    /// Get the length of a key, but *only* if it is a string:
+    /// [`StreamRead::block`]: request/struct.StreamRead.html#method.block
+    pub fn stream_read<K: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        after: StreamId,
+    ) -> StreamRead<'_, B, K> {
+        StreamRead::new(self.backend(), key, after)
+    }
+
+    /// Remove the oldest entries of a stream key until it has at most a
+    /// maximum number of entries.
+    ///
+    /// Resolves to the number of removed entries.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// client.set("foo").string("this is a string").await?;
     ///
-    /// assert_eq!(16, client.length("foo").str().await?);
+    /// for _ in 0..3 {
+    ///     client.stream_append("events", [("user", "alice")]).await?;
+    /// }
     ///
-    /// // if you try to get the length of a string key and specify list, it
-    /// // will error:
-    /// assert!(client.length("foo").list().await.is_err());
+    /// assert_eq!(2, client.stream_trim("events", 1).await?);
     /// # Ok(()) }
     /// ```
-    ///
-    /// [`Length`]: request/struct.Length.html
-    pub fn length<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Length<'_, B, K> {
-        Length::new(self.backend(), key)
+    pub fn stream_trim<K: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        max_length: i64,
+    ) -> StreamTrim<'_, B, K> {
+        StreamTrim::new(self.backend(), key, max_length)
     }
 
-    /// Rename a key to a new key name, if the new key name doesn't already
-    /// exist.
+    /// Remove the entries of a stream key whose IDs are older than a maximum
+    /// age.
     ///
-    /// Returns the new key value on success as a confirmation.
+    /// Resolves to the number of removed entries.
     ///
     /// # Examples
     ///
     /// ```
-    /// use hop::Client;
+    /// use core::time::Duration;
+    /// use hop::{Client, StreamId};
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// client.increment("foo").int().await?;
-    /// println!("New key name: {:?}", client.rename("foo", "bar").await?);
-    /// println!("New incremented value: {}", client.increment("foo").int().await?);
+    /// client
+    ///     .stream_append("events", [("user", "alice")])
+    ///     .id(StreamId::new(1, 0))
+    ///     .await?;
+    ///
+    /// assert_eq!(1, client.stream_trim_age("events", Duration::from_secs(60)).await?);
     /// # Ok(()) }
     /// ```
-    pub fn rename<F: AsRef<[u8]> + Send + Unpin, T: AsRef<[u8]> + Send + Unpin>(
+    pub fn stream_trim_age<K: AsRef<[u8]> + Send + Unpin>(
         &self,
-        from: F,
-        to: T,
-    ) -> Rename<'_, B, F, T> {
-        Rename::new(self.backend(), from, to)
+        key: K,
+        max_age: Duration,
+    ) -> StreamTrimAge<'_, B, K> {
+        let max_age = max_age.as_millis().try_into().unwrap_or(u64::MAX);
+
+        StreamTrimAge::new(self.backend(), key, max_age)
     }
 
-    /// Set a key to a new value, overriding it regardless of whether it exists
-    /// and its current key type.
+    /// Retrieve part of a bytes or string key.
     ///
-    /// Returns the new value on success as confirmation.
+    /// Offsets are in characters for strings and in bytes for bytes. The end
+    /// offset is exclusive, and negative offsets count backwards from the end
+    /// of the value.
     ///
-    /// Refer to [`SetUnconfigured`] for more information and available methods.
+    /// Returns the retrieved part of the value on success.
     ///
     /// # Examples
     ///
-    /// Set the key "foo" to the integer `123`, and then set "foo" to the string
-    /// "bar".
-    ///
     /// ```
     /// use hop::Client;
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// assert_eq!(123, client.set("foo").int(123).await?);
+    /// client.set("foo").string("hopdb").await?;
     ///
-    /// assert_eq!("bar", client.set("foo").string("bar").await?);
+    /// let substring = client.substring("foo", 0).end(3).await?;
+    /// assert_eq!(Some("hop"), substring.as_string_ref());
+    ///
+    /// let substring = client.substring("foo", -2).str().await?;
+    /// assert_eq!(Some("db"), substring.as_string_ref());
     /// # Ok(()) }
     /// ```
-    ///
-    /// [`SetUnconfigured`]: request/set/struct.SetUnconfigured.html
-    pub fn set<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> SetUnconfigured<B, K> {
-        SetUnconfigured::new(self.backend(), key)
+    pub fn substring<K: AsRef<[u8]> + Send + Unpin>(
+        &self,
+        key: K,
+        start: i64,
+    ) -> Substring<'_, B, K> {
+        Substring::new(self.backend(), key, start)
     }
 
-    /// Retrieve statistics about the current runtime of Hop.
+    /// Convert a string key to uppercase in place.
     ///
-    /// When Hop is restarted, many of the statistics - like commands run - are
-    /// reset.
+    /// Returns the new string on success.
     ///
     /// # Examples
     ///
@@ -456,12 +2031,13 @@ impl<B: Backend> Client<B> {
     ///
     /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::memory();
-    /// let stats = client.stats().await?;
-    /// println!("Successful commands: {}", stats.commands_successful());
+    /// client.set("foo").string("hopdb").await?;
+    ///
+    /// assert_eq!("HOPDB", client.uppercase("foo").await?);
     /// # Ok(()) }
     /// ```
-    pub fn stats(&self) -> Stats<'_, B> {
-        Stats::new(self.backend())
+    pub fn uppercase<K: AsRef<[u8]> + Send + Unpin>(&self, key: K) -> Uppercase<'_, B, K> {
+        Uppercase::new(self.backend(), key)
     }
 }
 
@@ -1,6 +1,149 @@
-use alloc::vec::Vec;
-use core::convert::TryInto;
-use hop_engine::{dashmap::DashMap, metrics::Metric};
+use alloc::{format, string::String, vec::Vec};
+use core::convert::{TryFrom, TryInto};
+use hop_engine::{
+    dashmap::DashMap,
+    metrics::{Metric, NAMESPACE_KEYS_PREFIX},
+    state::KeyType,
+};
+
+/// Information about a key and how it has been accessed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ObjectInfoData {
+    accessed_at: i64,
+    created_at: i64,
+    frequency: i64,
+    idle: i64,
+    key_type: KeyType,
+    memory_usage: i64,
+}
+
+impl ObjectInfoData {
+    /// Parse the list of big-endian integers returned by the engine.
+    ///
+    /// Returns `None` if the list doesn't contain every field or the key type
+    /// is unknown.
+    pub(crate) fn from_fields(fields: &[Vec<u8>]) -> Option<Self> {
+        let mut ints = fields
+            .iter()
+            .map(|field| field.as_slice().try_into().ok().map(i64::from_be_bytes));
+        let mut next = || ints.next().flatten();
+
+        let key_type = u8::try_from(next()?).ok()?.try_into().ok()?;
+
+        Some(Self {
+            key_type,
+            memory_usage: next()?,
+            created_at: next()?,
+            accessed_at: next()?,
+            idle: next()?,
+            frequency: next()?,
+        })
+    }
+
+    /// Retrieve the Unix timestamp in milliseconds of when the key was last
+    /// accessed.
+    pub fn accessed_at(&self) -> i64 {
+        self.accessed_at
+    }
+
+    /// Retrieve the Unix timestamp in milliseconds of when the key was
+    /// created.
+    pub fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    /// Retrieve the number of times the key has been accessed, including its
+    /// creation.
+    pub fn frequency(&self) -> i64 {
+        self.frequency
+    }
+
+    /// Retrieve the number of milliseconds since the key was last accessed.
+    pub fn idle(&self) -> i64 {
+        self.idle
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    /// Retrieve the estimated number of bytes of memory used by the key and
+    /// its value.
+    pub fn memory_usage(&self) -> i64 {
+        self.memory_usage
+    }
+}
+
+/// Information about a session connected to the instance.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SessionData {
+    connected_at: i64,
+    id: u32,
+    idle: i64,
+    peer: Option<String>,
+    subscriptions: i64,
+}
+
+impl SessionData {
+    /// The number of fields the engine returns for each session.
+    const FIELDS: usize = 5;
+
+    /// Parse the list of fields returned by the engine into sessions.
+    ///
+    /// Returns `None` if a session doesn't contain every field or a field is
+    /// invalid.
+    pub(crate) fn from_fields(fields: &[Vec<u8>]) -> Option<Vec<Self>> {
+        if !fields.len().is_multiple_of(Self::FIELDS) {
+            return None;
+        }
+
+        let int = |field: &Vec<u8>| field.as_slice().try_into().ok().map(i64::from_be_bytes);
+
+        fields
+            .chunks(Self::FIELDS)
+            .map(|session| {
+                let peer = String::from_utf8(session[1].clone()).ok()?;
+
+                Some(Self {
+                    id: u32::try_from(int(&session[0])?).ok()?,
+                    peer: Some(peer).filter(|peer| !peer.is_empty()),
+                    connected_at: int(&session[2])?,
+                    idle: int(&session[3])?,
+                    subscriptions: int(&session[4])?,
+                })
+            })
+            .collect()
+    }
+
+    /// Retrieve the Unix timestamp in milliseconds of when the session
+    /// connected.
+    pub fn connected_at(&self) -> i64 {
+        self.connected_at
+    }
+
+    /// Retrieve the ID of the session, which can be used to kill it.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Retrieve the number of milliseconds since the session last ran a
+    /// command.
+    pub fn idle(&self) -> i64 {
+        self.idle
+    }
+
+    /// Retrieve the address of the peer, if it has one.
+    ///
+    /// Sessions connected over Unix sockets don't have an address.
+    pub fn peer(&self) -> Option<&str> {
+        self.peer.as_deref()
+    }
+
+    /// Retrieve the number of keys the session is subscribed to.
+    pub fn subscriptions(&self) -> i64 {
+        self.subscriptions
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct StatsData {
@@ -12,14 +155,18 @@ impl StatsData {
         Self { inner: map }
     }
 
-    fn int(&self, metric: Metric) -> i64 {
+    fn bytes_int(&self, key: &[u8]) -> i64 {
         self.inner
-            .get(metric.name().as_bytes())
+            .get(key)
             .and_then(|bytes| bytes.as_slice().try_into().ok())
             .map(i64::from_be_bytes)
             .unwrap_or_default()
     }
 
+    fn int(&self, metric: Metric) -> i64 {
+        self.bytes_int(metric.name().as_bytes())
+    }
+
     pub fn commands_errored(&self) -> i64 {
         self.int(Metric::CommandsErrored)
     }
@@ -28,6 +175,44 @@ impl StatsData {
         self.int(Metric::CommandsSuccessful)
     }
 
+    /// Retrieve the number of keys removed to keep memory usage under the
+    /// limit.
+    pub fn keys_evicted(&self) -> i64 {
+        self.int(Metric::KeysEvicted)
+    }
+
+    /// Retrieve the estimated number of bytes of memory used by the keys and
+    /// values of every namespace.
+    pub fn memory_used(&self) -> i64 {
+        self.int(Metric::MemoryUsed)
+    }
+
+    /// Retrieve the number of keys in a namespace.
+    pub fn namespace_keys(&self, namespace: usize) -> i64 {
+        let key = format!("{}{}", NAMESPACE_KEYS_PREFIX, namespace);
+
+        self.bytes_int(key.as_bytes())
+    }
+
+    /// Retrieve the number of keys in each namespace that has keys, in order
+    /// of the namespaces' numbers.
+    pub fn namespaces_keys(&self) -> Vec<(usize, i64)> {
+        let mut namespaces = self
+            .inner
+            .iter()
+            .filter_map(|entry| {
+                let namespace = entry.key().strip_prefix(NAMESPACE_KEYS_PREFIX.as_bytes())?;
+                let namespace = core::str::from_utf8(namespace).ok()?.parse().ok()?;
+                let count = entry.value().as_slice().try_into().ok()?;
+
+                Some((namespace, i64::from_be_bytes(count)))
+            })
+            .collect::<Vec<_>>();
+        namespaces.sort_unstable();
+
+        namespaces
+    }
+
     pub fn sessions_ended(&self) -> i64 {
         self.int(Metric::SessionsEnded)
     }
@@ -35,13 +220,68 @@ impl StatsData {
     pub fn sessions_started(&self) -> i64 {
         self.int(Metric::SessionsStarted)
     }
+
+    /// Retrieve the number of sessions closed for being idle for too long.
+    pub fn sessions_timed_out(&self) -> i64 {
+        self.int(Metric::SessionsTimedOut)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::StatsData;
-    use core::fmt::Debug;
+    use super::{ObjectInfoData, SessionData, StatsData};
+    use core::{fmt::Debug, hash::Hash};
+    use hop_engine::state::KeyType;
     use static_assertions::assert_impl_all;
 
+    assert_impl_all!(ObjectInfoData: Clone, Copy, Debug, Eq, Hash, PartialEq);
+    assert_impl_all!(SessionData: Clone, Debug, Eq, Hash, PartialEq);
     assert_impl_all!(StatsData: Clone, Debug);
+
+    #[test]
+    fn test_object_info_from_fields() {
+        let fields = [KeyType::Bytes as i64, 100, 1000, 1500, 20, 3]
+            .iter()
+            .map(|field| field.to_be_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let info = ObjectInfoData::from_fields(&fields).unwrap();
+
+        assert_eq!(KeyType::Bytes, info.key_type());
+        assert_eq!(100, info.memory_usage());
+        assert_eq!(1000, info.created_at());
+        assert_eq!(1500, info.accessed_at());
+        assert_eq!(20, info.idle());
+        assert_eq!(3, info.frequency());
+
+        assert!(ObjectInfoData::from_fields(&fields[..5]).is_none());
+    }
+
+    #[test]
+    fn test_session_from_fields() {
+        let int = |int: i64| int.to_be_bytes().to_vec();
+        let fields = [
+            int(1),
+            b"127.0.0.1:1234".to_vec(),
+            int(1000),
+            int(20),
+            int(2),
+            int(2),
+            Vec::new(),
+            int(1500),
+            int(0),
+            int(0),
+        ];
+        let sessions = SessionData::from_fields(&fields).unwrap();
+        assert_eq!(2, sessions.len());
+
+        assert_eq!(1, sessions[0].id());
+        assert_eq!(Some("127.0.0.1:1234"), sessions[0].peer());
+        assert_eq!(1000, sessions[0].connected_at());
+        assert_eq!(20, sessions[0].idle());
+        assert_eq!(2, sessions[0].subscriptions());
+        assert_eq!(2, sessions[1].id());
+        assert!(sessions[1].peer().is_none());
+
+        assert!(SessionData::from_fields(&fields[..4]).is_none());
+    }
 }
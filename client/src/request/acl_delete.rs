@@ -0,0 +1,55 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to delete a user from the access control list.
+pub struct AclDelete<'a, B: Backend, N: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, bool, B::Error>,
+    name: Option<N>,
+}
+
+impl<'a, B: Backend, N: AsRef<[u8]> + 'a + Send + Unpin> AclDelete<'a, B, N> {
+    pub(crate) fn new(backend: Arc<B>, name: N) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            name: Some(name),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, N: AsRef<[u8]> + Send + Unpin> Future
+    for AclDelete<'a, B, N>
+{
+    type Output = Result<bool, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let name = self.name.take().expect("name only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let name = name.as_ref();
+                backend.acl_delete(name).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AclDelete;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(AclDelete<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,47 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to list the users of the access control list.
+pub struct AclList<'a, B: Backend> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, Vec<String>, B::Error>,
+}
+
+impl<'a, B: Backend> AclList<'a, B> {
+    pub(crate) fn new(backend: Arc<B>) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Future for AclList<'a, B> {
+    type Output = Result<Vec<String>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+
+            self.fut
+                .replace(Box::pin(async move { backend.acl_list().await }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AclList;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(AclList<MemoryBackend>: Send);
+}
@@ -0,0 +1,58 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to create a user or apply rules to an existing user.
+pub struct AclSet<'a, B: Backend, N: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, String, B::Error>,
+    name: Option<N>,
+    rules: Option<Vec<Vec<u8>>>,
+}
+
+impl<'a, B: Backend, N: AsRef<[u8]> + 'a + Send + Unpin> AclSet<'a, B, N> {
+    pub(crate) fn new(backend: Arc<B>, name: N, rules: Vec<Vec<u8>>) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            name: Some(name),
+            rules: Some(rules),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, N: AsRef<[u8]> + Send + Unpin> Future
+    for AclSet<'a, B, N>
+{
+    type Output = Result<String, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let name = self.name.take().expect("name only taken once");
+            let rules = self.rules.take().expect("rules only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let name = name.as_ref();
+                backend.acl_set(name, rules).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AclSet;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(AclSet<MemoryBackend, Vec<u8>>: Send);
+}
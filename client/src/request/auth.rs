@@ -0,0 +1,65 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to authenticate the session as a user with a password.
+pub struct Auth<'a, B: Backend, P: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, bool, B::Error>,
+    password: Option<P>,
+    username: Option<Vec<u8>>,
+}
+
+impl<'a, B: Backend, P: AsRef<[u8]> + 'a + Send + Unpin> Auth<'a, B, P> {
+    pub(crate) fn new(backend: Arc<B>, password: P) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            password: Some(password),
+            username: None,
+        }
+    }
+
+    /// Authenticate as a named user instead of the default user.
+    pub fn username(mut self, username: impl Into<Vec<u8>>) -> Self {
+        self.username = Some(username.into());
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, P: AsRef<[u8]> + Send + Unpin> Future
+    for Auth<'a, B, P>
+{
+    type Output = Result<bool, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let password = self.password.take().expect("password only taken once");
+            let username = self.username.take();
+
+            self.fut.replace(Box::pin(async move {
+                let password = password.as_ref();
+                backend.auth(username.as_deref(), password).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Auth;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Auth<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,61 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to combine bytes keys with a bitwise AND into a destination key.
+pub struct BitAnd<'a, B: Backend, D: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    destination: Option<D>,
+    fut: MaybeInFlightFuture<'a, Vec<u8>, B::Error>,
+    sources: Option<Vec<Vec<u8>>>,
+}
+
+impl<'a, B: Backend, D: AsRef<[u8]> + 'a + Send + Unpin> BitAnd<'a, B, D> {
+    pub(crate) fn new(backend: Arc<B>, destination: D, sources: Vec<Vec<u8>>) -> Self {
+        Self {
+            backend: Some(backend),
+            destination: Some(destination),
+            fut: None,
+            sources: Some(sources),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, D: AsRef<[u8]> + Send + Unpin> Future
+    for BitAnd<'a, B, D>
+{
+    type Output = Result<Vec<u8>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let destination = self
+                .destination
+                .take()
+                .expect("destination only taken once");
+            let sources = self.sources.take().expect("sources only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let destination = destination.as_ref();
+                backend.bit_and(destination, sources).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitAnd;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(BitAnd<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,80 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to count the number of set bits of a bytes key, optionally within
+/// a range of bytes.
+pub struct BitCount<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    end: Option<i64>,
+    fut: MaybeInFlightFuture<'a, i64, B::Error>,
+    key: Option<K>,
+    start: Option<i64>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> BitCount<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K) -> Self {
+        Self {
+            backend: Some(backend),
+            end: None,
+            fut: None,
+            key: Some(key),
+            start: None,
+        }
+    }
+
+    /// Set the exclusive byte offset to stop counting at.
+    ///
+    /// Negative offsets count backwards from the end of the value.
+    pub fn end(mut self, end: i64) -> Self {
+        self.end.replace(end);
+
+        self
+    }
+
+    /// Set the byte offset to start counting from.
+    ///
+    /// Negative offsets count backwards from the end of the value.
+    pub fn start(mut self, start: i64) -> Self {
+        self.start.replace(start);
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for BitCount<'a, B, K>
+{
+    type Output = Result<i64, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let start = self.start.take();
+            let end = self.end.take();
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.bit_count(key, start, end).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitCount;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(BitCount<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,58 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to retrieve a bit of a bytes key at an offset.
+pub struct BitGet<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, bool, B::Error>,
+    key: Option<K>,
+    offset: Option<i64>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> BitGet<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K, offset: i64) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            key: Some(key),
+            offset: Some(offset),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for BitGet<'a, B, K>
+{
+    type Output = Result<bool, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let offset = self.offset.take().expect("offset only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.bit_get(key, offset).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitGet;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(BitGet<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,73 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to invert the bits of a bytes key into a destination key.
+pub struct BitNot<
+    'a,
+    B: Backend,
+    D: AsRef<[u8]> + 'a + Send + Unpin,
+    S: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    backend: Option<Arc<B>>,
+    destination: Option<D>,
+    fut: MaybeInFlightFuture<'a, Vec<u8>, B::Error>,
+    source: Option<S>,
+}
+
+impl<'a, B: Backend, D: AsRef<[u8]> + 'a + Send + Unpin, S: AsRef<[u8]> + 'a + Send + Unpin>
+    BitNot<'a, B, D, S>
+{
+    pub(crate) fn new(backend: Arc<B>, destination: D, source: S) -> Self {
+        Self {
+            backend: Some(backend),
+            destination: Some(destination),
+            fut: None,
+            source: Some(source),
+        }
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        D: AsRef<[u8]> + Send + Unpin,
+        S: AsRef<[u8]> + Send + Unpin,
+    > Future for BitNot<'a, B, D, S>
+{
+    type Output = Result<Vec<u8>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let destination = self
+                .destination
+                .take()
+                .expect("destination only taken once");
+            let source = self.source.take().expect("source only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let destination = destination.as_ref();
+                let source = source.as_ref();
+                backend.bit_not(destination, source).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitNot;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(BitNot<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
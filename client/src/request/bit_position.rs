@@ -0,0 +1,58 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to find the offset of the first set or clear bit of a bytes key.
+pub struct BitPosition<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    bit: Option<bool>,
+    fut: MaybeInFlightFuture<'a, i64, B::Error>,
+    key: Option<K>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> BitPosition<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K, bit: bool) -> Self {
+        Self {
+            backend: Some(backend),
+            bit: Some(bit),
+            fut: None,
+            key: Some(key),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for BitPosition<'a, B, K>
+{
+    type Output = Result<i64, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let bit = self.bit.take().expect("bit only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.bit_position(key, bit).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitPosition;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(BitPosition<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,62 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::Value;
+
+/// Request to clamp a numeric key to a range.
+pub struct Clamp<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, Value, B::Error>,
+    key: Option<K>,
+    max: Option<Value>,
+    min: Option<Value>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> Clamp<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K, min: Value, max: Value) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            key: Some(key),
+            max: Some(max),
+            min: Some(min),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for Clamp<'a, B, K>
+{
+    type Output = Result<Value, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let min = self.min.take().expect("min only taken once");
+            let max = self.max.take().expect("max only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.clamp(key, min, max).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clamp;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Clamp<MemoryBackend, Vec<u8>>: Send);
+}
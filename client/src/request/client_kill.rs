@@ -0,0 +1,50 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to kill a session connected to the instance.
+pub struct ClientKill<'a, B: Backend> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, bool, B::Error>,
+    id: Option<u32>,
+}
+
+impl<'a, B: Backend> ClientKill<'a, B> {
+    pub(crate) fn new(backend: Arc<B>, id: u32) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            id: Some(id),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Future for ClientKill<'a, B> {
+    type Output = Result<bool, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let id = self.id.take().expect("id only taken once");
+
+            self.fut
+                .replace(Box::pin(async move { backend.client_kill(id).await }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientKill;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(ClientKill<MemoryBackend>: Send);
+}
@@ -0,0 +1,48 @@
+use super::MaybeInFlightFuture;
+use crate::model::SessionData;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to list the sessions connected to the instance.
+pub struct ClientList<'a, B: Backend> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, Vec<SessionData>, B::Error>,
+}
+
+impl<'a, B: Backend> ClientList<'a, B> {
+    pub(crate) fn new(backend: Arc<B>) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Future for ClientList<'a, B> {
+    type Output = Result<Vec<SessionData>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+
+            self.fut
+                .replace(Box::pin(async move { backend.client_list().await }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientList;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(ClientList<MemoryBackend>: Send);
+}
@@ -0,0 +1,51 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::dashmap::DashMap;
+
+/// Request to retrieve the values of configuration parameters.
+pub struct ConfigGet<'a, B: Backend> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, DashMap<Vec<u8>, Vec<u8>>, B::Error>,
+    names: Option<Vec<Vec<u8>>>,
+}
+
+impl<'a, B: Backend> ConfigGet<'a, B> {
+    pub(crate) fn new(backend: Arc<B>, names: Vec<Vec<u8>>) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            names: Some(names),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Future for ConfigGet<'a, B> {
+    type Output = Result<DashMap<Vec<u8>, Vec<u8>>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let names = self.names.take().expect("names only taken once");
+
+            self.fut
+                .replace(Box::pin(async move { backend.config_get(names).await }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigGet;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(ConfigGet<MemoryBackend>: Send);
+}
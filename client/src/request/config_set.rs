@@ -0,0 +1,70 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, string::String, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to change the value of a configuration parameter.
+pub struct ConfigSet<
+    'a,
+    B: Backend,
+    N: AsRef<[u8]> + 'a + Send + Unpin,
+    V: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, String, B::Error>,
+    name: Option<N>,
+    value: Option<V>,
+}
+
+impl<'a, B: Backend, N: AsRef<[u8]> + 'a + Send + Unpin, V: AsRef<[u8]> + 'a + Send + Unpin>
+    ConfigSet<'a, B, N, V>
+{
+    pub(crate) fn new(backend: Arc<B>, name: N, value: V) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            name: Some(name),
+            value: Some(value),
+        }
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        N: AsRef<[u8]> + Send + Unpin,
+        V: AsRef<[u8]> + Send + Unpin,
+    > Future for ConfigSet<'a, B, N, V>
+{
+    type Output = Result<String, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let name = self.name.take().expect("name only taken once");
+            let value = self.value.take().expect("value only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let name = name.as_ref();
+                let value = value.as_ref();
+                backend.config_set(name, value).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigSet;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(ConfigSet<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
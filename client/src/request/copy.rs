@@ -0,0 +1,81 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to copy the value of a key to another key.
+pub struct Copy<
+    'a,
+    B: Backend,
+    F: AsRef<[u8]> + 'a + Send + Unpin,
+    T: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, Vec<u8>, B::Error>,
+    from: Option<F>,
+    replace: bool,
+    to: Option<T>,
+}
+
+impl<'a, B: Backend, F: AsRef<[u8]> + 'a + Send + Unpin, T: AsRef<[u8]> + 'a + Send + Unpin>
+    Copy<'a, B, F, T>
+{
+    pub(crate) fn new(backend: Arc<B>, from: F, to: T) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            from: Some(from),
+            replace: false,
+            to: Some(to),
+        }
+    }
+
+    /// Overwrite the destination key if it already exists.
+    pub fn replace(mut self) -> Self {
+        self.replace = true;
+
+        self
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        F: AsRef<[u8]> + Send + Unpin,
+        T: AsRef<[u8]> + Send + Unpin,
+    > Future for Copy<'a, B, F, T>
+{
+    type Output = Result<Vec<u8>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let from = self.from.take().expect("from only taken once");
+            let to = self.to.take().expect("to only taken once");
+            let replace = self.replace;
+
+            self.fut.replace(Box::pin(async move {
+                let from = from.as_ref();
+                let to = to.as_ref();
+
+                backend.copy(from, to, replace).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Copy;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Copy<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
@@ -0,0 +1,74 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::Document;
+
+/// Request to append values to an array within a document key.
+pub struct DocumentAppend<
+    'a,
+    B: Backend,
+    K: AsRef<[u8]> + 'a + Send + Unpin,
+    P: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, i64, B::Error>,
+    key: Option<K>,
+    path: Option<P>,
+    values: Option<Vec<Document>>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin, P: AsRef<[u8]> + 'a + Send + Unpin>
+    DocumentAppend<'a, B, K, P>
+{
+    pub(crate) fn new(backend: Arc<B>, key: K, path: P, values: Vec<Document>) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            key: Some(key),
+            path: Some(path),
+            values: Some(values),
+        }
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        K: AsRef<[u8]> + Send + Unpin,
+        P: AsRef<[u8]> + Send + Unpin,
+    > Future for DocumentAppend<'a, B, K, P>
+{
+    type Output = Result<i64, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let path = self.path.take().expect("path only taken once");
+            let values = self.values.take().expect("values only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                let path = path.as_ref();
+                backend.document_append(key, path, values).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocumentAppend;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(DocumentAppend<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
@@ -0,0 +1,74 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::Document;
+
+/// Request to increment a number within a document key.
+pub struct DocumentIncrement<
+    'a,
+    B: Backend,
+    K: AsRef<[u8]> + 'a + Send + Unpin,
+    P: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    amount: Option<Document>,
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, Document, B::Error>,
+    key: Option<K>,
+    path: Option<P>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin, P: AsRef<[u8]> + 'a + Send + Unpin>
+    DocumentIncrement<'a, B, K, P>
+{
+    pub(crate) fn new(backend: Arc<B>, key: K, path: P, amount: Document) -> Self {
+        Self {
+            amount: Some(amount),
+            backend: Some(backend),
+            fut: None,
+            key: Some(key),
+            path: Some(path),
+        }
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        K: AsRef<[u8]> + Send + Unpin,
+        P: AsRef<[u8]> + Send + Unpin,
+    > Future for DocumentIncrement<'a, B, K, P>
+{
+    type Output = Result<Document, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let path = self.path.take().expect("path only taken once");
+            let amount = self.amount.take().expect("amount only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                let path = path.as_ref();
+                backend.document_increment(key, path, amount).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocumentIncrement;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(DocumentIncrement<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
@@ -1,6 +1,6 @@
 use super::{CommandConfigurationError, MaybeInFlightFuture};
 use crate::Backend;
-use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
 use core::{
     future::Future,
     pin::Pin,
@@ -58,8 +58,7 @@ impl<'a, B: Backend> Exists<B> {
     ///
     /// [struct docs]: #main
     pub fn key<K: AsRef<[u8]> + 'a + Send + Unpin>(self, key: K) -> ExistsConfigured<'a, B, K> {
-        let mut keys = Vec::new();
-        keys.push(key);
+        let keys = vec![key];
 
         ExistsConfigured::new(self.backend, keys)
     }
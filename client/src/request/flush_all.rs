@@ -0,0 +1,58 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to remove every key in every namespace.
+pub struct FlushAll<'a, B: Backend> {
+    backend: Option<Arc<B>>,
+    background: bool,
+    fut: MaybeInFlightFuture<'a, i64, B::Error>,
+}
+
+impl<'a, B: Backend> FlushAll<'a, B> {
+    pub(crate) fn new(backend: Arc<B>) -> Self {
+        Self {
+            backend: Some(backend),
+            background: false,
+            fut: None,
+        }
+    }
+
+    /// Drop the removed keys in the background, responding as soon as they've
+    /// been removed.
+    pub fn background(mut self) -> Self {
+        self.background = true;
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Future for FlushAll<'a, B> {
+    type Output = Result<i64, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let background = self.background;
+
+            self.fut
+                .replace(Box::pin(async move { backend.flush_all(background).await }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlushAll;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(FlushAll<MemoryBackend>: Send);
+}
@@ -0,0 +1,66 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to remove every key starting with a prefix in the selected
+/// namespace.
+pub struct FlushPrefix<'a, B: Backend, P: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    background: bool,
+    fut: MaybeInFlightFuture<'a, i64, B::Error>,
+    prefix: Option<P>,
+}
+
+impl<'a, B: Backend, P: AsRef<[u8]> + 'a + Send + Unpin> FlushPrefix<'a, B, P> {
+    pub(crate) fn new(backend: Arc<B>, prefix: P) -> Self {
+        Self {
+            backend: Some(backend),
+            background: false,
+            fut: None,
+            prefix: Some(prefix),
+        }
+    }
+
+    /// Drop the removed keys in the background, responding as soon as they've
+    /// been removed.
+    pub fn background(mut self) -> Self {
+        self.background = true;
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, P: AsRef<[u8]> + Send + Unpin> Future
+    for FlushPrefix<'a, B, P>
+{
+    type Output = Result<i64, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let prefix = self.prefix.take().expect("prefix only taken once");
+            let background = self.background;
+
+            self.fut.replace(Box::pin(async move {
+                backend.flush_prefix(prefix.as_ref(), background).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlushPrefix;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(FlushPrefix<MemoryBackend, Vec<u8>>: Send);
+}
@@ -1,15 +1,19 @@
 mod get_boolean;
 mod get_bytes;
+mod get_document;
 mod get_float;
+mod get_hyper_log_log;
 mod get_integer;
 mod get_list;
 mod get_map;
 mod get_set;
+mod get_stream;
 mod get_string;
 
 pub use self::{
-    get_boolean::GetBoolean, get_bytes::GetBytes, get_float::GetFloat, get_integer::GetInteger,
-    get_list::GetList, get_map::GetMap, get_set::GetSet, get_string::GetString,
+    get_boolean::GetBoolean, get_bytes::GetBytes, get_document::GetDocument, get_float::GetFloat,
+    get_hyper_log_log::GetHyperLogLog, get_integer::GetInteger, get_list::GetList, get_map::GetMap,
+    get_set::GetSet, get_stream::GetStream, get_string::GetString,
 };
 
 use super::MaybeInFlightFuture;
@@ -126,6 +130,29 @@ impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> GetUnconfigured<'a, B,
         GetBytes::new(self.backend.unwrap(), self.key.unwrap())
     }
 
+    /// Get a key as a document.
+    ///
+    /// The returned struct, when `await`ed, will resolve to a document on
+    /// success.
+    ///
+    /// # Examples
+    ///
+    /// Get the key "foo" as a document:
+    ///
+    /// ```
+    /// use hop::{Client, Document};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.document_set("foo", "$", Document::from(true)).await?;
+    ///
+    /// assert_eq!(Document::Boolean(true), client.get("foo").document().await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn document(self) -> GetDocument<'a, B, K> {
+        GetDocument::new(self.backend.unwrap(), self.key.unwrap())
+    }
+
     /// Get a key as a float.
     ///
     /// The returned struct, when `await`ed, will resolve to a float on success.
@@ -148,6 +175,29 @@ impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> GetUnconfigured<'a, B,
         GetFloat::new(self.backend.unwrap(), self.key.unwrap())
     }
 
+    /// Get a key as a HyperLogLog.
+    ///
+    /// The returned struct, when `await`ed, will resolve to a HyperLogLog on
+    /// success.
+    ///
+    /// # Examples
+    ///
+    /// Get the key "foo" as a HyperLogLog:
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.hyper_log_log_add("foo", &["bar", "baz"]).await?;
+    ///
+    /// assert_eq!(2, client.get("foo").hyper_log_log().await?.count());
+    /// # Ok(()) }
+    /// ```
+    pub fn hyper_log_log(self) -> GetHyperLogLog<'a, B, K> {
+        GetHyperLogLog::new(self.backend.unwrap(), self.key.unwrap())
+    }
+
     /// An alias for [`int`].
     ///
     /// [`int`]: #method.int
@@ -237,6 +287,29 @@ impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> GetUnconfigured<'a, B,
         GetSet::new(self.backend.unwrap(), self.key.unwrap())
     }
 
+    /// Get a key as a stream.
+    ///
+    /// The returned struct, when `await`ed, will resolve to a stream on
+    /// success.
+    ///
+    /// # Examples
+    ///
+    /// Get the key "foo" as a stream:
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.stream_append("foo", [("bar", "baz")]).await?;
+    ///
+    /// assert_eq!(1, client.get("foo").stream().await?.len());
+    /// # Ok(()) }
+    /// ```
+    pub fn stream(self) -> GetStream<'a, B, K> {
+        GetStream::new(self.backend.unwrap(), self.key.unwrap())
+    }
+
     /// An alias for [`str`].
     ///
     /// [`str`]: #method.str
@@ -0,0 +1,52 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to estimate the number of unique items in the union of HyperLogLog
+/// keys.
+pub struct HyperLogLogCount<'a, B: Backend> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, i64, B::Error>,
+    keys: Option<Vec<Vec<u8>>>,
+}
+
+impl<'a, B: Backend> HyperLogLogCount<'a, B> {
+    pub(crate) fn new(backend: Arc<B>, keys: Vec<Vec<u8>>) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            keys: Some(keys),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Future for HyperLogLogCount<'a, B> {
+    type Output = Result<i64, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let keys = self.keys.take().expect("keys only taken once");
+
+            self.fut.replace(Box::pin(
+                async move { backend.hyper_log_log_count(keys).await },
+            ));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HyperLogLogCount;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(HyperLogLogCount<MemoryBackend>: Send);
+}
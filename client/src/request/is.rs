@@ -1,6 +1,6 @@
 use super::{CommandConfigurationError, MaybeInFlightFuture};
 use crate::Backend;
-use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
 use core::{
     future::Future,
     pin::Pin,
@@ -60,8 +60,7 @@ impl<'a, B: Backend> Is<B> {
     ///
     /// [struct docs]: #main
     pub fn key<K: AsRef<[u8]> + 'a + Send + Unpin>(self, key: K) -> IsConfigured<'a, B, K> {
-        let mut keys = Vec::new();
-        keys.push(key);
+        let keys = vec![key];
 
         IsConfigured::new(self.backend, self.key_type, keys)
     }
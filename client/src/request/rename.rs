@@ -16,6 +16,7 @@ pub struct Rename<
     backend: Option<Arc<B>>,
     fut: MaybeInFlightFuture<'a, Vec<u8>, B::Error>,
     from: Option<F>,
+    replace: bool,
     to: Option<T>,
 }
 
@@ -27,9 +28,17 @@ impl<'a, B: Backend, F: AsRef<[u8]> + 'a + Send + Unpin, T: AsRef<[u8]> + 'a + S
             backend: Some(backend),
             fut: None,
             from: Some(from),
+            replace: false,
             to: Some(to),
         }
     }
+
+    /// Overwrite the new key name if it already exists.
+    pub fn replace(mut self) -> Self {
+        self.replace = true;
+
+        self
+    }
 }
 
 impl<
@@ -46,12 +55,13 @@ impl<
             let backend = { self.backend.take().expect("backend only taken once") };
             let from = self.from.take().expect("from only taken once");
             let to = self.to.take().expect("to only taken once");
+            let replace = self.replace;
 
             self.fut.replace(Box::pin(async move {
                 let from = from.as_ref();
                 let to = to.as_ref();
 
-                backend.rename(from, to).await
+                backend.rename(from, to, replace).await
             }));
         }
 
@@ -0,0 +1,81 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to recreate a key from its serialized form.
+pub struct Restore<
+    'a,
+    B: Backend,
+    K: AsRef<[u8]> + 'a + Send + Unpin,
+    D: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    backend: Option<Arc<B>>,
+    dump: Option<D>,
+    fut: MaybeInFlightFuture<'a, Vec<u8>, B::Error>,
+    key: Option<K>,
+    replace: bool,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin, D: AsRef<[u8]> + 'a + Send + Unpin>
+    Restore<'a, B, K, D>
+{
+    pub(crate) fn new(backend: Arc<B>, key: K, dump: D) -> Self {
+        Self {
+            backend: Some(backend),
+            dump: Some(dump),
+            fut: None,
+            key: Some(key),
+            replace: false,
+        }
+    }
+
+    /// Overwrite the key if it already exists.
+    pub fn replace(mut self) -> Self {
+        self.replace = true;
+
+        self
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        K: AsRef<[u8]> + Send + Unpin,
+        D: AsRef<[u8]> + Send + Unpin,
+    > Future for Restore<'a, B, K, D>
+{
+    type Output = Result<Vec<u8>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let dump = self.dump.take().expect("dump only taken once");
+            let replace = self.replace;
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                let dump = dump.as_ref();
+
+                backend.restore(key, dump, replace).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Restore;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Restore<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
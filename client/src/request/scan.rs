@@ -0,0 +1,126 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, collections::VecDeque, sync::Arc, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+use hop_engine::state::KeyType;
+
+/// The cursor that starts a scan and marks the end of one.
+const CURSOR_DONE: &[u8] = b"0";
+
+/// The next cursor and the keys of a batch.
+type Batch = (Vec<u8>, Vec<Vec<u8>>);
+
+/// Stream of the keys matching a pattern, retrieved from the backend in
+/// batches.
+///
+/// Keys are yielded in no particular order. Each key that exists for the
+/// whole duration of the scan is yielded exactly once; keys created or removed
+/// while the scan is in progress may or may not be yielded.
+pub struct Scan<'a, B: Backend> {
+    backend: Arc<B>,
+    count: Option<i64>,
+    cursor: Option<Vec<u8>>,
+    fut: MaybeInFlightFuture<'a, Batch, B::Error>,
+    key_type: Option<KeyType>,
+    keys: VecDeque<Vec<u8>>,
+    pattern: Option<Vec<u8>>,
+}
+
+impl<'a, B: Backend> Scan<'a, B> {
+    pub(crate) fn new(backend: Arc<B>) -> Self {
+        Self {
+            backend,
+            count: None,
+            cursor: Some(CURSOR_DONE.to_vec()),
+            fut: None,
+            key_type: None,
+            keys: VecDeque::new(),
+            pattern: None,
+        }
+    }
+
+    /// Set the number of keys to retrieve from the backend per batch.
+    ///
+    /// Defaults to 10.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count.replace(count);
+
+        self
+    }
+
+    /// Only yield keys of a certain type.
+    pub fn key_type(mut self, key_type: KeyType) -> Self {
+        self.key_type.replace(key_type);
+
+        self
+    }
+
+    /// Only yield keys matching a glob pattern.
+    ///
+    /// Refer to the engine's `glob` module for the supported syntax.
+    pub fn pattern(mut self, pattern: impl AsRef<[u8]>) -> Self {
+        self.pattern.replace(pattern.as_ref().to_vec());
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Stream for Scan<'a, B> {
+    type Item = Result<Vec<u8>, B::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(key) = self.keys.pop_front() {
+                return Poll::Ready(Some(Ok(key)));
+            }
+
+            if self.fut.is_none() {
+                let cursor = match self.cursor.take() {
+                    Some(cursor) => cursor,
+                    None => return Poll::Ready(None),
+                };
+                let backend = Arc::clone(&self.backend);
+                let count = self.count;
+                let key_type = self.key_type;
+                let pattern = self.pattern.clone();
+
+                self.fut.replace(Box::pin(async move {
+                    backend
+                        .scan(&cursor, pattern.as_deref(), key_type, count)
+                        .await
+                }));
+            }
+
+            let result = match self.fut.as_mut().expect("future exists").as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+
+            self.fut.take();
+
+            let (cursor, keys) = match result {
+                Ok(batch) => batch,
+                Err(source) => return Poll::Ready(Some(Err(source))),
+            };
+
+            if cursor != CURSOR_DONE {
+                self.cursor.replace(cursor);
+            }
+
+            self.keys.extend(keys);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scan;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Scan<MemoryBackend>: Send);
+}
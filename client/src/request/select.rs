@@ -0,0 +1,50 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to select the namespace that the session works with.
+pub struct Select<'a, B: Backend> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, i64, B::Error>,
+    namespace: Option<i64>,
+}
+
+impl<'a, B: Backend> Select<'a, B> {
+    pub(crate) fn new(backend: Arc<B>, namespace: i64) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            namespace: Some(namespace),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static> Future for Select<'a, B> {
+    type Output = Result<i64, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let namespace = self.namespace.take().expect("namespace only taken once");
+
+            self.fut
+                .replace(Box::pin(async move { backend.select(namespace).await }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Select;
+    use crate::backend::MemoryBackend;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Select<MemoryBackend>: Send);
+}
@@ -1,23 +1,26 @@
 mod set_boolean;
 mod set_bytes;
+mod set_document;
 mod set_float;
+mod set_hyper_log_log;
 mod set_integer;
 mod set_list;
 mod set_map;
 mod set_set;
+mod set_stream;
 mod set_string;
 mod set_value;
 
 pub use self::{
-    set_boolean::SetBoolean, set_bytes::SetBytes, set_float::SetFloat, set_integer::SetInteger,
-    set_list::SetList, set_map::SetMap, set_set::SetSet, set_string::SetString,
-    set_value::SetValue,
+    set_boolean::SetBoolean, set_bytes::SetBytes, set_document::SetDocument, set_float::SetFloat,
+    set_hyper_log_log::SetHyperLogLog, set_integer::SetInteger, set_list::SetList, set_map::SetMap,
+    set_set::SetSet, set_stream::SetStream, set_string::SetString, set_value::SetValue,
 };
 
 use crate::Backend;
 use alloc::{string::String, sync::Arc, vec::Vec};
 use core::iter::FromIterator;
-use hop_engine::state::Value;
+use hop_engine::state::{Document, HyperLogLog, Stream, Value};
 
 /// An Set request that hasn't been configured with a value to set.
 ///
@@ -114,6 +117,27 @@ impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> SetUnconfigured<B, K> {
         SetBytes::new(self.backend, self.key, bytes.into())
     }
 
+    /// Set a key to a document.
+    ///
+    /// # Examples
+    ///
+    /// Set the key "foo" to a document:
+    ///
+    /// ```
+    /// use hop::{Client, Document};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// let document = r#"{"bar":[1,2]}"#.parse::<Document>().unwrap();
+    ///
+    /// client.set("foo").document(document).await?;
+    /// assert_eq!(Document::Integer(2), client.document_get("foo", "bar[1]").await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn document(self, document: Document) -> SetDocument<'a, B, K> {
+        SetDocument::new(self.backend, self.key, document)
+    }
+
     /// Set a key to a float.
     ///
     /// The returned struct, when `await`ed, will resolve to a float on success.
@@ -134,6 +158,31 @@ impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> SetUnconfigured<B, K> {
         SetFloat::new(self.backend, self.key, float)
     }
 
+    /// Set a key to a HyperLogLog.
+    ///
+    /// This is useful for restoring a HyperLogLog retrieved from another
+    /// database.
+    ///
+    /// # Examples
+    ///
+    /// Copy the HyperLogLog of the key "foo" to the key "bar":
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.hyper_log_log_add("foo", &["baz"]).await?;
+    /// let hll = client.get("foo").hyper_log_log().await?;
+    ///
+    /// client.set("bar").hyper_log_log(hll).await?;
+    /// assert_eq!(1, client.hyper_log_log_count(&["bar"]).await?);
+    /// # Ok(()) }
+    /// ```
+    pub fn hyper_log_log(self, hll: HyperLogLog) -> SetHyperLogLog<'a, B, K> {
+        SetHyperLogLog::new(self.backend, self.key, hll)
+    }
+
     /// An alias for [`int`].
     ///
     /// [`int`]: #method.int
@@ -223,6 +272,30 @@ impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> SetUnconfigured<B, K> {
         SetSet::new(self.backend, self.key, FromIterator::from_iter(set.into()))
     }
 
+    /// Set a key to a stream.
+    ///
+    /// This is useful for restoring a stream retrieved from another database.
+    ///
+    /// # Examples
+    ///
+    /// Copy the stream of the key "foo" to the key "bar":
+    ///
+    /// ```
+    /// use hop::Client;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::memory();
+    /// client.stream_append("foo", [("baz", "qux")]).await?;
+    /// let stream = client.get("foo").stream().await?;
+    ///
+    /// client.set("bar").stream(stream).await?;
+    /// assert_eq!(1, client.stream_range("bar").await?.len());
+    /// # Ok(()) }
+    /// ```
+    pub fn stream(self, stream: Stream) -> SetStream<'a, B, K> {
+        SetStream::new(self.backend, self.key, stream)
+    }
+
     /// An alias for [`str`].
     ///
     /// [`str`]: #method.str
@@ -0,0 +1,68 @@
+use super::super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::{Document, Value};
+
+/// A configured `set` command that will resolve to a document when `await`ed.
+///
+/// This is returned by [`SetUnconfigured::document`].
+///
+/// [`SetUnconfigured::document`]: struct.SetUnconfigured.html#method.document
+pub struct SetDocument<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, Document, B::Error>,
+    key: Option<K>,
+    value: Option<Document>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> SetDocument<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K, value: Document) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            key: Some(key),
+            value: Some(value),
+        }
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for SetDocument<'a, B, K>
+{
+    type Output = Result<Document, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = self.backend.take().expect("backend only taken once");
+            let key = self.key.take().expect("key only taken once");
+            let document = self.value.take().expect("value only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                let value = backend.set(key, Value::Document(document)).await?;
+
+                match value {
+                    Value::Document(document) => Ok(document),
+                    _ => unreachable!(),
+                }
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SetDocument;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(SetDocument<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,97 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::{KeyType, Value};
+
+/// Request to retrieve part of a value by byte offsets, optionally only if
+/// it is of a certain type.
+pub struct Slice<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    end: Option<i64>,
+    fut: MaybeInFlightFuture<'a, Value, B::Error>,
+    key: Option<K>,
+    kind: Option<KeyType>,
+    start: i64,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> Slice<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K, start: i64) -> Self {
+        Self {
+            backend: Some(backend),
+            end: None,
+            fut: None,
+            key: Some(key),
+            kind: None,
+            start,
+        }
+    }
+
+    /// Set the exclusive offset to end at.
+    ///
+    /// Negative offsets count backwards from the end of the value. If no end
+    /// is specified, then the rest of the value is retrieved.
+    pub fn end(mut self, end: i64) -> Self {
+        self.end.replace(end);
+
+        self
+    }
+
+    /// Retrieve the slice *only* if the key is some bytes.
+    pub fn bytes(mut self) -> Self {
+        self.kind.replace(KeyType::Bytes);
+
+        self
+    }
+
+    /// An alais for [`str`].
+    ///
+    /// [`str`]: #method.str
+    pub fn string(self) -> Self {
+        self.str()
+    }
+
+    /// Retrieve the slice *only* if the key is a string.
+    pub fn str(mut self) -> Self {
+        self.kind.replace(KeyType::String);
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for Slice<'a, B, K>
+{
+    type Output = Result<Value, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let kind = self.kind.take();
+            let start = self.start;
+            let end = self.end;
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.slice(key, kind, start, end).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slice;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Slice<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,71 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Request to split a string into a list by a separator.
+pub struct Split<
+    'a,
+    B: Backend,
+    K: AsRef<[u8]> + 'a + Send + Unpin,
+    S: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, Vec<Vec<u8>>, B::Error>,
+    key: Option<K>,
+    separator: Option<S>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin, S: AsRef<[u8]> + 'a + Send + Unpin>
+    Split<'a, B, K, S>
+{
+    pub(crate) fn new(backend: Arc<B>, key: K, separator: S) -> Self {
+        Self {
+            backend: Some(backend),
+            fut: None,
+            key: Some(key),
+            separator: Some(separator),
+        }
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        K: AsRef<[u8]> + Send + Unpin,
+        S: AsRef<[u8]> + Send + Unpin,
+    > Future for Split<'a, B, K, S>
+{
+    type Output = Result<Vec<Vec<u8>>, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let separator = self.separator.take().expect("separator only taken once");
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                let separator = separator.as_ref();
+
+                backend.split(key, separator).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Split;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Split<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
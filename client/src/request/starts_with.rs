@@ -0,0 +1,96 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::KeyType;
+
+/// Request to check whether a value starts with a prefix.
+pub struct StartsWith<
+    'a,
+    B: Backend,
+    K: AsRef<[u8]> + 'a + Send + Unpin,
+    A: AsRef<[u8]> + 'a + Send + Unpin,
+> {
+    prefix: Option<A>,
+    backend: Option<Arc<B>>,
+    fut: MaybeInFlightFuture<'a, bool, B::Error>,
+    key: Option<K>,
+    kind: Option<KeyType>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin, A: AsRef<[u8]> + 'a + Send + Unpin>
+    StartsWith<'a, B, K, A>
+{
+    pub(crate) fn new(backend: Arc<B>, key: K, prefix: A) -> Self {
+        Self {
+            prefix: Some(prefix),
+            backend: Some(backend),
+            fut: None,
+            key: Some(key),
+            kind: None,
+        }
+    }
+
+    /// Check the prefix *only* if the key is some bytes.
+    pub fn bytes(mut self) -> Self {
+        self.kind.replace(KeyType::Bytes);
+
+        self
+    }
+
+    /// An alais for [`str`].
+    ///
+    /// [`str`]: #method.str
+    pub fn string(self) -> Self {
+        self.str()
+    }
+
+    /// Check the prefix *only* if the key is a string.
+    pub fn str(mut self) -> Self {
+        self.kind.replace(KeyType::String);
+
+        self
+    }
+}
+
+impl<
+        'a,
+        B: Backend + Send + Sync + 'static,
+        K: AsRef<[u8]> + Send + Unpin,
+        A: AsRef<[u8]> + Send + Unpin,
+    > Future for StartsWith<'a, B, K, A>
+{
+    type Output = Result<bool, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let prefix = self.prefix.take().expect("prefix only taken once");
+            let kind = self.kind.take();
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                let prefix = prefix.as_ref();
+
+                backend.starts_with(key, kind, prefix).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StartsWith;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(StartsWith<MemoryBackend, Vec<u8>, Vec<u8>>: Send);
+}
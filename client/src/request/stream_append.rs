@@ -0,0 +1,72 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::StreamId;
+
+/// Request to append an entry to a stream key, creating the key if it doesn't
+/// exist.
+pub struct StreamAppend<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    fields: Option<Vec<(Vec<u8>, Vec<u8>)>>,
+    fut: MaybeInFlightFuture<'a, StreamId, B::Error>,
+    id: Option<StreamId>,
+    key: Option<K>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> StreamAppend<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K, fields: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+        Self {
+            backend: Some(backend),
+            fields: Some(fields),
+            fut: None,
+            id: None,
+            key: Some(key),
+        }
+    }
+
+    /// Set the ID of the entry instead of generating one.
+    ///
+    /// The ID must be greater than the ID of the last entry in the stream.
+    pub fn id(mut self, id: StreamId) -> Self {
+        self.id.replace(id);
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for StreamAppend<'a, B, K>
+{
+    type Output = Result<StreamId, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let fields = self.fields.take().expect("fields only taken once");
+            let id = self.id.take();
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.stream_append(key, id, fields).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamAppend;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(StreamAppend<MemoryBackend, Vec<u8>>: Send);
+}
@@ -0,0 +1,91 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::{Stream, StreamId};
+
+/// Request to retrieve the entries of a stream key within an inclusive range of
+/// IDs.
+pub struct StreamRange<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    backend: Option<Arc<B>>,
+    count: Option<i64>,
+    end: Option<StreamId>,
+    fut: MaybeInFlightFuture<'a, Stream, B::Error>,
+    key: Option<K>,
+    start: Option<StreamId>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> StreamRange<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K) -> Self {
+        Self {
+            backend: Some(backend),
+            count: None,
+            end: None,
+            fut: None,
+            key: Some(key),
+            start: None,
+        }
+    }
+
+    /// Set the maximum number of entries to retrieve.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count.replace(count);
+
+        self
+    }
+
+    /// Set the inclusive ID to stop at.
+    ///
+    /// By default the range ends at the last entry.
+    pub fn end(mut self, end: StreamId) -> Self {
+        self.end.replace(end);
+
+        self
+    }
+
+    /// Set the inclusive ID to start from.
+    ///
+    /// By default the range starts at the first entry.
+    pub fn start(mut self, start: StreamId) -> Self {
+        self.start.replace(start);
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for StreamRange<'a, B, K>
+{
+    type Output = Result<Stream, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let count = self.count.take();
+            let end = self.end.take();
+            let start = self.start.take();
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.stream_range(key, start, end, count).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamRange;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(StreamRange<MemoryBackend, Vec<u8>>: Send);
+}
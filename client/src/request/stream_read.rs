@@ -0,0 +1,84 @@
+use super::MaybeInFlightFuture;
+use crate::Backend;
+use alloc::{boxed::Box, sync::Arc};
+use core::{convert::TryInto, time::Duration};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use hop_engine::state::{Stream, StreamId};
+
+/// Request to read the entries of a stream key that come after an ID.
+pub struct StreamRead<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> {
+    after: Option<StreamId>,
+    backend: Option<Arc<B>>,
+    block: Option<u64>,
+    count: Option<i64>,
+    fut: MaybeInFlightFuture<'a, Stream, B::Error>,
+    key: Option<K>,
+}
+
+impl<'a, B: Backend, K: AsRef<[u8]> + 'a + Send + Unpin> StreamRead<'a, B, K> {
+    pub(crate) fn new(backend: Arc<B>, key: K, after: StreamId) -> Self {
+        Self {
+            after: Some(after),
+            backend: Some(backend),
+            block: None,
+            count: None,
+            fut: None,
+            key: Some(key),
+        }
+    }
+
+    /// Wait up to a duration for new entries if there aren't any yet.
+    ///
+    /// Only server instances wait; the in-memory backend resolves
+    /// immediately.
+    pub fn block(mut self, duration: Duration) -> Self {
+        self.block
+            .replace(duration.as_millis().try_into().unwrap_or(u64::MAX));
+
+        self
+    }
+
+    /// Set the maximum number of entries to read.
+    pub fn count(mut self, count: i64) -> Self {
+        self.count.replace(count);
+
+        self
+    }
+}
+
+impl<'a, B: Backend + Send + Sync + 'static, K: AsRef<[u8]> + Send + Unpin> Future
+    for StreamRead<'a, B, K>
+{
+    type Output = Result<Stream, B::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.fut.is_none() {
+            let backend = { self.backend.take().expect("backend only taken once") };
+            let key = self.key.take().expect("key only taken once");
+            let after = self.after.take().expect("after only taken once");
+            let block = self.block.take();
+            let count = self.count.take();
+
+            self.fut.replace(Box::pin(async move {
+                let key = key.as_ref();
+                backend.stream_read(key, after, count, block).await
+            }));
+        }
+
+        self.fut.as_mut().expect("future exists").as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamRead;
+    use crate::backend::MemoryBackend;
+    use alloc::vec::Vec;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(StreamRead<MemoryBackend, Vec<u8>>: Send);
+}
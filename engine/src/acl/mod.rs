@@ -0,0 +1,345 @@
+//! Access control lists of users permitted to run some commands on some keys.
+//!
+//! Refer to [`Acl`] for more information.
+//!
+//! [`Acl`]: struct.Acl.html
+
+mod user;
+
+pub use self::user::User;
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use dashmap::{mapref::one::Ref, DashMap};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AclError {
+    /// A line of an ACL file doesn't start with `user` followed by a name.
+    LineInvalid { line: usize },
+    /// A rule configuring a user isn't valid, optionally on a line of an ACL
+    /// file.
+    RuleInvalid { line: Option<usize>, rule: String },
+}
+
+impl Display for AclError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::LineInvalid { line } => f.write_fmt(format_args!(
+                "line {} must start with 'user' and a name",
+                line
+            )),
+            Self::RuleInvalid {
+                line: Some(line),
+                rule,
+            } => f.write_fmt(format_args!(
+                "the rule '{}' on line {} is invalid",
+                rule, line
+            )),
+            Self::RuleInvalid { line: None, rule } => {
+                f.write_fmt(format_args!("the rule '{}' is invalid", rule))
+            }
+        }
+    }
+}
+
+/// The users of an instance of the engine.
+///
+/// There's always a [default user] that's permitted to run every command on
+/// every key. Sessions use it until they authenticate as another user, as
+/// long as it doesn't require a password.
+///
+/// Users can be loaded from an ACL file, where each line configures a user
+/// with its name and [rules]:
+///
+/// ```text
+/// # Comments and empty lines are ignored.
+/// user default >hunter2
+/// user cache >secret +@read +@write ~cache:*
+/// user metrics nopass readonly +@read +stats
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::{acl::Acl, command::CommandId};
+///
+/// let acl = Acl::new(None);
+/// acl.load("user reader >secret +@read ~*").unwrap();
+///
+/// let reader = acl.user("reader").unwrap();
+/// assert!(reader.check_password(b"secret"));
+/// assert!(!reader.permits_command(CommandId::Set));
+/// ```
+///
+/// [default user]: #associatedconstant.DEFAULT_USER
+/// [rules]: struct.User.html
+#[derive(Debug)]
+pub struct Acl {
+    /// The IDs of users by their name.
+    ids: DashMap<String, usize>,
+    next_id: AtomicUsize,
+    users: DashMap<usize, User>,
+}
+
+impl Acl {
+    /// The name of the default user.
+    pub const DEFAULT_USER: &'static str = "default";
+
+    /// The ID of the default user.
+    pub(crate) const DEFAULT_ID: usize = 1;
+
+    /// Create a list with only the default user, which requires a password if
+    /// one is provided.
+    pub fn new(password: Option<&[u8]>) -> Self {
+        let mut default = User::new(Self::DEFAULT_USER);
+
+        match password {
+            Some(password) => default.set_password(password),
+            None => default.apply("nopass").expect("nopass is valid"),
+        }
+
+        default.apply("+@all").expect("all commands is valid");
+        default.apply("allkeys").expect("all keys is valid");
+
+        let acl = Self {
+            ids: DashMap::new(),
+            next_id: AtomicUsize::new(Self::DEFAULT_ID),
+            users: DashMap::new(),
+        };
+        acl.insert(Self::DEFAULT_USER, default);
+
+        acl
+    }
+
+    /// Find the ID of a user if the password authenticates it.
+    pub(crate) fn authenticate(&self, name: &str, password: &[u8]) -> Option<usize> {
+        let id = *self.ids.get(name)?;
+
+        if self.users.get(&id)?.check_password(password) {
+            Some(id)
+        } else {
+            None
+        }
+    }
+
+    /// Delete a user.
+    ///
+    /// Sessions authenticated as the user are no longer authenticated.
+    /// Returns whether the user existed. The default user can't be deleted, so
+    /// this always returns `false` for it.
+    pub fn delete_user(&self, name: &str) -> bool {
+        if name == Self::DEFAULT_USER {
+            return false;
+        }
+
+        match self.ids.remove(name) {
+            Some((_, id)) => self.users.remove(&id).is_some(),
+            None => false,
+        }
+    }
+
+    /// Configure users from the contents of an ACL file.
+    ///
+    /// Users in the file are created or have the rules applied to them.
+    /// Returns an error with the number of the line if a line is invalid, in
+    /// which case no users are changed.
+    pub fn load(&self, contents: &str) -> Result<(), AclError> {
+        let mut users: Vec<User> = Vec::new();
+
+        for (idx, line) in contents.lines().enumerate() {
+            let line_number = idx + 1;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+
+            let name = match (parts.next(), parts.next()) {
+                (Some("user"), Some(name)) => name,
+                _ => return Err(AclError::LineInvalid { line: line_number }),
+            };
+
+            // Rules for a user may be split across several lines.
+            let user = match users.iter().position(|user| user.name() == name) {
+                Some(position) => users.remove(position),
+                None => self.user(name).unwrap_or_else(|| User::new(name)),
+            };
+
+            let user = Self::configure(user, parts).map_err(|source| match source {
+                AclError::RuleInvalid { rule, .. } => AclError::RuleInvalid {
+                    line: Some(line_number),
+                    rule,
+                },
+                other => other,
+            })?;
+
+            users.push(user);
+        }
+
+        for user in users {
+            let name = user.name().to_owned();
+            self.insert(&name, user);
+        }
+
+        Ok(())
+    }
+
+    /// Create a user or apply rules to an existing user.
+    ///
+    /// Returns an error if a rule is invalid, in which case the user isn't
+    /// changed.
+    pub fn set_user<'a>(
+        &self,
+        name: &str,
+        rules: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), AclError> {
+        let user = self.user(name).unwrap_or_else(|| User::new(name));
+        self.insert(name, Self::configure(user, rules)?);
+
+        Ok(())
+    }
+
+    /// Retrieve a copy of a user by name.
+    pub fn user(&self, name: &str) -> Option<User> {
+        let id = *self.ids.get(name)?;
+
+        self.users.get(&id).map(|user| user.clone())
+    }
+
+    /// Retrieve a reference to a user by ID.
+    pub(crate) fn user_by_id(&self, id: usize) -> Option<Ref<'_, usize, User>> {
+        self.users.get(&id)
+    }
+
+    /// Retrieve copies of every user, sorted by name.
+    pub fn users(&self) -> Vec<User> {
+        let mut users = self
+            .users
+            .iter()
+            .map(|user| user.value().clone())
+            .collect::<Vec<_>>();
+        users.sort_by(|a, b| a.name().cmp(b.name()));
+
+        users
+    }
+
+    /// Apply rules to a user.
+    fn configure<'a>(
+        mut user: User,
+        rules: impl IntoIterator<Item = &'a str>,
+    ) -> Result<User, AclError> {
+        for rule in rules {
+            user.apply(rule)?;
+        }
+
+        Ok(user)
+    }
+
+    /// Insert a user, keeping its ID if it already exists so that sessions
+    /// authenticated as it stay authenticated.
+    fn insert(&self, name: &str, user: User) {
+        let id = *self
+            .ids
+            .entry(name.to_owned())
+            .or_insert_with(|| self.next_id.fetch_add(1, Ordering::Relaxed));
+
+        self.users.insert(id, user);
+    }
+}
+
+impl Default for Acl {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Acl, AclError};
+    use crate::command::CommandId;
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_default_user() {
+        let acl = Acl::new(None);
+        let default = acl.user(Acl::DEFAULT_USER).unwrap();
+        assert!(default.nopass());
+        assert!(default.permits_command(CommandId::FlushAll));
+        assert!(default.permits_key(b"foo"));
+        assert!(!acl.delete_user(Acl::DEFAULT_USER));
+
+        let acl = Acl::new(Some(b"hunter2"));
+        assert_eq!(
+            Some(Acl::DEFAULT_ID),
+            acl.authenticate(Acl::DEFAULT_USER, b"hunter2")
+        );
+        assert!(acl.authenticate(Acl::DEFAULT_USER, b"hunter3").is_none());
+    }
+
+    #[test]
+    fn test_set_user() {
+        let acl = Acl::new(None);
+        assert!(acl
+            .set_user("foo", [">bar", "+get"].iter().copied())
+            .is_ok());
+        let id = acl.authenticate("foo", b"bar").unwrap();
+
+        // Applying rules keeps the user's ID and previous rules.
+        assert!(acl.set_user("foo", ["+set"].iter().copied()).is_ok());
+        assert_eq!(Some(id), acl.authenticate("foo", b"bar"));
+        let foo = acl.user("foo").unwrap();
+        assert!(foo.permits_command(CommandId::Get));
+        assert!(foo.permits_command(CommandId::Set));
+
+        // An invalid rule leaves the user unchanged.
+        assert!(acl
+            .set_user("foo", ["-set", "baz"].iter().copied())
+            .is_err());
+        assert!(acl.user("foo").unwrap().permits_command(CommandId::Set));
+
+        assert!(acl.delete_user("foo"));
+        assert!(!acl.delete_user("foo"));
+        assert!(acl.authenticate("foo", b"bar").is_none());
+    }
+
+    #[test]
+    fn test_load() {
+        let acl = Acl::new(None);
+        let file = "# users\n\nuser default >hunter2\nuser cache nopass +@read ~cache:*\n";
+        assert!(acl.load(file).is_ok());
+
+        let names = acl
+            .users()
+            .iter()
+            .map(|user| user.name().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(["cache", "default"], names.as_slice());
+        assert!(acl.authenticate(Acl::DEFAULT_USER, b"").is_none());
+        assert!(acl.authenticate("cache", b"").is_some());
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let acl = Acl::new(None);
+
+        assert_eq!(
+            Err(AclError::LineInvalid { line: 2 }),
+            acl.load("user foo nopass\nfoo bar")
+        );
+        assert_eq!(
+            Err(AclError::RuleInvalid {
+                line: Some(1),
+                rule: "+baz".to_owned(),
+            }),
+            acl.load("user foo +baz")
+        );
+
+        // Nothing is changed if any line is invalid.
+        assert!(acl.user("foo").is_none());
+    }
+}
@@ -0,0 +1,402 @@
+use super::AclError;
+use crate::{
+    command::command_id::{Category, CommandId},
+    state::glob,
+};
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::{
+    convert::TryFrom,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// A set of commands, with one bit for each possible command ID.
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+struct CommandSet([u64; 4]);
+
+impl CommandSet {
+    fn contains(&self, command_id: CommandId) -> bool {
+        let id = command_id as usize;
+
+        self.0[id / 64] & (1 << (id % 64)) != 0
+    }
+
+    fn insert(&mut self, command_id: CommandId) {
+        let id = command_id as usize;
+
+        self.0[id / 64] |= 1 << (id % 64);
+    }
+
+    fn remove(&mut self, command_id: CommandId) {
+        let id = command_id as usize;
+
+        self.0[id / 64] &= !(1 << (id % 64));
+    }
+}
+
+/// Iterate over every command.
+fn commands() -> impl Iterator<Item = CommandId> {
+    (0..=u8::MAX).filter_map(|id| CommandId::try_from(id).ok())
+}
+
+/// Iterate over every command in a category.
+fn commands_in(category: Category) -> impl Iterator<Item = CommandId> {
+    commands().filter(move |command_id| command_id.category() == category)
+}
+
+/// A user of an access control list, which can authenticate with a password
+/// and is permitted to run some commands on some keys.
+///
+/// Users are configured with rules, which are applied in order:
+///
+/// - `>password` sets the password the user authenticates with
+/// - `nopass` allows the user to authenticate with any password
+/// - `+command` and `-command` permit or deny a command by its name, such as
+///   `+get` or `-flush:all`
+/// - `+@category` and `-@category` permit or deny every command in a
+///   [`Category`], such as `+@read`; `+@all` and `-@all` permit or deny every
+///   command
+/// - `~pattern` permits keys matching a [glob pattern], such as `~user:*`;
+///   `allkeys` is the same as `~*` and `resetkeys` forgets every pattern
+/// - `readonly` denies every admin and write command regardless of the other
+///   rules, and `readwrite` undoes it
+///
+/// A new user can't authenticate and isn't permitted to run any commands
+/// until rules are applied. Commands in the [`Category::Connection`] category
+/// are always permitted.
+///
+/// Key patterns restrict the keys named in a request, such as the key whose
+/// fields `keys` lists. The results of commands enumerating keys, such as
+/// `scan`, are filtered by them, so only permitted keys are returned.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::{acl::User, command::CommandId};
+///
+/// let mut user = User::new("reader");
+/// user.apply("+@read").unwrap();
+/// user.apply("~cache:*").unwrap();
+///
+/// assert!(user.permits_command(CommandId::Get));
+/// assert!(!user.permits_command(CommandId::Set));
+/// assert!(user.permits_key(b"cache:foo"));
+/// assert!(!user.permits_key(b"foo"));
+/// ```
+///
+/// [`Category`]: ../command/command_id/enum.Category.html
+/// [`Category::Connection`]: ../command/command_id/enum.Category.html#variant.Connection
+/// [glob pattern]: ../state/glob/index.html
+#[derive(Clone)]
+pub struct User {
+    commands: CommandSet,
+    keys: Vec<Vec<u8>>,
+    name: String,
+    nopass: bool,
+    password: Option<Vec<u8>>,
+    read_only: bool,
+}
+
+impl User {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            commands: CommandSet::default(),
+            keys: Vec::new(),
+            name: name.into(),
+            nopass: false,
+            password: None,
+            read_only: false,
+        }
+    }
+
+    /// Apply a rule to the user.
+    ///
+    /// Returns [`AclError::RuleInvalid`] if the rule isn't valid, in which case
+    /// the user is left unchanged.
+    ///
+    /// [`AclError::RuleInvalid`]: enum.AclError.html#variant.RuleInvalid
+    pub fn apply(&mut self, rule: &str) -> Result<(), AclError> {
+        let invalid = || AclError::RuleInvalid {
+            line: None,
+            rule: rule.to_owned(),
+        };
+
+        match rule {
+            "allcommands" => return self.apply("+@all"),
+            "allkeys" => return self.apply("~*"),
+            "nocommands" => return self.apply("-@all"),
+            "nopass" => {
+                self.nopass = true;
+                self.password = None;
+            }
+            "readonly" => self.read_only = true,
+            "readwrite" => self.read_only = false,
+            "resetkeys" => self.keys.clear(),
+            _ => {
+                let (first, rest) = rule.split_at(rule.chars().next().map_or(0, char::len_utf8));
+
+                match first {
+                    ">" if !rest.is_empty() => {
+                        self.nopass = false;
+                        self.password = Some(rest.as_bytes().to_vec());
+                    }
+                    "~" if !rest.is_empty() => self.keys.push(rest.as_bytes().to_vec()),
+                    "+" | "-" => {
+                        let permit = first == "+";
+                        let commands: Vec<CommandId> = match rest.strip_prefix('@') {
+                            Some("all") => commands().collect(),
+                            Some(category) => {
+                                commands_in(Category::from_str(category).map_err(|_| invalid())?)
+                                    .collect()
+                            }
+                            None => {
+                                alloc::vec![CommandId::from_str(rest).map_err(|_| invalid())?]
+                            }
+                        };
+
+                        for command_id in commands {
+                            if permit {
+                                self.commands.insert(command_id);
+                            } else {
+                                self.commands.remove(command_id);
+                            }
+                        }
+                    }
+                    _ => return Err(invalid()),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the password the user authenticates with, regardless of whether
+    /// it's valid UTF-8 like the passwords of rules are.
+    pub(crate) fn set_password(&mut self, password: impl Into<Vec<u8>>) {
+        self.nopass = false;
+        self.password = Some(password.into());
+    }
+
+    /// Check whether a password authenticates the user.
+    pub fn check_password(&self, password: &[u8]) -> bool {
+        if self.nopass {
+            return true;
+        }
+
+        let expected = match self.password.as_ref() {
+            Some(expected) => expected,
+            None => return false,
+        };
+
+        // Compare every byte so that the time taken doesn't reveal how much of
+        // the password is correct.
+        expected.len() == password.len()
+            && expected
+                .iter()
+                .zip(password)
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Return whether the user can authenticate with any password.
+    pub fn nopass(&self) -> bool {
+        self.nopass
+    }
+
+    /// Return whether the user is denied every admin and write command.
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Return whether the user is permitted to run a command.
+    pub fn permits_command(&self, command_id: CommandId) -> bool {
+        match command_id.category() {
+            Category::Connection => true,
+            Category::Admin | Category::Write if self.read_only => false,
+            _ => self.commands.contains(command_id),
+        }
+    }
+
+    /// Return whether the user is permitted to use a key.
+    pub fn permits_key(&self, key: &[u8]) -> bool {
+        self.keys.iter().any(|pattern| glob::matches(pattern, key))
+    }
+
+    /// Describe the user as rules that would configure an identical user,
+    /// except for the password, which is never included.
+    pub fn rules(&self) -> String {
+        let mut rules = Vec::new();
+
+        if self.nopass {
+            rules.push("nopass".to_owned());
+        }
+
+        if self.read_only {
+            rules.push("readonly".to_owned());
+        }
+
+        let full = |category: Category| {
+            commands_in(category).all(|command_id| self.commands.contains(command_id))
+        };
+
+        if Category::ALL.iter().all(|category| full(*category)) {
+            rules.push("+@all".to_owned());
+        } else {
+            let full_categories = Category::ALL
+                .iter()
+                .copied()
+                .filter(|category| full(*category))
+                .collect::<Vec<_>>();
+
+            for category in &full_categories {
+                rules.push(["+@", category.name()].concat());
+            }
+
+            for command_id in commands() {
+                if self.commands.contains(command_id)
+                    && !full_categories.contains(&command_id.category())
+                {
+                    rules.push(["+", command_id.name()].concat());
+                }
+            }
+        }
+
+        for pattern in &self.keys {
+            rules.push(["~", &String::from_utf8_lossy(pattern)].concat());
+        }
+
+        rules.join(" ")
+    }
+}
+
+impl Debug for User {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // The password is redacted so that it doesn't end up in logs.
+        f.debug_struct("User")
+            .field("name", &self.name)
+            .field("rules", &self.rules())
+            .finish()
+    }
+}
+
+/// Display the user as a line of an ACL file.
+impl Display for User {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("user ")?;
+        f.write_str(&self.name)?;
+
+        let rules = self.rules();
+
+        if !rules.is_empty() {
+            f.write_str(" ")?;
+            f.write_str(&rules)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AclError, User};
+    use crate::command::CommandId;
+    use alloc::{borrow::ToOwned, string::ToString};
+
+    #[test]
+    fn test_new_user_denied() {
+        let user = User::new("foo");
+
+        assert!(!user.check_password(b""));
+        assert!(!user.permits_command(CommandId::Get));
+        assert!(user.permits_command(CommandId::Auth));
+        assert!(!user.permits_key(b"foo"));
+    }
+
+    #[test]
+    fn test_password() {
+        let mut user = User::new("foo");
+        assert!(user.apply(">hunter2").is_ok());
+        assert!(user.check_password(b"hunter2"));
+        assert!(!user.check_password(b"hunter3"));
+
+        assert!(user.apply("nopass").is_ok());
+        assert!(user.check_password(b"anything"));
+    }
+
+    #[test]
+    fn test_commands() {
+        let mut user = User::new("foo");
+        assert!(user.apply("+@all").is_ok());
+        assert!(user.apply("-@admin").is_ok());
+        assert!(user.apply("+stats").is_ok());
+        assert!(user.apply("-delete").is_ok());
+
+        assert!(user.permits_command(CommandId::Set));
+        assert!(user.permits_command(CommandId::Stats));
+        assert!(!user.permits_command(CommandId::FlushAll));
+        assert!(!user.permits_command(CommandId::Delete));
+    }
+
+    #[test]
+    fn test_read_only() {
+        let mut user = User::new("foo");
+        assert!(user.apply("allcommands").is_ok());
+        assert!(user.apply("readonly").is_ok());
+
+        assert!(user.permits_command(CommandId::Get));
+        assert!(!user.permits_command(CommandId::Set));
+        assert!(!user.permits_command(CommandId::Flush));
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut user = User::new("foo");
+        assert!(user.apply("~user:*").is_ok());
+        assert!(user.apply("~session:?").is_ok());
+
+        assert!(user.permits_key(b"user:1"));
+        assert!(user.permits_key(b"session:1"));
+        assert!(!user.permits_key(b"session:10"));
+
+        assert!(user.apply("resetkeys").is_ok());
+        assert!(!user.permits_key(b"user:1"));
+        assert!(user.apply("allkeys").is_ok());
+        assert!(user.permits_key(b"anything"));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let mut user = User::new("foo");
+
+        for rule in &["", ">", "~", "+", "+@", "+@foo", "-bar", "baz"] {
+            assert_eq!(
+                Err(AclError::RuleInvalid {
+                    line: None,
+                    rule: (*rule).to_owned(),
+                }),
+                user.apply(rule)
+            );
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        let mut user = User::new("foo");
+        assert_eq!("user foo", user.to_string());
+
+        for rule in &[">secret", "readonly", "+@read", "+set", "~a:*"] {
+            assert!(user.apply(rule).is_ok());
+        }
+
+        assert_eq!("readonly +@read +set ~a:*", user.rules());
+
+        let mut all = User::new("bar");
+        assert!(all.apply("nopass").is_ok());
+        assert!(all.apply("+@all").is_ok());
+        assert_eq!("user bar nopass +@all", all.to_string());
+    }
+}
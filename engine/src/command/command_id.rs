@@ -4,6 +4,48 @@ use core::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 
+/// A group of commands with a similar effect.
+///
+/// Users of an access control list can be permitted or denied every command in
+/// a category at once.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Category {
+    /// Commands managing the instance as a whole, such as flushing every key.
+    Admin,
+    /// Commands affecting only the session, which every user can run.
+    Connection,
+    /// Commands that only read keys.
+    Read,
+    /// Commands that may create, modify or remove keys.
+    Write,
+}
+
+impl Category {
+    /// Every category.
+    pub const ALL: [Self; 4] = [Self::Admin, Self::Connection, Self::Read, Self::Write];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Admin => "admin",
+            Self::Connection => "connection",
+            Self::Read => "read",
+            Self::Write => "write",
+        }
+    }
+}
+
+impl FromStr for Category {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|category| category.name() == s)
+            .ok_or(())
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ArgumentNotation {
     Multiple,
@@ -29,6 +71,12 @@ pub enum CommandId {
     Decrement = 1,
     IncrementBy = 2,
     DecrementBy = 3,
+    Multiply = 4,
+    Divide = 5,
+    Modulo = 6,
+    SetIfGreater = 7,
+    SetIfLess = 8,
+    Clamp = 9,
     Set = 10,
     Get = 11,
     Delete = 12,
@@ -36,35 +84,294 @@ pub enum CommandId {
     Is = 14,
     Rename = 15,
     Type = 16,
+    Scan = 17,
+    Copy = 18,
+    MemoryUsage = 19,
     Append = 20,
     Length = 21,
     Keys = 22,
+    ObjectInfo = 23,
+    Dump = 24,
+    Restore = 25,
+    Substring = 30,
+    Slice = 31,
+    SetRange = 32,
+    StartsWith = 33,
+    EndsWith = 34,
+    Uppercase = 35,
+    Lowercase = 36,
+    Split = 37,
+    BitGet = 40,
+    BitSet = 41,
+    BitCount = 42,
+    BitPosition = 43,
+    BitAnd = 44,
+    BitOr = 45,
+    BitXor = 46,
+    BitNot = 47,
+    HyperLogLogAdd = 50,
+    HyperLogLogCount = 51,
+    HyperLogLogMerge = 52,
+    StreamAppend = 60,
+    StreamRange = 61,
+    StreamRead = 62,
+    StreamTrim = 63,
+    StreamTrimAge = 64,
+    DocumentGet = 70,
+    DocumentSet = 71,
+    DocumentDelete = 72,
+    DocumentAppend = 73,
+    DocumentIncrement = 74,
     Echo = 100,
     Stats = 101,
+    Select = 102,
+    Flush = 103,
+    FlushAll = 104,
+    FlushPrefix = 105,
+    Auth = 106,
+    AclSet = 107,
+    AclDelete = 108,
+    AclList = 109,
+    ConfigGet = 110,
+    ConfigSet = 111,
+    ClientKill = 112,
+    ClientList = 113,
 }
 
 impl CommandId {
+    /// Whether the command may store new data, using more memory.
+    ///
+    /// These are the commands rejected once the memory limit has been reached
+    /// and no keys can be evicted. Commands that only read or remove data are
+    /// always allowed, so that memory can be freed.
+    pub fn allocates(self) -> bool {
+        use CommandId::*;
+
+        match self {
+            AclDelete => false,
+            AclList => false,
+            AclSet => false,
+            Append => true,
+            Auth => false,
+            BitAnd => true,
+            BitCount => false,
+            BitGet => false,
+            BitNot => true,
+            BitOr => true,
+            BitPosition => false,
+            BitSet => true,
+            BitXor => true,
+            Clamp => false,
+            ClientKill => false,
+            ClientList => false,
+            ConfigGet => false,
+            ConfigSet => false,
+            Copy => true,
+            Decrement => true,
+            DecrementBy => true,
+            Delete => false,
+            Divide => true,
+            DocumentAppend => true,
+            DocumentDelete => false,
+            DocumentGet => false,
+            DocumentIncrement => true,
+            DocumentSet => true,
+            Dump => false,
+            Echo => false,
+            EndsWith => false,
+            Exists => false,
+            Flush => false,
+            FlushAll => false,
+            FlushPrefix => false,
+            Get => false,
+            HyperLogLogAdd => true,
+            HyperLogLogCount => false,
+            HyperLogLogMerge => true,
+            Increment => true,
+            IncrementBy => true,
+            Is => false,
+            Keys => false,
+            Length => false,
+            Lowercase => true,
+            MemoryUsage => false,
+            Modulo => true,
+            Multiply => true,
+            ObjectInfo => false,
+            Rename => false,
+            Restore => true,
+            Scan => false,
+            Select => false,
+            Set => true,
+            SetIfGreater => true,
+            SetIfLess => true,
+            SetRange => true,
+            Slice => false,
+            Split => false,
+            StartsWith => false,
+            Stats => false,
+            StreamAppend => true,
+            StreamRange => false,
+            StreamRead => false,
+            StreamTrim => false,
+            StreamTrimAge => false,
+            Substring => false,
+            Type => false,
+            Uppercase => true,
+        }
+    }
+
+    /// Retrieve the category of the command, used to permit or deny groups of
+    /// commands to users.
+    pub fn category(self) -> Category {
+        use CommandId::*;
+
+        match self {
+            AclDelete => Category::Admin,
+            AclList => Category::Admin,
+            AclSet => Category::Admin,
+            Append => Category::Write,
+            Auth => Category::Connection,
+            BitAnd => Category::Write,
+            BitCount => Category::Read,
+            BitGet => Category::Read,
+            BitNot => Category::Write,
+            BitOr => Category::Write,
+            BitPosition => Category::Read,
+            BitSet => Category::Write,
+            BitXor => Category::Write,
+            Clamp => Category::Write,
+            ClientKill => Category::Admin,
+            ClientList => Category::Admin,
+            ConfigGet => Category::Admin,
+            ConfigSet => Category::Admin,
+            Copy => Category::Write,
+            Decrement => Category::Write,
+            DecrementBy => Category::Write,
+            Delete => Category::Write,
+            Divide => Category::Write,
+            DocumentAppend => Category::Write,
+            DocumentDelete => Category::Write,
+            DocumentGet => Category::Read,
+            DocumentIncrement => Category::Write,
+            DocumentSet => Category::Write,
+            Dump => Category::Read,
+            Echo => Category::Connection,
+            EndsWith => Category::Read,
+            Exists => Category::Read,
+            Flush => Category::Admin,
+            FlushAll => Category::Admin,
+            FlushPrefix => Category::Admin,
+            Get => Category::Read,
+            HyperLogLogAdd => Category::Write,
+            HyperLogLogCount => Category::Read,
+            HyperLogLogMerge => Category::Write,
+            Increment => Category::Write,
+            IncrementBy => Category::Write,
+            Is => Category::Read,
+            Keys => Category::Read,
+            Length => Category::Read,
+            Lowercase => Category::Write,
+            MemoryUsage => Category::Read,
+            Modulo => Category::Write,
+            Multiply => Category::Write,
+            ObjectInfo => Category::Read,
+            Rename => Category::Write,
+            Restore => Category::Write,
+            Scan => Category::Read,
+            Select => Category::Connection,
+            Set => Category::Write,
+            SetIfGreater => Category::Write,
+            SetIfLess => Category::Write,
+            SetRange => Category::Write,
+            Slice => Category::Read,
+            Split => Category::Read,
+            StartsWith => Category::Read,
+            Stats => Category::Admin,
+            StreamAppend => Category::Write,
+            StreamRange => Category::Read,
+            StreamRead => Category::Read,
+            StreamTrim => Category::Write,
+            StreamTrimAge => Category::Write,
+            Substring => Category::Read,
+            Type => Category::Read,
+            Uppercase => Category::Write,
+        }
+    }
+
     pub fn argument_notation(self) -> ArgumentNotation {
         use ArgumentNotation::{Multiple, None, One};
         use CommandId::*;
 
         match self {
+            AclDelete => One,
+            AclList => None,
+            AclSet => Multiple,
             Append => One,
+            Auth => Multiple,
+            BitAnd => None,
+            BitCount => Multiple,
+            BitGet => One,
+            BitNot => None,
+            BitOr => None,
+            BitPosition => One,
+            BitSet => Multiple,
+            BitXor => None,
+            Clamp => Multiple,
+            ClientKill => One,
+            ClientList => None,
+            ConfigGet => Multiple,
+            ConfigSet => Multiple,
+            Copy => One,
             Delete => One,
             Decrement => None,
             DecrementBy => One,
+            Divide => One,
+            DocumentAppend => Multiple,
+            DocumentDelete => One,
+            DocumentGet => One,
+            DocumentIncrement => Multiple,
+            DocumentSet => Multiple,
+            Dump => None,
             Echo => Multiple,
+            EndsWith => One,
             Exists => None,
+            Flush => One,
+            FlushAll => One,
+            FlushPrefix => Multiple,
             Get => None,
+            HyperLogLogAdd => Multiple,
+            HyperLogLogCount => None,
+            HyperLogLogMerge => None,
             Increment => None,
             IncrementBy => One,
             Is => None,
             Keys => None,
             Length => One,
-            Rename => None,
+            Lowercase => None,
+            MemoryUsage => None,
+            Modulo => One,
+            Multiply => One,
+            ObjectInfo => None,
+            Rename => One,
+            Restore => One,
+            Scan => Multiple,
+            Select => One,
             Set => One,
+            SetIfGreater => One,
+            SetIfLess => One,
+            SetRange => Multiple,
+            Slice => Multiple,
+            Split => One,
+            StartsWith => One,
             Stats => None,
+            StreamAppend => Multiple,
+            StreamRange => Multiple,
+            StreamRead => Multiple,
+            StreamTrim => One,
+            StreamTrimAge => One,
+            Substring => Multiple,
             Type => None,
+            Uppercase => None,
         }
     }
 
@@ -73,22 +380,75 @@ impl CommandId {
         use KeyNotation::{Multiple, None, One, Two};
 
         match self {
+            AclDelete => None,
+            AclList => None,
+            AclSet => None,
             Append => One,
+            Auth => None,
+            BitAnd => Multiple,
+            BitCount => One,
+            BitGet => One,
+            BitNot => Two,
+            BitOr => Multiple,
+            BitPosition => One,
+            BitSet => One,
+            BitXor => Multiple,
+            Clamp => One,
+            ClientKill => None,
+            ClientList => None,
+            ConfigGet => None,
+            ConfigSet => None,
+            Copy => Two,
             Delete => One,
             Decrement => One,
             DecrementBy => One,
+            Divide => One,
+            DocumentAppend => One,
+            DocumentDelete => One,
+            DocumentGet => One,
+            DocumentIncrement => One,
+            DocumentSet => One,
+            Dump => One,
             Echo => None,
+            EndsWith => One,
             Exists => Multiple,
+            Flush => None,
+            FlushAll => None,
+            FlushPrefix => None,
             Get => One,
+            HyperLogLogAdd => One,
+            HyperLogLogCount => Multiple,
+            HyperLogLogMerge => Multiple,
             Increment => One,
             IncrementBy => One,
             Is => Multiple,
             Keys => One,
             Length => One,
+            Lowercase => One,
+            MemoryUsage => One,
+            Modulo => One,
+            Multiply => One,
+            ObjectInfo => One,
             Rename => Two,
+            Restore => One,
+            Scan => None,
+            Select => None,
             Set => One,
+            SetIfGreater => One,
+            SetIfLess => One,
+            SetRange => One,
+            Slice => One,
+            Split => One,
+            StartsWith => One,
             Stats => None,
+            StreamAppend => One,
+            StreamRange => One,
+            StreamRead => One,
+            StreamTrim => One,
+            StreamTrimAge => One,
+            Substring => One,
             Type => One,
+            Uppercase => One,
         }
     }
 
@@ -101,22 +461,75 @@ impl CommandId {
 
     pub fn name(&self) -> &str {
         match self {
+            Self::AclDelete => "acl:delete",
+            Self::AclList => "acl:list",
+            Self::AclSet => "acl:set",
             Self::Append => "append",
+            Self::Auth => "auth",
+            Self::BitAnd => "bit:and",
+            Self::BitCount => "bit:count",
+            Self::BitGet => "bit:get",
+            Self::BitNot => "bit:not",
+            Self::BitOr => "bit:or",
+            Self::BitPosition => "bit:position",
+            Self::BitSet => "bit:set",
+            Self::BitXor => "bit:xor",
+            Self::Clamp => "clamp",
+            Self::ClientKill => "client:kill",
+            Self::ClientList => "client:list",
+            Self::ConfigGet => "config:get",
+            Self::ConfigSet => "config:set",
+            Self::Copy => "copy",
             Self::DecrementBy => "decrement:by",
             Self::Decrement => "decrement",
             Self::Delete => "delete",
+            Self::Divide => "divide",
+            Self::DocumentAppend => "document:append",
+            Self::DocumentDelete => "document:delete",
+            Self::DocumentGet => "document:get",
+            Self::DocumentIncrement => "document:increment",
+            Self::DocumentSet => "document:set",
+            Self::Dump => "dump",
             Self::Echo => "echo",
+            Self::EndsWith => "ends:with",
             Self::Exists => "exists",
+            Self::Flush => "flush",
+            Self::FlushAll => "flush:all",
+            Self::FlushPrefix => "flush:prefix",
             Self::Get => "get",
+            Self::HyperLogLogAdd => "hll:add",
+            Self::HyperLogLogCount => "hll:count",
+            Self::HyperLogLogMerge => "hll:merge",
             Self::IncrementBy => "increment:by",
             Self::Increment => "increment",
             Self::Is => "is",
             Self::Keys => "keys",
             Self::Length => "length",
+            Self::Lowercase => "lowercase",
+            Self::MemoryUsage => "memory:usage",
+            Self::Modulo => "modulo",
+            Self::Multiply => "multiply",
+            Self::ObjectInfo => "object:info",
             Self::Rename => "rename",
+            Self::Restore => "restore",
+            Self::Scan => "scan",
+            Self::Select => "select",
             Self::Set => "set",
+            Self::SetIfGreater => "set:greater",
+            Self::SetIfLess => "set:less",
+            Self::SetRange => "set:range",
+            Self::Slice => "slice",
+            Self::Split => "split",
+            Self::StartsWith => "starts:with",
             Self::Stats => "stats",
+            Self::StreamAppend => "stream:append",
+            Self::StreamRange => "stream:range",
+            Self::StreamRead => "stream:read",
+            Self::StreamTrim => "stream:trim",
+            Self::StreamTrimAge => "stream:trim:age",
+            Self::Substring => "substring",
             Self::Type => "type",
+            Self::Uppercase => "uppercase",
         }
     }
 }
@@ -132,22 +545,75 @@ impl FromStr for CommandId {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
+            "acl:delete" => Self::AclDelete,
+            "acl:list" => Self::AclList,
+            "acl:set" => Self::AclSet,
             "append" => Self::Append,
+            "auth" => Self::Auth,
+            "bit:and" => Self::BitAnd,
+            "bit:count" => Self::BitCount,
+            "bit:get" => Self::BitGet,
+            "bit:not" => Self::BitNot,
+            "bit:or" => Self::BitOr,
+            "bit:position" => Self::BitPosition,
+            "bit:set" => Self::BitSet,
+            "bit:xor" => Self::BitXor,
+            "clamp" => Self::Clamp,
+            "client:kill" => Self::ClientKill,
+            "client:list" => Self::ClientList,
+            "config:get" => Self::ConfigGet,
+            "config:set" => Self::ConfigSet,
+            "copy" => Self::Copy,
             "decrement:by" => Self::DecrementBy,
             "decrement" => Self::Decrement,
             "delete" => Self::Delete,
+            "divide" => Self::Divide,
+            "document:append" => Self::DocumentAppend,
+            "document:delete" => Self::DocumentDelete,
+            "document:get" => Self::DocumentGet,
+            "document:increment" => Self::DocumentIncrement,
+            "document:set" => Self::DocumentSet,
+            "dump" => Self::Dump,
             "echo" => Self::Echo,
+            "ends:with" => Self::EndsWith,
             "exists" => Self::Exists,
+            "flush" => Self::Flush,
+            "flush:all" => Self::FlushAll,
+            "flush:prefix" => Self::FlushPrefix,
             "get" => Self::Get,
+            "hll:add" => Self::HyperLogLogAdd,
+            "hll:count" => Self::HyperLogLogCount,
+            "hll:merge" => Self::HyperLogLogMerge,
             "increment:by" => Self::IncrementBy,
             "increment" => Self::Increment,
             "is" => Self::Is,
             "keys" => Self::Keys,
             "length" => Self::Length,
+            "lowercase" => Self::Lowercase,
+            "memory:usage" => Self::MemoryUsage,
+            "modulo" => Self::Modulo,
+            "multiply" => Self::Multiply,
+            "object:info" => Self::ObjectInfo,
             "rename" => Self::Rename,
+            "restore" => Self::Restore,
+            "scan" => Self::Scan,
+            "select" => Self::Select,
             "set" => Self::Set,
+            "set:greater" => Self::SetIfGreater,
+            "set:less" => Self::SetIfLess,
+            "set:range" => Self::SetRange,
+            "slice" => Self::Slice,
+            "split" => Self::Split,
+            "starts:with" => Self::StartsWith,
             "stats" => Self::Stats,
+            "stream:append" => Self::StreamAppend,
+            "stream:range" => Self::StreamRange,
+            "stream:read" => Self::StreamRead,
+            "stream:trim" => Self::StreamTrim,
+            "stream:trim:age" => Self::StreamTrimAge,
+            "substring" => Self::Substring,
             "type" => Self::Type,
+            "uppercase" => Self::Uppercase,
             _ => return Err(InvalidCommandId),
         })
     }
@@ -162,6 +628,12 @@ impl TryFrom<u8> for CommandId {
             1 => Self::Decrement,
             2 => Self::IncrementBy,
             3 => Self::DecrementBy,
+            4 => Self::Multiply,
+            5 => Self::Divide,
+            6 => Self::Modulo,
+            7 => Self::SetIfGreater,
+            8 => Self::SetIfLess,
+            9 => Self::Clamp,
             10 => Self::Set,
             11 => Self::Get,
             12 => Self::Delete,
@@ -169,11 +641,58 @@ impl TryFrom<u8> for CommandId {
             14 => Self::Is,
             15 => Self::Rename,
             16 => Self::Type,
+            17 => Self::Scan,
+            18 => Self::Copy,
+            19 => Self::MemoryUsage,
             20 => Self::Append,
             21 => Self::Length,
             22 => Self::Keys,
+            23 => Self::ObjectInfo,
+            24 => Self::Dump,
+            25 => Self::Restore,
+            30 => Self::Substring,
+            31 => Self::Slice,
+            32 => Self::SetRange,
+            33 => Self::StartsWith,
+            34 => Self::EndsWith,
+            35 => Self::Uppercase,
+            36 => Self::Lowercase,
+            37 => Self::Split,
+            40 => Self::BitGet,
+            41 => Self::BitSet,
+            42 => Self::BitCount,
+            43 => Self::BitPosition,
+            44 => Self::BitAnd,
+            45 => Self::BitOr,
+            46 => Self::BitXor,
+            47 => Self::BitNot,
+            50 => Self::HyperLogLogAdd,
+            51 => Self::HyperLogLogCount,
+            52 => Self::HyperLogLogMerge,
+            60 => Self::StreamAppend,
+            61 => Self::StreamRange,
+            62 => Self::StreamRead,
+            63 => Self::StreamTrim,
+            64 => Self::StreamTrimAge,
+            70 => Self::DocumentGet,
+            71 => Self::DocumentSet,
+            72 => Self::DocumentDelete,
+            73 => Self::DocumentAppend,
+            74 => Self::DocumentIncrement,
             100 => Self::Echo,
             101 => Self::Stats,
+            102 => Self::Select,
+            103 => Self::Flush,
+            104 => Self::FlushAll,
+            105 => Self::FlushPrefix,
+            106 => Self::Auth,
+            107 => Self::AclSet,
+            108 => Self::AclDelete,
+            109 => Self::AclList,
+            110 => Self::ConfigGet,
+            111 => Self::ConfigSet,
+            112 => Self::ClientKill,
+            113 => Self::ClientList,
             _ => return Err(InvalidCommandId),
         })
     }
@@ -217,6 +736,12 @@ mod tests {
 
     #[test]
     fn test_from_str() {
+        assert_eq!(
+            CommandId::AclDelete,
+            CommandId::from_str("acl:delete").unwrap()
+        );
+        assert_eq!(CommandId::AclList, CommandId::from_str("acl:list").unwrap());
+        assert_eq!(CommandId::AclSet, CommandId::from_str("acl:set").unwrap());
         assert_eq!(CommandId::Append, CommandId::from_str("append").unwrap());
         assert_eq!(
             CommandId::DecrementBy,
@@ -226,9 +751,77 @@ mod tests {
             CommandId::Decrement,
             CommandId::from_str("decrement").unwrap()
         );
+        assert_eq!(CommandId::Auth, CommandId::from_str("auth").unwrap());
+        assert_eq!(CommandId::BitAnd, CommandId::from_str("bit:and").unwrap());
+        assert_eq!(
+            CommandId::BitCount,
+            CommandId::from_str("bit:count").unwrap()
+        );
+        assert_eq!(CommandId::BitGet, CommandId::from_str("bit:get").unwrap());
+        assert_eq!(CommandId::BitNot, CommandId::from_str("bit:not").unwrap());
+        assert_eq!(CommandId::BitOr, CommandId::from_str("bit:or").unwrap());
+        assert_eq!(
+            CommandId::BitPosition,
+            CommandId::from_str("bit:position").unwrap()
+        );
+        assert_eq!(CommandId::BitSet, CommandId::from_str("bit:set").unwrap());
+        assert_eq!(CommandId::BitXor, CommandId::from_str("bit:xor").unwrap());
+        assert_eq!(CommandId::Clamp, CommandId::from_str("clamp").unwrap());
+        assert_eq!(
+            CommandId::ConfigGet,
+            CommandId::from_str("config:get").unwrap()
+        );
+        assert_eq!(
+            CommandId::ConfigSet,
+            CommandId::from_str("config:set").unwrap()
+        );
+        assert_eq!(
+            CommandId::ClientKill,
+            CommandId::from_str("client:kill").unwrap()
+        );
+        assert_eq!(
+            CommandId::ClientList,
+            CommandId::from_str("client:list").unwrap()
+        );
+        assert_eq!(CommandId::Copy, CommandId::from_str("copy").unwrap());
         assert_eq!(CommandId::Delete, CommandId::from_str("delete").unwrap());
+        assert_eq!(CommandId::Divide, CommandId::from_str("divide").unwrap());
+        assert_eq!(
+            CommandId::DocumentAppend,
+            CommandId::from_str("document:append").unwrap()
+        );
+        assert_eq!(
+            CommandId::DocumentDelete,
+            CommandId::from_str("document:delete").unwrap()
+        );
+        assert_eq!(
+            CommandId::DocumentGet,
+            CommandId::from_str("document:get").unwrap()
+        );
+        assert_eq!(
+            CommandId::DocumentIncrement,
+            CommandId::from_str("document:increment").unwrap()
+        );
+        assert_eq!(
+            CommandId::DocumentSet,
+            CommandId::from_str("document:set").unwrap()
+        );
+        assert_eq!(CommandId::Dump, CommandId::from_str("dump").unwrap());
         assert_eq!(CommandId::Echo, CommandId::from_str("echo").unwrap());
+        assert_eq!(
+            CommandId::EndsWith,
+            CommandId::from_str("ends:with").unwrap()
+        );
         assert_eq!(CommandId::Exists, CommandId::from_str("exists").unwrap());
+        assert_eq!(CommandId::Flush, CommandId::from_str("flush").unwrap());
+        assert_eq!(
+            CommandId::FlushAll,
+            CommandId::from_str("flush:all").unwrap()
+        );
+        assert_eq!(
+            CommandId::FlushPrefix,
+            CommandId::from_str("flush:prefix").unwrap()
+        );
         assert_eq!(CommandId::Get, CommandId::from_str("get").unwrap());
         assert_eq!(
             CommandId::IncrementBy,
@@ -238,52 +831,245 @@ mod tests {
             CommandId::Increment,
             CommandId::from_str("increment").unwrap()
         );
+        assert_eq!(
+            CommandId::HyperLogLogAdd,
+            CommandId::from_str("hll:add").unwrap()
+        );
+        assert_eq!(
+            CommandId::HyperLogLogCount,
+            CommandId::from_str("hll:count").unwrap()
+        );
+        assert_eq!(
+            CommandId::HyperLogLogMerge,
+            CommandId::from_str("hll:merge").unwrap()
+        );
         assert_eq!(CommandId::Is, CommandId::from_str("is").unwrap());
         assert_eq!(CommandId::Keys, CommandId::from_str("keys").unwrap());
         assert_eq!(CommandId::Length, CommandId::from_str("length").unwrap());
+        assert_eq!(
+            CommandId::Lowercase,
+            CommandId::from_str("lowercase").unwrap()
+        );
+        assert_eq!(
+            CommandId::MemoryUsage,
+            CommandId::from_str("memory:usage").unwrap()
+        );
+        assert_eq!(CommandId::Modulo, CommandId::from_str("modulo").unwrap());
+        assert_eq!(
+            CommandId::Multiply,
+            CommandId::from_str("multiply").unwrap()
+        );
+        assert_eq!(
+            CommandId::ObjectInfo,
+            CommandId::from_str("object:info").unwrap()
+        );
         assert_eq!(CommandId::Rename, CommandId::from_str("rename").unwrap());
+        assert_eq!(CommandId::Restore, CommandId::from_str("restore").unwrap());
+        assert_eq!(CommandId::Scan, CommandId::from_str("scan").unwrap());
+        assert_eq!(CommandId::Select, CommandId::from_str("select").unwrap());
         assert_eq!(CommandId::Set, CommandId::from_str("set").unwrap());
+        assert_eq!(
+            CommandId::SetRange,
+            CommandId::from_str("set:range").unwrap()
+        );
+        assert_eq!(
+            CommandId::SetIfGreater,
+            CommandId::from_str("set:greater").unwrap()
+        );
+        assert_eq!(
+            CommandId::SetIfLess,
+            CommandId::from_str("set:less").unwrap()
+        );
+        assert_eq!(CommandId::Slice, CommandId::from_str("slice").unwrap());
+        assert_eq!(CommandId::Split, CommandId::from_str("split").unwrap());
+        assert_eq!(
+            CommandId::StartsWith,
+            CommandId::from_str("starts:with").unwrap()
+        );
         assert_eq!(CommandId::Stats, CommandId::from_str("stats").unwrap());
+        assert_eq!(
+            CommandId::Substring,
+            CommandId::from_str("substring").unwrap()
+        );
+        assert_eq!(
+            CommandId::StreamAppend,
+            CommandId::from_str("stream:append").unwrap()
+        );
+        assert_eq!(
+            CommandId::StreamRange,
+            CommandId::from_str("stream:range").unwrap()
+        );
+        assert_eq!(
+            CommandId::StreamRead,
+            CommandId::from_str("stream:read").unwrap()
+        );
+        assert_eq!(
+            CommandId::StreamTrim,
+            CommandId::from_str("stream:trim").unwrap()
+        );
+        assert_eq!(
+            CommandId::StreamTrimAge,
+            CommandId::from_str("stream:trim:age").unwrap()
+        );
         assert_eq!(CommandId::Type, CommandId::from_str("type").unwrap());
+        assert_eq!(
+            CommandId::Uppercase,
+            CommandId::from_str("uppercase").unwrap()
+        );
     }
 
     #[test]
     fn test_try_from_u8() {
+        assert_eq!(CommandId::AclDelete, CommandId::try_from(108).unwrap());
+        assert_eq!(CommandId::AclList, CommandId::try_from(109).unwrap());
+        assert_eq!(CommandId::AclSet, CommandId::try_from(107).unwrap());
         assert_eq!(CommandId::Append, CommandId::try_from(20).unwrap());
+        assert_eq!(CommandId::Auth, CommandId::try_from(106).unwrap());
+        assert_eq!(CommandId::BitAnd, CommandId::try_from(44).unwrap());
+        assert_eq!(CommandId::BitCount, CommandId::try_from(42).unwrap());
+        assert_eq!(CommandId::BitGet, CommandId::try_from(40).unwrap());
+        assert_eq!(CommandId::BitNot, CommandId::try_from(47).unwrap());
+        assert_eq!(CommandId::BitOr, CommandId::try_from(45).unwrap());
+        assert_eq!(CommandId::BitPosition, CommandId::try_from(43).unwrap());
+        assert_eq!(CommandId::BitSet, CommandId::try_from(41).unwrap());
+        assert_eq!(CommandId::BitXor, CommandId::try_from(46).unwrap());
+        assert_eq!(CommandId::Clamp, CommandId::try_from(9).unwrap());
+        assert_eq!(CommandId::ClientKill, CommandId::try_from(112).unwrap());
+        assert_eq!(CommandId::ClientList, CommandId::try_from(113).unwrap());
+        assert_eq!(CommandId::ConfigGet, CommandId::try_from(110).unwrap());
+        assert_eq!(CommandId::ConfigSet, CommandId::try_from(111).unwrap());
+        assert_eq!(CommandId::Copy, CommandId::try_from(18).unwrap());
         assert_eq!(CommandId::DecrementBy, CommandId::try_from(3).unwrap());
         assert_eq!(CommandId::Decrement, CommandId::try_from(1).unwrap());
         assert_eq!(CommandId::Delete, CommandId::try_from(12).unwrap());
+        assert_eq!(CommandId::Divide, CommandId::try_from(5).unwrap());
+        assert_eq!(CommandId::DocumentAppend, CommandId::try_from(73).unwrap());
+        assert_eq!(CommandId::DocumentDelete, CommandId::try_from(72).unwrap());
+        assert_eq!(CommandId::DocumentGet, CommandId::try_from(70).unwrap());
+        assert_eq!(
+            CommandId::DocumentIncrement,
+            CommandId::try_from(74).unwrap()
+        );
+        assert_eq!(CommandId::DocumentSet, CommandId::try_from(71).unwrap());
+        assert_eq!(CommandId::Dump, CommandId::try_from(24).unwrap());
         assert_eq!(CommandId::Echo, CommandId::try_from(100).unwrap());
+        assert_eq!(CommandId::EndsWith, CommandId::try_from(34).unwrap());
         assert_eq!(CommandId::Exists, CommandId::try_from(13).unwrap());
+        assert_eq!(CommandId::Flush, CommandId::try_from(103).unwrap());
+        assert_eq!(CommandId::FlushAll, CommandId::try_from(104).unwrap());
+        assert_eq!(CommandId::FlushPrefix, CommandId::try_from(105).unwrap());
         assert_eq!(CommandId::Get, CommandId::try_from(11).unwrap());
+        assert_eq!(CommandId::HyperLogLogAdd, CommandId::try_from(50).unwrap());
+        assert_eq!(
+            CommandId::HyperLogLogCount,
+            CommandId::try_from(51).unwrap()
+        );
+        assert_eq!(
+            CommandId::HyperLogLogMerge,
+            CommandId::try_from(52).unwrap()
+        );
         assert_eq!(CommandId::IncrementBy, CommandId::try_from(2).unwrap());
         assert_eq!(CommandId::Increment, CommandId::try_from(0).unwrap());
         assert_eq!(CommandId::Is, CommandId::try_from(14).unwrap());
         assert_eq!(CommandId::Keys, CommandId::try_from(22).unwrap());
         assert_eq!(CommandId::Length, CommandId::try_from(21).unwrap());
+        assert_eq!(CommandId::Lowercase, CommandId::try_from(36).unwrap());
+        assert_eq!(CommandId::MemoryUsage, CommandId::try_from(19).unwrap());
+        assert_eq!(CommandId::Modulo, CommandId::try_from(6).unwrap());
+        assert_eq!(CommandId::Multiply, CommandId::try_from(4).unwrap());
+        assert_eq!(CommandId::ObjectInfo, CommandId::try_from(23).unwrap());
         assert_eq!(CommandId::Rename, CommandId::try_from(15).unwrap());
+        assert_eq!(CommandId::Restore, CommandId::try_from(25).unwrap());
+        assert_eq!(CommandId::Scan, CommandId::try_from(17).unwrap());
+        assert_eq!(CommandId::Select, CommandId::try_from(102).unwrap());
         assert_eq!(CommandId::Set, CommandId::try_from(10).unwrap());
+        assert_eq!(CommandId::SetIfGreater, CommandId::try_from(7).unwrap());
+        assert_eq!(CommandId::SetIfLess, CommandId::try_from(8).unwrap());
+        assert_eq!(CommandId::SetRange, CommandId::try_from(32).unwrap());
+        assert_eq!(CommandId::Slice, CommandId::try_from(31).unwrap());
+        assert_eq!(CommandId::Split, CommandId::try_from(37).unwrap());
+        assert_eq!(CommandId::StartsWith, CommandId::try_from(33).unwrap());
         assert_eq!(CommandId::Stats, CommandId::try_from(101).unwrap());
+        assert_eq!(CommandId::StreamAppend, CommandId::try_from(60).unwrap());
+        assert_eq!(CommandId::StreamRange, CommandId::try_from(61).unwrap());
+        assert_eq!(CommandId::StreamRead, CommandId::try_from(62).unwrap());
+        assert_eq!(CommandId::StreamTrim, CommandId::try_from(63).unwrap());
+        assert_eq!(CommandId::StreamTrimAge, CommandId::try_from(64).unwrap());
+        assert_eq!(CommandId::Substring, CommandId::try_from(30).unwrap());
         assert_eq!(CommandId::Type, CommandId::try_from(16).unwrap());
+        assert_eq!(CommandId::Uppercase, CommandId::try_from(35).unwrap());
     }
 
     #[test]
     fn test_name() {
+        assert_eq!("acl:delete", CommandId::AclDelete.name());
+        assert_eq!("acl:list", CommandId::AclList.name());
+        assert_eq!("acl:set", CommandId::AclSet.name());
         assert_eq!("append", CommandId::Append.name());
+        assert_eq!("auth", CommandId::Auth.name());
+        assert_eq!("bit:and", CommandId::BitAnd.name());
+        assert_eq!("bit:count", CommandId::BitCount.name());
+        assert_eq!("bit:get", CommandId::BitGet.name());
+        assert_eq!("bit:not", CommandId::BitNot.name());
+        assert_eq!("bit:or", CommandId::BitOr.name());
+        assert_eq!("bit:position", CommandId::BitPosition.name());
+        assert_eq!("bit:set", CommandId::BitSet.name());
+        assert_eq!("bit:xor", CommandId::BitXor.name());
+        assert_eq!("clamp", CommandId::Clamp.name());
+        assert_eq!("client:kill", CommandId::ClientKill.name());
+        assert_eq!("client:list", CommandId::ClientList.name());
+        assert_eq!("config:get", CommandId::ConfigGet.name());
+        assert_eq!("config:set", CommandId::ConfigSet.name());
+        assert_eq!("copy", CommandId::Copy.name());
         assert_eq!("decrement:by", CommandId::DecrementBy.name());
         assert_eq!("decrement", CommandId::Decrement.name());
         assert_eq!("delete", CommandId::Delete.name());
+        assert_eq!("divide", CommandId::Divide.name());
+        assert_eq!("document:append", CommandId::DocumentAppend.name());
+        assert_eq!("document:delete", CommandId::DocumentDelete.name());
+        assert_eq!("document:get", CommandId::DocumentGet.name());
+        assert_eq!("document:increment", CommandId::DocumentIncrement.name());
+        assert_eq!("document:set", CommandId::DocumentSet.name());
+        assert_eq!("dump", CommandId::Dump.name());
         assert_eq!("echo", CommandId::Echo.name());
+        assert_eq!("ends:with", CommandId::EndsWith.name());
         assert_eq!("exists", CommandId::Exists.name());
+        assert_eq!("flush", CommandId::Flush.name());
+        assert_eq!("flush:all", CommandId::FlushAll.name());
+        assert_eq!("flush:prefix", CommandId::FlushPrefix.name());
         assert_eq!("get", CommandId::Get.name());
+        assert_eq!("hll:add", CommandId::HyperLogLogAdd.name());
+        assert_eq!("hll:count", CommandId::HyperLogLogCount.name());
+        assert_eq!("hll:merge", CommandId::HyperLogLogMerge.name());
         assert_eq!("increment:by", CommandId::IncrementBy.name());
         assert_eq!("increment", CommandId::Increment.name());
         assert_eq!("is", CommandId::Is.name());
         assert_eq!("keys", CommandId::Keys.name());
         assert_eq!("length", CommandId::Length.name());
+        assert_eq!("lowercase", CommandId::Lowercase.name());
+        assert_eq!("memory:usage", CommandId::MemoryUsage.name());
+        assert_eq!("modulo", CommandId::Modulo.name());
+        assert_eq!("multiply", CommandId::Multiply.name());
+        assert_eq!("object:info", CommandId::ObjectInfo.name());
         assert_eq!("rename", CommandId::Rename.name());
+        assert_eq!("restore", CommandId::Restore.name());
+        assert_eq!("scan", CommandId::Scan.name());
+        assert_eq!("select", CommandId::Select.name());
         assert_eq!("set", CommandId::Set.name());
+        assert_eq!("set:greater", CommandId::SetIfGreater.name());
+        assert_eq!("set:less", CommandId::SetIfLess.name());
+        assert_eq!("set:range", CommandId::SetRange.name());
+        assert_eq!("slice", CommandId::Slice.name());
+        assert_eq!("split", CommandId::Split.name());
+        assert_eq!("starts:with", CommandId::StartsWith.name());
         assert_eq!("stats", CommandId::Stats.name());
+        assert_eq!("stream:append", CommandId::StreamAppend.name());
+        assert_eq!("stream:range", CommandId::StreamRange.name());
+        assert_eq!("stream:read", CommandId::StreamRead.name());
+        assert_eq!("stream:trim", CommandId::StreamTrim.name());
+        assert_eq!("stream:trim:age", CommandId::StreamTrimAge.name());
+        assert_eq!("substring", CommandId::Substring.name());
         assert_eq!("type", CommandId::Type.name());
+        assert_eq!("uppercase", CommandId::Uppercase.name());
     }
 }
@@ -17,12 +17,35 @@ pub enum Error {
     KeyNonexistent = 5,
     KeyTypeRequired = 6,
     KeyTypeInvalid = 7,
+    CharBoundaryInvalid = 8,
+    ArithmeticOverflow = 9,
+    DivisionByZero = 10,
+    MemoryLimitReached = 11,
+    SizeLimitExceeded = 12,
+    DumpInvalid = 13,
+    Unauthenticated = 14,
+    PermissionDenied = 15,
+    AclRuleInvalid = 16,
+    ConfigInvalid = 17,
+    RateLimited = 18,
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
+            Self::AclRuleInvalid => f.write_str("a rule configuring a user is invalid"),
             Self::ArgumentRetrieval => f.write_str("couldn't retrieve required argument"),
+            Self::ArithmeticOverflow => f.write_str("the arithmetic operation overflowed"),
+            Self::CharBoundaryInvalid => {
+                f.write_str("the offset doesn't lie on a UTF-8 character boundary")
+            }
+            Self::ConfigInvalid => f.write_str(
+                "the configuration parameter is unknown, can't be changed or the value is invalid",
+            ),
+            Self::DivisionByZero => f.write_str("attempted to divide by zero"),
+            Self::DumpInvalid => {
+                f.write_str("the dump is invalid, corrupted or from another version")
+            }
             Self::KeyNonexistent => f.write_str("the specified key does not exist"),
             Self::KeyTypeDifferent => f.write_str("the key has a different type than required"),
             Self::KeyTypeInvalid => {
@@ -31,7 +54,22 @@ impl Display for Error {
             Self::KeyTypeRequired => f.write_str("a key type is required to be specified"),
             Self::KeyTypeUnexpected => f.write_str("didn't expect a specified request key type"),
             Self::KeyUnspecified => f.write_str("the key wasn't specified"),
+            Self::MemoryLimitReached => {
+                f.write_str("the memory limit was reached and no keys can be evicted")
+            }
+            Self::PermissionDenied => {
+                f.write_str("the user isn't permitted to run the command on the keys")
+            }
             Self::PreconditionFailed => f.write_str("a precondition for the command failed"),
+            Self::RateLimited => {
+                f.write_str("the session or its address is sending requests too quickly")
+            }
+            Self::SizeLimitExceeded => {
+                f.write_str("a key or value would be larger than the configured limit")
+            }
+            Self::Unauthenticated => {
+                f.write_str("the session must authenticate before running commands")
+            }
         }
     }
 }
@@ -49,6 +87,17 @@ impl TryFrom<u8> for Error {
             5 => Self::KeyNonexistent,
             6 => Self::KeyTypeRequired,
             7 => Self::KeyTypeInvalid,
+            8 => Self::CharBoundaryInvalid,
+            9 => Self::ArithmeticOverflow,
+            10 => Self::DivisionByZero,
+            11 => Self::MemoryLimitReached,
+            12 => Self::SizeLimitExceeded,
+            13 => Self::DumpInvalid,
+            14 => Self::Unauthenticated,
+            15 => Self::PermissionDenied,
+            16 => Self::AclRuleInvalid,
+            17 => Self::ConfigInvalid,
+            18 => Self::RateLimited,
             _ => return Err(()),
         })
     }
@@ -78,14 +127,25 @@ mod tests {
     #[test]
     fn test_error_try_from_u8() {
         let variants = &[
+            Error::AclRuleInvalid,
             Error::ArgumentRetrieval,
+            Error::ArithmeticOverflow,
+            Error::CharBoundaryInvalid,
+            Error::ConfigInvalid,
+            Error::DivisionByZero,
+            Error::DumpInvalid,
             Error::KeyNonexistent,
             Error::KeyTypeDifferent,
             Error::KeyTypeInvalid,
             Error::KeyTypeRequired,
             Error::KeyTypeUnexpected,
             Error::KeyUnspecified,
+            Error::MemoryLimitReached,
+            Error::PermissionDenied,
             Error::PreconditionFailed,
+            Error::RateLimited,
+            Error::SizeLimitExceeded,
+            Error::Unauthenticated,
         ];
 
         for variant in variants {
@@ -0,0 +1,68 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{acl::Acl, Hop};
+use alloc::vec::Vec;
+
+pub struct AclDelete;
+
+impl Dispatch for AclDelete {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let name = req
+            .typed_arg::<&str>(0)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        if name == Acl::DEFAULT_USER {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        response::write_bool(resp, hop.acl().delete_user(name));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AclDelete;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn request(name: &[u8]) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::AclDelete);
+        assert!(builder.bytes(name).is_ok());
+
+        builder
+    }
+
+    #[test]
+    fn test_delete() {
+        let hop = Hop::new();
+        assert!(hop.acl().set_user("foo", Vec::new()).is_ok());
+        let req = request(b"foo").into_request();
+
+        let mut resp = Vec::new();
+        assert!(AclDelete::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(true).as_bytes());
+        resp.clear();
+
+        assert!(AclDelete::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(false).as_bytes());
+    }
+
+    #[test]
+    fn test_delete_default() {
+        let hop = Hop::new();
+        let req = request(b"default").into_request();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            AclDelete::dispatch(&hop, &req, &mut Vec::new()).unwrap_err()
+        );
+    }
+}
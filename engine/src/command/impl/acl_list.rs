@@ -0,0 +1,49 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::Hop;
+use alloc::{string::ToString, vec::Vec};
+
+pub struct AclList;
+
+impl Dispatch for AclList {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let users = hop.acl().users();
+
+        response::write_list(resp, users.iter().map(ToString::to_string));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AclList;
+    use crate::{
+        command::{request::RequestBuilder, response, CommandId, Dispatch},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_list() {
+        let hop = Hop::new();
+        assert!(hop
+            .acl()
+            .set_user("foo", [">bar", "+get", "~*"].iter().copied())
+            .is_ok());
+        let req = RequestBuilder::new(CommandId::AclList).into_request();
+
+        let mut resp = Vec::new();
+        assert!(AclList::dispatch(&hop, &req, &mut resp).is_ok());
+
+        let mut expected = Vec::new();
+        response::write_list(
+            &mut expected,
+            ["user default nopass +@all ~*", "user foo +get ~*"].iter(),
+        );
+        assert_eq!(expected, resp);
+    }
+}
@@ -0,0 +1,82 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::Hop;
+use alloc::vec::Vec;
+use core::str;
+
+pub struct AclSet;
+
+impl Dispatch for AclSet {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let name = req
+            .typed_arg::<&str>(0)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        let rules = match req.args(1..) {
+            Some(args) => args
+                .map(str::from_utf8)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| DispatchError::AclRuleInvalid)?,
+            None => Vec::new(),
+        };
+
+        hop.acl()
+            .set_user(name, rules)
+            .map_err(|_| DispatchError::AclRuleInvalid)?;
+
+        let user = hop
+            .acl()
+            .user(name)
+            .ok_or(DispatchError::PreconditionFailed)?;
+
+        response::write_str(resp, &user.rules());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AclSet;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    fn request(rules: &[&str]) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::AclSet);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+
+        for rule in rules {
+            assert!(builder.bytes(rule.as_bytes()).is_ok());
+        }
+
+        builder
+    }
+
+    #[test]
+    fn test_set() {
+        let hop = Hop::new();
+        let req = request(&[">bar", "+@read", "~a:*"]).into_request();
+
+        let mut resp = Vec::new();
+        assert!(AclSet::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("+@read ~a:*".to_owned()).as_bytes());
+        assert!(hop.acl().user("foo").is_some());
+    }
+
+    #[test]
+    fn test_set_invalid() {
+        let hop = Hop::new();
+        let req = request(&["+@read", "+foo"]).into_request();
+
+        assert_eq!(
+            DispatchError::AclRuleInvalid,
+            AclSet::dispatch(&hop, &req, &mut Vec::new()).unwrap_err()
+        );
+        assert!(hop.acl().user("foo").is_none());
+    }
+}
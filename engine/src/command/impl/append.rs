@@ -12,9 +12,19 @@ use core::str;
 pub struct Append;
 
 impl Append {
+    /// Calculate the number of bytes the arguments add to a value.
+    fn added_size(args: &Arguments<'_>) -> usize {
+        args.clone()
+            .fold(0, |size: usize, arg| size.saturating_add(arg.len()))
+    }
     fn bytes(hop: &Hop, args: Arguments<'_>, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
+        let added = Self::added_size(&args);
+        hop.config().check_value_size(added)?;
+
         let mut key = hop.state().key_or_insert_with(key, Value::bytes);
         let bytes = key.as_bytes_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+        hop.config()
+            .check_value_size(bytes.len().saturating_add(added))?;
 
         for arg in args {
             bytes.extend_from_slice(arg);
@@ -26,8 +36,14 @@ impl Append {
     }
 
     fn list(hop: &Hop, args: Arguments<'_>, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
+        let added = args.clone().count();
+        hop.config()
+            .check_collection(added, args.clone().map(<[u8]>::len))?;
+
         let mut key = hop.state().key_or_insert_with(key, Value::list);
         let list = key.as_list_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+        hop.config()
+            .check_elements(list.len().saturating_add(added))?;
 
         list.append(&mut args.map(ToOwned::to_owned).collect());
 
@@ -42,8 +58,13 @@ impl Append {
         resp: &mut Vec<u8>,
         key: &[u8],
     ) -> DispatchResult<()> {
+        let added = Self::added_size(&args);
+        hop.config().check_value_size(added)?;
+
         let mut key = hop.state().key_or_insert_with(key, Value::string);
         let string = key.as_string_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+        hop.config()
+            .check_value_size(string.len().saturating_add(added))?;
 
         for arg in args {
             if let Ok(arg) = str::from_utf8(arg) {
@@ -51,7 +72,7 @@ impl Append {
             }
         }
 
-        response::write_str(resp, &string);
+        response::write_str(resp, string);
 
         Ok(())
     }
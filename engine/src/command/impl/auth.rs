@@ -0,0 +1,131 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{acl::Acl, Hop};
+use alloc::vec::Vec;
+use core::str;
+
+pub struct Auth;
+
+impl Dispatch for Auth {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        // Either only the password of the default user is provided, or the
+        // name of a user and its password.
+        let (username, password) = match (req.arg(0), req.arg(1)) {
+            (Some(username), Some(password)) => (
+                str::from_utf8(username).map_err(|_| DispatchError::ArgumentRetrieval)?,
+                password,
+            ),
+            (Some(password), None) => (Acl::DEFAULT_USER, password),
+            _ => return Err(DispatchError::ArgumentRetrieval),
+        };
+
+        if req.arg_count() == 1
+            && hop
+                .acl()
+                .user(Acl::DEFAULT_USER)
+                .is_some_and(|user| user.nopass())
+        {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        if !hop.authenticate(username, password) {
+            return Err(DispatchError::Unauthenticated);
+        }
+
+        response::write_bool(resp, true);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Auth;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn hop_with_password() -> Hop {
+        let mut builder = Hop::builder();
+        builder.password("hunter2");
+
+        builder.build()
+    }
+
+    fn request(password: &[u8]) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::Auth);
+        assert!(builder.bytes(password).is_ok());
+
+        builder
+    }
+
+    #[test]
+    fn test_auth() {
+        let hop = hop_with_password();
+        assert!(!hop.authenticated());
+
+        let mut resp = Vec::new();
+        let req = request(b"hunter2").into_request();
+        assert!(Auth::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(true).as_bytes());
+        assert!(hop.authenticated());
+
+        // Other handles to the same engine have to authenticate separately.
+        let other = hop_with_password();
+        assert!(!other.authenticated());
+    }
+
+    #[test]
+    fn test_auth_wrong_password() {
+        let hop = hop_with_password();
+
+        for password in [b"hunter".as_ref(), b"hunter3", b"hunter22"].iter() {
+            let req = request(password).into_request();
+            assert_eq!(
+                DispatchError::Unauthenticated,
+                Auth::dispatch(&hop, &req, &mut Vec::new()).unwrap_err()
+            );
+            assert!(!hop.authenticated());
+        }
+    }
+
+    #[test]
+    fn test_auth_no_password() {
+        let hop = Hop::new();
+        let req = request(b"hunter2").into_request();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            Auth::dispatch(&hop, &req, &mut Vec::new()).unwrap_err()
+        );
+        assert!(hop.authenticated());
+    }
+
+    #[test]
+    fn test_auth_user() {
+        let hop = Hop::new();
+        assert!(hop
+            .acl()
+            .set_user("foo", [">bar", "+@read", "~*"].iter().copied())
+            .is_ok());
+
+        let mut builder = RequestBuilder::new(CommandId::Auth);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"baz".as_ref()).is_ok());
+        assert_eq!(
+            DispatchError::Unauthenticated,
+            Auth::dispatch(&hop, &builder.into_request(), &mut Vec::new()).unwrap_err()
+        );
+
+        let mut builder = RequestBuilder::new(CommandId::Auth);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        assert!(Auth::dispatch(&hop, &builder.into_request(), &mut Vec::new()).is_ok());
+        assert!(hop.authenticated());
+    }
+}
@@ -0,0 +1,138 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    bit_get::BitGet,
+};
+use crate::{state::Value, Hop};
+use alloc::vec::Vec;
+
+pub struct BitAnd;
+
+impl BitAnd {
+    /// Combine the bytes of all of the source keys with an operation, storing
+    /// the result in the destination key.
+    ///
+    /// The first argument is the destination key and the remaining arguments
+    /// are the source keys. Sources shorter than the longest source are padded
+    /// with zeroes, and nonexistent sources are treated as empty.
+    pub fn operate(
+        hop: &Hop,
+        req: &Request,
+        resp: &mut Vec<u8>,
+        op: fn(u8, u8) -> u8,
+    ) -> DispatchResult<()> {
+        BitGet::check_key_type(req)?;
+        let destination = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let sources = req.args(1..).ok_or(DispatchError::ArgumentRetrieval)?;
+
+        // Copy the sources before touching the destination, since the
+        // destination may be one of the sources.
+        let mut result: Option<Vec<u8>> = None;
+
+        for source in sources {
+            let bytes = match hop.state().key_ref(source) {
+                Some(value) => value
+                    .as_bytes_ref()
+                    .ok_or(DispatchError::KeyTypeDifferent)?
+                    .to_vec(),
+                None => Vec::new(),
+            };
+
+            result = Some(match result {
+                Some(mut result) => {
+                    if result.len() < bytes.len() {
+                        result.resize(bytes.len(), 0);
+                    }
+
+                    for (idx, byte) in result.iter_mut().enumerate() {
+                        *byte = op(*byte, bytes.get(idx).copied().unwrap_or(0));
+                    }
+
+                    result
+                }
+                None => bytes,
+            });
+        }
+
+        let result = result.ok_or(DispatchError::ArgumentRetrieval)?;
+        response::write_bytes(resp, &result);
+        hop.state()
+            .insert(destination.to_vec(), Value::Bytes(result));
+
+        Ok(())
+    }
+}
+
+impl Dispatch for BitAnd {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::operate(hop, req, resp, |a, b| a & b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitAnd;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_and_pads_shorter() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"a".to_vec(), Value::Bytes([0b1100, 0xff].to_vec()));
+        hop.state()
+            .insert(b"b".to_vec(), Value::Bytes([0b1010].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::BitAnd);
+        assert!(builder.bytes(b"dest".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"b".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitAnd::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([0b1000, 0].to_vec()).as_bytes());
+        assert_eq!(
+            Some([0b1000, 0].as_ref()),
+            hop.state().key_ref(b"dest").unwrap().as_bytes_ref()
+        );
+    }
+
+    #[test]
+    fn test_destination_is_source() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"a".to_vec(), Value::Bytes([0b1100].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::BitAnd);
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"b".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitAnd::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([0].to_vec()).as_bytes());
+    }
+
+    #[test]
+    fn test_source_key_type_different() {
+        let hop = Hop::new();
+        hop.state().insert(b"a".to_vec(), Value::Integer(1));
+
+        let mut builder = RequestBuilder::new(CommandId::BitAnd);
+        assert!(builder.bytes(b"dest".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            BitAnd::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"dest"));
+    }
+}
@@ -0,0 +1,80 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    bit_get::BitGet,
+    substring::Substring,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct BitCount;
+
+impl Dispatch for BitCount {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        BitGet::check_key_type(req)?;
+        let start = req.typed_arg(1).unwrap_or(0);
+        let end = req.typed_arg(2);
+
+        let count = match hop.state().key_ref(key) {
+            Some(value) => {
+                let bytes = value
+                    .as_bytes_ref()
+                    .ok_or(DispatchError::KeyTypeDifferent)?;
+                let (start, end) = Substring::bounds(bytes.len(), start, end);
+
+                bytes[start..end]
+                    .iter()
+                    .map(|byte| byte.count_ones() as i64)
+                    .sum()
+            }
+            None => 0,
+        };
+
+        response::write_int(resp, count);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitCount;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_count_all() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([0xff, 0b101, 1].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::BitCount);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitCount::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(11).as_bytes());
+    }
+
+    #[test]
+    fn test_count_range() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([0xff, 0b101, 1].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::BitCount);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(1).is_ok());
+        assert!(builder.value(-1).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitCount::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(2).as_bytes());
+    }
+}
@@ -0,0 +1,123 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+
+/// The maximum bit offset that can be addressed, which limits bytes values
+/// grown by bit commands to 512 MiB.
+pub const BIT_OFFSET_MAX: usize = u32::MAX as usize;
+
+pub struct BitGet;
+
+impl BitGet {
+    /// Retrieve a bit offset argument, ensuring that it's positive and within
+    /// [`BIT_OFFSET_MAX`].
+    ///
+    /// [`BIT_OFFSET_MAX`]: constant.BIT_OFFSET_MAX.html
+    pub fn offset(req: &Request, idx: usize) -> DispatchResult<usize> {
+        let offset = req
+            .typed_arg::<i64>(idx)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        if offset < 0 || offset as u64 > BIT_OFFSET_MAX as u64 {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        Ok(offset as usize)
+    }
+
+    /// Ensure that the key type of a request, if any, is bytes.
+    pub fn check_key_type(req: &Request) -> DispatchResult<()> {
+        match req.key_type() {
+            None | Some(KeyType::Bytes) => Ok(()),
+            Some(_) => Err(DispatchError::KeyTypeInvalid),
+        }
+    }
+
+    /// Retrieve a bit from some bytes, with the most significant bit of the
+    /// first byte being offset 0.
+    ///
+    /// Bits past the end of the bytes are unset.
+    pub fn bit(bytes: &[u8], offset: usize) -> bool {
+        matches!(bytes.get(offset / 8), Some(byte) if byte & (0x80 >> (offset % 8)) != 0)
+    }
+}
+
+impl Dispatch for BitGet {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        Self::check_key_type(req)?;
+        let offset = Self::offset(req, 1)?;
+
+        let bit = match hop.state().key_ref(key) {
+            Some(value) => {
+                let bytes = value
+                    .as_bytes_ref()
+                    .ok_or(DispatchError::KeyTypeDifferent)?;
+
+                Self::bit(bytes, offset)
+            }
+            None => false,
+        };
+
+        response::write_bool(resp, bit);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitGet;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn get(hop: &Hop, offset: i64) -> Result<Vec<u8>, DispatchError> {
+        let mut builder = RequestBuilder::new(CommandId::BitGet);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(offset).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        BitGet::dispatch(hop, &req, &mut resp).map(|_| resp)
+    }
+
+    #[test]
+    fn test_bit() {
+        assert!(BitGet::bit(&[0b1000_0000], 0));
+        assert!(!BitGet::bit(&[0b1000_0000], 1));
+        assert!(BitGet::bit(&[0, 0b0000_0001], 15));
+        assert!(!BitGet::bit(&[0xff], 8));
+    }
+
+    #[test]
+    fn test_get() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([0b0100_0000].to_vec()));
+
+        assert_eq!(get(&hop, 1).unwrap(), Response::from(true).as_bytes());
+        assert_eq!(get(&hop, 0).unwrap(), Response::from(false).as_bytes());
+        assert_eq!(get(&hop, 100).unwrap(), Response::from(false).as_bytes());
+    }
+
+    #[test]
+    fn test_nonexistent() {
+        let hop = Hop::new();
+
+        assert_eq!(get(&hop, 3).unwrap(), Response::from(false).as_bytes());
+    }
+
+    #[test]
+    fn test_negative_offset() {
+        let hop = Hop::new();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            get(&hop, -1).unwrap_err()
+        );
+    }
+}
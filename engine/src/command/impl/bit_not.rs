@@ -0,0 +1,67 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    bit_get::BitGet,
+};
+use crate::{state::Value, Hop};
+use alloc::vec::Vec;
+
+pub struct BitNot;
+
+impl Dispatch for BitNot {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        BitGet::check_key_type(req)?;
+        let destination = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let source = req.arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+
+        if req.arg_count() > 2 {
+            return Err(DispatchError::ArgumentRetrieval);
+        }
+
+        let result = match hop.state().key_ref(source) {
+            Some(value) => value
+                .as_bytes_ref()
+                .ok_or(DispatchError::KeyTypeDifferent)?
+                .iter()
+                .map(|byte| !byte)
+                .collect::<Vec<u8>>(),
+            None => Vec::new(),
+        };
+
+        response::write_bytes(resp, &result);
+        hop.state()
+            .insert(destination.to_vec(), Value::Bytes(result));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitNot;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_not() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"a".to_vec(), Value::Bytes([0b1100, 0xff].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::BitNot);
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitNot::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([0b1111_0011, 0].to_vec()).as_bytes());
+        assert_eq!(
+            Some([0b1111_0011, 0].as_ref()),
+            hop.state().key_ref(b"a").unwrap().as_bytes_ref()
+        );
+    }
+}
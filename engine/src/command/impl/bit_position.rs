@@ -0,0 +1,100 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    bit_get::BitGet,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct BitPosition;
+
+impl BitPosition {
+    /// Find the offset of the first bit that is set or clear in some bytes.
+    pub fn position(bytes: &[u8], bit: bool) -> Option<usize> {
+        let (idx, byte) =
+            bytes
+                .iter()
+                .enumerate()
+                .find(|(_, byte)| if bit { **byte != 0 } else { **byte != 0xff })?;
+
+        let zeros = if bit {
+            byte.leading_zeros()
+        } else {
+            (!byte).leading_zeros()
+        };
+
+        Some(idx * 8 + zeros as usize)
+    }
+}
+
+impl Dispatch for BitPosition {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        BitGet::check_key_type(req)?;
+        let bit = req
+            .typed_arg::<bool>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        let position = match hop.state().key_ref(key) {
+            Some(value) => {
+                let bytes = value
+                    .as_bytes_ref()
+                    .ok_or(DispatchError::KeyTypeDifferent)?;
+
+                Self::position(bytes, bit)
+            }
+            None => None,
+        };
+
+        response::write_int(resp, position.map_or(-1, |position| position as i64));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitPosition;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_position() {
+        assert_eq!(Some(10), BitPosition::position(&[0, 0b0010_0000], true));
+        assert_eq!(Some(3), BitPosition::position(&[0b1110_1111], false));
+        assert_eq!(None, BitPosition::position(&[0, 0], true));
+        assert_eq!(None, BitPosition::position(&[0xff], false));
+    }
+
+    #[test]
+    fn test_nonexistent() {
+        let hop = Hop::new();
+        let mut builder = RequestBuilder::new(CommandId::BitPosition);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(true).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitPosition::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(-1).as_bytes());
+    }
+
+    #[test]
+    fn test_clear_bit() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([0xff, 0xfe].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::BitPosition);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(false).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitPosition::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(15).as_bytes());
+    }
+}
@@ -0,0 +1,124 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    bit_get::BitGet,
+};
+use crate::{state::Value, Hop};
+use alloc::vec::Vec;
+
+pub struct BitSet;
+
+impl Dispatch for BitSet {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        BitGet::check_key_type(req)?;
+        let offset = BitGet::offset(req, 1)?;
+        let bit = req
+            .typed_arg::<bool>(2)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        hop.config().check_value_size(offset / 8 + 1)?;
+
+        let mut value = hop.state().key_or_insert_with(key, Value::bytes);
+        let bytes = value
+            .as_bytes_mut()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+
+        let idx = offset / 8;
+        let mask = 0x80 >> (offset % 8);
+
+        if bytes.len() <= idx {
+            bytes.resize(idx + 1, 0);
+        }
+
+        let previous = bytes[idx] & mask != 0;
+
+        if bit {
+            bytes[idx] |= mask;
+        } else {
+            bytes[idx] &= !mask;
+        }
+
+        response::write_bool(resp, previous);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::bit_get::BIT_OFFSET_MAX, BitSet};
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn set(hop: &Hop, offset: i64, bit: bool) -> Result<Vec<u8>, DispatchError> {
+        let mut builder = RequestBuilder::new(CommandId::BitSet);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(offset).is_ok());
+        assert!(builder.value(bit).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        BitSet::dispatch(hop, &req, &mut resp).map(|_| resp)
+    }
+
+    #[test]
+    fn test_set_grows() {
+        let hop = Hop::new();
+
+        assert_eq!(
+            set(&hop, 9, true).unwrap(),
+            Response::from(false).as_bytes()
+        );
+        assert_eq!(
+            Some([0, 0b0100_0000].as_ref()),
+            hop.state().key_ref(b"foo").unwrap().as_bytes_ref()
+        );
+    }
+
+    #[test]
+    fn test_unset_returns_previous() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([0xff].to_vec()));
+
+        assert_eq!(
+            set(&hop, 0, false).unwrap(),
+            Response::from(true).as_bytes()
+        );
+        assert_eq!(
+            Some([0x7f].as_ref()),
+            hop.state().key_ref(b"foo").unwrap().as_bytes_ref()
+        );
+    }
+
+    #[test]
+    fn test_offset_too_large() {
+        let hop = Hop::new();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            set(&hop, BIT_OFFSET_MAX as i64 + 1, true).unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"foo"));
+    }
+
+    #[test]
+    fn test_key_type_invalid() {
+        let hop = Hop::new();
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::BitSet, KeyType::String);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(0).is_ok());
+        assert!(builder.value(true).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeInvalid,
+            BitSet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
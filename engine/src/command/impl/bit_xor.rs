@@ -0,0 +1,44 @@
+use super::{
+    super::{Dispatch, DispatchResult, Request},
+    bit_and::BitAnd,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct BitXor;
+
+impl Dispatch for BitXor {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        BitAnd::operate(hop, req, resp, |a, b| a ^ b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitXor;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_xor() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"a".to_vec(), Value::Bytes([0b1100, 0xff].to_vec()));
+        hop.state()
+            .insert(b"b".to_vec(), Value::Bytes([0b1010].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::BitXor);
+        assert!(builder.bytes(b"dest".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"b".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(BitXor::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([0b0110, 0xff].to_vec()).as_bytes());
+    }
+}
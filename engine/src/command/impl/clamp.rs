@@ -0,0 +1,138 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    increment_by::IncrementBy,
+};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+
+pub struct Clamp;
+
+impl Dispatch for Clamp {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if IncrementBy::key_type(hop, req, key)? == KeyType::Float {
+            let min = req
+                .typed_arg::<f64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+            let max = req
+                .typed_arg::<f64>(2)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            if min.is_nan() || max.is_nan() || min > max {
+                return Err(DispatchError::PreconditionFailed);
+            }
+
+            let mut key = hop
+                .state()
+                .key_mut(key)
+                .ok_or(DispatchError::KeyNonexistent)?;
+            let float = key.as_float_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+            *float = float.max(min).min(max);
+
+            response::write_float(resp, *float);
+        } else {
+            let min = req
+                .typed_arg::<i64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+            let max = req
+                .typed_arg::<i64>(2)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            if min > max {
+                return Err(DispatchError::PreconditionFailed);
+            }
+
+            let mut key = hop
+                .state()
+                .key_mut(key)
+                .ok_or(DispatchError::KeyNonexistent)?;
+            let int = key
+                .as_integer_mut()
+                .ok_or(DispatchError::KeyTypeDifferent)?;
+            *int = (*int).max(min).min(max);
+
+            response::write_int(resp, *int);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clamp;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_int() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(15));
+
+        let mut builder = RequestBuilder::new(CommandId::Clamp);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(0)).is_ok());
+        assert!(builder.value(Value::Integer(10)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Clamp::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(10i64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_float() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Float(-1.5));
+
+        let mut builder = RequestBuilder::new(CommandId::Clamp);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Float(0.)).is_ok());
+        assert!(builder.value(Value::Float(1.)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Clamp::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(0f64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_range_invalid() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+
+        let mut builder = RequestBuilder::new(CommandId::Clamp);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(5)).is_ok());
+        assert!(builder.value(Value::Integer(0)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            Clamp::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_nonexistent() {
+        let hop = Hop::new();
+
+        let mut builder = RequestBuilder::new(CommandId::Clamp);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(0)).is_ok());
+        assert!(builder.value(Value::Integer(1)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            Clamp::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{session::SessionId, Hop};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// Kill a session by its ID, so that its connection is closed, removing its
+/// pubsub subscriptions.
+///
+/// Responds with whether the session existed.
+pub struct ClientKill;
+
+impl Dispatch for ClientKill {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let id = req
+            .typed_arg::<i64>(0)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        let killed = u32::try_from(id).is_ok_and(|id| hop.kill_session(SessionId::new(id)));
+
+        response::write_bool(resp, killed);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientKill;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+    use futures_intrusive::channel::TryReceiveError;
+
+    #[test]
+    fn test_kill() {
+        let hop = Hop::new();
+        let session = hop.clone();
+        let id = session.start_session(None).unwrap();
+        let killed = hop.sessions().get(id).unwrap().killed();
+        assert!(hop.pubsub().subscribe(0, b"foo".to_vec(), id).is_some());
+
+        for (target, expected) in [(i64::from(id.get()), true), (-1, false)].iter() {
+            let mut builder = RequestBuilder::new(CommandId::ClientKill);
+            assert!(builder.value(Value::Integer(*target)).is_ok());
+            let req = builder.into_request();
+
+            let mut resp = Vec::new();
+            assert!(ClientKill::dispatch(&hop, &req, &mut resp).is_ok());
+            assert_eq!(resp, Response::from(*expected).as_bytes());
+        }
+
+        assert_eq!(Err(TryReceiveError::Closed), killed.try_receive());
+        assert_eq!(0, hop.pubsub().subscriptions(id));
+        assert!(hop.sessions().is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::Hop;
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::convert::TryFrom;
+
+/// List the sessions connected to the engine.
+///
+/// The response is a list with five fields for each session, in order of
+/// their IDs: the session ID as a big-endian integer, the address of the peer
+/// or nothing if it doesn't have one, and then as big-endian integers the Unix
+/// timestamp in milliseconds of when it connected, the number of milliseconds
+/// since it last ran a command, and the number of keys it's subscribed to.
+pub struct ClientList;
+
+impl Dispatch for ClientList {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let now = (hop.config().clock())();
+        let int = |int: u64| {
+            i64::try_from(int)
+                .unwrap_or(i64::MAX)
+                .to_be_bytes()
+                .to_vec()
+        };
+        let mut sessions = Vec::new();
+
+        hop.sessions().for_each(|session| {
+            let id = session.id();
+            let idle = now.saturating_sub(session.last_active());
+            let subscriptions = hop.pubsub().subscriptions(id);
+
+            let fields = [
+                int(id.get().into()),
+                session.peer().unwrap_or_default().as_bytes().to_owned(),
+                int(session.connected_at()),
+                int(idle),
+                int(u64::try_from(subscriptions).unwrap_or(u64::MAX)),
+            ];
+
+            sessions.push((id, fields));
+        });
+
+        sessions.sort_unstable_by_key(|(id, _)| *id);
+
+        response::write_list(resp, sessions.iter().flat_map(|(_, fields)| fields));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClientList;
+    use crate::{
+        command::{request::RequestBuilder, response, CommandId, Dispatch},
+        hop::Builder,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_list() {
+        let mut builder = Builder::new();
+        builder.clock(|| 5000);
+        let hop = builder.build();
+
+        let first = hop.clone();
+        let first_id = first
+            .start_session(Some("127.0.0.1:1234".to_owned()))
+            .unwrap();
+        assert!(hop
+            .pubsub()
+            .subscribe(0, b"foo".to_vec(), first_id)
+            .is_some());
+        let second = hop.clone();
+        second.start_session(None).unwrap();
+
+        let req = RequestBuilder::new(CommandId::ClientList).into_request();
+        let mut resp = Vec::new();
+        assert!(ClientList::dispatch(&hop, &req, &mut resp).is_ok());
+
+        let int = |int: i64| int.to_be_bytes().to_vec();
+        let mut expected = Vec::new();
+        response::write_list(
+            &mut expected,
+            [
+                int(0),
+                b"127.0.0.1:1234".to_vec(),
+                int(5000),
+                int(0),
+                int(1),
+                int(1),
+                Vec::new(),
+                int(5000),
+                int(0),
+                int(0),
+            ]
+            .iter(),
+        );
+        assert_eq!(expected, resp);
+    }
+}
@@ -0,0 +1,122 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{hop::Config, Hop};
+use alloc::vec::Vec;
+use core::str;
+use dashmap::DashMap;
+
+pub struct ConfigGet;
+
+impl Dispatch for ConfigGet {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let config = hop.config();
+        let map = DashMap::new();
+
+        match req.args(..) {
+            // Only the parameters named are retrieved, each of which must
+            // exist.
+            Some(names) => {
+                for name in names {
+                    let value = str::from_utf8(name)
+                        .ok()
+                        .and_then(|name| config.parameter(name))
+                        .ok_or(DispatchError::ConfigInvalid)?;
+
+                    map.insert(name.to_vec(), value.into_bytes());
+                }
+            }
+            None => {
+                for name in Config::PARAMETERS {
+                    if let Some(value) = config.parameter(name) {
+                        map.insert(name.as_bytes().to_vec(), value.into_bytes());
+                    }
+                }
+            }
+        }
+
+        response::write_map(resp, &map);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigGet;
+    use crate::{
+        command::{
+            request::{Request, RequestBuilder},
+            response::{Context, Instruction},
+            CommandId, Dispatch, DispatchError, Response,
+        },
+        hop::{Builder, Config},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+    use dashmap::DashMap;
+
+    fn dispatch(hop: &Hop, req: &Request) -> DashMap<Vec<u8>, Vec<u8>> {
+        let mut resp = Vec::new();
+        assert!(ConfigGet::dispatch(hop, req, &mut resp).is_ok());
+
+        match Context::new().feed(&resp) {
+            Ok(Instruction::Concluded(Response::Value(Value::Map(map)))) => map,
+            other => panic!("other response: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let mut builder = Builder::new();
+        builder.max_memory(1024);
+        let hop = builder.build();
+
+        let mut req = RequestBuilder::new(CommandId::ConfigGet);
+        assert!(req.bytes(b"max_memory".as_ref()).is_ok());
+        assert!(req.bytes(b"max_key_length".as_ref()).is_ok());
+        let map = dispatch(&hop, &req.into_request());
+
+        assert_eq!(2, map.len());
+        assert_eq!(
+            Some(b"1024".as_ref()),
+            map.get(b"max_memory".as_ref())
+                .as_deref()
+                .map(Vec::as_slice)
+        );
+        assert_eq!(
+            Some(b"none".as_ref()),
+            map.get(b"max_key_length".as_ref())
+                .as_deref()
+                .map(Vec::as_slice)
+        );
+    }
+
+    #[test]
+    fn test_get_all() {
+        let hop = Hop::new();
+        let req = RequestBuilder::new(CommandId::ConfigGet).into_request();
+        let map = dispatch(&hop, &req);
+
+        for name in Config::PARAMETERS {
+            if *name != "log_level" {
+                assert!(map.contains_key(name.as_bytes()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_unknown() {
+        let hop = Hop::new();
+        let mut req = RequestBuilder::new(CommandId::ConfigGet);
+        assert!(req.bytes(b"foo".as_ref()).is_ok());
+
+        assert_eq!(
+            DispatchError::ConfigInvalid,
+            ConfigGet::dispatch(&hop, &req.into_request(), &mut Vec::new()).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct ConfigSet;
+
+impl Dispatch for ConfigSet {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let name = req
+            .typed_arg::<&str>(0)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        let value = req
+            .typed_arg::<&str>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        hop.config().set_parameter(name, value)?;
+
+        let value = hop
+            .config()
+            .parameter(name)
+            .ok_or(DispatchError::ConfigInvalid)?;
+
+        response::write_str(resp, &value);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConfigSet;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        hop::EvictionPolicy,
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    fn request(name: &str, value: &str) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::ConfigSet);
+        assert!(builder.bytes(name.as_bytes()).is_ok());
+        assert!(builder.bytes(value.as_bytes()).is_ok());
+
+        builder
+    }
+
+    #[test]
+    fn test_set() {
+        let hop = Hop::new();
+        let req = request("eviction_policy", "lru").into_request();
+
+        let mut resp = Vec::new();
+        assert!(ConfigSet::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("lru".to_owned()).as_bytes());
+        assert_eq!(
+            EvictionPolicy::LeastRecentlyUsed,
+            hop.config().eviction_policy()
+        );
+
+        // Other handles to the engine see the change.
+        let req = request("max_value_size", "16").into_request();
+        assert!(ConfigSet::dispatch(&hop.clone(), &req, &mut Vec::new()).is_ok());
+        assert_eq!(16, hop.config().max_value_size());
+
+        let req = request("max_value_size", "none").into_request();
+        assert!(ConfigSet::dispatch(&hop, &req, &mut Vec::new()).is_ok());
+        assert_eq!(usize::MAX, hop.config().max_value_size());
+    }
+
+    #[test]
+    fn test_set_sessions_active_max() {
+        let hop = Hop::new();
+        let session = hop.clone();
+        assert!(session.start_session(None).is_ok());
+
+        let req = request("sessions_active_max", "1").into_request();
+        assert!(ConfigSet::dispatch(&hop, &req, &mut Vec::new()).is_ok());
+        assert_eq!(1, hop.config().sessions_active_max());
+
+        // The new limit applies to the next session to start.
+        assert!(hop.clone().start_session(None).is_err());
+        assert!(session.end_session());
+        assert!(hop.clone().start_session(None).is_ok());
+    }
+
+    #[test]
+    fn test_set_invalid() {
+        let hop = Hop::new();
+
+        for (name, value) in &[
+            ("eviction_policy", "oldest"),
+            ("max_memory", "-1"),
+            ("namespaces", "4"),
+            ("sessions_active_max", "0"),
+            ("foo", "bar"),
+        ] {
+            let req = request(name, value).into_request();
+
+            assert_eq!(
+                DispatchError::ConfigInvalid,
+                ConfigSet::dispatch(&hop, &req, &mut Vec::new()).unwrap_err()
+            );
+        }
+    }
+}
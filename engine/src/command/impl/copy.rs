@@ -0,0 +1,127 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    Rename,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct Copy;
+
+impl Dispatch for Copy {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let key = req.arg(0).ok_or(DispatchError::KeyUnspecified)?;
+        let arg = req.arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+        let replace = Rename::replace(req, 2)?;
+        let state = hop.state();
+
+        if !replace && state.contains_key(arg) {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        // Clone the value before inserting it, since the source key's lock
+        // can't be held while the destination is written to.
+        let value = state
+            .key_ref(key)
+            .map(|value| value.value().clone())
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let previous = state.insert(arg.to_vec(), value);
+
+        Rename::publish_written(hop, arg, previous);
+
+        response::write_bytes(resp, arg);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Copy;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        pubsub::KeyUpdate,
+        session::SessionId,
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+    use dashmap::DashMap;
+
+    fn request(replace: bool) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::Copy);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+
+        if replace {
+            assert!(builder.value(Value::Boolean(true)).is_ok());
+        }
+
+        builder
+    }
+
+    #[tokio::test]
+    async fn test_copy_deep() {
+        let req = request(false).into_request();
+
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+        let map = DashMap::new();
+        map.insert(b"a".to_vec(), b"1".to_vec());
+        hop.state().insert(b"foo".to_vec(), Value::Map(map));
+        let sub = hop
+            .pubsub()
+            .subscribe(0, b"bar".to_vec(), SessionId::new(1))
+            .unwrap();
+
+        assert!(Copy::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(b"bar".to_vec()).as_bytes());
+
+        // Changing the copy must leave the original untouched.
+        if let Some(Value::Map(map)) = hop.state().key_mut(b"bar").as_deref_mut() {
+            map.insert(b"b".to_vec(), b"2".to_vec());
+        }
+
+        let original = hop.state().key_ref(b"foo").unwrap();
+        assert_eq!(1, original.as_map_ref().unwrap().len());
+        let copy = hop.state().key_ref(b"bar").unwrap();
+        assert_eq!(2, copy.as_map_ref().unwrap().len());
+        assert!(matches!(
+            sub.upgrade().unwrap().receiver().receive().await,
+            Some(KeyUpdate::Initialized(Value::Map(_)))
+        ));
+    }
+
+    #[test]
+    fn test_copy_replace() {
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        hop.state().insert(b"bar".to_vec(), Value::Integer(2));
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            Copy::dispatch(&hop, &request(false).into_request(), &mut resp).unwrap_err()
+        );
+
+        assert!(Copy::dispatch(&hop, &request(true).into_request(), &mut resp).is_ok());
+        assert!(matches!(
+            hop.state().key_ref(b"bar").as_deref(),
+            Some(Value::Integer(1))
+        ));
+        assert!(hop.state().contains_key(b"foo"));
+    }
+
+    #[test]
+    fn test_copy_src_nonexistent() {
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            Copy::dispatch(&Hop::new(), &request(false).into_request(), &mut resp).unwrap_err()
+        );
+    }
+}
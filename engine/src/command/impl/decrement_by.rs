@@ -2,7 +2,7 @@ use super::{
     super::{Dispatch, DispatchError, DispatchResult, Request},
     increment_by::IncrementBy,
 };
-use crate::Hop;
+use crate::{state::KeyType, Hop};
 use alloc::vec::Vec;
 
 pub struct DecrementBy;
@@ -11,12 +11,21 @@ impl Dispatch for DecrementBy {
     fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
         let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
 
-        if let Some(int) = req.typed_arg::<i64>(1) {
-            IncrementBy::increment_int_by(hop, key, 0 - int, resp)
-        } else if let Some(float) = req.typed_arg::<f64>(1) {
-            IncrementBy::increment_float_by(hop, key, 0f64 - float, resp)
+        if IncrementBy::key_type(hop, req, key)? == KeyType::Float {
+            let amount = req
+                .typed_arg::<f64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            IncrementBy::float(hop, key, resp, |float| Ok(float - amount))
         } else {
-            Err(DispatchError::ArgumentRetrieval)
+            let amount = req
+                .typed_arg::<i64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            IncrementBy::int(hop, key, resp, |int| {
+                int.checked_sub(amount)
+                    .ok_or(DispatchError::ArithmeticOverflow)
+            })
         }
     }
 }
@@ -51,6 +60,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_overflow() {
+        let mut builder = RequestBuilder::new(CommandId::DecrementBy);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(i64::MIN)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(-1));
+        let mut resp = Vec::new();
+
+        assert!(DecrementBy::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(i64::MAX).as_bytes(), resp);
+
+        let mut resp = Vec::new();
+        assert_eq!(
+            DispatchError::ArithmeticOverflow,
+            DecrementBy::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
     #[test]
     fn test_no_key() {
         let req = RequestBuilder::new(CommandId::Decrement).into_request();
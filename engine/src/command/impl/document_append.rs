@@ -0,0 +1,206 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    document_get::DocumentGet,
+};
+use crate::{
+    state::{document::DEPTH_MAX, Document},
+    Hop,
+};
+use alloc::vec::Vec;
+
+pub struct DocumentAppend;
+
+impl Dispatch for DocumentAppend {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        DocumentGet::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let path = DocumentGet::path(req, 1)?;
+
+        // At least one value is required.
+        req.arg(2).ok_or(DispatchError::ArgumentRetrieval)?;
+
+        let args = req.args(2..).ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config().check_collection(
+            req.arg_count().saturating_sub(2),
+            args.clone().map(<[u8]>::len),
+        )?;
+
+        let values = args
+            .map(Document::from_bytes)
+            .collect::<Option<Vec<_>>>()
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        // The values become elements nested one level below the array.
+        let depth = values.iter().map(Document::depth).max().unwrap_or(0);
+
+        if path.segments().len().saturating_add(depth + 1) > DEPTH_MAX {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        let mut value = hop
+            .state()
+            .key_mut(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let document = value
+            .as_document_mut()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+        let array = document
+            .get_mut(&path)
+            .and_then(Document::as_array_mut)
+            .ok_or(DispatchError::PreconditionFailed)?;
+        hop.config()
+            .check_elements(array.len().saturating_add(values.len()))?;
+
+        let previous = array.len();
+        array.extend(values);
+        let len = array.len();
+
+        // Measuring the whole document means serializing it, so only do so
+        // when there's a limit to check it against.
+        if hop.config().max_value_size() != usize::MAX {
+            if let Err(source) = hop.config().check_value_size(document.as_bytes().len()) {
+                if let Some(array) = document.get_mut(&path).and_then(Document::as_array_mut) {
+                    array.truncate(previous);
+                }
+
+                return Err(source);
+            }
+        }
+
+        response::write_int(resp, len as i64);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocumentAppend;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{document::DEPTH_MAX, Document, Value},
+        Hop,
+    };
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    fn hop() -> Hop {
+        let hop = Hop::new();
+        let document = r#"{"a":[1],"b":2}"#.parse::<Document>().unwrap();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Document(document));
+
+        hop
+    }
+
+    #[test]
+    fn test_append() {
+        let mut builder = RequestBuilder::new(CommandId::DocumentAppend);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"2".as_ref()).is_ok());
+        assert!(builder.bytes(br#"{"c":3}"#.as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = hop();
+        let mut resp = Vec::new();
+
+        assert!(DocumentAppend::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(3).as_bytes(), resp);
+        assert_eq!(
+            r#"{"a":[1,2,{"c":3}],"b":2}"#,
+            hop.state()
+                .key_ref(b"foo")
+                .unwrap()
+                .as_document_ref()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_size_limit() {
+        let mut builder = RequestBuilder::new(CommandId::DocumentAppend);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"2".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = hop();
+        hop.config().set_max_value_size(17);
+        let mut resp = Vec::new();
+
+        assert!(DocumentAppend::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(
+            DispatchError::SizeLimitExceeded,
+            DocumentAppend::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert_eq!(
+            r#"{"a":[1,2],"b":2}"#,
+            hop.state()
+                .key_ref(b"foo")
+                .unwrap()
+                .as_document_ref()
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+        let hop = Hop::new();
+        let document = nested(2).parse::<Document>().unwrap();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Document(document));
+        let request = |value: String| {
+            let mut builder = RequestBuilder::new(CommandId::DocumentAppend);
+            assert!(builder.bytes(b"foo".as_ref()).is_ok());
+            assert!(builder.bytes(b"[0]".as_ref()).is_ok());
+            assert!(builder.bytes(value.into_bytes()).is_ok());
+
+            builder.into_request()
+        };
+        let mut resp = Vec::new();
+
+        let req = request(nested(DEPTH_MAX));
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            DocumentAppend::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+
+        let req = request(nested(DEPTH_MAX - 1));
+        assert!(DocumentAppend::dispatch(&hop, &req, &mut resp).is_ok());
+    }
+
+    #[test]
+    fn test_not_array() {
+        let mut builder = RequestBuilder::new(CommandId::DocumentAppend);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"b".as_ref()).is_ok());
+        assert!(builder.bytes(b"2".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = hop();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            DocumentAppend::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_values_missing() {
+        let mut builder = RequestBuilder::new(CommandId::DocumentAppend);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = hop();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            DocumentAppend::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
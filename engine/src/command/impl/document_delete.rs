@@ -0,0 +1,94 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    document_get::DocumentGet,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct DocumentDelete;
+
+impl Dispatch for DocumentDelete {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        DocumentGet::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let path = DocumentGet::path(req, 1)?;
+
+        let removed = if path.is_root() {
+            match hop.state().key_ref(key) {
+                Some(value) if !value.is_document() => {
+                    return Err(DispatchError::KeyTypeDifferent);
+                }
+                Some(_) => {}
+                None => {
+                    response::write_int(resp, 0);
+
+                    return Ok(());
+                }
+            }
+
+            // Deleting the root deletes the whole key.
+            hop.state().remove(key).is_some()
+        } else {
+            match hop.state().key_mut(key) {
+                Some(mut value) => value
+                    .as_document_mut()
+                    .ok_or(DispatchError::KeyTypeDifferent)?
+                    .remove(&path)
+                    .is_some(),
+                None => false,
+            }
+        };
+
+        response::write_int(resp, removed as i64);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocumentDelete;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::{Document, Value},
+        Hop,
+    };
+    use alloc::{string::ToString, vec::Vec};
+
+    fn dispatch(hop: &Hop, path: &[u8]) -> Vec<u8> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentDelete);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(path).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+        assert!(DocumentDelete::dispatch(hop, &req, &mut resp).is_ok());
+
+        resp
+    }
+
+    #[test]
+    fn test_delete() {
+        let hop = Hop::new();
+        let document = r#"{"a":[1,2],"b":null}"#.parse::<Document>().unwrap();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Document(document));
+
+        assert_eq!(Response::from(1).as_bytes(), dispatch(&hop, b"a[0]"));
+        assert_eq!(Response::from(1).as_bytes(), dispatch(&hop, b"b"));
+        assert_eq!(Response::from(0).as_bytes(), dispatch(&hop, b"b"));
+        assert_eq!(
+            r#"{"a":[2]}"#,
+            hop.state()
+                .key_ref(b"foo")
+                .unwrap()
+                .as_document_ref()
+                .unwrap()
+                .to_string()
+        );
+
+        assert_eq!(Response::from(1).as_bytes(), dispatch(&hop, b"$"));
+        assert!(hop.state().key_ref(b"foo").is_none());
+        assert_eq!(Response::from(0).as_bytes(), dispatch(&hop, b"$"));
+        assert_eq!(Response::from(0).as_bytes(), dispatch(&hop, b"a"));
+    }
+}
@@ -0,0 +1,118 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{
+    state::{DocumentPath, KeyType},
+    Hop,
+};
+use alloc::vec::Vec;
+
+pub struct DocumentGet;
+
+impl DocumentGet {
+    /// Check that the request's key type, if any, is a document.
+    pub fn check_key_type(req: &Request) -> DispatchResult<()> {
+        match req.key_type() {
+            Some(KeyType::Document) | None => Ok(()),
+            Some(_) => Err(DispatchError::KeyTypeInvalid),
+        }
+    }
+
+    /// Retrieve the path argument at an index.
+    pub fn path(req: &Request, idx: usize) -> DispatchResult<DocumentPath> {
+        req.typed_arg(idx).ok_or(DispatchError::ArgumentRetrieval)
+    }
+}
+
+impl Dispatch for DocumentGet {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let path = Self::path(req, 1)?;
+
+        let value = hop
+            .state()
+            .key_ref(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let document = value
+            .as_document_ref()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+        let found = document
+            .get(&path)
+            .ok_or(DispatchError::PreconditionFailed)?;
+
+        response::write_document(resp, found);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocumentGet;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{Document, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn request(path: &[u8]) -> Vec<u8> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentGet);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(path).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let document = r#"{"a":{"b":[1,2]}}"#.parse::<Document>().unwrap();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Document(document));
+        let mut resp = Vec::new();
+
+        match DocumentGet::dispatch(&hop, &req, &mut resp) {
+            Ok(()) => resp,
+            Err(why) => Response::DispatchError(why).as_bytes(),
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(
+            Response::from(Document::Integer(2)).as_bytes(),
+            request(b"a.b[1]")
+        );
+        assert_eq!(
+            Response::from("[1,2]".parse::<Document>().unwrap()).as_bytes(),
+            request(b"$.a.b")
+        );
+    }
+
+    #[test]
+    fn test_path_missing() {
+        assert_eq!(
+            Response::DispatchError(DispatchError::PreconditionFailed).as_bytes(),
+            request(b"a.c")
+        );
+    }
+
+    #[test]
+    fn test_path_invalid() {
+        assert_eq!(
+            Response::DispatchError(DispatchError::ArgumentRetrieval).as_bytes(),
+            request(b"a..b")
+        );
+    }
+
+    #[test]
+    fn test_key_type_different() {
+        let mut builder = RequestBuilder::new(CommandId::DocumentGet);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"$".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            DocumentGet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    document_get::DocumentGet,
+};
+use crate::{state::Document, Hop};
+use alloc::vec::Vec;
+
+pub struct DocumentIncrement;
+
+impl Dispatch for DocumentIncrement {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        DocumentGet::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let path = DocumentGet::path(req, 1)?;
+        // The amount is JSON text like the other document values, so that it
+        // can be either an integer or a float.
+        let amount = req
+            .typed_arg::<Document>(2)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        let mut value = hop
+            .state()
+            .key_mut(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let number = value
+            .as_document_mut()
+            .ok_or(DispatchError::KeyTypeDifferent)?
+            .get_mut(&path)
+            .ok_or(DispatchError::PreconditionFailed)?;
+
+        let new = match (&*number, amount) {
+            (Document::Integer(current), Document::Integer(amount)) => Document::Integer(
+                current
+                    .checked_add(amount)
+                    .ok_or(DispatchError::ArithmeticOverflow)?,
+            ),
+            (Document::Integer(current), Document::Float(amount)) => {
+                Document::Float(*current as f64 + amount)
+            }
+            (Document::Float(current), Document::Integer(amount)) => {
+                Document::Float(current + amount as f64)
+            }
+            (Document::Float(current), Document::Float(amount)) => {
+                Document::Float(current + amount)
+            }
+            (_, Document::Integer(_)) | (_, Document::Float(_)) => {
+                return Err(DispatchError::PreconditionFailed);
+            }
+            _ => return Err(DispatchError::ArgumentRetrieval),
+        };
+
+        match new {
+            Document::Float(float) if !float.is_finite() => {
+                return Err(DispatchError::ArithmeticOverflow);
+            }
+            Document::Float(float) => response::write_float(resp, float),
+            Document::Integer(int) => response::write_int(resp, int),
+            _ => unreachable!(),
+        }
+
+        *number = new;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocumentIncrement;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{Document, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn dispatch(hop: &Hop, path: &[u8], amount: &[u8]) -> Result<Vec<u8>, DispatchError> {
+        let mut builder = RequestBuilder::new(CommandId::DocumentIncrement);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(path).is_ok());
+        assert!(builder.bytes(amount).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        DocumentIncrement::dispatch(hop, &req, &mut resp).map(|()| resp)
+    }
+
+    fn hop() -> Hop {
+        let hop = Hop::new();
+        let document = r#"{"a":[1],"b":1.5,"c":"d"}"#.parse::<Document>().unwrap();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Document(document));
+
+        hop
+    }
+
+    #[test]
+    fn test_increment() {
+        let hop = hop();
+
+        assert_eq!(
+            Response::from(4).as_bytes(),
+            dispatch(&hop, b"a[0]", b"3").unwrap()
+        );
+        assert_eq!(
+            Response::from(4.5).as_bytes(),
+            dispatch(&hop, b"a[0]", b"0.5").unwrap()
+        );
+        assert_eq!(
+            Response::from(0.5).as_bytes(),
+            dispatch(&hop, b"b", b"-1").unwrap()
+        );
+        assert_eq!(
+            Some(&Document::Float(4.5)),
+            hop.state()
+                .key_ref(b"foo")
+                .unwrap()
+                .as_document_ref()
+                .unwrap()
+                .get(&"a[0]".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let hop = hop();
+
+        assert_eq!(
+            Err(DispatchError::PreconditionFailed),
+            dispatch(&hop, b"c", b"1")
+        );
+        assert_eq!(
+            Err(DispatchError::PreconditionFailed),
+            dispatch(&hop, b"x", b"1")
+        );
+        assert_eq!(
+            Err(DispatchError::ArgumentRetrieval),
+            dispatch(&hop, b"b", br#""1""#)
+        );
+
+        assert!(dispatch(&hop, b"a[0]", b"9223372036854775806").is_ok());
+        assert_eq!(
+            Err(DispatchError::ArithmeticOverflow),
+            dispatch(&hop, b"a[0]", b"1")
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    document_get::DocumentGet,
+};
+use crate::{
+    state::{document::Segment, Document, Value},
+    Hop,
+};
+use alloc::vec::Vec;
+
+pub struct DocumentSet;
+
+impl Dispatch for DocumentSet {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        DocumentGet::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let path = DocumentGet::path(req, 1)?;
+        let value = req
+            .typed_arg::<Document>(2)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config()
+            .check_value_size(req.arg(2).map_or(0, <[u8]>::len))?;
+
+        // Only the root can be set on a document that doesn't exist yet, since
+        // there's no parent to set anything else on.
+        let mut entry = if path.is_root() {
+            hop.state().key_or_insert_with(key, Value::document)
+        } else {
+            hop.state()
+                .key_mut(key)
+                .ok_or(DispatchError::KeyNonexistent)?
+        };
+        let document = entry
+            .as_document_mut()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+        let existing = document.get(&path);
+
+        // Setting a field that doesn't exist yet adds one to its object.
+        if existing.is_none() && matches!(path.segments().last(), Some(Segment::Field(_))) {
+            let count = path
+                .parent()
+                .and_then(|parent| document.get(&parent))
+                .and_then(Document::element_count);
+
+            if let Some(count) = count {
+                hop.config().check_elements(count.saturating_add(1))?;
+            }
+        }
+
+        // Measuring the whole document means serializing it, so only do so
+        // when there's a limit to check it against.
+        let limited = hop.config().max_value_size() != usize::MAX;
+        let previous = if limited { existing.cloned() } else { None };
+
+        response::write_document(resp, &value);
+
+        if !document.set(&path, value) {
+            resp.clear();
+
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        if limited {
+            if let Err(source) = hop.config().check_value_size(document.as_bytes().len()) {
+                // Put back whatever was at the path before.
+                match previous {
+                    Some(previous) => {
+                        document.set(&path, previous);
+                    }
+                    None => {
+                        document.remove(&path);
+                    }
+                }
+
+                resp.clear();
+
+                return Err(source);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DocumentSet;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{document::DEPTH_MAX, Document, Value},
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+
+    fn request(path: &[u8], value: &[u8]) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::DocumentSet);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(path).is_ok());
+        assert!(builder.bytes(value).is_ok());
+
+        builder
+    }
+
+    fn document(hop: &Hop) -> String {
+        hop.state()
+            .key_ref(b"foo")
+            .unwrap()
+            .as_document_ref()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_set_creates_root() {
+        let req = request(b"$", br#"{"a":[1]}"#).into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert!(DocumentSet::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(
+            Response::from(r#"{"a":[1]}"#.parse::<Document>().unwrap()).as_bytes(),
+            resp
+        );
+        assert_eq!(r#"{"a":[1]}"#, document(&hop));
+    }
+
+    #[test]
+    fn test_set_nested() {
+        let hop = Hop::new();
+        let value = r#"{"a":[1]}"#.parse::<Document>().unwrap();
+        hop.state().insert(b"foo".to_vec(), Value::Document(value));
+        let mut resp = Vec::new();
+
+        let req = request(b"a[0]", b"true").into_request();
+        assert!(DocumentSet::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(Document::Boolean(true)).as_bytes(), resp);
+
+        let req = request(b"b", br#""c""#).into_request();
+        assert!(DocumentSet::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(r#"{"a":[true],"b":"c"}"#, document(&hop));
+
+        resp.clear();
+        let req = request(b"x.y", b"1").into_request();
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            DocumentSet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(resp.is_empty());
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let mut builder = Hop::builder();
+        builder.max_value_size(16).max_collection_elements(2);
+        let hop = builder.build();
+        let value = r#"{"a":1}"#.parse::<Document>().unwrap();
+        hop.state().insert(b"foo".to_vec(), Value::Document(value));
+        let mut resp = Vec::new();
+
+        let req = request(b"b", b"2").into_request();
+        assert!(DocumentSet::dispatch(&hop, &req, &mut resp).is_ok());
+
+        // The object already has as many fields as allowed.
+        resp.clear();
+        let req = request(b"c", b"3").into_request();
+        assert_eq!(
+            DispatchError::SizeLimitExceeded,
+            DocumentSet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(resp.is_empty());
+
+        // Each value is small, but the document would become too large.
+        let req = request(b"a", br#""abcdef""#).into_request();
+        assert_eq!(
+            DispatchError::SizeLimitExceeded,
+            DocumentSet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(resp.is_empty());
+        assert_eq!(r#"{"a":1,"b":2}"#, document(&hop));
+
+        let req = request(b"a", b"10").into_request();
+        assert!(DocumentSet::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(r#"{"a":10,"b":2}"#, document(&hop));
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+        let nested = |depth: usize| "[".repeat(depth) + "0" + &"]".repeat(depth);
+        let deepest = "$".to_owned() + &"[0]".repeat(100);
+
+        let req = request(b"$", nested(100).as_bytes()).into_request();
+        assert!(DocumentSet::dispatch(&hop, &req, &mut resp).is_ok());
+
+        // Each value can be parsed, but setting it would nest the document
+        // deeper than a document can be.
+        resp.clear();
+        let req = request(deepest.as_bytes(), nested(100).as_bytes()).into_request();
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            DocumentSet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(resp.is_empty());
+
+        let req = request(deepest.as_bytes(), nested(DEPTH_MAX - 100).as_bytes()).into_request();
+        assert!(DocumentSet::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(nested(DEPTH_MAX), document(&hop));
+    }
+
+    #[test]
+    fn test_nonexistent_key() {
+        let req = request(b"a", b"1").into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            DocumentSet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_value_invalid() {
+        let req = request(b"$", b"{").into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            DocumentSet::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    command::{response, Dispatch, DispatchError, DispatchResult, Request},
+    state::dump,
+    Hop,
+};
+use alloc::vec::Vec;
+
+pub struct Dump;
+
+impl Dispatch for Dump {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let bytes = hop
+            .state()
+            .key_ref(key)
+            .map(|value| dump::dump(value.value()))
+            .ok_or(DispatchError::KeyNonexistent)?;
+
+        response::write_bytes(resp, &bytes);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dump;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{dump, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_dump() {
+        let mut builder = RequestBuilder::new(CommandId::Dump);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            Dump::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+
+        hop.state().insert(b"foo".to_vec(), Value::Integer(7));
+        assert!(Dump::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(
+            resp,
+            Response::from(dump::dump(&Value::Integer(7))).as_bytes()
+        );
+    }
+}
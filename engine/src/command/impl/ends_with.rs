@@ -0,0 +1,57 @@
+use super::{
+    super::{Dispatch, DispatchResult, Request},
+    starts_with::StartsWith,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct EndsWith;
+
+impl Dispatch for EndsWith {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        StartsWith::affix(hop, req, resp, <[u8]>::ends_with)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EndsWith;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_string() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("hopdb".to_owned()));
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::EndsWith, KeyType::String);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"db".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(EndsWith::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(true).as_bytes());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([1, 2, 3].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::EndsWith);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes([2, 3].as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(EndsWith::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(true).as_bytes());
+    }
+}
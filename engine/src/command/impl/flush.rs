@@ -0,0 +1,145 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{pubsub::KeyUpdate, state::State, Hop};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+pub struct Flush;
+
+impl Flush {
+    /// Retrieve whether the keys should be dropped in the background from an
+    /// optional boolean argument.
+    pub fn background(req: &Request, idx: usize) -> DispatchResult<bool> {
+        match req.arg(idx) {
+            Some(_) => req
+                .typed_arg::<bool>(idx)
+                .ok_or(DispatchError::ArgumentRetrieval),
+            None => Ok(false),
+        }
+    }
+
+    /// Remove the keys starting with a prefix from some namespaces, given by
+    /// their number and state, writing the number of removed keys to the
+    /// response.
+    ///
+    /// Subscribers of each removed key are notified of its deletion. The
+    /// removed keys are then dropped, either immediately or by the configured
+    /// background function.
+    pub fn flush<'a>(
+        hop: &Hop,
+        namespaces: impl Iterator<Item = (usize, &'a State)>,
+        prefix: &[u8],
+        background: bool,
+        resp: &mut Vec<u8>,
+    ) {
+        let mut removed = Vec::new();
+
+        for (namespace, state) in namespaces {
+            let keys = state.remove_prefix(prefix);
+
+            if hop.config().pubsub_enabled() {
+                for (key, value) in &keys {
+                    hop.pubsub()
+                        .publish(namespace, key, || KeyUpdate::Deleted(value.clone()));
+                }
+            }
+
+            removed.extend(keys);
+        }
+
+        let count = i64::try_from(removed.len()).unwrap_or(i64::MAX);
+
+        if background {
+            (hop.config().drop_background())(removed);
+        }
+
+        response::write_int(resp, count);
+    }
+}
+
+impl Dispatch for Flush {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let background = Self::background(req, 0)?;
+
+        Self::flush(
+            hop,
+            core::iter::once((hop.namespace(), hop.state())),
+            b"",
+            background,
+            resp,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Flush;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        pubsub::KeyUpdate,
+        session::SessionId,
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[tokio::test]
+    async fn test_flush() {
+        let req = RequestBuilder::new(CommandId::Flush).into_request();
+
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        hop.state().insert(b"bar".to_vec(), Value::Integer(2));
+        let other = hop.clone();
+        assert!(other.select(1));
+        other.state().insert(b"baz".to_vec(), Value::Integer(3));
+        let sub = hop
+            .pubsub()
+            .subscribe(0, b"foo".to_vec(), SessionId::new(1))
+            .unwrap();
+
+        let mut resp = Vec::new();
+        assert!(Flush::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(2).as_bytes());
+        assert!(!hop.state().contains_key(b"foo"));
+        assert!(other.state().contains_key(b"baz"));
+        assert!(matches!(
+            sub.upgrade().unwrap().receiver().receive().await,
+            Some(KeyUpdate::Deleted(Value::Integer(1)))
+        ));
+    }
+
+    #[test]
+    fn test_flush_background() {
+        let mut builder = Hop::builder();
+        builder.drop_background(|removed| assert_eq!(1, removed.len()));
+        let hop = builder.build();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+
+        let mut builder = RequestBuilder::new(CommandId::Flush);
+        assert!(builder.value(Value::Boolean(true)).is_ok());
+        let req = builder.into_request();
+
+        let mut resp = Vec::new();
+        assert!(Flush::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(1).as_bytes());
+        assert!(!hop.state().contains_key(b"foo"));
+    }
+
+    #[test]
+    fn test_key_type_unexpected() {
+        let req =
+            RequestBuilder::new_with_key_type(CommandId::Flush, KeyType::Integer).into_request();
+
+        let mut resp = Vec::new();
+        assert_eq!(
+            DispatchError::KeyTypeUnexpected,
+            Flush::dispatch(&Hop::new(), &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use super::{
+    super::{Dispatch, DispatchError, DispatchResult, Request},
+    Flush,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct FlushAll;
+
+impl Dispatch for FlushAll {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let background = Flush::background(req, 0)?;
+
+        Flush::flush(hop, hop.namespaces().enumerate(), b"", background, resp);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlushAll;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_flush_all() {
+        let req = RequestBuilder::new(CommandId::FlushAll).into_request();
+
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        let other = hop.clone();
+        assert!(other.select(15));
+        other.state().insert(b"bar".to_vec(), Value::Integer(2));
+
+        let mut resp = Vec::new();
+        assert!(FlushAll::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(2).as_bytes());
+        assert!(hop.namespaces().all(|state| state.0.is_empty()));
+    }
+}
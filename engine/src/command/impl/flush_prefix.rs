@@ -0,0 +1,68 @@
+use super::{
+    super::{Dispatch, DispatchError, DispatchResult, Request},
+    Flush,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct FlushPrefix;
+
+impl Dispatch for FlushPrefix {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let prefix = req.arg(0).ok_or(DispatchError::ArgumentRetrieval)?;
+        let background = Flush::background(req, 1)?;
+
+        Flush::flush(
+            hop,
+            core::iter::once((hop.namespace(), hop.state())),
+            prefix,
+            background,
+            resp,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlushPrefix;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_flush_prefix() {
+        let mut builder = RequestBuilder::new(CommandId::FlushPrefix);
+        assert!(builder.bytes(b"user:".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let hop = Hop::new();
+        hop.state().insert(b"user:1".to_vec(), Value::Integer(1));
+        hop.state().insert(b"user:2".to_vec(), Value::Integer(2));
+        hop.state().insert(b"post:1".to_vec(), Value::Integer(3));
+
+        let mut resp = Vec::new();
+        assert!(FlushPrefix::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(2).as_bytes());
+        assert!(hop.state().contains_key(b"post:1"));
+    }
+
+    #[test]
+    fn test_prefix_unspecified() {
+        let req = RequestBuilder::new(CommandId::FlushPrefix).into_request();
+
+        let mut resp = Vec::new();
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            FlushPrefix::dispatch(&Hop::new(), &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -12,7 +12,7 @@ impl Dispatch for Get {
         let r = hop
             .state()
             .key_ref(key)
-            .ok_or_else(|| DispatchError::KeyNonexistent)?;
+            .ok_or(DispatchError::KeyNonexistent)?;
 
         if let Some(key_type) = req.key_type() {
             if r.value().kind() != key_type {
@@ -0,0 +1,110 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{
+    state::{KeyType, Value},
+    Hop,
+};
+use alloc::vec::Vec;
+
+pub struct HyperLogLogAdd;
+
+impl HyperLogLogAdd {
+    /// Check that the request's key type, if any, is a HyperLogLog.
+    pub fn check_key_type(req: &Request) -> DispatchResult<()> {
+        match req.key_type() {
+            Some(KeyType::HyperLogLog) | None => Ok(()),
+            Some(_) => Err(DispatchError::KeyTypeInvalid),
+        }
+    }
+}
+
+impl Dispatch for HyperLogLogAdd {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let items = req.args(1..).ok_or(DispatchError::ArgumentRetrieval)?;
+
+        let mut key = hop.state().key_or_insert_with(key, Value::hyper_log_log);
+        let hll = key
+            .as_hyper_log_log_mut()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+        let mut changed = false;
+
+        for item in items {
+            changed |= hll.add(item);
+        }
+
+        response::write_bool(resp, changed);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HyperLogLogAdd;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_add() {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogAdd);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        assert!(builder.bytes(b"baz".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert!(HyperLogLogAdd::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(true).as_bytes(), resp);
+        assert_eq!(
+            Some(2),
+            hop.state()
+                .key_ref(b"foo")
+                .as_deref()
+                .and_then(Value::as_hyper_log_log_ref)
+                .map(|hll| hll.count())
+        );
+
+        let mut resp = Vec::new();
+        assert!(HyperLogLogAdd::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(false).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_key_type_different() {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogAdd);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            HyperLogLogAdd::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_key_type_invalid() {
+        let mut builder =
+            RequestBuilder::new_with_key_type(CommandId::HyperLogLogAdd, KeyType::Set);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeInvalid,
+            HyperLogLogAdd::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"foo"));
+    }
+}
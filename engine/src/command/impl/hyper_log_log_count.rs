@@ -0,0 +1,105 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    hyper_log_log_add::HyperLogLogAdd,
+};
+use crate::{state::HyperLogLog, Hop};
+use alloc::vec::Vec;
+
+pub struct HyperLogLogCount;
+
+impl HyperLogLogCount {
+    /// Merge the HyperLogLogs of the given keys into a new HyperLogLog.
+    ///
+    /// Nonexistent keys are treated as empty HyperLogLogs.
+    pub fn union<'a>(
+        hop: &Hop,
+        keys: impl Iterator<Item = &'a [u8]>,
+    ) -> DispatchResult<HyperLogLog> {
+        let mut union = HyperLogLog::new();
+
+        for key in keys {
+            if let Some(value) = hop.state().key_ref(key) {
+                let hll = value
+                    .as_hyper_log_log_ref()
+                    .ok_or(DispatchError::KeyTypeDifferent)?;
+
+                union.merge(hll);
+            }
+        }
+
+        Ok(union)
+    }
+}
+
+impl Dispatch for HyperLogLogCount {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        HyperLogLogAdd::check_key_type(req)?;
+        let keys = req.args(..).ok_or(DispatchError::KeyUnspecified)?;
+        let count = Self::union(hop, keys)?.count();
+
+        response::write_int(resp, count as i64);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HyperLogLogCount;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{HyperLogLog, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_count_union() {
+        let mut foo = HyperLogLog::new();
+        foo.add(b"a");
+        foo.add(b"b");
+        let mut bar = HyperLogLog::new();
+        bar.add(b"b");
+        bar.add(b"c");
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::HyperLogLog(foo));
+        hop.state().insert(b"bar".to_vec(), Value::HyperLogLog(bar));
+
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogCount);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        assert!(builder.bytes(b"baz".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(HyperLogLogCount::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(3i64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_no_keys() {
+        let req = RequestBuilder::new(CommandId::HyperLogLogCount).into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyUnspecified,
+            HyperLogLogCount::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_key_type_different() {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogCount);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            HyperLogLogCount::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
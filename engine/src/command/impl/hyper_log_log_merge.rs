@@ -0,0 +1,89 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    hyper_log_log_add::HyperLogLogAdd,
+    hyper_log_log_count::HyperLogLogCount,
+};
+use crate::{state::Value, Hop};
+use alloc::vec::Vec;
+
+pub struct HyperLogLogMerge;
+
+impl Dispatch for HyperLogLogMerge {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        HyperLogLogAdd::check_key_type(req)?;
+        let destination = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let sources = req.args(1..).ok_or(DispatchError::ArgumentRetrieval)?;
+
+        // Merge the sources before touching the destination, since the
+        // destination may be one of the sources.
+        let union = HyperLogLogCount::union(hop, sources)?;
+
+        let mut key = hop
+            .state()
+            .key_or_insert_with(destination, Value::hyper_log_log);
+        let hll = key
+            .as_hyper_log_log_mut()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+        hll.merge(&union);
+
+        response::write_int(resp, hll.count() as i64);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HyperLogLogMerge;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{HyperLogLog, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_merge_into_destination() {
+        let mut foo = HyperLogLog::new();
+        foo.add(b"a");
+        let mut bar = HyperLogLog::new();
+        bar.add(b"b");
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::HyperLogLog(foo));
+        hop.state().insert(b"bar".to_vec(), Value::HyperLogLog(bar));
+
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogMerge);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(HyperLogLogMerge::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(2i64).as_bytes(), resp);
+        assert_eq!(
+            Some(2),
+            hop.state()
+                .key_ref(b"foo")
+                .as_deref()
+                .and_then(Value::as_hyper_log_log_ref)
+                .map(HyperLogLog::count)
+        );
+    }
+
+    #[test]
+    fn test_destination_key_type_different() {
+        let mut builder = RequestBuilder::new(CommandId::HyperLogLogMerge);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            HyperLogLogMerge::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
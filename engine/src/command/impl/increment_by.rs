@@ -1,5 +1,8 @@
 use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
-use crate::{state::Value, Hop};
+use crate::{
+    state::{KeyType, Value},
+    Hop,
+};
 use alloc::vec::Vec;
 
 pub struct IncrementBy;
@@ -11,45 +14,97 @@ impl IncrementBy {
         amount: f64,
         resp: &mut Vec<u8>,
     ) -> DispatchResult<()> {
-        let mut key = hop.state().key_or_insert_with(key, Value::integer);
+        Self::float(hop, key, resp, |float| Ok(float + amount))
+    }
+
+    pub fn increment_int_by(
+        hop: &Hop,
+        key: &[u8],
+        amount: i64,
+        resp: &mut Vec<u8>,
+    ) -> DispatchResult<()> {
+        Self::int(hop, key, resp, |int| {
+            int.checked_add(amount)
+                .ok_or(DispatchError::ArithmeticOverflow)
+        })
+    }
+
+    /// Apply an operation to a float key, creating the key with a value of
+    /// zero if it doesn't exist.
+    ///
+    /// The value isn't modified if the operation fails or if it overflows
+    /// into infinity.
+    pub fn float(
+        hop: &Hop,
+        key: &[u8],
+        resp: &mut Vec<u8>,
+        op: impl FnOnce(f64) -> DispatchResult<f64>,
+    ) -> DispatchResult<()> {
+        let mut key = hop.state().key_or_insert_with(key, Value::float);
         let float = key.as_float_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+        let new = op(*float)?;
+
+        if new.is_infinite() && float.is_finite() {
+            return Err(DispatchError::ArithmeticOverflow);
+        }
 
-        *float += amount;
+        *float = new;
 
         response::write_float(resp, *float);
 
         Ok(())
     }
 
-    pub fn increment_int_by(
+    /// Apply an operation to an integer key, creating the key with a value of
+    /// zero if it doesn't exist.
+    ///
+    /// The value isn't modified if the operation fails.
+    pub fn int(
         hop: &Hop,
         key: &[u8],
-        amount: i64,
         resp: &mut Vec<u8>,
+        op: impl FnOnce(i64) -> DispatchResult<i64>,
     ) -> DispatchResult<()> {
         let mut key = hop.state().key_or_insert_with(key, Value::integer);
         let int = key
             .as_integer_mut()
             .ok_or(DispatchError::KeyTypeDifferent)?;
 
-        *int += amount;
+        *int = op(*int)?;
 
         response::write_int(resp, *int);
 
         Ok(())
     }
+
+    /// Determine whether a numeric command should operate on an integer or a
+    /// float.
+    ///
+    /// The key type of the request is used if specified, otherwise the type of
+    /// the existing key, defaulting to an integer.
+    pub fn key_type(hop: &Hop, req: &Request, key: &[u8]) -> DispatchResult<KeyType> {
+        match req.key_type() {
+            Some(KeyType::Float) => Ok(KeyType::Float),
+            Some(KeyType::Integer) => Ok(KeyType::Integer),
+            Some(_) => Err(DispatchError::KeyTypeInvalid),
+            None if hop.state().key_type(key) == Some(KeyType::Float) => Ok(KeyType::Float),
+            None => Ok(KeyType::Integer),
+        }
+    }
 }
 
 impl Dispatch for IncrementBy {
     fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
         let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
 
-        if let Some(int) = req.typed_arg::<i64>(1) {
-            Self::increment_int_by(hop, key, int, resp)
-        } else if let Some(float) = req.typed_arg::<f64>(1) {
-            Self::increment_float_by(hop, key, float, resp)
+        if Self::key_type(hop, req, key)? == KeyType::Float {
+            let amount = req.typed_arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+
+            Self::increment_float_by(hop, key, amount, resp)
         } else {
-            Err(DispatchError::ArgumentRetrieval)
+            let amount = req.typed_arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+
+            Self::increment_int_by(hop, key, amount, resp)
         }
     }
 }
@@ -59,7 +114,7 @@ mod tests {
     use super::IncrementBy;
     use crate::{
         command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
-        state::Value,
+        state::{KeyType, Value},
         Hop,
     };
     use alloc::vec::Vec;
@@ -84,6 +139,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_float() {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::IncrementBy, KeyType::Float);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Float(1.5)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert!(IncrementBy::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(1.5f64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut builder = RequestBuilder::new(CommandId::IncrementBy);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(1)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Integer(i64::MAX));
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArithmeticOverflow,
+            IncrementBy::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert_eq!(
+            Some(&i64::MAX),
+            hop.state()
+                .key_ref(b"foo")
+                .as_deref()
+                .and_then(Value::as_integer_ref)
+        );
+    }
+
     #[test]
     fn test_no_key() {
         let req = RequestBuilder::new(CommandId::Decrement).into_request();
@@ -110,4 +110,32 @@ mod tests {
             Keys::dispatch(&hop, &req, &mut resp).unwrap_err()
         );
     }
+
+    #[test]
+    fn test_permitted_keys() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"cache:1".to_vec(), Value::Map(DashMap::new()));
+        hop.state()
+            .insert(b"secret".to_vec(), Value::Map(DashMap::new()));
+        assert!(hop
+            .acl()
+            .set_user("foo", [">bar", "+keys", "~cache:*"])
+            .is_ok());
+        assert!(hop.authenticate("foo", b"bar"));
+
+        let mut resp = Vec::new();
+
+        for (key, permitted) in [(b"cache:1".as_ref(), true), (b"secret", false)] {
+            let mut builder = RequestBuilder::new(CommandId::Keys);
+            assert!(builder.bytes(key).is_ok());
+            let result = hop.dispatch(&builder.into_request(), &mut resp);
+
+            if permitted {
+                assert!(result.is_ok());
+            } else {
+                assert_eq!(Err(DispatchError::PermissionDenied), result);
+            }
+        }
+    }
 }
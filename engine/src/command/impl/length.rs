@@ -53,6 +53,18 @@ impl Length {
         Ok(())
     }
 
+    fn stream(hop: &Hop, key: &[u8], resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = hop
+            .state()
+            .key_ref(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let stream = key.as_stream_ref().ok_or(DispatchError::KeyTypeDifferent)?;
+
+        response::write_int(resp, stream.len() as i64);
+
+        Ok(())
+    }
+
     fn string(hop: &Hop, key: &[u8], resp: &mut Vec<u8>) -> DispatchResult<()> {
         let key = hop
             .state()
@@ -79,6 +91,7 @@ impl Dispatch for Length {
             KeyType::List => Self::list(hop, key, resp),
             KeyType::Map => Self::map(hop, key, resp),
             KeyType::Set => Self::set(hop, key, resp),
+            KeyType::Stream => Self::stream(hop, key, resp),
             KeyType::String => Self::string(hop, key, resp),
             _ => Err(DispatchError::KeyTypeInvalid),
         }
@@ -90,7 +103,7 @@ mod tests {
     use super::Length;
     use crate::{
         command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
-        state::{KeyType, Value},
+        state::{KeyType, Stream, StreamId, Value},
         Hop,
     };
     use alloc::{borrow::ToOwned, vec::Vec};
@@ -154,7 +167,6 @@ mod tests {
         let mut resp = Vec::new();
         let hop = Hop::new();
         hop.state()
-            .0
             .insert(b"foo".to_vec(), Value::Bytes([1, 2, 3].to_vec()));
 
         assert!(Length::dispatch(&hop, &req, &mut resp).is_ok());
@@ -169,9 +181,8 @@ mod tests {
 
         let mut resp = Vec::new();
         let hop = Hop::new();
-        let mut list = Vec::new();
-        list.push(b"db".to_vec());
-        hop.state().0.insert(b"hop".to_vec(), Value::List(list));
+        let list = vec![b"db".to_vec()];
+        hop.state().insert(b"hop".to_vec(), Value::List(list));
 
         assert!(Length::dispatch(&hop, &req, &mut resp).is_ok());
         assert_eq!(resp, Response::from(1).as_bytes());
@@ -187,7 +198,7 @@ mod tests {
         let hop = Hop::new();
         let map = DashMap::new();
         map.insert(b"foo".to_vec(), b"bar".to_vec());
-        hop.state().0.insert(b"hop".to_vec(), Value::Map(map));
+        hop.state().insert(b"hop".to_vec(), Value::Map(map));
 
         assert!(Length::dispatch(&hop, &req, &mut resp).is_ok());
         assert_eq!(resp, Response::from(1).as_bytes());
@@ -203,7 +214,23 @@ mod tests {
         let hop = Hop::new();
         let set = DashSet::new();
         set.insert(b"foo".to_vec());
-        hop.state().0.insert(b"hop".to_vec(), Value::Set(set));
+        hop.state().insert(b"hop".to_vec(), Value::Set(set));
+
+        assert!(Length::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(1).as_bytes());
+    }
+
+    #[test]
+    fn test_default_when_stream_exists() {
+        let mut builder = RequestBuilder::new(CommandId::Length);
+        assert!(builder.bytes(b"hop".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+        let mut stream = Stream::new();
+        assert!(stream.append(StreamId::new(1, 0), Vec::new()));
+        hop.state().insert(b"hop".to_vec(), Value::Stream(stream));
 
         assert!(Length::dispatch(&hop, &req, &mut resp).is_ok());
         assert_eq!(resp, Response::from(1).as_bytes());
@@ -217,13 +244,11 @@ mod tests {
 
         let hop = Hop::new();
         hop.state()
-            .0
             .insert(b"foo".to_vec(), Value::String("1234".to_owned()));
 
         let cowboy = "🤠";
         assert_eq!(cowboy.len(), 4);
         hop.state()
-            .0
             .insert(b"cowboy".to_vec(), Value::String(cowboy.to_owned()));
 
         let mut builder = RequestBuilder::new(CommandId::Length);
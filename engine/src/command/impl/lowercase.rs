@@ -0,0 +1,55 @@
+use super::{
+    super::{Dispatch, DispatchResult, Request},
+    uppercase::Uppercase,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct Lowercase;
+
+impl Dispatch for Lowercase {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Uppercase::convert(hop, req, resp, str::to_lowercase)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Lowercase;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_string() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("HopDB".to_owned()));
+
+        let mut builder = RequestBuilder::new(CommandId::Lowercase);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Lowercase::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("hopdb".to_owned()).as_bytes());
+    }
+
+    #[test]
+    fn test_nonexistent() {
+        let hop = Hop::new();
+
+        let mut builder = RequestBuilder::new(CommandId::Lowercase);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            Lowercase::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    command::{response, Dispatch, DispatchError, DispatchResult, Request},
+    Hop,
+};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+pub struct MemoryUsage;
+
+impl Dispatch for MemoryUsage {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let usage = hop
+            .state()
+            .memory_usage(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+
+        response::write_int(resp, i64::try_from(usage).unwrap_or(i64::MAX));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryUsage;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_grows_with_value() {
+        let mut builder = RequestBuilder::new(CommandId::MemoryUsage);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes(Vec::new()));
+        let empty = hop.state().memory_usage(b"foo").unwrap();
+
+        assert!(MemoryUsage::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(empty as i64).as_bytes());
+
+        hop.state()
+            .key_mut(b"foo")
+            .unwrap()
+            .as_bytes_mut()
+            .unwrap()
+            .extend_from_slice(&[0; 1000]);
+        assert!(hop.state().memory_usage(b"foo").unwrap() >= empty + 1000);
+        assert_eq!(
+            hop.state().memory_usage(b"foo"),
+            Some(hop.state().memory_used())
+        );
+    }
+
+    #[test]
+    fn test_key_nonexistent() {
+        let mut builder = RequestBuilder::new(CommandId::MemoryUsage);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            MemoryUsage::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -1,22 +1,90 @@
+mod acl_delete;
+mod acl_list;
+mod acl_set;
 mod append;
+mod auth;
+mod bit_and;
+mod bit_count;
+mod bit_get;
+mod bit_not;
+mod bit_or;
+mod bit_position;
+mod bit_set;
+mod bit_xor;
+mod clamp;
+mod client_kill;
+mod client_list;
+mod config_get;
+mod config_set;
+mod copy;
 mod decrement;
 mod decrement_by;
 mod delete;
+mod divide;
+mod document_append;
+mod document_delete;
+mod document_get;
+mod document_increment;
+mod document_set;
+mod dump;
 mod echo;
+mod ends_with;
 mod exists;
+mod flush;
+mod flush_all;
+mod flush_prefix;
 mod get;
+mod hyper_log_log_add;
+mod hyper_log_log_count;
+mod hyper_log_log_merge;
 mod increment;
 mod increment_by;
 mod is;
 mod keys;
 mod length;
+mod lowercase;
+mod memory_usage;
+mod modulo;
+mod multiply;
+mod object_info;
 mod rename;
+mod restore;
+mod scan;
+mod select;
 mod set;
+mod set_if_greater;
+mod set_if_less;
+mod set_range;
+mod slice;
+mod split;
+mod starts_with;
 mod stats;
+mod stream_append;
+mod stream_range;
+mod stream_read;
+mod stream_trim;
+mod stream_trim_age;
+mod substring;
 mod r#type;
+mod uppercase;
 
 pub use self::{
-    append::Append, decrement::Decrement, decrement_by::DecrementBy, delete::Delete, echo::Echo,
-    exists::Exists, get::Get, increment::Increment, increment_by::IncrementBy, is::Is, keys::Keys,
-    length::Length, r#type::Type, rename::Rename, set::Set, stats::Stats,
+    acl_delete::AclDelete, acl_list::AclList, acl_set::AclSet, append::Append, auth::Auth,
+    bit_and::BitAnd, bit_count::BitCount, bit_get::BitGet, bit_not::BitNot, bit_or::BitOr,
+    bit_position::BitPosition, bit_set::BitSet, bit_xor::BitXor, clamp::Clamp,
+    client_kill::ClientKill, client_list::ClientList, config_get::ConfigGet, config_set::ConfigSet,
+    copy::Copy, decrement::Decrement, decrement_by::DecrementBy, delete::Delete, divide::Divide,
+    document_append::DocumentAppend, document_delete::DocumentDelete, document_get::DocumentGet,
+    document_increment::DocumentIncrement, document_set::DocumentSet, dump::Dump, echo::Echo,
+    ends_with::EndsWith, exists::Exists, flush::Flush, flush_all::FlushAll,
+    flush_prefix::FlushPrefix, get::Get, hyper_log_log_add::HyperLogLogAdd,
+    hyper_log_log_count::HyperLogLogCount, hyper_log_log_merge::HyperLogLogMerge,
+    increment::Increment, increment_by::IncrementBy, is::Is, keys::Keys, length::Length,
+    lowercase::Lowercase, memory_usage::MemoryUsage, modulo::Modulo, multiply::Multiply,
+    object_info::ObjectInfo, r#type::Type, rename::Rename, restore::Restore, scan::Scan,
+    select::Select, set::Set, set_if_greater::SetIfGreater, set_if_less::SetIfLess,
+    set_range::SetRange, slice::Slice, split::Split, starts_with::StartsWith, stats::Stats,
+    stream_append::StreamAppend, stream_range::StreamRange, stream_read::StreamRead,
+    stream_trim::StreamTrim, stream_trim_age::StreamTrimAge, substring::Substring,
+    uppercase::Uppercase,
 };
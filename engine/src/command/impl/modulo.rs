@@ -0,0 +1,79 @@
+use super::{
+    super::{Dispatch, DispatchError, DispatchResult, Request},
+    increment_by::IncrementBy,
+};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+
+pub struct Modulo;
+
+impl Dispatch for Modulo {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if IncrementBy::key_type(hop, req, key)? == KeyType::Float {
+            let amount = req
+                .typed_arg::<f64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            if amount == 0. {
+                return Err(DispatchError::DivisionByZero);
+            }
+
+            IncrementBy::float(hop, key, resp, |float| Ok(float % amount))
+        } else {
+            let amount = req
+                .typed_arg::<i64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            if amount == 0 {
+                return Err(DispatchError::DivisionByZero);
+            }
+
+            IncrementBy::int(hop, key, resp, |int| {
+                int.checked_rem(amount)
+                    .ok_or(DispatchError::ArithmeticOverflow)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Modulo;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_int() {
+        let mut builder = RequestBuilder::new(CommandId::Modulo);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(4)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(-7));
+        let mut resp = Vec::new();
+
+        assert!(Modulo::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(-3i64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_by_zero() {
+        let mut builder = RequestBuilder::new(CommandId::Modulo);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(0)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::DivisionByZero,
+            Modulo::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use super::{
+    super::{Dispatch, DispatchError, DispatchResult, Request},
+    increment_by::IncrementBy,
+};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+
+pub struct Multiply;
+
+impl Dispatch for Multiply {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if IncrementBy::key_type(hop, req, key)? == KeyType::Float {
+            let amount = req
+                .typed_arg::<f64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            IncrementBy::float(hop, key, resp, |float| Ok(float * amount))
+        } else {
+            let amount = req
+                .typed_arg::<i64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            IncrementBy::int(hop, key, resp, |int| {
+                int.checked_mul(amount)
+                    .ok_or(DispatchError::ArithmeticOverflow)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Multiply;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_int() {
+        let mut builder = RequestBuilder::new(CommandId::Multiply);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(3)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(-4));
+        let mut resp = Vec::new();
+
+        assert!(Multiply::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(-12i64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_float_existing_key() {
+        let mut builder = RequestBuilder::new(CommandId::Multiply);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Float(0.5)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Float(3.0));
+        let mut resp = Vec::new();
+
+        assert!(Multiply::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(1.5f64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut builder = RequestBuilder::new(CommandId::Multiply);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(2)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Integer(i64::MAX / 2 + 1));
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArithmeticOverflow,
+            Multiply::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_float_overflow() {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Multiply, KeyType::Float);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Float(f64::MAX)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Float(2.0));
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArithmeticOverflow,
+            Multiply::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    command::{response, Dispatch, DispatchError, DispatchResult, Request},
+    Hop,
+};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// Retrieve information about a key's value and how it has been accessed.
+///
+/// The response is a list of big-endian integers, in order: the key type, the
+/// estimated memory usage in bytes, the creation and last access Unix
+/// timestamps in milliseconds, the number of milliseconds since the last
+/// access, and the number of accesses.
+pub struct ObjectInfo;
+
+impl Dispatch for ObjectInfo {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let info = hop
+            .state()
+            .object_info(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let idle = (hop.config().clock())().saturating_sub(info.accessed_at);
+
+        let fields = [
+            info.key_type as i64,
+            i64::try_from(info.memory_usage).unwrap_or(i64::MAX),
+            i64::try_from(info.created_at).unwrap_or(i64::MAX),
+            i64::try_from(info.accessed_at).unwrap_or(i64::MAX),
+            i64::try_from(idle).unwrap_or(i64::MAX),
+            i64::try_from(info.frequency).unwrap_or(i64::MAX),
+        ];
+
+        response::write_list(resp, fields.iter().map(|field| field.to_be_bytes()));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ObjectInfo;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        hop::Builder,
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_info() {
+        let mut builder = RequestBuilder::new(CommandId::ObjectInfo);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let mut hop_builder = Builder::new();
+        hop_builder.clock(|| 5000);
+        let hop = hop_builder.build();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        hop.state().key_ref(b"foo");
+        let size = hop.state().memory_usage(b"foo").unwrap() as i64;
+
+        let mut resp = Vec::new();
+        assert!(ObjectInfo::dispatch(&hop, &req, &mut resp).is_ok());
+
+        let expected = [KeyType::Integer as i64, size, 5000, 5000, 0, 2]
+            .iter()
+            .map(|field| field.to_be_bytes().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(resp, Response::from(expected).as_bytes());
+
+        // Retrieving the information doesn't count as an access.
+        assert_eq!(2, hop.state().object_info(b"foo").unwrap().frequency);
+    }
+
+    #[test]
+    fn test_key_nonexistent() {
+        let mut builder = RequestBuilder::new(CommandId::ObjectInfo);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            ObjectInfo::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
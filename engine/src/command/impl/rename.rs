@@ -1,9 +1,44 @@
 use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
-use crate::Hop;
+use crate::{pubsub::KeyUpdate, state::Value, Hop};
 use alloc::vec::Vec;
 
 pub struct Rename;
 
+impl Rename {
+    /// Retrieve whether an existing destination key may be overwritten from
+    /// an optional boolean argument.
+    pub fn replace(req: &Request, idx: usize) -> DispatchResult<bool> {
+        match req.arg(idx) {
+            Some(_) => req
+                .typed_arg::<bool>(idx)
+                .ok_or(DispatchError::ArgumentRetrieval),
+            None => Ok(false),
+        }
+    }
+
+    /// Notify subscribers of a destination key that a value was written to it,
+    /// replacing the previous value if there was one.
+    pub fn publish_written(hop: &Hop, to: &[u8], previous: Option<Value>) {
+        if !hop.config().pubsub_enabled() {
+            return;
+        }
+
+        hop.pubsub().publish(hop.namespace(), to, || {
+            let value = hop
+                .state()
+                .key_ref(to)
+                .map(|value| value.value().clone())
+                .unwrap_or_else(Value::bytes);
+
+            if previous.is_some() {
+                KeyUpdate::Updated(value)
+            } else {
+                KeyUpdate::Initialized(value)
+            }
+        });
+    }
+}
+
 impl Dispatch for Rename {
     fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
         if req.key_type().is_some() {
@@ -12,18 +47,28 @@ impl Dispatch for Rename {
 
         let key = req.arg(0).ok_or(DispatchError::KeyUnspecified)?;
         let arg = req.arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+        let replace = Self::replace(req, 2)?;
         let state = hop.state();
 
         if !state.contains_key(key) {
             return Err(DispatchError::KeyNonexistent);
         }
 
-        if state.contains_key(arg) {
+        if !replace && state.contains_key(arg) {
             return Err(DispatchError::PreconditionFailed);
         }
 
         let (_, v) = state.remove(key).ok_or(DispatchError::KeyNonexistent)?;
-        state.insert(arg.to_vec(), v);
+        let previous = state.insert(arg.to_vec(), v);
+
+        if hop.config().pubsub_enabled() {
+            hop.pubsub()
+                .publish(hop.namespace(), key, || KeyUpdate::Renamed {
+                    to: arg.to_vec(),
+                });
+        }
+
+        Self::publish_written(hop, arg, previous);
 
         response::write_bytes(resp, arg);
 
@@ -36,6 +81,8 @@ mod tests {
     use super::Rename;
     use crate::{
         command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        pubsub::KeyUpdate,
+        session::SessionId,
         state::Value,
         Hop,
     };
@@ -91,6 +138,65 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_rename_replace() {
+        let mut builder = RequestBuilder::new(CommandId::Rename);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        assert!(builder.value(Value::Boolean(true)).is_ok());
+        let req = builder.into_request();
+
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        hop.state().insert(b"bar".to_vec(), Value::Integer(2));
+        let from = hop
+            .pubsub()
+            .subscribe(0, b"foo".to_vec(), SessionId::new(1))
+            .unwrap();
+        let to = hop
+            .pubsub()
+            .subscribe(0, b"bar".to_vec(), SessionId::new(2))
+            .unwrap();
+
+        assert!(Rename::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(b"bar".to_vec()).as_bytes());
+        assert!(!hop.state().contains_key(b"foo"));
+        assert!(matches!(
+            hop.state().key_ref(b"bar").as_deref(),
+            Some(Value::Integer(1))
+        ));
+        assert!(matches!(
+            from.upgrade().unwrap().receiver().receive().await,
+            Some(KeyUpdate::Renamed { to }) if to == b"bar"
+        ));
+        assert!(matches!(
+            to.upgrade().unwrap().receiver().receive().await,
+            Some(KeyUpdate::Updated(Value::Integer(1)))
+        ));
+    }
+
+    #[test]
+    fn test_rename_other_namespace() {
+        let mut builder = RequestBuilder::new(CommandId::Rename);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+        let sub = hop
+            .pubsub()
+            .subscribe(0, b"foo".to_vec(), SessionId::new(1))
+            .unwrap();
+        assert!(hop.select(1));
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+
+        // Only subscribers in the namespace of the key are notified.
+        assert!(Rename::dispatch(&hop, &req, &mut resp).is_ok());
+        assert!(sub.upgrade().unwrap().receiver().try_receive().is_err());
+    }
+
     #[test]
     fn test_too_few_arguments() {
         let mut builder = RequestBuilder::new(CommandId::Rename);
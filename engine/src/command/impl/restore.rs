@@ -0,0 +1,112 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    Rename,
+};
+use crate::{state::dump, Hop};
+use alloc::vec::Vec;
+
+pub struct Restore;
+
+impl Dispatch for Restore {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        if req.key_type().is_some() {
+            return Err(DispatchError::KeyTypeUnexpected);
+        }
+
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let arg = req.arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+        let replace = Rename::replace(req, 2)?;
+        let value = dump::restore(arg).ok_or(DispatchError::DumpInvalid)?;
+        hop.config().check_value(&value)?;
+        let state = hop.state();
+
+        if !replace && state.contains_key(key) {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        let previous = state.insert(key.to_vec(), value);
+
+        Rename::publish_written(hop, key, previous);
+
+        response::write_bytes(resp, key);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Restore;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{dump, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn request(bytes: &[u8], replace: bool) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::Restore);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(bytes).is_ok());
+
+        if replace {
+            assert!(builder.value(Value::Boolean(true)).is_ok());
+        }
+
+        builder
+    }
+
+    #[test]
+    fn test_restore() {
+        let bytes = dump::dump(&Value::Bytes(b"bar".to_vec()));
+        let req = request(&bytes, false).into_request();
+
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+        assert!(Restore::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(b"foo".to_vec()).as_bytes());
+        assert_eq!(
+            Some(b"bar".as_ref()),
+            hop.state().key_ref(b"foo").unwrap().as_bytes_ref()
+        );
+    }
+
+    #[test]
+    fn test_restore_replace() {
+        let bytes = dump::dump(&Value::Integer(2));
+
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+
+        let req = request(&bytes, false).into_request();
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            Restore::dispatch(&hop, &req, &mut Vec::new()).unwrap_err()
+        );
+        assert_eq!(
+            Some(&1),
+            hop.state().key_ref(b"foo").unwrap().as_integer_ref()
+        );
+
+        let req = request(&bytes, true).into_request();
+        assert!(Restore::dispatch(&hop, &req, &mut Vec::new()).is_ok());
+        assert_eq!(
+            Some(&2),
+            hop.state().key_ref(b"foo").unwrap().as_integer_ref()
+        );
+    }
+
+    #[test]
+    fn test_restore_invalid() {
+        let mut bytes = dump::dump(&Value::Integer(2));
+        bytes[5] ^= 1;
+        let req = request(&bytes, false).into_request();
+
+        let hop = Hop::new();
+        assert_eq!(
+            DispatchError::DumpInvalid,
+            Restore::dispatch(&hop, &req, &mut Vec::new()).unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"foo"));
+    }
+}
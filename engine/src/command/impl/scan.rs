@@ -0,0 +1,261 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{
+    state::{glob, ScanCursor},
+    Hop,
+};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// The cursor that starts a new scan and is returned once a scan is complete.
+const CURSOR_DONE: &[u8] = b"0";
+
+/// The number of keys returned per call when no count is given.
+const COUNT_DEFAULT: usize = 10;
+
+/// The maximum number of keys returned per call, leaving room for the cursor
+/// in the list.
+const COUNT_MAX: usize = u16::MAX as usize - 1;
+
+pub struct Scan;
+
+impl Scan {
+    /// Decode a cursor into the position to continue the previous call from.
+    ///
+    /// Cursors are the lowercase hexadecimal representation of the shard
+    /// index as a big-endian 32-bit integer followed by the last key returned
+    /// from it, if any. They can never be confused with the `0` cursor since
+    /// they always have an even length.
+    fn decode(cursor: &[u8]) -> DispatchResult<ScanCursor> {
+        if cursor == CURSOR_DONE {
+            return Ok(ScanCursor::default());
+        }
+
+        if !cursor.len().is_multiple_of(2) {
+            return Err(DispatchError::ArgumentRetrieval);
+        }
+
+        let bytes = cursor
+            .chunks(2)
+            .map(|pair| {
+                let high = Self::nibble(pair[0])?;
+                let low = Self::nibble(pair[1])?;
+
+                Ok(high << 4 | low)
+            })
+            .collect::<DispatchResult<Vec<u8>>>()?;
+
+        if bytes.len() < 4 {
+            return Err(DispatchError::ArgumentRetrieval);
+        }
+
+        let (shard, after) = bytes.split_at(4);
+        let shard = u32::from_be_bytes([shard[0], shard[1], shard[2], shard[3]]);
+
+        Ok(ScanCursor {
+            shard: shard as usize,
+            after: if after.is_empty() {
+                None
+            } else {
+                Some(after.to_vec())
+            },
+        })
+    }
+
+    fn encode(cursor: &ScanCursor) -> Vec<u8> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let shard = u32::try_from(cursor.shard).unwrap_or(u32::MAX);
+
+        shard
+            .to_be_bytes()
+            .iter()
+            .chain(cursor.after.iter().flatten())
+            .flat_map(|byte| {
+                [
+                    DIGITS[usize::from(byte >> 4)],
+                    DIGITS[usize::from(byte & 0xf)],
+                ]
+            })
+            .collect()
+    }
+
+    fn nibble(digit: u8) -> DispatchResult<u8> {
+        match digit {
+            b'0'..=b'9' => Ok(digit - b'0'),
+            b'a'..=b'f' => Ok(digit - b'a' + 10),
+            _ => Err(DispatchError::ArgumentRetrieval),
+        }
+    }
+}
+
+impl Dispatch for Scan {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let cursor = req.arg(0).ok_or(DispatchError::ArgumentRetrieval)?;
+        let cursor = Self::decode(cursor)?;
+        let pattern = req.arg(1).unwrap_or(b"*");
+        let count = match req.arg(2) {
+            Some(_) => match req.typed_arg::<i64>(2) {
+                Some(count) if count > 0 => usize::try_from(count).unwrap_or(usize::MAX),
+                Some(_) => return Err(DispatchError::PreconditionFailed),
+                None => return Err(DispatchError::ArgumentRetrieval),
+            },
+            None => COUNT_DEFAULT,
+        }
+        .min(COUNT_MAX);
+
+        let key_type = req.key_type();
+        // Scans don't name any keys to check permissions for up front, so
+        // only keys that the user can access are returned.
+        let user = hop.user().ok_or(DispatchError::Unauthenticated)?;
+
+        let (keys, next) = hop.state().scan(&cursor, count, |key, value| {
+            key_type.is_none_or(|key_type| value.kind() == key_type)
+                && glob::matches(pattern, key)
+                && user.permits_key(key)
+        });
+        let next = next.map_or_else(|| CURSOR_DONE.to_vec(), |next| Self::encode(&next));
+
+        response::write_list(resp, core::iter::once(next).chain(keys));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scan;
+    use crate::{
+        command::{
+            request::RequestBuilder,
+            response::{Context, Instruction},
+            CommandId, Dispatch, DispatchError, Response,
+        },
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn scan(
+        hop: &Hop,
+        key_type: Option<KeyType>,
+        args: &[&[u8]],
+    ) -> Result<Vec<u8>, DispatchError> {
+        let mut builder = match key_type {
+            Some(key_type) => RequestBuilder::new_with_key_type(CommandId::Scan, key_type),
+            None => RequestBuilder::new(CommandId::Scan),
+        };
+
+        for arg in args {
+            assert!(builder.bytes(*arg).is_ok());
+        }
+
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        Scan::dispatch(hop, &req, &mut resp).map(|_| resp)
+    }
+
+    fn page(hop: &Hop, cursor: &[u8], count: i64) -> (Vec<u8>, Vec<Vec<u8>>) {
+        let resp = scan(hop, None, &[cursor, b"*", &count.to_be_bytes()]).unwrap();
+
+        let mut list = match Context::new().feed(&resp) {
+            Ok(Instruction::Concluded(Response::Value(Value::List(list)))) => list,
+            other => panic!("other response: {:?}", other),
+        };
+        let cursor = list.remove(0);
+
+        (cursor, list)
+    }
+
+    #[test]
+    fn test_pages() {
+        let hop = Hop::new();
+
+        for idx in 0..50u8 {
+            hop.state()
+                .insert([idx].to_vec(), Value::Integer(idx.into()));
+        }
+
+        let (mut cursor, mut keys) = page(&hop, b"0", 3);
+        assert_eq!(3, keys.len());
+
+        while cursor != b"0" {
+            let (next, batch) = page(&hop, &cursor, 3);
+            assert!(!batch.is_empty() && batch.len() <= 3);
+            keys.extend(batch);
+            cursor = next;
+        }
+
+        keys.sort();
+        assert_eq!(
+            (0..50u8).map(|idx| [idx].to_vec()).collect::<Vec<_>>(),
+            keys
+        );
+    }
+
+    #[test]
+    fn test_filters() {
+        let hop = Hop::new();
+        hop.state().insert(b"user:1".to_vec(), Value::Integer(1));
+        hop.state().insert(b"user:2".to_vec(), Value::Boolean(true));
+        hop.state().insert(b"post:1".to_vec(), Value::Integer(2));
+
+        let resp = scan(&hop, Some(KeyType::Integer), &[b"0", b"user:*"]).unwrap();
+        let expected = [b"0".to_vec(), b"user:1".to_vec()].to_vec();
+        assert_eq!(Response::from(expected).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_permitted_keys() {
+        let hop = Hop::new();
+        hop.state().insert(b"cache:1".to_vec(), Value::Integer(1));
+        hop.state().insert(b"secret".to_vec(), Value::Integer(2));
+        assert!(hop
+            .acl()
+            .set_user("foo", [">bar", "+scan", "~cache:*"])
+            .is_ok());
+        assert!(hop.authenticate("foo", b"bar"));
+
+        let mut builder = RequestBuilder::new(CommandId::Scan);
+        assert!(builder.bytes(b"0".as_ref()).is_ok());
+        let mut resp = Vec::new();
+        assert!(hop.dispatch(&builder.into_request(), &mut resp).is_ok());
+
+        let expected = [b"0".to_vec(), b"cache:1".to_vec()].to_vec();
+        assert_eq!(Response::from(expected).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_empty() {
+        let hop = Hop::new();
+
+        let resp = scan(&hop, None, &[b"0"]).unwrap();
+        assert_eq!(Response::from([b"0".to_vec()].to_vec()).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let hop = Hop::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            scan(&hop, None, &[]).unwrap_err()
+        );
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            scan(&hop, None, &[b"abc"]).unwrap_err()
+        );
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            scan(&hop, None, &[b"zz"]).unwrap_err()
+        );
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            scan(&hop, None, &[b"00"]).unwrap_err()
+        );
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            scan(&hop, None, &[b"0", b"*", &0i64.to_be_bytes()]).unwrap_err()
+        );
+    }
+}
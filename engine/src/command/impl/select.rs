@@ -0,0 +1,67 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::Hop;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+pub struct Select;
+
+impl Dispatch for Select {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let namespace = req
+            .typed_arg::<i64>(0)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        match usize::try_from(namespace) {
+            Ok(idx) if hop.select(idx) => {}
+            _ => return Err(DispatchError::PreconditionFailed),
+        }
+
+        response::write_int(resp, namespace);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Select;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_select() {
+        let mut builder = RequestBuilder::new(CommandId::Select);
+        assert!(builder.value(Value::Integer(3)).is_ok());
+        let req = builder.into_request();
+
+        let mut resp = Vec::new();
+        let hop = Hop::new();
+
+        assert!(Select::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(3).as_bytes());
+        assert_eq!(3, hop.namespace());
+    }
+
+    #[test]
+    fn test_namespace_nonexistent() {
+        let hop = Hop::new();
+
+        for namespace in &[-1, 16] {
+            let mut builder = RequestBuilder::new(CommandId::Select);
+            assert!(builder.value(Value::Integer(*namespace)).is_ok());
+            let req = builder.into_request();
+
+            let mut resp = Vec::new();
+            assert_eq!(
+                DispatchError::PreconditionFailed,
+                Select::dispatch(&hop, &req, &mut resp).unwrap_err()
+            );
+        }
+
+        assert_eq!(0, hop.namespace());
+    }
+}
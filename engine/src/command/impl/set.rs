@@ -1,9 +1,10 @@
 use crate::{
     command::{response, Dispatch, DispatchError, DispatchResult, Request},
-    state::{KeyType, Value},
+    state::{Document, HyperLogLog, KeyType, Stream, Value},
     Hop,
 };
 use alloc::{borrow::ToOwned, vec::Vec};
+use dashmap::{DashMap, DashSet};
 
 pub struct Set;
 
@@ -27,6 +28,7 @@ impl Set {
         let arg = req
             .typed_arg::<&[u8]>(1)
             .ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config().check_value_size(arg.len())?;
         hop.state().remove(key);
         let mut key = hop.state().key_or_insert_with(key, Value::bytes);
         let bytes = key.as_bytes_mut().ok_or(DispatchError::KeyTypeDifferent)?;
@@ -51,6 +53,24 @@ impl Set {
         Ok(())
     }
 
+    fn hyper_log_log(
+        hop: &Hop,
+        req: &Request,
+        resp: &mut Vec<u8>,
+        key: &[u8],
+    ) -> DispatchResult<()> {
+        let arg = req
+            .typed_arg::<HyperLogLog>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.state().remove(key);
+
+        response::write_hyper_log_log(resp, &arg);
+
+        hop.state().insert(key.to_vec(), Value::HyperLogLog(arg));
+
+        Ok(())
+    }
+
     fn integer(hop: &Hop, req: &Request, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
         let arg = req.typed_arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
         hop.state().remove(key);
@@ -68,6 +88,12 @@ impl Set {
 
     fn list(hop: &Hop, req: &Request, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
         let args = req.args(1..).ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config().check_collection(
+            req.arg_count().saturating_sub(1),
+            req.args(1..)
+                .ok_or(DispatchError::ArgumentRetrieval)?
+                .map(<[u8]>::len),
+        )?;
         hop.state().remove(key);
         let mut key = hop.state().key_or_insert_with(key, Value::list);
         let list = key.as_list_mut().ok_or(DispatchError::KeyTypeDifferent)?;
@@ -81,7 +107,13 @@ impl Set {
     }
 
     fn map(hop: &Hop, req: &Request, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
-        let args = req.typed_args().ok_or(DispatchError::ArgumentRetrieval)?;
+        let args: DashMap<Vec<u8>, Vec<u8>> =
+            req.typed_args().ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config().check_collection(
+            args.len(),
+            args.iter()
+                .flat_map(|entry| [entry.key().len(), entry.value().len()]),
+        )?;
         hop.state().remove(key);
         let mut key = hop.state().key_or_insert_with(key, Value::map);
         let map = key.as_map_mut().ok_or(DispatchError::KeyTypeDifferent)?;
@@ -94,7 +126,9 @@ impl Set {
     }
 
     fn set(hop: &Hop, req: &Request, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
-        let args = req.typed_args().ok_or(DispatchError::ArgumentRetrieval)?;
+        let args: DashSet<Vec<u8>> = req.typed_args().ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config()
+            .check_collection(args.len(), args.iter().map(|element| element.len()))?;
         hop.state().remove(key);
         let mut key = hop.state().key_or_insert_with(key, Value::set);
         let set = key.as_set_mut().ok_or(DispatchError::KeyTypeDifferent)?;
@@ -106,10 +140,45 @@ impl Set {
         Ok(())
     }
 
+    fn document(hop: &Hop, req: &Request, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
+        let arg = req
+            .typed_arg::<Document>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config()
+            .check_value_size(req.arg(1).map_or(0, <[u8]>::len))?;
+        hop.state().remove(key);
+
+        response::write_document(resp, &arg);
+
+        hop.state().insert(key.to_vec(), Value::Document(arg));
+
+        Ok(())
+    }
+
+    fn stream(hop: &Hop, req: &Request, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
+        let arg = req
+            .typed_arg::<Stream>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config().check_collection(
+            arg.len(),
+            arg.entries()
+                .flat_map(|entry| entry.fields())
+                .flat_map(|(field, value)| [field.len(), value.len()]),
+        )?;
+        hop.state().remove(key);
+
+        response::write_stream(resp, &arg);
+
+        hop.state().insert(key.to_vec(), Value::Stream(arg));
+
+        Ok(())
+    }
+
     fn string(hop: &Hop, req: &Request, resp: &mut Vec<u8>, key: &[u8]) -> DispatchResult<()> {
         let arg = req
             .typed_arg::<&str>(1)
             .ok_or(DispatchError::ArgumentRetrieval)?;
+        hop.config().check_value_size(arg.len())?;
         hop.state().remove(key);
         let mut key = hop.state().key_or_insert_with(key, Value::string);
         let string = key.as_string_mut().ok_or(DispatchError::KeyTypeDifferent)?;
@@ -138,12 +207,15 @@ impl Dispatch for Set {
 
         match key_type {
             KeyType::Bytes => Self::bytes(hop, req, resp, key),
+            KeyType::Document => Self::document(hop, req, resp, key),
             KeyType::Boolean => Self::boolean(hop, req, resp, key),
             KeyType::Float => Self::float(hop, req, resp, key),
+            KeyType::HyperLogLog => Self::hyper_log_log(hop, req, resp, key),
             KeyType::Integer => Self::integer(hop, req, resp, key),
             KeyType::List => Self::list(hop, req, resp, key),
             KeyType::Map => Self::map(hop, req, resp, key),
             KeyType::Set => Self::set(hop, req, resp, key),
+            KeyType::Stream => Self::stream(hop, req, resp, key),
             KeyType::String => Self::string(hop, req, resp, key),
         }
     }
@@ -154,7 +226,7 @@ mod tests {
     use super::Set;
     use crate::{
         command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
-        state::{KeyType, Value},
+        state::{HyperLogLog, KeyType, Value},
         Hop,
     };
     use alloc::vec::Vec;
@@ -167,11 +239,14 @@ mod tests {
         let types = [
             KeyType::Boolean,
             KeyType::Bytes,
+            KeyType::Document,
             KeyType::Float,
+            KeyType::HyperLogLog,
             KeyType::Integer,
             KeyType::List,
             KeyType::Map,
             KeyType::Set,
+            KeyType::Stream,
             KeyType::String,
         ];
 
@@ -255,6 +330,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hyper_log_log() {
+        let mut hll = HyperLogLog::new();
+        hll.add(b"bar");
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, KeyType::HyperLogLog);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(hll.clone()).is_ok());
+        let req = builder.into_request();
+
+        let hop = Hop::new();
+
+        let mut resp = Vec::new();
+
+        assert!(Set::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(hll.clone()).as_bytes());
+        assert_eq!(
+            Some(&hll),
+            hop.state()
+                .key_ref(b"foo")
+                .as_deref()
+                .and_then(Value::as_hyper_log_log_ref)
+        );
+    }
+
+    #[test]
+    fn test_hyper_log_log_invalid() {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, KeyType::HyperLogLog);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        let hop = Hop::new();
+
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            Set::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
     #[test]
     fn test_int() {
         let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, KeyType::Integer);
@@ -370,4 +486,36 @@ mod tests {
                 .and_then(Value::as_string_ref)
         );
     }
+
+    #[test]
+    fn test_size_limits() {
+        let mut builder = Hop::builder();
+        builder.max_value_size(3).max_collection_elements(2);
+        let hop = builder.build();
+        let mut resp = Vec::new();
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, KeyType::Bytes);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"barbaz".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        assert_eq!(
+            DispatchError::SizeLimitExceeded,
+            Set::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"foo"));
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, KeyType::List);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"b".as_ref()).is_ok());
+        assert!(builder.bytes(b"c".as_ref()).is_ok());
+        let req = builder.into_request();
+
+        assert_eq!(
+            DispatchError::SizeLimitExceeded,
+            Set::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"foo"));
+    }
 }
@@ -0,0 +1,111 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    increment_by::IncrementBy,
+};
+use crate::{
+    state::{KeyType, Value},
+    Hop,
+};
+use alloc::vec::Vec;
+
+pub struct SetIfGreater;
+
+impl SetIfGreater {
+    /// Set a numeric key to the provided value if the key doesn't exist or if
+    /// the comparison of the new value to the current value succeeds.
+    ///
+    /// Responds with the value of the key afterwards.
+    pub fn set_if(
+        hop: &Hop,
+        req: &Request,
+        resp: &mut Vec<u8>,
+        int_cmp: fn(&i64, &i64) -> bool,
+        float_cmp: fn(&f64, &f64) -> bool,
+    ) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if IncrementBy::key_type(hop, req, key)? == KeyType::Float {
+            let value = req
+                .typed_arg::<f64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+            let mut key = hop.state().key_or_insert_with(key, || Value::Float(value));
+            let float = key.as_float_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+
+            if float_cmp(&value, float) {
+                *float = value;
+            }
+
+            response::write_float(resp, *float);
+        } else {
+            let value = req
+                .typed_arg::<i64>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+            let mut key = hop
+                .state()
+                .key_or_insert_with(key, || Value::Integer(value));
+            let int = key
+                .as_integer_mut()
+                .ok_or(DispatchError::KeyTypeDifferent)?;
+
+            if int_cmp(&value, int) {
+                *int = value;
+            }
+
+            response::write_int(resp, *int);
+        }
+
+        Ok(())
+    }
+}
+
+impl Dispatch for SetIfGreater {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::set_if(hop, req, resp, i64::gt, f64::gt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SetIfGreater;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_nonexistent() {
+        let mut builder = RequestBuilder::new(CommandId::SetIfGreater);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(-5)).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert!(SetIfGreater::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(-5i64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_greater_and_not() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Float(2.5));
+
+        let mut builder = RequestBuilder::new(CommandId::SetIfGreater);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Float(3.0)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+        assert!(SetIfGreater::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(3.0f64).as_bytes(), resp);
+
+        let mut builder = RequestBuilder::new(CommandId::SetIfGreater);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Float(1.0)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+        assert!(SetIfGreater::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(3.0f64).as_bytes(), resp);
+    }
+}
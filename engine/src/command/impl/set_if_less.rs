@@ -0,0 +1,58 @@
+use super::{
+    super::{Dispatch, DispatchResult, Request},
+    set_if_greater::SetIfGreater,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct SetIfLess;
+
+impl Dispatch for SetIfLess {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        SetIfGreater::set_if(hop, req, resp, i64::lt, f64::lt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SetIfLess;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_less() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(3));
+
+        let mut builder = RequestBuilder::new(CommandId::SetIfLess);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(1)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(SetIfLess::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(1i64).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_key_type_different() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes(b"bar".to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::SetIfLess);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(1)).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            SetIfLess::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
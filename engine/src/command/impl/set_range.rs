@@ -0,0 +1,218 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    substring::Substring,
+};
+use crate::{
+    state::{KeyType, Value},
+    Hop,
+};
+use alloc::vec::Vec;
+use core::iter;
+
+/// The maximum size that a value can be grown to by writing at an offset past
+/// its end, which limits values grown by `set:range` to 512 MiB.
+pub const SET_RANGE_SIZE_MAX: usize = 512 * 1024 * 1024;
+
+pub struct SetRange;
+
+impl SetRange {
+    fn bytes(
+        hop: &Hop,
+        key: &[u8],
+        offset: usize,
+        value: &[u8],
+        resp: &mut Vec<u8>,
+    ) -> DispatchResult<()> {
+        let end = offset.saturating_add(value.len());
+
+        if end > SET_RANGE_SIZE_MAX {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        hop.config().check_value_size(end)?;
+
+        let mut key = hop.state().key_or_insert_with(key, Value::bytes);
+        let bytes = key.as_bytes_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+
+        if bytes.len() < end {
+            bytes.resize(end, 0);
+        }
+
+        bytes[offset..end].copy_from_slice(value);
+
+        response::write_bytes(resp, bytes);
+
+        Ok(())
+    }
+
+    fn string(
+        hop: &Hop,
+        key: &[u8],
+        offset: usize,
+        value: &str,
+        resp: &mut Vec<u8>,
+    ) -> DispatchResult<()> {
+        // Null characters are one byte each, so this is the smallest size the
+        // string can have afterwards.
+        let min_size = offset.saturating_add(value.len());
+
+        if min_size > SET_RANGE_SIZE_MAX {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        hop.config().check_value_size(min_size)?;
+
+        let mut key = hop.state().key_or_insert_with(key, Value::string);
+        let string = key.as_string_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+        let len = string.chars().count();
+        let replaced =
+            Substring::char_position(string, offset.saturating_add(value.chars().count()))
+                - Substring::char_position(string, offset);
+        let size = (string.len() - replaced)
+            .saturating_add(offset.saturating_sub(len))
+            .saturating_add(value.len());
+        hop.config().check_value_size(size)?;
+
+        // Pad the string with null characters if the offset is past the end.
+        string.extend(iter::repeat_n('\0', offset.saturating_sub(len)));
+
+        let start = Substring::char_position(string, offset);
+        let end = Substring::char_position(string, offset + value.chars().count());
+        string.replace_range(start..end, value);
+
+        response::write_str(resp, string);
+
+        Ok(())
+    }
+}
+
+impl Dispatch for SetRange {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let offset = req
+            .typed_arg::<i64>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        if offset < 0 {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        let offset = offset as u64 as usize;
+        let key_type = req
+            .key_type()
+            .or_else(|| hop.state().key_type(key))
+            .unwrap_or(KeyType::Bytes);
+
+        match key_type {
+            KeyType::Bytes => {
+                let value = req.arg(2).ok_or(DispatchError::ArgumentRetrieval)?;
+
+                Self::bytes(hop, key, offset, value, resp)
+            }
+            KeyType::String => {
+                let value = req.typed_arg(2).ok_or(DispatchError::ArgumentRetrieval)?;
+
+                Self::string(hop, key, offset, value, resp)
+            }
+            _ => Err(DispatchError::KeyTypeInvalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SetRange, SET_RANGE_SIZE_MAX};
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_bytes_overwrite_and_grow() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([1, 2, 3].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::SetRange);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(2).is_ok());
+        assert!(builder.bytes([4, 5].as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(SetRange::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([1, 2, 4, 5].to_vec()).as_bytes());
+    }
+
+    #[test]
+    fn test_bytes_pad_new_key() {
+        let hop = Hop::new();
+
+        let mut builder = RequestBuilder::new(CommandId::SetRange);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(2).is_ok());
+        assert!(builder.bytes([1].as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(SetRange::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([0, 0, 1].to_vec()).as_bytes());
+    }
+
+    #[test]
+    fn test_string_chars() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("h🤠pdb".to_owned()));
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::SetRange, KeyType::String);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(1).is_ok());
+        assert!(builder.bytes(b"o".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(SetRange::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("hopdb".to_owned()).as_bytes());
+    }
+
+    #[test]
+    fn test_negative_offset() {
+        let hop = Hop::new();
+
+        let mut builder = RequestBuilder::new(CommandId::SetRange);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(-1).is_ok());
+        assert!(builder.bytes([1].as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            SetRange::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"foo"));
+    }
+
+    #[test]
+    fn test_offset_too_large() {
+        let hop = Hop::new();
+
+        for key_type in [KeyType::Bytes, KeyType::String] {
+            let mut builder = RequestBuilder::new_with_key_type(CommandId::SetRange, key_type);
+            assert!(builder.bytes(b"foo".as_ref()).is_ok());
+            assert!(builder.value(SET_RANGE_SIZE_MAX as i64).is_ok());
+            assert!(builder.bytes(b"x".as_ref()).is_ok());
+            let req = builder.into_request();
+            let mut resp = Vec::new();
+
+            assert_eq!(
+                DispatchError::PreconditionFailed,
+                SetRange::dispatch(&hop, &req, &mut resp).unwrap_err()
+            );
+            assert!(!hop.state().contains_key(b"foo"));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    substring::Substring,
+};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+
+pub struct Slice;
+
+impl Slice {
+    fn string(
+        hop: &Hop,
+        key: &[u8],
+        start: i64,
+        end: Option<i64>,
+        resp: &mut Vec<u8>,
+    ) -> DispatchResult<()> {
+        let key = hop
+            .state()
+            .key_ref(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let string = key.as_string_ref().ok_or(DispatchError::KeyTypeDifferent)?;
+        let (start, end) = Substring::bounds(string.len(), start, end);
+        let slice = string
+            .get(start..end)
+            .ok_or(DispatchError::CharBoundaryInvalid)?;
+
+        response::write_str(resp, slice);
+
+        Ok(())
+    }
+}
+
+impl Dispatch for Slice {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let start = req.typed_arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+        let end = req.typed_arg(2);
+        let key_type = req
+            .key_type()
+            .or_else(|| hop.state().key_type(key))
+            .unwrap_or(KeyType::String);
+
+        match key_type {
+            KeyType::Bytes => Substring::bytes(hop, key, start, end, resp),
+            KeyType::String => Self::string(hop, key, start, end, resp),
+            _ => Err(DispatchError::KeyTypeInvalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slice;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_string_bytes_offsets() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("h🤠pdb".to_owned()));
+
+        let mut builder = RequestBuilder::new(CommandId::Slice);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(1).is_ok());
+        assert!(builder.value(5).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Slice::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("🤠".to_owned()).as_bytes());
+    }
+
+    #[test]
+    fn test_string_char_boundary_invalid() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("h🤠pdb".to_owned()));
+
+        let mut builder = RequestBuilder::new(CommandId::Slice);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(2).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::CharBoundaryInvalid,
+            Slice::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([1, 2, 3].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::Slice);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(-2).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Slice::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([2, 3].to_vec()).as_bytes());
+    }
+}
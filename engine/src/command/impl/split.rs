@@ -0,0 +1,92 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+
+pub struct Split;
+
+impl Dispatch for Split {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let separator = req
+            .typed_arg::<&str>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        if let Some(key_type) = req.key_type() {
+            if key_type != KeyType::String {
+                return Err(DispatchError::KeyTypeInvalid);
+            }
+        }
+
+        let value = hop
+            .state()
+            .key_ref(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let string = value
+            .as_string_ref()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+
+        response::write_list(resp, string.split(separator));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Split;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_string() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("a,b,,c".to_owned()));
+
+        let mut builder = RequestBuilder::new(CommandId::Split);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b",".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Split::dispatch(&hop, &req, &mut resp).is_ok());
+        let expected = [b"a".to_vec(), b"b".to_vec(), Vec::new(), b"c".to_vec()].to_vec();
+        assert_eq!(resp, Response::from(expected).as_bytes());
+    }
+
+    #[test]
+    fn test_key_type_different() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+
+        let mut builder = RequestBuilder::new(CommandId::Split);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b",".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            Split::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_no_separator() {
+        let hop = Hop::new();
+
+        let mut builder = RequestBuilder::new(CommandId::Split);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            Split::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+use core::str;
+
+pub struct StartsWith;
+
+impl StartsWith {
+    /// Check whether the value of the requested key matches an affix argument
+    /// using the provided check.
+    ///
+    /// Used by both `StartsWith` and `EndsWith`, since bytes and strings can
+    /// both be compared as byte slices.
+    pub fn affix(
+        hop: &Hop,
+        req: &Request,
+        resp: &mut Vec<u8>,
+        check: fn(&[u8], &[u8]) -> bool,
+    ) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let affix = req.arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+        let key_type = req
+            .key_type()
+            .or_else(|| hop.state().key_type(key))
+            .unwrap_or(KeyType::Bytes);
+        let value = hop
+            .state()
+            .key_ref(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+
+        let matches = match key_type {
+            KeyType::Bytes => {
+                let bytes = value
+                    .as_bytes_ref()
+                    .ok_or(DispatchError::KeyTypeDifferent)?;
+
+                check(bytes, affix)
+            }
+            KeyType::String => {
+                let string = value
+                    .as_string_ref()
+                    .ok_or(DispatchError::KeyTypeDifferent)?;
+                let affix = str::from_utf8(affix).map_err(|_| DispatchError::ArgumentRetrieval)?;
+
+                check(string.as_bytes(), affix.as_bytes())
+            }
+            _ => return Err(DispatchError::KeyTypeInvalid),
+        };
+
+        response::write_bool(resp, matches);
+
+        Ok(())
+    }
+}
+
+impl Dispatch for StartsWith {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::affix(hop, req, resp, <[u8]>::starts_with)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StartsWith;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_string() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("hopdb".to_owned()));
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::StartsWith, KeyType::String);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"hop".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(StartsWith::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(true).as_bytes());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([1, 2, 3].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::StartsWith);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes([2, 3].as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(StartsWith::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from(false).as_bytes());
+    }
+
+    #[test]
+    fn test_nonexistent() {
+        let hop = Hop::new();
+
+        let mut builder = RequestBuilder::new(CommandId::StartsWith);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            StartsWith::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
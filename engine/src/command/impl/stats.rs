@@ -1,7 +1,7 @@
 use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
-use crate::metrics::Metric;
+use crate::metrics::{Metric, NAMESPACE_KEYS_PREFIX};
 use crate::Hop;
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 use core::convert::TryFrom;
 use dashmap::DashMap;
 
@@ -11,8 +11,12 @@ impl Stats {
     const COUNTERS: &'static [Metric] = &[
         Metric::CommandsErrored,
         Metric::CommandsSuccessful,
+        Metric::KeysEvicted,
+        Metric::SessionsEnded,
         Metric::SessionsStarted,
+        Metric::SessionsTimedOut,
     ];
+    const GAUGES: &'static [Metric] = &[Metric::MemoryUsed];
 }
 
 impl Dispatch for Stats {
@@ -21,7 +25,9 @@ impl Dispatch for Stats {
             return Err(DispatchError::KeyTypeUnexpected);
         }
 
-        let map = DashMap::with_capacity(4);
+        hop.refresh_memory_used();
+
+        let map = DashMap::with_capacity(5);
         let metrics = hop.metrics();
 
         for counter in Self::COUNTERS {
@@ -35,6 +41,29 @@ impl Dispatch for Stats {
             map.insert(key, value);
         }
 
+        for gauge in Self::GAUGES {
+            let value = match metrics.gauge(gauge).and_then(|x| i64::try_from(x).ok()) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            map.insert(
+                gauge.name().as_bytes().to_vec(),
+                value.to_be_bytes().to_vec(),
+            );
+        }
+
+        // Only include namespaces with keys, since most are usually unused.
+        for (idx, state) in hop.namespaces().enumerate() {
+            let count = match i64::try_from(state.0.len()) {
+                Ok(0) | Err(_) => continue,
+                Ok(count) => count,
+            };
+            let key = format!("{}{}", NAMESPACE_KEYS_PREFIX, idx).into_bytes();
+
+            map.insert(key, count.to_be_bytes().to_vec());
+        }
+
         response::write_map(res, &map);
 
         Ok(())
@@ -45,9 +74,13 @@ impl Dispatch for Stats {
 mod tests {
     use super::Stats;
     use crate::{
-        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        command::{
+            request::RequestBuilder,
+            response::{Context, Instruction},
+            CommandId, Dispatch, DispatchError, Response,
+        },
         metrics::Metric,
-        state::KeyType,
+        state::{KeyType, Value},
         Hop,
     };
     use dashmap::DashMap;
@@ -58,9 +91,11 @@ mod tests {
 
         let hop = Hop::new();
         let mut resp = Vec::new();
+        let expected = DashMap::new();
+        expected.insert(b"memory_used".to_vec(), 0i64.to_be_bytes().to_vec());
 
         assert!(Stats::dispatch(&hop, &req, &mut resp).is_ok());
-        assert_eq!(resp, Response::from(DashMap::new()).as_bytes());
+        assert_eq!(resp, Response::from(expected).as_bytes());
     }
 
     #[test]
@@ -71,11 +106,60 @@ mod tests {
         hop.0.metrics_writer.increment(Metric::CommandsSuccessful);
 
         let mut resp = Vec::new();
-        let expected = DashMap::new();
-        expected.insert(b"commands_successful".to_vec(), 1i64.to_be_bytes().to_vec());
+        assert!(Stats::dispatch(&hop, &req, &mut resp).is_ok());
+
+        let mut ctx = Context::new();
+        let map = match ctx.feed(&resp) {
+            Ok(Instruction::Concluded(Response::Value(Value::Map(map)))) => map,
+            other => panic!("other response: {:?}", other),
+        };
+        assert_eq!(2, map.len());
+        assert_eq!(
+            Some(1i64.to_be_bytes().as_ref()),
+            map.get(b"commands_successful".as_ref())
+                .as_deref()
+                .map(Vec::as_slice)
+        );
+        assert_eq!(
+            Some(0i64.to_be_bytes().as_ref()),
+            map.get(b"memory_used".as_ref())
+                .as_deref()
+                .map(Vec::as_slice)
+        );
+    }
+
+    #[test]
+    fn test_stats_namespace_keys() {
+        let req = RequestBuilder::new(CommandId::Stats).into_request();
+
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        assert!(hop.select(2));
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        hop.state().insert(b"bar".to_vec(), Value::Integer(2));
 
+        let mut resp = Vec::new();
         assert!(Stats::dispatch(&hop, &req, &mut resp).is_ok());
-        assert_eq!(resp, Response::from(expected).as_bytes());
+
+        let mut ctx = Context::new();
+        let map = match ctx.feed(&resp) {
+            Ok(Instruction::Concluded(Response::Value(Value::Map(map)))) => map,
+            other => panic!("other response: {:?}", other),
+        };
+        assert_eq!(3, map.len());
+        assert!(map.contains_key(b"memory_used".as_ref()));
+        assert_eq!(
+            Some(1i64.to_be_bytes().as_ref()),
+            map.get(b"namespace_keys:0".as_ref())
+                .as_deref()
+                .map(Vec::as_slice)
+        );
+        assert_eq!(
+            Some(2i64.to_be_bytes().as_ref()),
+            map.get(b"namespace_keys:2".as_ref())
+                .as_deref()
+                .map(Vec::as_slice)
+        );
     }
 
     #[test]
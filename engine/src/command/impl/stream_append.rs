@@ -0,0 +1,202 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{
+    state::{KeyType, StreamId, Value},
+    Hop,
+};
+use alloc::{string::ToString, vec::Vec};
+
+pub struct StreamAppend;
+
+impl StreamAppend {
+    /// Check that the request's key type, if any, is a stream.
+    pub fn check_key_type(req: &Request) -> DispatchResult<()> {
+        match req.key_type() {
+            Some(KeyType::Stream) | None => Ok(()),
+            Some(_) => Err(DispatchError::KeyTypeInvalid),
+        }
+    }
+}
+
+impl Dispatch for StreamAppend {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let id_arg = req.arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+
+        // An ID of "*" means that one should be generated.
+        let id = if id_arg == b"*" {
+            None
+        } else {
+            let id = req
+                .typed_arg::<StreamId>(1)
+                .ok_or(DispatchError::ArgumentRetrieval)?;
+
+            // Check this early so that a new key isn't created for an ID that
+            // can never be appended.
+            if id == StreamId::MIN {
+                return Err(DispatchError::PreconditionFailed);
+            }
+
+            Some(id)
+        };
+
+        let field_count = req.arg_count().saturating_sub(2);
+
+        if field_count == 0 || !field_count.is_multiple_of(2) {
+            return Err(DispatchError::ArgumentRetrieval);
+        }
+
+        let mut args = req.args(2..).ok_or(DispatchError::ArgumentRetrieval)?;
+        let mut fields = Vec::with_capacity(field_count / 2);
+
+        while let (Some(field), Some(value)) = (args.next(), args.next()) {
+            fields.push((field.to_vec(), value.to_vec()));
+        }
+
+        hop.config().check_collection(
+            0,
+            fields
+                .iter()
+                .flat_map(|(field, value)| [field.len(), value.len()]),
+        )?;
+
+        let mut key = hop.state().key_or_insert_with(key, Value::stream);
+        let stream = key.as_stream_mut().ok_or(DispatchError::KeyTypeDifferent)?;
+        hop.config()
+            .check_elements(stream.len().saturating_add(1))?;
+
+        let id = match id {
+            Some(id) => id,
+            None => stream
+                .next_id((hop.config().clock())())
+                .ok_or(DispatchError::PreconditionFailed)?,
+        };
+
+        if !stream.append(id, fields) {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        // Release the key before notifying readers so that they can read it.
+        drop(key);
+        let _ = hop.0.stream_sender.send(());
+
+        response::write_str(resp, &id.to_string());
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamAppend;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        hop::Builder,
+        state::{StreamId, Value},
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+    use futures_intrusive::channel::StateId;
+
+    fn request(id: &[u8], fields: &[&[u8]]) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::StreamAppend);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(id).is_ok());
+
+        for field in fields {
+            assert!(builder.bytes(*field).is_ok());
+        }
+
+        builder
+    }
+
+    #[test]
+    fn test_generated_ids() {
+        let mut builder = Builder::new();
+        builder.clock(|| 1500);
+        let hop = builder.build();
+        let req = request(b"*", &[b"a", b"b"]).into_request();
+
+        let mut resp = Vec::new();
+        assert!(StreamAppend::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from("1500-0".to_owned()).as_bytes(), resp);
+
+        let mut resp = Vec::new();
+        assert!(StreamAppend::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from("1500-1".to_owned()).as_bytes(), resp);
+
+        let stream = hop.state().key_ref(b"foo").unwrap();
+        let entry = stream.as_stream_ref().unwrap().entries().next().unwrap();
+        assert_eq!(StreamId::new(1500, 0), entry.id());
+        assert_eq!([(b"a".to_vec(), b"b".to_vec())].as_ref(), entry.fields());
+    }
+
+    #[test]
+    fn test_explicit_ids() {
+        let hop = Hop::new();
+
+        let req = request(b"5-1", &[b"a", b"b"]).into_request();
+        let mut resp = Vec::new();
+        assert!(StreamAppend::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from("5-1".to_owned()).as_bytes(), resp);
+
+        let mut resp = Vec::new();
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            StreamAppend::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+
+        let req = request(b"0-0", &[b"a", b"b"]).into_request();
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            StreamAppend::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_fields_invalid() {
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        for req in [
+            request(b"*", &[]),
+            request(b"*", &[b"a"]),
+            request(b"foo", &[b"a", b"b"]),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                DispatchError::ArgumentRetrieval,
+                StreamAppend::dispatch(&hop, &req.clone().into_request(), &mut resp).unwrap_err()
+            );
+        }
+
+        assert!(!hop.state().contains_key(b"foo"));
+    }
+
+    #[test]
+    fn test_key_type_different() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+        let req = request(b"*", &[b"a", b"b"]).into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            StreamAppend::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_notifies_updates() {
+        let hop = Hop::new();
+        let updates = hop.stream_updates();
+        let (state, _) = updates.try_receive(StateId::new()).unwrap();
+        assert!(updates.try_receive(state).is_none());
+
+        let req = request(b"*", &[b"a", b"b"]).into_request();
+        let mut resp = Vec::new();
+        assert!(StreamAppend::dispatch(&hop, &req, &mut resp).is_ok());
+        assert!(updates.try_receive(state).is_some());
+    }
+}
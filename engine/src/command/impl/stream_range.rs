@@ -0,0 +1,174 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    stream_append::StreamAppend,
+};
+use crate::{
+    state::{Stream, StreamId},
+    Hop,
+};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+pub struct StreamRange;
+
+impl StreamRange {
+    /// Retrieve a stream ID argument, using the default if the argument is the
+    /// given wildcard.
+    pub fn bound(
+        req: &Request,
+        idx: usize,
+        wildcard: &[u8],
+        default: StreamId,
+    ) -> DispatchResult<StreamId> {
+        if req.arg(idx).ok_or(DispatchError::ArgumentRetrieval)? == wildcard {
+            return Ok(default);
+        }
+
+        req.typed_arg(idx).ok_or(DispatchError::ArgumentRetrieval)
+    }
+
+    /// Retrieve the optional maximum number of entries to return.
+    ///
+    /// Negative counts aren't allowed.
+    pub fn count(req: &Request, idx: usize) -> DispatchResult<usize> {
+        match req.typed_arg::<i64>(idx) {
+            Some(count) if count < 0 => Err(DispatchError::PreconditionFailed),
+            Some(count) => Ok(usize::try_from(count).unwrap_or(usize::MAX)),
+            None => Ok(usize::MAX),
+        }
+    }
+
+    /// Write a range of a stream to the response.
+    ///
+    /// Nonexistent keys are treated as empty streams.
+    pub fn write(
+        hop: &Hop,
+        key: &[u8],
+        start: StreamId,
+        end: StreamId,
+        count: usize,
+        resp: &mut Vec<u8>,
+    ) -> DispatchResult<()> {
+        let range = match hop.state().key_ref(key) {
+            Some(value) => value
+                .as_stream_ref()
+                .ok_or(DispatchError::KeyTypeDifferent)?
+                .range(start, end, count),
+            None => Stream::new(),
+        };
+
+        response::write_stream(resp, &range);
+
+        Ok(())
+    }
+}
+
+impl Dispatch for StreamRange {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        StreamAppend::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let start = Self::bound(req, 1, b"-", StreamId::MIN)?;
+        let end = Self::bound(req, 2, b"+", StreamId::MAX)?;
+        let count = Self::count(req, 3)?;
+
+        Self::write(hop, key, start, end, count, resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamRange;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{Stream, StreamId, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn hop() -> Hop {
+        let mut stream = Stream::new();
+
+        for millis in 1..=3 {
+            assert!(stream.append(StreamId::new(millis, 0), Vec::new()));
+        }
+
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Stream(stream));
+
+        hop
+    }
+
+    #[test]
+    fn test_range() {
+        let hop = hop();
+        let mut builder = RequestBuilder::new(CommandId::StreamRange);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"2".as_ref()).is_ok());
+        assert!(builder.bytes(b"+".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(StreamRange::dispatch(&hop, &req, &mut resp).is_ok());
+        let expected = hop
+            .state()
+            .key_ref(b"foo")
+            .unwrap()
+            .as_stream_ref()
+            .unwrap()
+            .range(StreamId::new(2, 0), StreamId::MAX, usize::MAX);
+        assert_eq!(2, expected.len());
+        assert_eq!(Response::from(expected).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_count() {
+        let hop = hop();
+        let mut builder = RequestBuilder::new(CommandId::StreamRange);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"-".as_ref()).is_ok());
+        assert!(builder.bytes(b"+".as_ref()).is_ok());
+        assert!(builder.value(1).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(StreamRange::dispatch(&hop, &req, &mut resp).is_ok());
+        let expected = hop
+            .state()
+            .key_ref(b"foo")
+            .unwrap()
+            .as_stream_ref()
+            .unwrap()
+            .range(StreamId::MIN, StreamId::MAX, 1);
+        assert_eq!(Response::from(expected).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_nonexistent_key() {
+        let mut builder = RequestBuilder::new(CommandId::StreamRange);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"-".as_ref()).is_ok());
+        assert!(builder.bytes(b"+".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert!(StreamRange::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(Stream::new()).as_bytes(), resp);
+    }
+
+    #[test]
+    fn test_bounds_invalid() {
+        let mut builder = RequestBuilder::new(CommandId::StreamRange);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        assert!(builder.bytes(b"+".as_ref()).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            StreamRange::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use super::{
+    super::{CommandId, Dispatch, DispatchError, DispatchResult, Request},
+    stream_append::StreamAppend,
+    stream_range::StreamRange,
+};
+use crate::{state::StreamId, Hop};
+use alloc::vec::Vec;
+
+pub struct StreamRead;
+
+impl StreamRead {
+    /// Retrieve the number of milliseconds that a request wants to wait for new
+    /// entries if there aren't any yet.
+    ///
+    /// This is exposed to servers through [`Hop::stream_read_block`].
+    ///
+    /// [`Hop::stream_read_block`]: ../../hop/struct.Hop.html#method.stream_read_block
+    pub fn block(req: &Request) -> Option<u64> {
+        if req.command_id() != CommandId::StreamRead {
+            return None;
+        }
+
+        match req.typed_arg::<i64>(3) {
+            Some(block) if block > 0 => Some(block as u64),
+            _ => None,
+        }
+    }
+
+    /// Check whether a read request has no entries to read yet.
+    ///
+    /// Requests that are invalid aren't pending, since dispatching them will
+    /// return an error.
+    pub fn pending(hop: &Hop, req: &Request) -> bool {
+        let (key, after) = match (req.key(), req.typed_arg::<StreamId>(1)) {
+            (Some(key), Some(after)) => (key, after),
+            _ => return false,
+        };
+
+        match hop.state().key_ref(key) {
+            Some(value) => match value.as_stream_ref() {
+                Some(stream) => stream.last_id() <= after || stream.is_empty(),
+                None => false,
+            },
+            None => true,
+        }
+    }
+}
+
+impl Dispatch for StreamRead {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        StreamAppend::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let after = req
+            .typed_arg::<StreamId>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+        let count = StreamRange::count(req, 2)?;
+
+        // Reading after the highest possible ID can never return anything, so
+        // use an empty range.
+        let (start, end) = match after.next() {
+            Some(start) => (start, StreamId::MAX),
+            None => (StreamId::MAX, StreamId::MIN),
+        };
+
+        StreamRange::write(hop, key, start, end, count, resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamRead;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{Stream, StreamId, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    fn request(after: &[u8], block: Option<i64>) -> RequestBuilder {
+        let mut builder = RequestBuilder::new(CommandId::StreamRead);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(after).is_ok());
+
+        if let Some(block) = block {
+            assert!(builder.value(i64::MAX).is_ok());
+            assert!(builder.value(block).is_ok());
+        }
+
+        builder
+    }
+
+    fn stream() -> Stream {
+        let mut stream = Stream::new();
+
+        for millis in 1..=3 {
+            assert!(stream.append(StreamId::new(millis, 0), Vec::new()));
+        }
+
+        stream
+    }
+
+    #[test]
+    fn test_read_after() {
+        let hop = Hop::new();
+        let stream = stream();
+        let expected = stream.range(StreamId::new(2, 1), StreamId::MAX, usize::MAX);
+        hop.state().insert(b"foo".to_vec(), Value::Stream(stream));
+        let req = request(b"2-0", None).into_request();
+        let mut resp = Vec::new();
+
+        assert!(StreamRead::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(1, expected.len());
+        assert_eq!(Response::from(expected).as_bytes(), resp);
+        assert!(!StreamRead::pending(&hop, &req));
+
+        let req = request(b"3-0", None).into_request();
+        assert!(StreamRead::pending(&hop, &req));
+    }
+
+    #[test]
+    fn test_block() {
+        let hop = Hop::new();
+        let req = request(b"0", Some(100)).into_request();
+        assert_eq!(Some(100), StreamRead::block(&req));
+        assert!(StreamRead::pending(&hop, &req));
+
+        let req = request(b"0", None).into_request();
+        assert!(StreamRead::block(&req).is_none());
+    }
+
+    #[test]
+    fn test_after_max() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Stream(stream()));
+        let after = StreamId::MAX.to_string();
+        let req = request(after.as_bytes(), None).into_request();
+        let mut resp = Vec::new();
+
+        assert!(StreamRead::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(
+            Response::from(stream().range(StreamId::MAX, StreamId::MIN, 0)).as_bytes(),
+            resp
+        );
+    }
+
+    #[test]
+    fn test_after_invalid() {
+        let hop = Hop::new();
+        let req = request(b"foo", None).into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            StreamRead::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+        assert!(!StreamRead::pending(&hop, &req));
+    }
+}
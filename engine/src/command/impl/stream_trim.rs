@@ -0,0 +1,108 @@
+use super::{
+    super::{response, Dispatch, DispatchError, DispatchResult, Request},
+    stream_append::StreamAppend,
+};
+use crate::{state::Stream, Hop};
+use alloc::vec::Vec;
+
+pub struct StreamTrim;
+
+impl StreamTrim {
+    /// Trim a stream with a trimming function given a non-negative argument,
+    /// writing the number of removed entries to the response.
+    ///
+    /// Nonexistent keys have nothing to trim.
+    pub fn trim(
+        hop: &Hop,
+        req: &Request,
+        resp: &mut Vec<u8>,
+        trim: impl FnOnce(&mut Stream, u64) -> usize,
+    ) -> DispatchResult<()> {
+        StreamAppend::check_key_type(req)?;
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let arg = req
+            .typed_arg::<i64>(1)
+            .ok_or(DispatchError::ArgumentRetrieval)?;
+
+        if arg < 0 {
+            return Err(DispatchError::PreconditionFailed);
+        }
+
+        let removed = match hop.state().key_mut(key) {
+            Some(mut value) => {
+                let stream = value
+                    .as_stream_mut()
+                    .ok_or(DispatchError::KeyTypeDifferent)?;
+
+                trim(stream, arg as u64)
+            }
+            None => 0,
+        };
+
+        response::write_int(resp, removed as i64);
+
+        Ok(())
+    }
+}
+
+impl Dispatch for StreamTrim {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::trim(hop, req, resp, |stream, max| {
+            stream.trim_length(max as usize)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamTrim;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{Stream, StreamId, Value},
+        Hop,
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_trim() {
+        let mut stream = Stream::new();
+
+        for millis in 1..=3 {
+            assert!(stream.append(StreamId::new(millis, 0), Vec::new()));
+        }
+
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Stream(stream));
+        let mut builder = RequestBuilder::new(CommandId::StreamTrim);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(1).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(StreamTrim::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(2).as_bytes(), resp);
+        assert_eq!(
+            Some(1),
+            hop.state()
+                .key_ref(b"foo")
+                .as_deref()
+                .and_then(Value::as_stream_ref)
+                .map(Stream::len)
+        );
+    }
+
+    #[test]
+    fn test_negative() {
+        let mut builder = RequestBuilder::new(CommandId::StreamTrim);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(-1).is_ok());
+        let req = builder.into_request();
+        let hop = Hop::new();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::PreconditionFailed,
+            StreamTrim::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
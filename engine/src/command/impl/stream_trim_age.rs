@@ -0,0 +1,60 @@
+use super::{
+    super::{Dispatch, DispatchResult, Request},
+    stream_trim::StreamTrim,
+};
+use crate::Hop;
+use alloc::vec::Vec;
+
+pub struct StreamTrimAge;
+
+impl Dispatch for StreamTrimAge {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let now = (hop.config().clock())();
+
+        StreamTrim::trim(hop, req, resp, |stream, max_age| {
+            stream.trim_before(now.saturating_sub(max_age))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamTrimAge;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, Response},
+        hop::Builder,
+        state::{Stream, StreamId, Value},
+    };
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_trim_age() {
+        let mut stream = Stream::new();
+
+        for millis in [1000, 2000, 3000].iter() {
+            assert!(stream.append(StreamId::new(*millis, 0), Vec::new()));
+        }
+
+        let mut builder = Builder::new();
+        builder.clock(|| 3500);
+        let hop = builder.build();
+        hop.state().insert(b"foo".to_vec(), Value::Stream(stream));
+
+        let mut builder = RequestBuilder::new(CommandId::StreamTrimAge);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(1000).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(StreamTrimAge::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(Response::from(2).as_bytes(), resp);
+        assert_eq!(
+            Some(1),
+            hop.state()
+                .key_ref(b"foo")
+                .as_deref()
+                .and_then(Value::as_stream_ref)
+                .map(Stream::len)
+        );
+    }
+}
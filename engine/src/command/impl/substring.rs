@@ -0,0 +1,212 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{state::KeyType, Hop};
+use alloc::vec::Vec;
+
+pub struct Substring;
+
+impl Substring {
+    /// Resolve a start offset and optional end offset into a range of a value
+    /// with the given length.
+    ///
+    /// Negative offsets count backwards from the end of the value, and offsets
+    /// are clamped to the length of the value. If no end offset is given then
+    /// the range ends at the end of the value.
+    pub fn bounds(len: usize, start: i64, end: Option<i64>) -> (usize, usize) {
+        let resolve = |offset: i64| {
+            if offset < 0 {
+                len.saturating_sub(offset.wrapping_neg() as u64 as usize)
+            } else {
+                (offset as u64 as usize).min(len)
+            }
+        };
+
+        let start = resolve(start);
+        let end = end.map_or(len, resolve).max(start);
+
+        (start, end)
+    }
+
+    /// Retrieve the byte position of a character in a string by its index.
+    ///
+    /// Returns the length of the string if the index is past the end.
+    pub fn char_position(string: &str, idx: usize) -> usize {
+        string
+            .char_indices()
+            .nth(idx)
+            .map_or(string.len(), |(pos, _)| pos)
+    }
+
+    pub fn bytes(
+        hop: &Hop,
+        key: &[u8],
+        start: i64,
+        end: Option<i64>,
+        resp: &mut Vec<u8>,
+    ) -> DispatchResult<()> {
+        let key = hop
+            .state()
+            .key_ref(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let bytes = key.as_bytes_ref().ok_or(DispatchError::KeyTypeDifferent)?;
+        let (start, end) = Self::bounds(bytes.len(), start, end);
+
+        response::write_bytes(resp, &bytes[start..end]);
+
+        Ok(())
+    }
+
+    fn string(
+        hop: &Hop,
+        key: &[u8],
+        start: i64,
+        end: Option<i64>,
+        resp: &mut Vec<u8>,
+    ) -> DispatchResult<()> {
+        let key = hop
+            .state()
+            .key_ref(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let string = key.as_string_ref().ok_or(DispatchError::KeyTypeDifferent)?;
+        let (start, end) = Self::bounds(string.chars().count(), start, end);
+        let start = Self::char_position(string, start);
+        let end = Self::char_position(string, end);
+
+        response::write_str(resp, &string[start..end]);
+
+        Ok(())
+    }
+}
+
+impl Dispatch for Substring {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+        let start = req.typed_arg(1).ok_or(DispatchError::ArgumentRetrieval)?;
+        let end = req.typed_arg(2);
+        let key_type = req
+            .key_type()
+            .or_else(|| hop.state().key_type(key))
+            .unwrap_or(KeyType::String);
+
+        match key_type {
+            KeyType::Bytes => Self::bytes(hop, key, start, end, resp),
+            KeyType::String => Self::string(hop, key, start, end, resp),
+            _ => Err(DispatchError::KeyTypeInvalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Substring;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::{KeyType, Value},
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!((0, 5), Substring::bounds(5, 0, None));
+        assert_eq!((1, 3), Substring::bounds(5, 1, Some(3)));
+        assert_eq!((3, 5), Substring::bounds(5, -2, None));
+        assert_eq!((0, 4), Substring::bounds(5, -10, Some(-1)));
+        assert_eq!((5, 5), Substring::bounds(5, 7, Some(9)));
+        assert_eq!((3, 3), Substring::bounds(5, 3, Some(1)));
+        assert_eq!((0, 5), Substring::bounds(5, i64::MIN, Some(i64::MAX)));
+    }
+
+    #[test]
+    fn test_string_chars() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("h🤠pdb".to_owned()));
+
+        let mut builder = RequestBuilder::new(CommandId::Substring);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(1).is_ok());
+        assert!(builder.value(3).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Substring::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("🤠p".to_owned()).as_bytes());
+    }
+
+    #[test]
+    fn test_string_negative_no_end() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("hopdb".to_owned()));
+
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Substring, KeyType::String);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(-2).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Substring::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("db".to_owned()).as_bytes());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::Bytes([1, 2, 3, 4].to_vec()));
+
+        let mut builder = RequestBuilder::new(CommandId::Substring);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(1).is_ok());
+        assert!(builder.value(-1).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Substring::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from([2, 3].to_vec()).as_bytes());
+    }
+
+    #[test]
+    fn test_no_start() {
+        let hop = Hop::new();
+        let mut builder = RequestBuilder::new(CommandId::Substring);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::ArgumentRetrieval,
+            Substring::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_key_type_invalid() {
+        let hop = Hop::new();
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Substring, KeyType::Integer);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(0).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeInvalid,
+            Substring::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_nonexistent() {
+        let hop = Hop::new();
+        let mut builder = RequestBuilder::new(CommandId::Substring);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(0).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyNonexistent,
+            Substring::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
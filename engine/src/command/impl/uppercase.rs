@@ -0,0 +1,93 @@
+use super::super::{response, Dispatch, DispatchError, DispatchResult, Request};
+use crate::{state::KeyType, Hop};
+use alloc::{string::String, vec::Vec};
+
+pub struct Uppercase;
+
+impl Uppercase {
+    /// Convert the case of the string stored at the requested key in place
+    /// using the provided conversion, responding with the new string.
+    pub fn convert(
+        hop: &Hop,
+        req: &Request,
+        resp: &mut Vec<u8>,
+        conversion: fn(&str) -> String,
+    ) -> DispatchResult<()> {
+        let key = req.key().ok_or(DispatchError::KeyUnspecified)?;
+
+        if let Some(key_type) = req.key_type() {
+            if key_type != KeyType::String {
+                return Err(DispatchError::KeyTypeInvalid);
+            }
+        }
+
+        let mut value = hop
+            .state()
+            .key_mut(key)
+            .ok_or(DispatchError::KeyNonexistent)?;
+        let string = value
+            .as_string_mut()
+            .ok_or(DispatchError::KeyTypeDifferent)?;
+        *string = conversion(string);
+
+        response::write_str(resp, string);
+
+        Ok(())
+    }
+}
+
+impl Dispatch for Uppercase {
+    fn dispatch(hop: &Hop, req: &Request, resp: &mut Vec<u8>) -> DispatchResult<()> {
+        Self::convert(hop, req, resp, str::to_uppercase)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Uppercase;
+    use crate::{
+        command::{request::RequestBuilder, CommandId, Dispatch, DispatchError, Response},
+        state::Value,
+        Hop,
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+
+    #[test]
+    fn test_string() {
+        let hop = Hop::new();
+        hop.state()
+            .insert(b"foo".to_vec(), Value::String("hopdb ß".to_owned()));
+
+        let mut builder = RequestBuilder::new(CommandId::Uppercase);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert!(Uppercase::dispatch(&hop, &req, &mut resp).is_ok());
+        assert_eq!(resp, Response::from("HOPDB SS".to_owned()).as_bytes());
+        assert_eq!(
+            "HOPDB SS",
+            hop.state()
+                .key_ref(b"foo")
+                .unwrap()
+                .as_string_ref()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_key_type_different() {
+        let hop = Hop::new();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+
+        let mut builder = RequestBuilder::new(CommandId::Uppercase);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let req = builder.into_request();
+        let mut resp = Vec::new();
+
+        assert_eq!(
+            DispatchError::KeyTypeDifferent,
+            Uppercase::dispatch(&hop, &req, &mut resp).unwrap_err()
+        );
+    }
+}
@@ -6,7 +6,7 @@ pub mod response;
 mod error;
 
 pub use self::{
-    command_id::{CommandId, InvalidCommandId},
+    command_id::{Category, CommandId, InvalidCommandId},
     error::{Error as DispatchError, Result as DispatchResult},
     request::Request,
     response::Response,
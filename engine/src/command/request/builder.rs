@@ -166,9 +166,11 @@ impl RequestBuilder {
 
                 self.push_argument(bytes)?;
             }
+            Value::Document(document) => self.push_argument(document.as_bytes())?,
             Value::Float(float) => {
                 self.push_argument(Cow::Borrowed(float.to_be_bytes().as_ref()))?
             }
+            Value::HyperLogLog(hll) => self.push_argument(hll.as_bytes())?,
             Value::Integer(int) => self.push_argument(Cow::Borrowed(int.to_be_bytes().as_ref()))?,
             Value::List(list) => {
                 if self.arguments_would_overfill(list.len() as u8) {
@@ -210,6 +212,7 @@ impl RequestBuilder {
                     self.push_argument(item)?;
                 }
             }
+            Value::Stream(stream) => self.push_argument(stream.as_bytes())?,
             Value::String(string) => {
                 if string.is_empty() {
                     return Err(RequestBuilderError::ValueEmpty);
@@ -335,9 +338,6 @@ mod tests {
         let mut builder = RequestBuilder::new_with_key_type(CommandId::Append, KeyType::List);
         assert!(builder.bytes(b"foo".as_ref()).is_ok());
 
-        let mut expected_args = Vec::new();
-        expected_args.push(b"foo".as_ref());
-
         let mut positions = ArrayVec::new();
         positions.push(9);
 
@@ -371,10 +371,6 @@ mod tests {
         assert!(builder.bytes(b"foo".as_ref()).is_ok());
         assert!(builder.value(Value::Integer(123)).is_ok());
 
-        let mut expected_args = Vec::new();
-        expected_args.push(b"foo".as_ref());
-        expected_args.push(123i64.to_be_bytes().as_ref());
-
         let mut positions = ArrayVec::new();
         positions.push(9);
         positions.push(21);
@@ -2,7 +2,10 @@ use super::{super::ContextConclusion, Request};
 use crate::{command::CommandId, state::KeyType};
 use alloc::borrow::Cow;
 use arrayvec::ArrayVec;
-use core::convert::{TryFrom, TryInto};
+use core::{
+    convert::{TryFrom, TryInto},
+    mem,
+};
 
 type Conclusion<'a> = ContextConclusion<(CommandId, Option<KeyType>)>;
 
@@ -11,6 +14,7 @@ type Conclusion<'a> = ContextConclusion<(CommandId, Option<KeyType>)>;
 pub enum ParseError {
     CommandIdInvalid = 0,
     KeyTypeInvalid = 1,
+    RequestTooLarge = 2,
 }
 
 impl TryFrom<u8> for ParseError {
@@ -20,13 +24,15 @@ impl TryFrom<u8> for ParseError {
         Ok(match value {
             0 => Self::CommandIdInvalid,
             1 => Self::KeyTypeInvalid,
+            2 => Self::RequestTooLarge,
             _ => return Err(()),
         })
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
 enum Stage {
+    #[default]
     Init,
     Kind {
         command_id: CommandId,
@@ -39,15 +45,10 @@ enum Stage {
     },
 }
 
-impl Default for Stage {
-    fn default() -> Self {
-        Stage::Init
-    }
-}
-
 #[derive(Debug)]
 pub struct Context {
     idx: usize,
+    max_size: usize,
     positions: ArrayVec<[usize; 256]>,
     stage: Stage,
 }
@@ -59,6 +60,17 @@ impl Context {
         Default::default()
     }
 
+    /// Create a context that rejects requests larger than a number of bytes.
+    ///
+    /// The size of a request is known from the length of each argument, so a
+    /// request is rejected before the rest of it needs to be buffered.
+    pub fn with_max_size(max_size: usize) -> Self {
+        Self {
+            max_size,
+            ..Default::default()
+        }
+    }
+
     pub fn feed<'a>(&'a mut self, buf: &'a [u8]) -> Result<Option<Request<'a>>, ParseError> {
         loop {
             let conclusion = {
@@ -87,13 +99,19 @@ impl Context {
 
             match conclusion {
                 Conclusion::Finished((command_id, key_type)) => {
+                    // Take the positions and length before resetting for the
+                    // next request. The buffer may already hold the start of
+                    // the next request, so only the request's own bytes are
+                    // borrowed.
+                    let positions = mem::take(&mut self.positions);
+                    let len = self.idx;
                     self.reset();
 
                     return Ok(Some(Request {
-                        buf: Cow::Borrowed(buf),
+                        buf: Cow::Borrowed(&buf[..len]),
                         command_id,
                         key_type,
-                        positions: Cow::Borrowed(&self.positions),
+                        positions: Cow::Owned(positions),
                     }));
                 }
                 Conclusion::Incomplete => return Ok(None),
@@ -108,32 +126,37 @@ impl Context {
             None => return Ok(Conclusion::Incomplete),
         };
 
-        // If the first bit is flipped, then this byte is denoting the type of
-        // key to work with. This means that byte idx 2 is the argument length.
+        // If the first bit is flipped, then the next byte is denoting the
+        // type of key to work with, followed by the argument count.
         //
-        // If the first bit is 0, then this byte is the argument length, and the
-        // type of key to work with is not a requirement.
+        // If the first bit is 0, then the next byte is the argument count, and
+        // the type of key to work with is not a requirement.
         let key_type = if byte >> 7 == 1 {
-            let key_type_id = byte >> 1;
+            let key_type_id = match buf.get(1) {
+                Some(key_type_id) => *key_type_id,
+                None => return Ok(Conclusion::Incomplete),
+            };
 
             Some(KeyType::try_from(key_type_id).map_err(|_| ParseError::KeyTypeInvalid)?)
         } else {
             None
         };
 
-        let command_id = CommandId::try_from(byte).map_err(|_| ParseError::CommandIdInvalid)?;
+        let command_id =
+            CommandId::try_from(byte & 0b0111_1111).map_err(|_| ParseError::CommandIdInvalid)?;
+
+        self.idx = 1 + key_type.is_some() as usize;
 
         // If the command type is simple and has no arguments or keys, then
         // we can just return a successful command here.
         if command_id.is_simple() {
-            return Ok(Conclusion::Finished((command_id, None)));
+            return Ok(Conclusion::Finished((command_id, key_type)));
         }
 
         self.stage = Stage::Kind {
             command_id,
             key_type,
         };
-        self.idx = self.idx.wrapping_add(1);
 
         Ok(Conclusion::Next)
     }
@@ -143,18 +166,23 @@ impl Context {
         buf: &[u8],
         key_type: Option<KeyType>,
         command_id: CommandId,
-    ) -> Result<Conclusion, ParseError> {
+    ) -> Result<Conclusion<'_>, ParseError> {
         let argument_count = match buf.get(self.idx) {
             Some(argument_count) => *argument_count,
             None => return Ok(Conclusion::Incomplete),
         };
 
+        self.idx = self.idx.saturating_add(1);
+
+        if argument_count == 0 {
+            return Ok(Conclusion::Finished((command_id, key_type)));
+        }
+
         self.stage = Stage::ArgumentParsing {
             argument_count,
             command_id,
             key_type,
         };
-        self.idx = self.idx.saturating_add(1);
 
         Ok(Conclusion::Next)
     }
@@ -165,21 +193,29 @@ impl Context {
         command_id: CommandId,
         key_type: Option<KeyType>,
         argument_count: u8,
-    ) -> Result<Conclusion, ParseError> {
+    ) -> Result<Conclusion<'a>, ParseError> {
         let len_bytes = match buf.get(self.idx..self.idx + Self::ARG_LEN_BYTES) {
             Some(bytes) => bytes.try_into().unwrap(),
             None => return Ok(Conclusion::Incomplete),
         };
 
         let arg_len = u32::from_be_bytes(len_bytes) as usize;
+        let end = self.idx + Self::ARG_LEN_BYTES + arg_len;
+
+        if end > self.max_size {
+            self.reset();
+
+            return Err(ParseError::RequestTooLarge);
+        }
 
-        if buf.get(self.idx..self.idx + arg_len).is_some() {
-            self.positions.push(self.idx + arg_len);
+        // Positions are the index of the last byte of each argument.
+        if buf.len() >= end {
+            self.positions.push(end - 1);
         } else {
             return Ok(Conclusion::Incomplete);
         }
 
-        self.idx += 4 + arg_len;
+        self.idx = end;
 
         if self.positions.len() == argument_count as usize {
             Ok(Conclusion::Finished((command_id, key_type)))
@@ -199,6 +235,7 @@ impl Default for Context {
     fn default() -> Self {
         Self {
             idx: 0,
+            max_size: usize::MAX,
             positions: ArrayVec::new(),
             stage: Stage::default(),
         }
@@ -208,9 +245,10 @@ impl Default for Context {
 #[cfg(test)]
 mod tests {
     use super::{
-        super::{super::error::Result, CommandId},
+        super::{super::error::Result, CommandId, RequestBuilder},
         Context, ParseError, Stage,
     };
+    use crate::state::{KeyType, Value};
     use core::{convert::TryFrom, fmt::Debug, hash::Hash};
     use static_assertions::assert_impl_all;
 
@@ -261,6 +299,83 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_builder_round_trip() {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, KeyType::Integer);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.value(Value::Integer(7)).is_ok());
+        let bytes = builder.into_request().into_bytes();
+
+        let mut ctx = Context::new();
+
+        // Requests may be fed a few bytes at a time.
+        for len in 0..bytes.len() {
+            assert!(matches!(ctx.feed(&bytes[..len]), Ok(None)));
+        }
+
+        let req = ctx.feed(&bytes).unwrap().unwrap();
+        assert_eq!(CommandId::Set, req.command_id());
+        assert_eq!(Some(KeyType::Integer), req.key_type());
+        assert_eq!(Some(b"foo".as_ref()), req.key());
+        assert_eq!(Some(7), req.typed_arg::<i64>(1));
+    }
+
+    #[test]
+    fn test_builder_round_trip_many() {
+        let mut builder = RequestBuilder::new(CommandId::Echo);
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(builder.bytes(b"bc".as_ref()).is_ok());
+        assert!(builder.bytes(b"def".as_ref()).is_ok());
+        let bytes = builder.into_request().into_bytes();
+
+        let mut ctx = Context::new();
+        let req = ctx.feed(&bytes).unwrap().unwrap();
+        assert_eq!(3, req.arg_count());
+        assert_eq!(Some(b"bc".as_ref()), req.arg(1));
+        assert_eq!(Some(b"def".as_ref()), req.arg(2));
+    }
+
+    #[test]
+    fn test_pipelined() {
+        let mut builder = RequestBuilder::new(CommandId::Echo);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let first = builder.into_request().into_bytes().into_owned();
+        let second = RequestBuilder::new(CommandId::Stats)
+            .into_request()
+            .into_bytes()
+            .into_owned();
+        let mut bytes = first.clone();
+        bytes.extend_from_slice(&second);
+
+        let mut ctx = Context::new();
+
+        // Only the bytes of the first request belong to it.
+        let req = ctx.feed(&bytes).unwrap().unwrap();
+        assert_eq!(first.as_slice(), req.as_bytes());
+        assert_eq!(Some(b"foo".as_ref()), req.arg(0));
+
+        let req = ctx.feed(&bytes[first.len()..]).unwrap().unwrap();
+        assert_eq!(CommandId::Stats, req.command_id());
+        assert_eq!(second.as_slice(), req.as_bytes());
+    }
+
+    #[test]
+    fn test_max_size() {
+        let mut builder = RequestBuilder::new(CommandId::Echo);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        let bytes = builder.into_request().into_bytes();
+
+        let mut ctx = Context::with_max_size(bytes.len());
+        assert!(ctx.feed(&bytes).unwrap().is_some());
+
+        // Only the header and argument length are needed to reject it.
+        let mut ctx = Context::with_max_size(bytes.len() - 1);
+        assert_eq!(
+            ParseError::RequestTooLarge,
+            ctx.feed(&bytes[..bytes.len() - 3]).unwrap_err()
+        );
+    }
+
     #[test]
     fn test_parse_error_try_from_u8() {
         assert_eq!(
@@ -268,5 +383,73 @@ mod tests {
             ParseError::CommandIdInvalid
         );
         assert_eq!(ParseError::try_from(1).unwrap(), ParseError::KeyTypeInvalid);
+        assert_eq!(
+            ParseError::try_from(2).unwrap(),
+            ParseError::RequestTooLarge
+        );
+    }
+
+    #[test]
+    fn test_key_type() {
+        // The first bit of the command ID flags that the next byte is the key
+        // type, and isn't part of the ID itself.
+        let cmd = [
+            0b1000_0000 | CommandId::Increment as u8,
+            KeyType::Integer as u8,
+            1,
+            0,
+            0,
+            0,
+            3,
+            b'f',
+            b'o',
+            b'o',
+        ];
+
+        let mut ctx = Context::new();
+        assert!(matches!(ctx.feed(&cmd[..1]), Ok(None)));
+
+        let req = ctx.feed(&cmd).unwrap().unwrap();
+        assert_eq!(CommandId::Increment, req.command_id());
+        assert_eq!(Some(KeyType::Integer), req.key_type());
+        assert_eq!(1, req.arg_count());
+        assert_eq!(Some(b"foo".as_ref()), req.key());
+    }
+
+    #[test]
+    fn test_key_type_invalid() {
+        let cmd = [0b1000_0000 | CommandId::Increment as u8, u8::MAX, 0];
+
+        let mut ctx = Context::new();
+        assert_eq!(ParseError::KeyTypeInvalid, ctx.feed(&cmd).unwrap_err());
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        let cmd = [CommandId::Echo as u8, 0];
+
+        let mut ctx = Context::new();
+        assert!(matches!(ctx.feed(&cmd[..1]), Ok(None)));
+
+        let req = ctx.feed(&cmd).unwrap().unwrap();
+        assert_eq!(CommandId::Echo, req.command_id());
+        assert_eq!(None, req.key_type());
+        assert_eq!(0, req.arg_count());
+    }
+
+    #[test]
+    fn test_arguments_outlive_context_reset() {
+        let mut builder = RequestBuilder::new(CommandId::Echo);
+        assert!(builder.bytes(b"foo".as_ref()).is_ok());
+        assert!(builder.bytes(b"bar".as_ref()).is_ok());
+        let bytes = builder.into_request().into_bytes();
+
+        // The context resets for the next request before returning this one,
+        // so the request keeps its own argument positions.
+        let mut ctx = Context::new();
+        let req = ctx.feed(&bytes).unwrap().unwrap();
+        assert_eq!(2, req.arg_count());
+        assert_eq!(Some(b"foo".as_ref()), req.arg(0));
+        assert_eq!(Some(b"bar".as_ref()), req.arg(1));
     }
 }
@@ -7,7 +7,7 @@ pub use self::{
 };
 
 use super::command_id::{CommandId, KeyNotation};
-use crate::state::KeyType;
+use crate::state::{Document, DocumentPath, HyperLogLog, KeyType, Stream, StreamId};
 use alloc::{
     borrow::{Cow, ToOwned},
     vec::Vec,
@@ -54,6 +54,12 @@ impl Argument<'_> for f64 {
     }
 }
 
+impl Argument<'_> for HyperLogLog {
+    fn convert(bytes: &[u8]) -> Option<Self> {
+        HyperLogLog::from_bytes(bytes)
+    }
+}
+
 impl Argument<'_> for i64 {
     fn convert(bytes: &[u8]) -> Option<Self> {
         let arr = bytes.get(..8)?.try_into().ok()?;
@@ -86,13 +92,40 @@ impl MultiArgument<'_> for DashSet<Vec<u8>> {
     }
 }
 
+/// Documents are sent as JSON text.
+impl Argument<'_> for Document {
+    fn convert(bytes: &[u8]) -> Option<Self> {
+        Document::from_bytes(bytes)
+    }
+}
+
+impl Argument<'_> for DocumentPath {
+    fn convert(bytes: &[u8]) -> Option<Self> {
+        str::from_utf8(bytes).ok()?.parse().ok()
+    }
+}
+
+impl Argument<'_> for Stream {
+    fn convert(bytes: &[u8]) -> Option<Self> {
+        Stream::from_bytes(bytes)
+    }
+}
+
+/// Stream IDs are sent as text in the form `millis-sequence`, so that they can
+/// be typed by people using the CLI.
+impl Argument<'_> for StreamId {
+    fn convert(bytes: &[u8]) -> Option<Self> {
+        str::from_utf8(bytes).ok()?.parse().ok()
+    }
+}
+
 impl<'a> Argument<'a> for &'a str {
     fn convert(bytes: &'a [u8]) -> Option<Self> {
         str::from_utf8(bytes).ok()
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Arguments<'a> {
     idx: usize,
     to: usize,
@@ -194,6 +227,19 @@ impl<'a> Request<'a> {
         self.arg(0)
     }
 
+    /// Returns an iterator over the keys the request works with, as determined
+    /// by the key notation of its command.
+    pub fn keys(&self) -> impl Iterator<Item = &[u8]> {
+        let count = match self.command_id.key_notation() {
+            KeyNotation::None => 0,
+            KeyNotation::One => 1,
+            KeyNotation::Two => 2,
+            KeyNotation::Multiple => self.arg_count(),
+        };
+
+        (0..count).map_while(move |idx| self.arg(idx))
+    }
+
     /// Returns the requested type of key to work with, if any.
     ///
     /// Some commands only work with one type of key, such as a boolean, where
@@ -4,7 +4,7 @@
 use super::{Response, ResponseType};
 use crate::{
     command::{request::ParseError as RequestParseError, DispatchError},
-    state::Value,
+    state::{Document, HyperLogLog, Stream, Value},
 };
 use alloc::{string::String, vec::Vec};
 use core::{
@@ -17,6 +17,8 @@ use dashmap::{DashMap, DashSet};
 #[repr(u8)]
 pub enum ParseError {
     DispatchErrorInvalid,
+    /// The serialized HyperLogLog is invalid.
+    HyperLogLogInvalid,
     ParseErrorInvalid,
     /// The payload is too large. The command should have been sent as multiple
     /// appends.
@@ -26,6 +28,10 @@ pub enum ParseError {
     /// re-initiated. The session can be resumed.
     PayloadTooLarge,
     ResponseTypeInvalid,
+    /// The serialized document is invalid.
+    DocumentInvalid,
+    /// The serialized stream is invalid.
+    StreamInvalid,
     /// The string isn't valid UTF-8.
     StringInvalid,
 }
@@ -36,17 +42,21 @@ pub enum Instruction {
     ReadBytes(usize),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 enum Stage {
+    #[default]
     Init,
     DetermineType,
     /// The type is known, and now the length of the argument(s) is being read
     /// for the following types:
     ///
     /// - bytes
+    /// - HyperLogLog
     /// - list
     /// - map
     /// - set
+    /// - document
+    /// - stream
     /// - string
     TypeInit {
         kind: ResponseType,
@@ -57,6 +67,9 @@ enum Stage {
         len: u32,
     },
     Float,
+    HyperLogLog {
+        len: u32,
+    },
     Integer,
     List {
         args: Vec<Vec<u8>>,
@@ -72,17 +85,17 @@ enum Stage {
         args: DashSet<Vec<u8>>,
         len: u16,
     },
+    Document {
+        len: u32,
+    },
+    Stream {
+        len: u32,
+    },
     String {
         len: u32,
     },
 }
 
-impl Default for Stage {
-    fn default() -> Self {
-        Self::Init
-    }
-}
-
 #[derive(Debug, Default)]
 pub struct Context {
     idx: usize,
@@ -102,10 +115,13 @@ impl Context {
                 Stage::Boolean => self.stage_boolean(buf)?,
                 Stage::Bytes { len } => self.stage_bytes(buf, len)?,
                 Stage::Float => self.stage_float(buf)?,
+                Stage::HyperLogLog { len } => self.stage_hyper_log_log(buf, len)?,
                 Stage::Integer => self.stage_integer(buf)?,
                 Stage::List { .. } => self.stage_list(buf)?,
                 Stage::Map { .. } => self.stage_map(buf)?,
                 Stage::Set { .. } => self.stage_set(buf)?,
+                Stage::Document { len } => self.stage_document(buf, len)?,
+                Stage::Stream { len } => self.stage_stream(buf, len)?,
                 Stage::String { len } => self.stage_string(buf, len)?,
                 Stage::TypeInit { kind, read_len } => self.stage_type_init(buf, kind, read_len)?,
                 Stage::DispatchError => self.stage_dispatch_error(buf)?,
@@ -169,7 +185,11 @@ impl Context {
             ResponseType::List | ResponseType::Map | ResponseType::Set => {
                 Stage::TypeInit { kind, read_len: 2 }
             }
-            ResponseType::Bytes | ResponseType::String => Stage::TypeInit { kind, read_len: 4 },
+            ResponseType::Bytes
+            | ResponseType::Document
+            | ResponseType::HyperLogLog
+            | ResponseType::Stream
+            | ResponseType::String => Stage::TypeInit { kind, read_len: 4 },
             ResponseType::DispatchError => Stage::DispatchError,
             ResponseType::ParseError => Stage::ParseError,
         };
@@ -233,6 +253,28 @@ impl Context {
         Ok(Some(Instruction::Concluded(Response::from(float))))
     }
 
+    fn stage_hyper_log_log(
+        &mut self,
+        buf: &[u8],
+        len: u32,
+    ) -> Result<Option<Instruction>, ParseError> {
+        debug_assert_eq!(self.idx, 9);
+
+        let bytes = match buf.get(self.idx..self.idx + len as usize) {
+            Some(bytes) => bytes,
+            None => {
+                let buf_len = buf.len();
+                let remaining = remaining_bytes(self.idx, buf_len, len as usize);
+
+                return Ok(Some(Instruction::ReadBytes(remaining)));
+            }
+        };
+
+        let hll = HyperLogLog::from_bytes(bytes).ok_or(ParseError::HyperLogLogInvalid)?;
+
+        Ok(Some(Instruction::Concluded(Response::from(hll))))
+    }
+
     fn stage_integer(&mut self, buf: &[u8]) -> Result<Option<Instruction>, ParseError> {
         debug_assert_eq!(self.idx, 5);
 
@@ -392,9 +434,20 @@ impl Context {
 
                 Stage::Bytes { len }
             }
+            ResponseType::HyperLogLog => {
+                let len = u32::from_be_bytes(bytes.try_into().unwrap());
+
+                Stage::HyperLogLog { len }
+            }
             ResponseType::List => {
                 let len = u16::from_be_bytes(bytes.try_into().unwrap());
 
+                if len == 0 {
+                    return Ok(Some(Instruction::Concluded(Response::from(
+                        Vec::<Vec<u8>>::new(),
+                    ))));
+                }
+
                 Stage::List {
                     args: Vec::new(),
                     len,
@@ -415,11 +468,25 @@ impl Context {
             ResponseType::Set => {
                 let len = u16::from_be_bytes(bytes.try_into().unwrap());
 
+                if len == 0 {
+                    return Ok(Some(Instruction::Concluded(Response::from(DashSet::new()))));
+                }
+
                 Stage::Set {
                     args: DashSet::new(),
                     len,
                 }
             }
+            ResponseType::Document => {
+                let len = u32::from_be_bytes(bytes.try_into().unwrap());
+
+                Stage::Document { len }
+            }
+            ResponseType::Stream => {
+                let len = u32::from_be_bytes(bytes.try_into().unwrap());
+
+                Stage::Stream { len }
+            }
             ResponseType::String => {
                 let len = u32::from_be_bytes(bytes.try_into().unwrap());
 
@@ -496,6 +563,42 @@ impl Context {
         }
     }
 
+    fn stage_document(&mut self, buf: &[u8], len: u32) -> Result<Option<Instruction>, ParseError> {
+        debug_assert_eq!(self.idx, 9);
+
+        let bytes = match buf.get(self.idx..self.idx + len as usize) {
+            Some(bytes) => bytes,
+            None => {
+                let buf_len = buf.len();
+                let remaining = remaining_bytes(self.idx, buf_len, len as usize);
+
+                return Ok(Some(Instruction::ReadBytes(remaining)));
+            }
+        };
+
+        let document = Document::from_bytes(bytes).ok_or(ParseError::DocumentInvalid)?;
+
+        Ok(Some(Instruction::Concluded(Response::from(document))))
+    }
+
+    fn stage_stream(&mut self, buf: &[u8], len: u32) -> Result<Option<Instruction>, ParseError> {
+        debug_assert_eq!(self.idx, 9);
+
+        let bytes = match buf.get(self.idx..self.idx + len as usize) {
+            Some(bytes) => bytes,
+            None => {
+                let buf_len = buf.len();
+                let remaining = remaining_bytes(self.idx, buf_len, len as usize);
+
+                return Ok(Some(Instruction::ReadBytes(remaining)));
+            }
+        };
+
+        let stream = Stream::from_bytes(bytes).ok_or(ParseError::StreamInvalid)?;
+
+        Ok(Some(Instruction::Concluded(Response::from(stream))))
+    }
+
     fn stage_string(
         &mut self,
         buf: &[u8],
@@ -539,7 +642,10 @@ mod tests {
         super::{Response, ResponseType},
         Context, Instruction, ParseError, Stage,
     };
-    use crate::{command::DispatchError, state::Value};
+    use crate::{
+        command::DispatchError,
+        state::{Document, HyperLogLog, Stream, StreamId, Value},
+    };
     use core::{fmt::Debug, hash::Hash};
     use static_assertions::assert_impl_all;
 
@@ -588,6 +694,76 @@ mod tests {
             Ok(Instruction::Concluded(Response::Value(Value::Bytes(x)))) if x == [2, 3, 0]),);
     }
 
+    #[test]
+    fn test_hyper_log_log() {
+        let mut hll = HyperLogLog::new();
+        hll.add(b"foo");
+        let mut ctx = Context::new();
+        let buf = Response::from(hll.clone()).as_bytes();
+        assert!(matches!(
+            ctx.feed(&buf[..buf.len() - 1]),
+            Ok(Instruction::ReadBytes(1))
+        ));
+        assert!(matches!(
+            ctx.feed(&buf),
+            Ok(Instruction::Concluded(Response::Value(Value::HyperLogLog(x)))) if x == hll),);
+    }
+
+    #[test]
+    fn test_hyper_log_log_invalid() {
+        let mut ctx = Context::new();
+        let buf = [
+            0,
+            0,
+            0,
+            8,
+            ResponseType::HyperLogLog as u8,
+            0,
+            0,
+            0,
+            3,
+            b'f',
+            b'o',
+            b'o',
+        ];
+        assert!(matches!(
+            ctx.feed(&buf),
+            Err(ParseError::HyperLogLogInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_document() {
+        let document = r#"{"a":[1,2.5,"b"]}"#.parse::<Document>().unwrap();
+        let mut ctx = Context::new();
+        let buf = Response::from(document.clone()).as_bytes();
+        assert!(matches!(
+            ctx.feed(&buf[..buf.len() - 1]),
+            Ok(Instruction::ReadBytes(1))
+        ));
+        assert!(matches!(
+            ctx.feed(&buf),
+            Ok(Instruction::Concluded(Response::Value(Value::Document(x)))) if x == document),);
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut stream = Stream::new();
+        assert!(stream.append(
+            StreamId::new(1, 0),
+            [(b"a".to_vec(), b"b".to_vec())].to_vec()
+        ));
+        let mut ctx = Context::new();
+        let buf = Response::from(stream.clone()).as_bytes();
+        assert!(matches!(
+            ctx.feed(&buf[..buf.len() - 1]),
+            Ok(Instruction::ReadBytes(1))
+        ));
+        assert!(matches!(
+            ctx.feed(&buf),
+            Ok(Instruction::Concluded(Response::Value(Value::Stream(x)))) if x == stream),);
+    }
+
     #[test]
     fn test_req_dispatch_error_unfinished() {
         let mut ctx = Context::new();
@@ -694,6 +870,15 @@ mod tests {
             Ok(Instruction::Concluded(Response::Value(Value::List(list)))) if list == [b"foo", b"bar"]));
     }
 
+    #[test]
+    fn test_list_no_items() {
+        let mut ctx = Context::new();
+        let buf = [0, 0, 0, 3, ResponseType::List as u8, 0, 0];
+        assert!(
+            matches!(ctx.feed(&buf), Ok(Instruction::Concluded(Response::Value(Value::List(list)))) if list.is_empty())
+        );
+    }
+
     #[test]
     fn test_map() {
         let mut ctx = Context::new();
@@ -763,6 +948,15 @@ mod tests {
         assert!(matches!(ctx.feed(&buf), Ok(Instruction::ReadBytes(1))));
     }
 
+    #[test]
+    fn test_set_no_items() {
+        let mut ctx = Context::new();
+        let buf = [0, 0, 0, 3, ResponseType::Set as u8, 0, 0];
+        assert!(
+            matches!(ctx.feed(&buf), Ok(Instruction::Concluded(Response::Value(Value::Set(set)))) if set.is_empty())
+        );
+    }
+
     #[test]
     fn test_string() {
         let mut ctx = Context::new();
@@ -3,7 +3,7 @@ mod context;
 pub use context::{Context, Instruction, ParseError};
 
 use super::{request::ParseError as RequestParseError, DispatchError};
-use crate::state::Value;
+use crate::state::{Document, HyperLogLog, Stream, Value};
 use alloc::{string::String, vec::Vec};
 use core::convert::TryFrom;
 use dashmap::{DashMap, DashSet};
@@ -22,6 +22,9 @@ pub enum ResponseType {
     String = 7,
     ParseError = 8,
     DispatchError = 9,
+    HyperLogLog = 10,
+    Stream = 11,
+    Document = 12,
 }
 
 impl TryFrom<u8> for ResponseType {
@@ -39,6 +42,9 @@ impl TryFrom<u8> for ResponseType {
             7 => Self::String,
             8 => Self::ParseError,
             9 => Self::DispatchError,
+            10 => Self::HyperLogLog,
+            11 => Self::Stream,
+            12 => Self::Document,
             _ => return Err(()),
         })
     }
@@ -86,6 +92,12 @@ impl From<f64> for Response {
     }
 }
 
+impl From<HyperLogLog> for Response {
+    fn from(value: HyperLogLog) -> Self {
+        Self::Value(Value::HyperLogLog(value))
+    }
+}
+
 impl From<i64> for Response {
     fn from(value: i64) -> Self {
         Self::Value(Value::Integer(value))
@@ -110,6 +122,18 @@ impl From<DashSet<Vec<u8>>> for Response {
     }
 }
 
+impl From<Document> for Response {
+    fn from(value: Document) -> Self {
+        Self::Value(Value::Document(value))
+    }
+}
+
+impl From<Stream> for Response {
+    fn from(value: Stream) -> Self {
+        Self::Value(Value::Stream(value))
+    }
+}
+
 impl From<String> for Response {
     fn from(value: String) -> Self {
         Self::Value(Value::String(value))
@@ -167,6 +191,18 @@ pub fn write_float(to: &mut Vec<u8>, value: f64) {
     to.extend_from_slice(&value.to_be_bytes());
 }
 
+pub fn write_hyper_log_log(to: &mut Vec<u8>, value: &HyperLogLog) {
+    let bytes = value.as_bytes();
+    let len = bytes.len() as u32;
+
+    // kind + 4 byte serialized len + serialized len
+    let response_len = 1 + 4 + len;
+    to.extend_from_slice(&response_len.to_be_bytes());
+    to.push(ResponseType::HyperLogLog as u8);
+    to.extend_from_slice(&len.to_be_bytes());
+    to.extend_from_slice(&bytes);
+}
+
 pub fn write_int(to: &mut Vec<u8>, value: i64) {
     // kind + 8 byte int
     to.extend_from_slice(&9u32.to_be_bytes());
@@ -278,6 +314,30 @@ pub fn write_set(to: &mut Vec<u8>, value: &DashSet<Vec<u8>>) {
     }
 }
 
+pub fn write_document(to: &mut Vec<u8>, value: &Document) {
+    let bytes = value.as_bytes();
+    let len = bytes.len() as u32;
+
+    // kind + 4 byte serialized len + serialized len
+    let response_len = 1 + 4 + len;
+    to.extend_from_slice(&response_len.to_be_bytes());
+    to.push(ResponseType::Document as u8);
+    to.extend_from_slice(&len.to_be_bytes());
+    to.extend_from_slice(&bytes);
+}
+
+pub fn write_stream(to: &mut Vec<u8>, value: &Stream) {
+    let bytes = value.as_bytes();
+    let len = bytes.len() as u32;
+
+    // kind + 4 byte serialized len + serialized len
+    let response_len = 1 + 4 + len;
+    to.extend_from_slice(&response_len.to_be_bytes());
+    to.push(ResponseType::Stream as u8);
+    to.extend_from_slice(&len.to_be_bytes());
+    to.extend_from_slice(&bytes);
+}
+
 pub fn write_str(to: &mut Vec<u8>, value: &str) {
     let len = value.len() as u32;
 
@@ -294,11 +354,14 @@ pub fn write_value(to: &mut Vec<u8>, value: &Value) {
     match value {
         Value::Boolean(boolean) => write_bool(to, *boolean),
         Value::Bytes(bytes) => write_bytes(to, bytes),
+        Value::Document(document) => write_document(to, document),
         Value::Float(float) => write_float(to, *float),
+        Value::HyperLogLog(hll) => write_hyper_log_log(to, hll),
         Value::Integer(int) => write_int(to, *int),
         Value::List(list) => write_list(to, list),
         Value::Map(map) => write_map(to, map),
         Value::Set(set) => write_set(to, set),
+        Value::Stream(stream) => write_stream(to, stream),
         Value::String(string) => write_str(to, string),
     }
 }
@@ -306,7 +369,7 @@ pub fn write_value(to: &mut Vec<u8>, value: &Value) {
 #[cfg(test)]
 mod tests {
     use super::{Response, ResponseType};
-    use crate::state::Value;
+    use crate::state::{Document, HyperLogLog, Stream, Value};
     use alloc::{borrow::ToOwned, string::String, vec::Vec};
     use core::{fmt::Debug, hash::Hash};
     use dashmap::{DashMap, DashSet};
@@ -318,10 +381,13 @@ mod tests {
         From<bool>,
         From<Vec<u8>>,
         From<f64>,
+        From<HyperLogLog>,
         From<i64>,
         From<Vec<Vec<u8>>>,
         From<DashMap<Vec<u8>, Vec<u8>>>,
         From<DashSet<Vec<u8>>>,
+        From<Document>,
+        From<Stream>,
         From<String>,
     );
 
@@ -402,6 +468,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hyper_log_log() {
+        assert_eq!(
+            Response::from(HyperLogLog::new()).as_bytes(),
+            [
+                0,
+                0,
+                0,
+                12,
+                ResponseType::HyperLogLog as u8,
+                // length, max u32
+                0,
+                0,
+                0,
+                7,
+                b'H',
+                b'L',
+                b'L',
+                // version
+                1,
+                // sparse encoding
+                0,
+                // no registers are set
+                0,
+                0,
+            ],
+        );
+    }
+
     #[test]
     fn test_int() {
         assert_eq!(
@@ -462,9 +557,7 @@ mod tests {
 
     #[test]
     fn test_list() {
-        let mut list = Vec::new();
-        list.push(b"hop".to_vec());
-        list.push(b"db".to_vec());
+        let list = vec![b"hop".to_vec(), b"db".to_vec()];
 
         assert_eq!(
             Response::from(list).as_bytes(),
@@ -10,13 +10,94 @@
 //! [`Hop`]: struct.Hop.html
 
 use crate::{
-    command::{r#impl::*, CommandId, Dispatch, DispatchResult, Request},
+    acl::{Acl, User},
+    command::{r#impl::*, CommandId, Dispatch, DispatchError, DispatchResult, Request},
     metrics::{Metric, Metrics, Reader, Writer},
-    pubsub::PubSubManager,
-    session::SessionManager,
-    state::State,
+    pubsub::{KeyUpdate, PubSubManager},
+    session::{SessionId, SessionLimitReached, SessionManager},
+    state::{Key, State, Value},
 };
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    convert::TryFrom,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    str::FromStr,
+    sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering},
+};
+use dashmap::mapref::one::Ref;
+use futures_intrusive::channel::shared::{self, StateReceiver, StateSender};
+
+/// The policy deciding which keys to remove once the memory limit has been
+/// reached.
+///
+/// Policies other than [`NoEviction`] approximate their ordering by sampling a
+/// few keys at a time rather than keeping every key ordered, so they don't
+/// always remove the very best candidate.
+///
+/// [`NoEviction`]: #variant.NoEviction
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum EvictionPolicy {
+    /// Don't remove any keys, and instead reject commands that may use more
+    /// memory with [`DispatchError::MemoryLimitReached`].
+    ///
+    /// [`DispatchError::MemoryLimitReached`]: ../command/enum.DispatchError.html#variant.MemoryLimitReached
+    #[default]
+    NoEviction,
+    /// Remove the keys that were accessed the least recently.
+    LeastRecentlyUsed,
+    /// Remove the keys that were accessed the least number of times.
+    LeastFrequentlyUsed,
+    /// Remove random keys.
+    Random,
+}
+
+impl EvictionPolicy {
+    /// Every policy, in order of their discriminant.
+    const ALL: [Self; 4] = [
+        Self::NoEviction,
+        Self::LeastRecentlyUsed,
+        Self::LeastFrequentlyUsed,
+        Self::Random,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::LeastFrequentlyUsed => "lfu",
+            Self::LeastRecentlyUsed => "lru",
+            Self::NoEviction => "no-eviction",
+            Self::Random => "random",
+        }
+    }
+}
+
+impl Display for EvictionPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for EvictionPolicy {
+    type Err = InvalidEvictionPolicy;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "lfu" => Self::LeastFrequentlyUsed,
+            "lru" => Self::LeastRecentlyUsed,
+            "no-eviction" => Self::NoEviction,
+            "random" => Self::Random,
+            _ => return Err(InvalidEvictionPolicy),
+        })
+    }
+}
+
+/// The name of an eviction policy isn't one of the supported policies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidEvictionPolicy;
 
 /// Configuration defining how a Hop engine will operate.
 ///
@@ -24,14 +105,339 @@ use alloc::{sync::Arc, vec::Vec};
 ///
 /// See [`Builder`] for constructing a configured Hop engine.
 ///
+/// The limits, eviction policy and slow command threshold can be changed
+/// while the engine is running through the engine's [`config`], such as with
+/// the `config:set` command. Every handle to the engine sees the change.
+///
 /// [`Builder`]: struct.Builder.html
-#[derive(Clone, Debug)]
+/// [`config`]: struct.Hop.html#method.config
+#[derive(Debug)]
 pub struct Config {
+    clock: fn() -> u64,
+    drop_background: fn(Vec<(Key, Value)>),
+    eviction_policy: AtomicU8,
+    max_collection_elements: AtomicUsize,
+    max_key_length: AtomicUsize,
+    /// The memory limit, or `usize::MAX` if memory usage isn't limited.
+    max_memory: AtomicUsize,
+    max_value_size: AtomicUsize,
+    namespaces: usize,
+    password: Option<Password>,
     pubsub_enabled: bool,
-    sessions_active_max: usize,
+    sessions_active_max: AtomicUsize,
+    /// The slow command threshold in milliseconds, or `u64::MAX` if slow
+    /// commands aren't logged.
+    slowlog_threshold: AtomicU64,
 }
 
 impl Config {
+    /// The names of the parameters that can be retrieved with [`parameter`].
+    ///
+    /// Every parameter except `namespaces` and `pubsub_enabled` can be
+    /// changed with [`set_parameter`]. The `log_level` parameter requires the
+    /// `log` feature.
+    ///
+    /// [`parameter`]: #method.parameter
+    /// [`set_parameter`]: #method.set_parameter
+    pub const PARAMETERS: &'static [&'static str] = &[
+        "eviction_policy",
+        "log_level",
+        "max_collection_elements",
+        "max_key_length",
+        "max_memory",
+        "max_value_size",
+        "namespaces",
+        "pubsub_enabled",
+        "sessions_active_max",
+        "slowlog_threshold",
+    ];
+
+    /// Retrieve the value of a parameter by name, formatted as it's accepted
+    /// by [`set_parameter`].
+    ///
+    /// Limits that aren't set are formatted as `none`. Returns `None` if the
+    /// parameter doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::hop::Builder;
+    ///
+    /// let mut builder = Builder::new();
+    /// builder.max_key_length(64);
+    /// let hop = builder.build();
+    ///
+    /// assert_eq!(Some("64".to_owned()), hop.config().parameter("max_key_length"));
+    /// assert_eq!(Some("none".to_owned()), hop.config().parameter("max_memory"));
+    /// ```
+    ///
+    /// [`set_parameter`]: #method.set_parameter
+    pub fn parameter(&self, name: &str) -> Option<String> {
+        fn limit(value: Option<impl ToString>) -> String {
+            value.map_or_else(|| "none".to_owned(), |value| value.to_string())
+        }
+
+        let unlimited = |value: usize| Some(value).filter(|value| *value != usize::MAX);
+
+        Some(match name {
+            "eviction_policy" => self.eviction_policy().name().to_owned(),
+            #[cfg(feature = "log")]
+            "log_level" => log::max_level().to_string().to_lowercase(),
+            "max_collection_elements" => limit(unlimited(self.max_collection_elements())),
+            "max_key_length" => limit(unlimited(self.max_key_length())),
+            "max_memory" => limit(self.max_memory()),
+            "max_value_size" => limit(unlimited(self.max_value_size())),
+            "namespaces" => self.namespaces.to_string(),
+            "pubsub_enabled" => self.pubsub_enabled.to_string(),
+            "sessions_active_max" => limit(unlimited(self.sessions_active_max())),
+            "slowlog_threshold" => limit(self.slowlog_threshold()),
+            _ => return None,
+        })
+    }
+
+    /// Change the value of a parameter by name.
+    ///
+    /// Limits are numbers, or `none` to remove the limit. Returns
+    /// [`DispatchError::ConfigInvalid`] if the parameter doesn't exist, can't
+    /// be changed while the engine is running, or the value isn't valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::{command::DispatchError, Hop};
+    ///
+    /// let hop = Hop::new();
+    /// assert!(hop.config().set_parameter("max_memory", "1024").is_ok());
+    /// assert_eq!(Some(1024), hop.config().max_memory());
+    ///
+    /// assert_eq!(
+    ///     Err(DispatchError::ConfigInvalid),
+    ///     hop.config().set_parameter("namespaces", "4"),
+    /// );
+    /// ```
+    ///
+    /// [`DispatchError::ConfigInvalid`]: ../command/enum.DispatchError.html#variant.ConfigInvalid
+    pub fn set_parameter(&self, name: &str, value: &str) -> DispatchResult<()> {
+        fn limit<T: FromStr>(value: &str) -> DispatchResult<Option<T>> {
+            match value {
+                "none" => Ok(None),
+                value => value
+                    .parse()
+                    .map(Some)
+                    .map_err(|_| DispatchError::ConfigInvalid),
+            }
+        }
+
+        match name {
+            "eviction_policy" => self.set_eviction_policy(
+                EvictionPolicy::from_str(value).map_err(|_| DispatchError::ConfigInvalid)?,
+            ),
+            #[cfg(feature = "log")]
+            "log_level" => log::set_max_level(
+                log::LevelFilter::from_str(value).map_err(|_| DispatchError::ConfigInvalid)?,
+            ),
+            "max_collection_elements" => {
+                self.set_max_collection_elements(limit(value)?.unwrap_or(usize::MAX))
+            }
+            "max_key_length" => self.set_max_key_length(limit(value)?.unwrap_or(usize::MAX)),
+            "max_memory" => self.set_max_memory(limit(value)?),
+            "max_value_size" => self.set_max_value_size(limit(value)?.unwrap_or(usize::MAX)),
+            // No new sessions could ever start, including one to raise it.
+            "sessions_active_max" => match limit(value)? {
+                Some(0) => return Err(DispatchError::ConfigInvalid),
+                max => self.set_sessions_active_max(max.unwrap_or(usize::MAX)),
+            },
+            "slowlog_threshold" => self.set_slowlog_threshold(limit(value)?),
+            _ => return Err(DispatchError::ConfigInvalid),
+        }
+
+        Ok(())
+    }
+
+    /// Retrieve the function used to get the current Unix timestamp in
+    /// milliseconds.
+    pub fn clock(&self) -> fn() -> u64 {
+        self.clock
+    }
+
+    /// Retrieve the function used to drop the keys removed by asynchronous
+    /// flushes.
+    pub fn drop_background(&self) -> fn(Vec<(Key, Value)>) {
+        self.drop_background
+    }
+
+    /// Retrieve the policy deciding which keys to remove once the memory limit
+    /// has been reached.
+    pub fn eviction_policy(&self) -> EvictionPolicy {
+        let policy = self.eviction_policy.load(Ordering::Relaxed);
+
+        EvictionPolicy::ALL
+            .iter()
+            .copied()
+            .find(|candidate| *candidate as u8 == policy)
+            .unwrap_or_default()
+    }
+
+    /// Change the policy deciding which keys to remove once the memory limit
+    /// has been reached.
+    pub fn set_eviction_policy(&self, eviction_policy: EvictionPolicy) {
+        self.eviction_policy
+            .store(eviction_policy as u8, Ordering::Relaxed);
+    }
+
+    /// Retrieve the maximum number of elements in a list, map, set, stream or
+    /// document array.
+    pub fn max_collection_elements(&self) -> usize {
+        self.max_collection_elements.load(Ordering::Relaxed)
+    }
+
+    /// Change the maximum number of elements in a list, map, set, stream or
+    /// document array.
+    ///
+    /// Existing collections over the new limit are left as they are, but
+    /// can't grow.
+    pub fn set_max_collection_elements(&self, max_collection_elements: usize) {
+        self.max_collection_elements
+            .store(max_collection_elements, Ordering::Relaxed);
+    }
+
+    /// Retrieve the maximum length of a key in bytes.
+    pub fn max_key_length(&self) -> usize {
+        self.max_key_length.load(Ordering::Relaxed)
+    }
+
+    /// Change the maximum length of a key in bytes.
+    pub fn set_max_key_length(&self, max_key_length: usize) {
+        self.max_key_length.store(max_key_length, Ordering::Relaxed);
+    }
+
+    /// Retrieve the estimated number of bytes of memory that keys and values
+    /// can use, if limited.
+    pub fn max_memory(&self) -> Option<usize> {
+        match self.max_memory.load(Ordering::Relaxed) {
+            usize::MAX => None,
+            max_memory => Some(max_memory),
+        }
+    }
+
+    /// Change the estimated number of bytes of memory that keys and values
+    /// can use, or remove the limit.
+    ///
+    /// If the memory used is over a lowered limit then keys are evicted
+    /// before the next command.
+    pub fn set_max_memory(&self, max_memory: Option<usize>) {
+        self.max_memory
+            .store(max_memory.unwrap_or(usize::MAX), Ordering::Relaxed);
+    }
+
+    /// Retrieve the maximum size in bytes of a bytes or string value, or of
+    /// an element of a collection.
+    pub fn max_value_size(&self) -> usize {
+        self.max_value_size.load(Ordering::Relaxed)
+    }
+
+    /// Change the maximum size in bytes of a bytes or string value, or of an
+    /// element of a collection.
+    pub fn set_max_value_size(&self, max_value_size: usize) {
+        self.max_value_size.store(max_value_size, Ordering::Relaxed);
+    }
+
+    /// Retrieve the number of milliseconds a command must take for it to be
+    /// logged as slow, if slow commands are logged.
+    pub fn slowlog_threshold(&self) -> Option<u64> {
+        match self.slowlog_threshold.load(Ordering::Relaxed) {
+            u64::MAX => None,
+            threshold => Some(threshold),
+        }
+    }
+
+    /// Change the number of milliseconds a command must take for it to be
+    /// logged as slow, or stop logging slow commands.
+    pub fn set_slowlog_threshold(&self, slowlog_threshold: Option<u64>) {
+        self.slowlog_threshold
+            .store(slowlog_threshold.unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    /// Check that a collection can hold a number of elements.
+    pub(crate) fn check_elements(&self, count: usize) -> DispatchResult<()> {
+        if count > self.max_collection_elements() {
+            return Err(DispatchError::SizeLimitExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Check that a key is within the maximum key length.
+    pub(crate) fn check_key_length(&self, key: &[u8]) -> DispatchResult<()> {
+        if key.len() > self.max_key_length() {
+            return Err(DispatchError::SizeLimitExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Check that a value or element has an allowed size in bytes.
+    pub(crate) fn check_value_size(&self, size: usize) -> DispatchResult<()> {
+        if size > self.max_value_size() {
+            return Err(DispatchError::SizeLimitExceeded);
+        }
+
+        Ok(())
+    }
+
+    /// Check that a collection can hold elements of some sizes.
+    pub(crate) fn check_collection(
+        &self,
+        count: usize,
+        sizes: impl IntoIterator<Item = usize>,
+    ) -> DispatchResult<()> {
+        self.check_elements(count)?;
+
+        sizes
+            .into_iter()
+            .try_for_each(|size| self.check_value_size(size))
+    }
+
+    /// Check that an entire value is within the size limits, such as one that
+    /// was created elsewhere and is about to be inserted.
+    pub(crate) fn check_value(&self, value: &Value) -> DispatchResult<()> {
+        match value {
+            Value::Boolean(_) | Value::Float(_) | Value::HyperLogLog(_) | Value::Integer(_) => {
+                Ok(())
+            }
+            Value::Bytes(bytes) => self.check_value_size(bytes.len()),
+            Value::Document(document) => self.check_value_size(document.as_bytes().len()),
+            Value::List(list) => self.check_collection(list.len(), list.iter().map(Vec::len)),
+            Value::Map(map) => self.check_collection(
+                map.len(),
+                map.iter()
+                    .flat_map(|entry| [entry.key().len(), entry.value().len()]),
+            ),
+            Value::Set(set) => {
+                self.check_collection(set.len(), set.iter().map(|element| element.len()))
+            }
+            Value::Stream(stream) => self.check_collection(
+                stream.len(),
+                stream
+                    .entries()
+                    .flat_map(|entry| entry.fields())
+                    .flat_map(|(field, value)| [field.len(), value.len()]),
+            ),
+            Value::String(string) => self.check_value_size(string.len()),
+        }
+    }
+
+    /// Retrieve the number of namespaces that sessions can select.
+    pub fn namespaces(&self) -> usize {
+        self.namespaces
+    }
+
+    /// Retrieve the password that sessions must authenticate with before
+    /// running commands, if one is required.
+    pub fn password(&self) -> Option<&[u8]> {
+        self.password.as_ref().map(|password| password.0.as_slice())
+    }
+
     /// Retrieve whether pubsub is enabled.
     pub fn pubsub_enabled(&self) -> bool {
         self.pubsub_enabled
@@ -40,19 +446,69 @@ impl Config {
     /// Retrieve the maximum number of active sessions that are allowed at a
     /// time.
     pub fn sessions_active_max(&self) -> usize {
+        self.sessions_active_max.load(Ordering::Relaxed)
+    }
+
+    /// Change the maximum number of active sessions that are allowed at a
+    /// time.
+    ///
+    /// Lowering the limit below the number of active sessions doesn't end
+    /// any of them, but no new sessions can start until enough have ended.
+    pub fn set_sessions_active_max(&self, sessions_active_max: usize) {
         self.sessions_active_max
+            .store(sessions_active_max, Ordering::Relaxed);
+    }
+}
+
+impl Clone for Config {
+    fn clone(&self) -> Self {
+        Self {
+            clock: self.clock,
+            drop_background: self.drop_background,
+            eviction_policy: AtomicU8::new(self.eviction_policy.load(Ordering::Relaxed)),
+            max_collection_elements: AtomicUsize::new(self.max_collection_elements()),
+            max_key_length: AtomicUsize::new(self.max_key_length()),
+            max_memory: AtomicUsize::new(self.max_memory.load(Ordering::Relaxed)),
+            max_value_size: AtomicUsize::new(self.max_value_size()),
+            namespaces: self.namespaces,
+            password: self.password.clone(),
+            pubsub_enabled: self.pubsub_enabled,
+            sessions_active_max: AtomicUsize::new(self.sessions_active_max()),
+            slowlog_threshold: AtomicU64::new(self.slowlog_threshold.load(Ordering::Relaxed)),
+        }
     }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            clock: || 0,
+            drop_background: drop,
+            eviction_policy: AtomicU8::new(EvictionPolicy::default() as u8),
+            max_collection_elements: AtomicUsize::new(usize::MAX),
+            max_key_length: AtomicUsize::new(usize::MAX),
+            max_memory: AtomicUsize::new(usize::MAX),
+            max_value_size: AtomicUsize::new(usize::MAX),
+            namespaces: 16,
+            password: None,
             pubsub_enabled: true,
-            sessions_active_max: usize::MAX,
+            sessions_active_max: AtomicUsize::new(usize::MAX),
+            slowlog_threshold: AtomicU64::new(u64::MAX),
         }
     }
 }
 
+/// A password required to authenticate, which is redacted when debug formatted
+/// so that it doesn't end up in logs.
+#[derive(Clone)]
+struct Password(Vec<u8>);
+
+impl Debug for Password {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Password(..)")
+    }
+}
+
 /// A builder to construct a configured [`Hop`] engine instance.
 ///
 /// Refer to each method for its default value.
@@ -90,6 +546,131 @@ impl Builder {
         self.into()
     }
 
+    /// Set the function used to get the current Unix timestamp in
+    /// milliseconds, such as for the IDs of stream entries.
+    ///
+    /// The engine doesn't depend on the standard library, so it can't read the
+    /// system clock itself. By default the time is always 0, in which case
+    /// stream entry IDs only increase by their sequence.
+    pub fn clock(&mut self, clock: fn() -> u64) -> &mut Self {
+        self.0.clock = clock;
+
+        self
+    }
+
+    /// Set the function used to drop the keys removed by asynchronous flushes.
+    ///
+    /// Dropping a very large number of keys can take a while, so asynchronous
+    /// flushes remove the keys and then hand them to this function to drop
+    /// them off of the request path. The engine can't spawn threads itself, so
+    /// by default the keys are dropped immediately.
+    pub fn drop_background(&mut self, drop_background: fn(Vec<(Key, Value)>)) -> &mut Self {
+        self.0.drop_background = drop_background;
+
+        self
+    }
+
+    /// Set the policy deciding which keys to remove once the memory limit has
+    /// been reached.
+    ///
+    /// This has no effect unless a limit is set with [`max_memory`].
+    ///
+    /// By default this is [`EvictionPolicy::NoEviction`].
+    ///
+    /// [`EvictionPolicy::NoEviction`]: enum.EvictionPolicy.html#variant.NoEviction
+    /// [`max_memory`]: #method.max_memory
+    pub fn eviction_policy(&mut self, eviction_policy: EvictionPolicy) -> &mut Self {
+        self.0.set_eviction_policy(eviction_policy);
+
+        self
+    }
+
+    /// Set the maximum number of elements in a list, map, set, stream or
+    /// document array.
+    ///
+    /// Commands that would go over the limit fail with
+    /// [`DispatchError::SizeLimitExceeded`] and leave the key unchanged.
+    ///
+    /// By default the number of elements isn't limited.
+    ///
+    /// [`DispatchError::SizeLimitExceeded`]: ../command/enum.DispatchError.html#variant.SizeLimitExceeded
+    pub fn max_collection_elements(&mut self, max_collection_elements: usize) -> &mut Self {
+        self.0.set_max_collection_elements(max_collection_elements);
+
+        self
+    }
+
+    /// Set the maximum length of a key in bytes.
+    ///
+    /// Commands that could create a key longer than this fail with
+    /// [`DispatchError::SizeLimitExceeded`].
+    ///
+    /// By default the length of keys isn't limited.
+    ///
+    /// [`DispatchError::SizeLimitExceeded`]: ../command/enum.DispatchError.html#variant.SizeLimitExceeded
+    pub fn max_key_length(&mut self, max_key_length: usize) -> &mut Self {
+        self.0.set_max_key_length(max_key_length);
+
+        self
+    }
+
+    /// Set the maximum size in bytes of a bytes or string value, or of an
+    /// element of a collection.
+    ///
+    /// Commands that would go over the limit fail with
+    /// [`DispatchError::SizeLimitExceeded`] and leave the key unchanged.
+    ///
+    /// By default the size of values isn't limited.
+    ///
+    /// [`DispatchError::SizeLimitExceeded`]: ../command/enum.DispatchError.html#variant.SizeLimitExceeded
+    pub fn max_value_size(&mut self, max_value_size: usize) -> &mut Self {
+        self.0.set_max_value_size(max_value_size);
+
+        self
+    }
+
+    /// Set the estimated number of bytes of memory that keys and values of
+    /// every namespace can use together.
+    ///
+    /// Before each command the engine checks whether the memory used is over
+    /// the limit, and if so removes keys according to the [`eviction_policy`]
+    /// until it isn't. A single command may go over the limit, in which case
+    /// keys are removed before the next command. Memory usage is an estimate,
+    /// so the actual memory used by the process will be somewhat higher.
+    ///
+    /// By default memory usage isn't limited.
+    ///
+    /// [`eviction_policy`]: #method.eviction_policy
+    pub fn max_memory(&mut self, max_memory: usize) -> &mut Self {
+        self.0.set_max_memory(Some(max_memory));
+
+        self
+    }
+
+    /// Set the number of namespaces that sessions can select.
+    ///
+    /// Each namespace has its own independent set of keys, so that multiple
+    /// services can share one engine without their keys colliding. Namespaces
+    /// are numbered from 0, which is the namespace selected by default. At
+    /// least one namespace always exists.
+    ///
+    /// By default this is 16.
+    pub fn namespaces(&mut self, namespaces: usize) -> &mut Self {
+        self.0.namespaces = namespaces.max(1);
+
+        self
+    }
+
+    /// Set the password or token that sessions must authenticate with using
+    /// the auth command before they can run any other command.
+    ///
+    /// By default no password is required.
+    pub fn password(&mut self, password: impl Into<Vec<u8>>) -> &mut Self {
+        self.0.password = Some(Password(password.into()));
+
+        self
+    }
+
     /// Set whether to enable pubsub.
     ///
     /// By default this is `true`.
@@ -99,11 +680,30 @@ impl Builder {
         self
     }
 
-    /// Set whether to enable pubsub.
+    /// Set the maximum number of active sessions that are allowed at a time.
     ///
-    /// By default this is the maximum usize value.
+    /// Sessions over the limit fail to start, so servers close their
+    /// connections.
+    ///
+    /// By default the number of sessions isn't limited.
     pub fn sessions_active_max(&mut self, sessions_active_max: usize) -> &mut Self {
-        self.0.sessions_active_max = sessions_active_max;
+        self.0.set_sessions_active_max(sessions_active_max);
+
+        self
+    }
+
+    /// Set the number of milliseconds a command must take for it to be logged
+    /// as slow.
+    ///
+    /// Slow commands are logged as warnings, which requires the `log`
+    /// feature. Durations are measured with the [`clock`], so a clock must be
+    /// set for any commands to be logged.
+    ///
+    /// By default slow commands aren't logged.
+    ///
+    /// [`clock`]: #method.clock
+    pub fn slowlog_threshold(&mut self, slowlog_threshold: u64) -> &mut Self {
+        self.0.set_slowlog_threshold(Some(slowlog_threshold));
 
         self
     }
@@ -111,44 +711,98 @@ impl Builder {
 
 impl From<Builder> for Hop {
     fn from(builder: Builder) -> Self {
-        Self(Arc::new(HopRef {
-            config: builder.0,
-            ..Default::default()
-        }))
+        Self(Arc::new(HopRef::new(builder.0)), HandleState::default())
     }
 }
 
 #[derive(Debug)]
 pub(crate) struct HopRef {
+    acl: Acl,
     config: Config,
     metrics: Metrics,
     pub(crate) metrics_writer: Writer,
     pubsub: PubSubManager,
+    namespaces: Vec<State>,
+    /// The state of the generator of random numbers used for eviction.
+    seed: AtomicUsize,
     sessions: SessionManager,
-    state: State,
+    pub(crate) stream_sender: StateSender<()>,
+    stream_receiver: StateReceiver<()>,
 }
 
-impl Default for HopRef {
-    fn default() -> Self {
+impl HopRef {
+    fn new(config: Config) -> Self {
         let metrics = Metrics::default();
         let writer = metrics.writer();
+        let (stream_sender, stream_receiver) = shared::state_broadcast_channel();
+        // Send an initial state so that receivers can always retrieve the
+        // current state ID without waiting.
+        let _ = stream_sender.send(());
+
+        let namespaces = (0..config.namespaces)
+            .map(|_| State::with_clock(config.clock))
+            .collect();
 
         Self {
-            config: Config::default(),
+            acl: Acl::new(config.password()),
+            config,
             metrics,
             metrics_writer: writer.clone(),
+            namespaces,
             pubsub: PubSubManager::default(),
+            seed: AtomicUsize::new(0),
             sessions: SessionManager::new(writer),
-            state: State::default(),
+            stream_sender,
+            stream_receiver,
+        }
+    }
+}
+
+impl Default for HopRef {
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
+
+/// The state of a single handle to the engine, which isn't shared with its
+/// clones.
+#[derive(Debug)]
+struct HandleState {
+    /// The number of the selected namespace.
+    namespace: AtomicUsize,
+    /// The ID of the authenticated user, or 0 if the handle hasn't
+    /// authenticated.
+    user: AtomicUsize,
+    /// The ID of the session the handle belongs to, or `Hop::NO_SESSION` if
+    /// it hasn't started one.
+    session: AtomicU64,
+}
+
+impl Default for HandleState {
+    fn default() -> Self {
+        Self {
+            namespace: AtomicUsize::new(0),
+            user: AtomicUsize::new(0),
+            session: AtomicU64::new(Hop::NO_SESSION),
         }
     }
 }
 
 /// The hop engine.
-#[derive(Clone, Debug, Default)]
-pub struct Hop(pub(crate) Arc<HopRef>);
+///
+/// Cloning an instance is cheap and results in a handle to the same engine.
+/// Each handle has its own selected namespace, authenticated user and
+/// session, so servers should give each session its own clone.
+#[derive(Debug)]
+pub struct Hop(pub(crate) Arc<HopRef>, HandleState);
 
 impl Hop {
+    /// The number of keys sampled when choosing a key to evict.
+    const EVICTION_SAMPLES: usize = 5;
+
+    /// The session ID stored by handles that haven't started a session.
+    const NO_SESSION: u64 = u64::MAX;
+
     /// Create a new instance of the engine using the default configuration.
     ///
     /// Refer to the [`builder`] method if you want to customise the engine.
@@ -168,7 +822,50 @@ impl Hop {
     /// Dispatch a request to the engine, providing a response to write the
     /// response to on success.
     pub fn dispatch(&self, req: &Request, res: &mut Vec<u8>) -> DispatchResult<()> {
-        let res = match req.command_id() {
+        if let Some(session) = self.session().and_then(|id| self.0.sessions.get(id)) {
+            session.touch((self.0.config.clock)());
+        }
+
+        #[cfg(feature = "log")]
+        let started = self
+            .0
+            .config
+            .slowlog_threshold()
+            .map(|threshold| (threshold, (self.0.config.clock)()));
+
+        let res = self
+            .enforce_permitted(req)
+            .and_then(|()| self.enforce_key_length(req))
+            .and_then(|()| self.enforce_memory_limit(req.command_id()))
+            .and_then(|()| self.dispatch_command(req, res));
+
+        self.0.metrics_writer.increment(if res.is_ok() {
+            Metric::CommandsSuccessful
+        } else {
+            Metric::CommandsErrored
+        });
+        self.refresh_memory_used();
+
+        #[cfg(feature = "log")]
+        {
+            if let Some((threshold, started)) = started {
+                let elapsed = (self.0.config.clock)().saturating_sub(started);
+
+                if elapsed >= threshold {
+                    log::warn!(
+                        "Slow command {} took {} ms",
+                        req.command_id().name(),
+                        elapsed
+                    );
+                }
+            }
+        }
+
+        res
+    }
+
+    fn dispatch_command(&self, req: &Request, res: &mut Vec<u8>) -> DispatchResult<()> {
+        match req.command_id() {
             CommandId::Append => Append::dispatch(self, req, res),
             CommandId::DecrementBy => DecrementBy::dispatch(self, req, res),
             CommandId::Decrement => Decrement::dispatch(self, req, res),
@@ -185,15 +882,178 @@ impl Hop {
             CommandId::Stats => Stats::dispatch(self, req, res),
             CommandId::Type => Type::dispatch(self, req, res),
             CommandId::Length => Length::dispatch(self, req, res),
-        };
+            CommandId::Substring => Substring::dispatch(self, req, res),
+            CommandId::Slice => Slice::dispatch(self, req, res),
+            CommandId::SetRange => SetRange::dispatch(self, req, res),
+            CommandId::StartsWith => StartsWith::dispatch(self, req, res),
+            CommandId::EndsWith => EndsWith::dispatch(self, req, res),
+            CommandId::Uppercase => Uppercase::dispatch(self, req, res),
+            CommandId::Lowercase => Lowercase::dispatch(self, req, res),
+            CommandId::Split => Split::dispatch(self, req, res),
+            CommandId::BitAnd => BitAnd::dispatch(self, req, res),
+            CommandId::BitCount => BitCount::dispatch(self, req, res),
+            CommandId::BitGet => BitGet::dispatch(self, req, res),
+            CommandId::BitNot => BitNot::dispatch(self, req, res),
+            CommandId::BitOr => BitOr::dispatch(self, req, res),
+            CommandId::BitPosition => BitPosition::dispatch(self, req, res),
+            CommandId::BitSet => BitSet::dispatch(self, req, res),
+            CommandId::BitXor => BitXor::dispatch(self, req, res),
+            CommandId::Clamp => Clamp::dispatch(self, req, res),
+            CommandId::Divide => Divide::dispatch(self, req, res),
+            CommandId::Modulo => Modulo::dispatch(self, req, res),
+            CommandId::Multiply => Multiply::dispatch(self, req, res),
+            CommandId::SetIfGreater => SetIfGreater::dispatch(self, req, res),
+            CommandId::SetIfLess => SetIfLess::dispatch(self, req, res),
+            CommandId::HyperLogLogAdd => HyperLogLogAdd::dispatch(self, req, res),
+            CommandId::HyperLogLogCount => HyperLogLogCount::dispatch(self, req, res),
+            CommandId::HyperLogLogMerge => HyperLogLogMerge::dispatch(self, req, res),
+            CommandId::StreamAppend => StreamAppend::dispatch(self, req, res),
+            CommandId::StreamRange => StreamRange::dispatch(self, req, res),
+            CommandId::StreamRead => StreamRead::dispatch(self, req, res),
+            CommandId::StreamTrim => StreamTrim::dispatch(self, req, res),
+            CommandId::StreamTrimAge => StreamTrimAge::dispatch(self, req, res),
+            CommandId::DocumentAppend => DocumentAppend::dispatch(self, req, res),
+            CommandId::DocumentDelete => DocumentDelete::dispatch(self, req, res),
+            CommandId::DocumentGet => DocumentGet::dispatch(self, req, res),
+            CommandId::DocumentIncrement => DocumentIncrement::dispatch(self, req, res),
+            CommandId::DocumentSet => DocumentSet::dispatch(self, req, res),
+            CommandId::Scan => Scan::dispatch(self, req, res),
+            CommandId::Select => Select::dispatch(self, req, res),
+            CommandId::Flush => Flush::dispatch(self, req, res),
+            CommandId::FlushAll => FlushAll::dispatch(self, req, res),
+            CommandId::FlushPrefix => FlushPrefix::dispatch(self, req, res),
+            CommandId::Copy => Copy::dispatch(self, req, res),
+            CommandId::MemoryUsage => MemoryUsage::dispatch(self, req, res),
+            CommandId::ObjectInfo => ObjectInfo::dispatch(self, req, res),
+            CommandId::Dump => Dump::dispatch(self, req, res),
+            CommandId::Restore => Restore::dispatch(self, req, res),
+            CommandId::Auth => Auth::dispatch(self, req, res),
+            CommandId::AclDelete => AclDelete::dispatch(self, req, res),
+            CommandId::AclList => AclList::dispatch(self, req, res),
+            CommandId::AclSet => AclSet::dispatch(self, req, res),
+            CommandId::ConfigGet => ConfigGet::dispatch(self, req, res),
+            CommandId::ConfigSet => ConfigSet::dispatch(self, req, res),
+            CommandId::ClientKill => ClientKill::dispatch(self, req, res),
+            CommandId::ClientList => ClientList::dispatch(self, req, res),
+        }
+    }
 
-        self.0.metrics_writer.increment(if res.is_ok() {
-            Metric::CommandsSuccessful
+    /// Check that the handle's user is permitted to run the command on its
+    /// keys, unless the command is the one used to authenticate.
+    fn enforce_permitted(&self, req: &Request) -> DispatchResult<()> {
+        if req.command_id() == CommandId::Auth {
+            return Ok(());
+        }
+
+        let user = self.user().ok_or(DispatchError::Unauthenticated)?;
+
+        if user.permits_command(req.command_id()) && req.keys().all(|key| user.permits_key(key)) {
+            Ok(())
         } else {
-            Metric::CommandsErrored
-        });
+            Err(DispatchError::PermissionDenied)
+        }
+    }
 
-        res
+    /// Check that the keys of a command that may create keys are within the
+    /// maximum key length.
+    fn enforce_key_length(&self, req: &Request) -> DispatchResult<()> {
+        let command_id = req.command_id();
+
+        if !command_id.allocates() && command_id != CommandId::Rename {
+            return Ok(());
+        }
+
+        req.keys()
+            .try_for_each(|key| self.0.config.check_key_length(key))
+    }
+
+    /// Evict keys until the memory used is within the configured limit.
+    ///
+    /// Returns an error if no more keys can be evicted and the command may
+    /// use more memory.
+    fn enforce_memory_limit(&self, command_id: CommandId) -> DispatchResult<()> {
+        let max_memory = match self.0.config.max_memory() {
+            Some(max_memory) => max_memory,
+            None => return Ok(()),
+        };
+
+        while self.memory_used() > max_memory {
+            if !self.evict() {
+                if command_id.allocates() {
+                    return Err(DispatchError::MemoryLimitReached);
+                }
+
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evict a key from the namespace using the most memory, chosen according
+    /// to the eviction policy.
+    ///
+    /// Returns whether there was a key to evict.
+    fn evict(&self) -> bool {
+        let policy = self.0.config.eviction_policy();
+
+        let (namespace, state) = match self
+            .namespaces()
+            .enumerate()
+            .max_by_key(|(_, state)| state.memory_used())
+        {
+            Some(namespace) => namespace,
+            None => return false,
+        };
+
+        let samples = match policy {
+            EvictionPolicy::NoEviction => return false,
+            EvictionPolicy::Random => state.sample(self.random(), 1),
+            _ => state.sample(self.random(), Self::EVICTION_SAMPLES),
+        };
+
+        let candidate = match policy {
+            EvictionPolicy::LeastFrequentlyUsed => samples
+                .into_iter()
+                .min_by_key(|sample| (sample.frequency, sample.accessed)),
+            _ => samples.into_iter().min_by_key(|sample| sample.accessed),
+        };
+
+        let candidate = match candidate {
+            Some(candidate) => candidate,
+            None => return false,
+        };
+
+        // The key may have been removed since it was sampled, in which case
+        // memory was freed anyway.
+        if let Some((key, value)) = state.remove(&candidate.key) {
+            self.0.metrics_writer.increment(Metric::KeysEvicted);
+
+            if self.0.config.pubsub_enabled {
+                self.0
+                    .pubsub
+                    .publish(namespace, &key, || KeyUpdate::Deleted(value));
+            }
+        }
+
+        true
+    }
+
+    /// Generate a pseudo-random number.
+    ///
+    /// This only needs to spread eviction samples over the keys, so it's
+    /// neither cryptographically secure nor very uniform.
+    fn random(&self) -> usize {
+        const INCREMENT: usize = 0x9e37_79b9;
+
+        let seed = self
+            .0
+            .seed
+            .fetch_add(INCREMENT, Ordering::Relaxed)
+            .wrapping_add(INCREMENT);
+        let mixed = (seed ^ (seed >> 16)).wrapping_mul(0x045d_9f3b);
+
+        mixed ^ (mixed >> 16)
     }
 
     /// Return an immutable reference to the configuration.
@@ -201,34 +1061,470 @@ impl Hop {
         &self.0.config
     }
 
+    /// Return an immutable reference to the access control list of users.
+    pub fn acl(&self) -> &Acl {
+        &self.0.acl
+    }
+
+    /// Authenticate this handle as a user with a password.
+    ///
+    /// Returns whether the user exists and the password is correct. The
+    /// handle's user is left unchanged if not.
+    pub fn authenticate(&self, username: &str, password: &[u8]) -> bool {
+        match self.0.acl.authenticate(username, password) {
+            Some(id) => {
+                self.1.user.store(id, Ordering::Relaxed);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Return whether this handle can run commands, which is when it has
+    /// authenticated as a user that still exists or the default user doesn't
+    /// require a password.
+    pub fn authenticated(&self) -> bool {
+        self.user().is_some()
+    }
+
+    /// Retrieve the user this handle runs commands as.
+    ///
+    /// Handles that haven't authenticated use the default user if it doesn't
+    /// require a password.
+    pub(crate) fn user(&self) -> Option<Ref<'_, usize, User>> {
+        match self.1.user.load(Ordering::Relaxed) {
+            0 => self
+                .0
+                .acl
+                .user_by_id(Acl::DEFAULT_ID)
+                .filter(|user| user.nopass()),
+            id => self.0.acl.user_by_id(id),
+        }
+    }
+
     /// Return a new reader to read metrics from.
     pub fn metrics(&self) -> Reader {
         self.0.metrics.reader()
     }
 
+    /// Return the estimated number of bytes of memory used by the keys and
+    /// values of every namespace.
+    pub fn memory_used(&self) -> usize {
+        self.namespaces().map(State::memory_used).sum()
+    }
+
+    /// Set the gauge of the memory used to its current value.
+    pub(crate) fn refresh_memory_used(&self) {
+        let used = u64::try_from(self.memory_used()).unwrap_or(u64::MAX);
+
+        self.0.metrics_writer.gauge(Metric::MemoryUsed, used);
+    }
+
+    /// Return the number of the namespace selected by this handle.
+    pub fn namespace(&self) -> usize {
+        self.1.namespace.load(Ordering::Relaxed)
+    }
+
+    /// Return an iterator over the states of every namespace, in order of
+    /// their number.
+    pub fn namespaces(&self) -> impl Iterator<Item = &State> {
+        self.0.namespaces.iter()
+    }
+
+    /// Select the namespace this handle works with.
+    ///
+    /// Returns whether the namespace exists. The selected namespace is left
+    /// unchanged if it doesn't.
+    pub fn select(&self, namespace: usize) -> bool {
+        if namespace >= self.0.namespaces.len() {
+            return false;
+        }
+
+        self.1.namespace.store(namespace, Ordering::Relaxed);
+
+        true
+    }
+
     /// Return an immutable reference to the pubsub manager.
     pub fn pubsub(&self) -> &PubSubManager {
         &self.0.pubsub
     }
 
+    /// Return the ID of the session this handle belongs to, if it has started
+    /// one.
+    pub fn session(&self) -> Option<SessionId> {
+        u32::try_from(self.1.session.load(Ordering::Relaxed))
+            .ok()
+            .map(SessionId::new)
+    }
+
     /// Return an immutable reference to the session manager.
     pub fn sessions(&self) -> &SessionManager {
         &self.0.sessions
     }
 
-    /// Return an immutable reference to the state.
+    /// Start a session for this handle, such as when a client connects, so
+    /// that it's listed and can be killed.
+    ///
+    /// The peer is the address of the client, if it has one. Servers should
+    /// close the connection once the session's [`killed`] receiver is closed.
+    ///
+    /// Returns [`SessionLimitReached`] if there are already
+    /// [`sessions_active_max`] sessions, in which case servers should close
+    /// the connection.
+    ///
+    /// [`killed`]: ../session/struct.Session.html#method.killed
+    /// [`SessionLimitReached`]: ../session/struct.SessionLimitReached.html
+    /// [`sessions_active_max`]: struct.Config.html#method.sessions_active_max
+    pub fn start_session(&self, peer: Option<String>) -> Result<SessionId, SessionLimitReached> {
+        let id = self.0.sessions.add(
+            peer,
+            (self.0.config.clock)(),
+            self.0.config.sessions_active_max(),
+        )?;
+        self.1.session.store(u64::from(id.get()), Ordering::Relaxed);
+
+        Ok(id)
+    }
+
+    /// End the session of this handle, such as once its client has
+    /// disconnected, removing its pubsub subscriptions.
+    ///
+    /// Returns whether the handle had started a session.
+    pub fn end_session(&self) -> bool {
+        let id = self.1.session.swap(Self::NO_SESSION, Ordering::Relaxed);
+
+        match u32::try_from(id).ok().map(SessionId::new) {
+            Some(id) => {
+                self.0.sessions.remove(id);
+                self.0.pubsub.remove_session(id);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Kill a session by its ID, so that its connection is closed, removing
+    /// its pubsub subscriptions.
+    ///
+    /// Returns whether the session existed.
+    pub fn kill_session(&self, id: SessionId) -> bool {
+        self.0.pubsub.remove_session(id);
+
+        self.0.sessions.kill(id)
+    }
+
+    /// Return a receiver that is notified whenever an entry is appended to any
+    /// stream.
+    ///
+    /// The receiver always has a state, so the current state ID can be
+    /// retrieved by calling `try_receive` with the initial state ID. To wait
+    /// for new entries, check the stream and then call `receive` with that
+    /// state ID.
+    pub fn stream_updates(&self) -> StateReceiver<()> {
+        self.0.stream_receiver.clone()
+    }
+
+    /// Return the number of milliseconds a request wants to wait before being
+    /// dispatched, if it is a blocking stream read with no entries to read
+    /// yet.
+    ///
+    /// The engine itself never waits. Servers supporting blocking reads can
+    /// wait on [`stream_updates`] for up to this long, checking again after
+    /// each update, and then dispatch the request.
+    ///
+    /// [`stream_updates`]: #method.stream_updates
+    pub fn stream_read_block(&self, req: &Request) -> Option<u64> {
+        let block = StreamRead::block(req)?;
+
+        if StreamRead::pending(self, req) {
+            Some(block)
+        } else {
+            None
+        }
+    }
+
+    /// Return an immutable reference to the state of the selected namespace.
     #[inline]
     pub fn state(&self) -> &State {
-        &self.0.state
+        &self.0.namespaces[self.namespace()]
+    }
+}
+
+impl Clone for Hop {
+    /// Create a new handle to the same engine with the same namespace
+    /// selected and authentication, which doesn't belong to a session.
+    fn clone(&self) -> Self {
+        let state = HandleState {
+            namespace: AtomicUsize::new(self.namespace()),
+            user: AtomicUsize::new(self.1.user.load(Ordering::Relaxed)),
+            session: AtomicU64::new(Self::NO_SESSION),
+        };
+
+        Self(Arc::clone(&self.0), state)
+    }
+}
+
+impl Default for Hop {
+    fn default() -> Self {
+        Builder::default().build()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Hop, HopRef};
-    use core::fmt::Debug;
+    use super::{EvictionPolicy, Hop, HopRef, Metric};
+    use crate::{
+        command::{request::RequestBuilder, CommandId, DispatchError, DispatchResult},
+        session::SessionLimitReached,
+        state::{KeyType, Value},
+    };
+    use alloc::{borrow::ToOwned, vec::Vec};
+    use core::{
+        fmt::Debug,
+        sync::atomic::{AtomicU64, Ordering},
+    };
     use static_assertions::assert_impl_all;
 
-    assert_impl_all!(Hop: Clone, Debug, Default);
+    assert_impl_all!(Hop: Clone, Debug, Default, Send, Sync);
     assert_impl_all!(HopRef: Debug);
+
+    #[test]
+    fn test_namespaces() {
+        let mut builder = Hop::builder();
+        builder.namespaces(2);
+        let hop = builder.build();
+        hop.state().insert(b"foo".to_vec(), Value::Integer(1));
+
+        let session = hop.clone();
+        assert!(session.select(1));
+        assert!(!session.state().contains_key(b"foo"));
+        assert!(hop.state().contains_key(b"foo"));
+        assert_eq!(1, session.clone().namespace());
+
+        assert!(!session.select(2));
+        assert_eq!(1, session.namespace());
+        assert_eq!(2, hop.namespaces().count());
+    }
+
+    #[test]
+    fn test_sessions() {
+        static NOW: AtomicU64 = AtomicU64::new(1000);
+
+        let mut builder = Hop::builder();
+        builder.clock(|| NOW.load(Ordering::Relaxed));
+        let hop = builder.build();
+
+        let session = hop.clone();
+        assert!(session.session().is_none());
+        let id = session
+            .start_session(Some("127.0.0.1:1234".to_owned()))
+            .unwrap();
+        assert_eq!(Some(id), session.session());
+        assert!(session.clone().session().is_none());
+        assert!(hop.pubsub().subscribe(0, b"foo".to_vec(), id).is_some());
+
+        NOW.store(1500, Ordering::Relaxed);
+        assert!(set(&session, b"foo").is_ok());
+        let info = hop.sessions().get(id).unwrap();
+        assert_eq!(1000, info.connected_at());
+        assert_eq!(1500, info.last_active());
+        drop(info);
+
+        assert!(session.end_session());
+        assert!(!session.end_session());
+        assert!(hop.sessions().is_empty());
+        assert_eq!(0, hop.pubsub().subscriptions(id));
+    }
+
+    #[test]
+    fn test_sessions_active_max() {
+        let mut builder = Hop::builder();
+        builder.sessions_active_max(1);
+        let hop = builder.build();
+
+        let first = hop.clone();
+        assert!(first.start_session(None).is_ok());
+        let second = hop.clone();
+        assert_eq!(Err(SessionLimitReached), second.start_session(None));
+        assert!(second.session().is_none());
+
+        assert!(first.end_session());
+        assert!(second.start_session(None).is_ok());
+    }
+
+    fn hop_with_limit(policy: EvictionPolicy) -> Hop {
+        let mut builder = Hop::builder();
+        builder.eviction_policy(policy).max_memory(3500);
+
+        builder.build()
+    }
+
+    fn set(hop: &Hop, key: &[u8]) -> DispatchResult<()> {
+        let mut builder = RequestBuilder::new_with_key_type(CommandId::Set, KeyType::Bytes);
+        assert!(builder.bytes(key).is_ok());
+        assert!(builder.bytes([0; 1000].as_ref()).is_ok());
+
+        hop.dispatch(&builder.into_request(), &mut Vec::new())
+    }
+
+    fn get(hop: &Hop, key: &[u8]) -> DispatchResult<()> {
+        let mut builder = RequestBuilder::new(CommandId::Get);
+        assert!(builder.bytes(key).is_ok());
+
+        hop.dispatch(&builder.into_request(), &mut Vec::new())
+    }
+
+    #[test]
+    fn test_no_eviction() {
+        let hop = hop_with_limit(EvictionPolicy::NoEviction);
+
+        // The command that goes over the limit is still allowed.
+        for key in &[b"a", b"b", b"c", b"d"] {
+            assert!(set(&hop, *key).is_ok());
+        }
+
+        assert_eq!(Err(DispatchError::MemoryLimitReached), set(&hop, b"e"));
+        assert!(get(&hop, b"a").is_ok());
+
+        let mut builder = RequestBuilder::new(CommandId::Delete);
+        assert!(builder.bytes(b"a".as_ref()).is_ok());
+        assert!(hop
+            .dispatch(&builder.into_request(), &mut Vec::new())
+            .is_ok());
+        assert!(set(&hop, b"e").is_ok());
+    }
+
+    #[test]
+    fn test_eviction_least_recently_used() {
+        let hop = hop_with_limit(EvictionPolicy::LeastRecentlyUsed);
+
+        for key in &[b"a", b"b", b"c"] {
+            assert!(set(&hop, *key).is_ok());
+        }
+
+        assert!(get(&hop, b"a").is_ok());
+        assert!(set(&hop, b"d").is_ok());
+        // Keys are evicted before the next command.
+        assert!(get(&hop, b"d").is_ok());
+        assert!(hop.state().contains_key(b"a"));
+        assert!(!hop.state().contains_key(b"b"));
+        assert!(hop.memory_used() <= 3500 + 1100);
+    }
+
+    #[test]
+    fn test_eviction_least_frequently_used() {
+        let hop = hop_with_limit(EvictionPolicy::LeastFrequentlyUsed);
+
+        for key in &[b"a", b"b", b"c"] {
+            assert!(set(&hop, *key).is_ok());
+        }
+
+        for key in &[b"a", b"b", b"a", b"b"] {
+            assert!(get(&hop, *key).is_ok());
+        }
+
+        assert!(set(&hop, b"d").is_ok());
+        // Keys are evicted before the next command.
+        assert!(get(&hop, b"d").is_ok());
+        assert!(hop.state().contains_key(b"a"));
+        assert!(hop.state().contains_key(b"b"));
+        assert!(!hop.state().contains_key(b"c"));
+        assert_eq!(1, hop.metrics().counter(&Metric::KeysEvicted).unwrap());
+    }
+
+    #[test]
+    fn test_eviction_random() {
+        let hop = hop_with_limit(EvictionPolicy::Random);
+
+        for key in 0..10u8 {
+            assert!(set(&hop, &[key]).is_ok());
+        }
+
+        assert!(hop.memory_used() <= 3500 + 1100);
+        assert!(hop.state().0.len() < 10);
+    }
+
+    #[test]
+    fn test_max_key_length() {
+        let mut builder = Hop::builder();
+        builder.max_key_length(3);
+        let hop = builder.build();
+
+        assert_eq!(
+            DispatchError::SizeLimitExceeded,
+            set(&hop, b"foobar").unwrap_err()
+        );
+        assert!(!hop.state().contains_key(b"foobar"));
+        assert!(set(&hop, b"foo").is_ok());
+        assert!(get(&hop, b"foobar").is_err());
+    }
+
+    #[test]
+    fn test_unauthenticated() {
+        let mut builder = Hop::builder();
+        builder.password("hunter2");
+        let hop = builder.build();
+        let session = hop.clone();
+
+        assert_eq!(
+            DispatchError::Unauthenticated,
+            set(&session, b"foo").unwrap_err()
+        );
+        assert!(!session.state().contains_key(b"foo"));
+
+        let mut auth = RequestBuilder::new(CommandId::Auth);
+        assert!(auth.bytes(b"hunter2".as_ref()).is_ok());
+        assert!(session
+            .dispatch(&auth.into_request(), &mut Vec::new())
+            .is_ok());
+        assert!(set(&session, b"foo").is_ok());
+
+        // New sessions start unauthenticated.
+        assert_eq!(
+            DispatchError::Unauthenticated,
+            get(&hop.clone(), b"foo").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_permission_denied() {
+        let hop = Hop::new();
+        assert!(hop
+            .acl()
+            .set_user("foo", [">bar", "+@all", "~foo:*"].iter().copied())
+            .is_ok());
+        assert!(hop
+            .acl()
+            .set_user(
+                "baz",
+                [">qux", "+@all", "allkeys", "readonly"].iter().copied()
+            )
+            .is_ok());
+
+        let session = hop.clone();
+        assert!(session.authenticate("foo", b"bar"));
+        assert!(set(&session, b"foo:1").is_ok());
+        assert_eq!(
+            DispatchError::PermissionDenied,
+            set(&session, b"bar").unwrap_err()
+        );
+
+        let session = hop.clone();
+        assert!(session.authenticate("baz", b"qux"));
+        assert!(get(&session, b"foo:1").is_ok());
+        assert_eq!(
+            DispatchError::PermissionDenied,
+            set(&session, b"foo:1").unwrap_err()
+        );
+
+        // Deleting a user de-authenticates its sessions.
+        assert!(hop.acl().delete_user("baz"));
+        assert_eq!(
+            DispatchError::Unauthenticated,
+            get(&session, b"foo:1").unwrap_err()
+        );
+    }
 }
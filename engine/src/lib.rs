@@ -7,6 +7,7 @@ pub extern crate dashmap;
 
 extern crate alloc;
 
+pub mod acl;
 pub mod command;
 pub mod hop;
 pub mod metrics;
@@ -4,6 +4,10 @@ pub type Metrics = BaseMetrics<Metric>;
 pub type Reader = BaseReader<Metric>;
 pub type Writer = BaseWriter<Metric>;
 
+/// Prefix of the names of the statistics holding the number of keys in each
+/// namespace, followed by the number of the namespace.
+pub const NAMESPACE_KEYS_PREFIX: &str = "namespace_keys:";
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[repr(u8)]
 pub enum Metric {
@@ -11,6 +15,9 @@ pub enum Metric {
     CommandsErrored = 1,
     SessionsStarted = 10,
     SessionsEnded = 11,
+    KeysEvicted = 12,
+    SessionsTimedOut = 13,
+    MemoryUsed = 20,
 }
 
 impl Metric {
@@ -18,8 +25,11 @@ impl Metric {
         match self {
             Metric::CommandsErrored => "commands_errored",
             Metric::CommandsSuccessful => "commands_successful",
+            Metric::KeysEvicted => "keys_evicted",
+            Metric::MemoryUsed => "memory_used",
             Metric::SessionsEnded => "sessions_ended",
             Metric::SessionsStarted => "sessions_started",
+            Metric::SessionsTimedOut => "sessions_timed_out",
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::state::{Key, Value};
 
+#[derive(Clone, Debug)]
 pub enum KeyUpdate {
     /// The value of the subscribed key was deleted, meaning that the key did
     /// have a value but no longer does.
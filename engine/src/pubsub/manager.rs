@@ -1,34 +1,93 @@
-use super::Subscription;
+use super::{KeyUpdate, Subscription};
 use crate::{session::SessionId, state::Key};
-use alloc::sync::{Arc, Weak};
+use alloc::{
+    sync::{Arc, Weak},
+    vec::Vec,
+};
 use dashmap::{mapref::entry::Entry, DashMap, DashSet};
 
+/// An object key along with the number of the namespace it's in.
+type NamespacedKey = (usize, Key);
+
 #[derive(Debug, Default)]
 struct PubSubManagerRef {
-    keys: DashMap<Key, DashSet<SessionId>>,
-    sessions: DashMap<SessionId, DashMap<Key, Arc<Subscription>>>,
+    keys: DashMap<NamespacedKey, DashSet<SessionId>>,
+    sessions: DashMap<SessionId, DashMap<NamespacedKey, Arc<Subscription>>>,
 }
 
+/// Manager of sessions' subscriptions to updates of object keys.
+///
+/// Each namespace has its own keys, so subscriptions are to a key within a
+/// namespace and only receive updates published for that namespace.
 #[derive(Clone, Debug, Default)]
 pub struct PubSubManager(Arc<PubSubManagerRef>);
 
 impl PubSubManager {
-    /// Retrieves a session's subscription for a key, if it exists.
-    pub fn get(&self, object_key: &[u8], session_id: SessionId) -> Option<Weak<Subscription>> {
+    /// Retrieves a session's subscription for a key in a namespace, if it
+    /// exists.
+    pub fn get(
+        &self,
+        namespace: usize,
+        object_key: &[u8],
+        session_id: SessionId,
+    ) -> Option<Weak<Subscription>> {
         let session = self.0.sessions.get(&session_id)?;
-        let sub = session.get(object_key)?;
+        let sub = session.get(&(namespace, object_key.to_vec()))?;
 
         Some(Arc::downgrade(sub.value()))
     }
 
-    /// Subscribes a session by ID to an object key.
+    /// Publishes an update to every subscription to an object key in a
+    /// namespace.
+    ///
+    /// The update is only created if the key has subscriptions, so that
+    /// values don't need to be cloned for keys nobody is subscribed to.
+    /// Subscriptions that have fallen too far behind miss the update. Returns
+    /// the number of subscriptions that received it.
+    pub fn publish(
+        &self,
+        namespace: usize,
+        object_key: &[u8],
+        update: impl FnOnce() -> KeyUpdate,
+    ) -> usize {
+        let key = (namespace, object_key.to_vec());
+
+        let sessions = match self.0.keys.get(&key) {
+            Some(sessions) if !sessions.is_empty() => sessions,
+            _ => return 0,
+        };
+        let update = update();
+
+        sessions
+            .iter()
+            .filter(|session_id| {
+                self.0
+                    .sessions
+                    .get(session_id.key())
+                    .and_then(|subscriptions| {
+                        let subscription = subscriptions.get(&key)?;
+
+                        subscription.sender().try_send(update.clone()).ok()
+                    })
+                    .is_some()
+            })
+            .count()
+    }
+
+    /// Subscribes a session by ID to an object key in a namespace.
     ///
     /// Returns the new subscription if subscribing was successful. Returns None
     /// if the subscription already existed.
-    pub fn subscribe(&self, object_key: Key, session_id: SessionId) -> Option<Weak<Subscription>> {
+    pub fn subscribe(
+        &self,
+        namespace: usize,
+        object_key: Key,
+        session_id: SessionId,
+    ) -> Option<Weak<Subscription>> {
+        let key = (namespace, object_key);
         let session = self.0.sessions.entry(session_id).or_default();
 
-        let subscription = match session.entry(object_key.clone()) {
+        let subscription = match session.entry(key.clone()) {
             Entry::Occupied(_) => return None,
             Entry::Vacant(v) => {
                 let subscription = Arc::new(Subscription::new());
@@ -40,7 +99,7 @@ impl PubSubManager {
             }
         };
 
-        match self.0.keys.entry(object_key) {
+        match self.0.keys.entry(key) {
             Entry::Occupied(_) => None,
             Entry::Vacant(v) => {
                 let set = v.insert(DashSet::new());
@@ -51,22 +110,23 @@ impl PubSubManager {
         }
     }
 
-    /// Unsubscribes a session by ID from an object key.
+    /// Unsubscribes a session by ID from an object key in a namespace.
     ///
     /// Returns whether unsubscribing was successful. This will only be
     /// unsuccessful if the session wasn't subscribed to the key.
-    pub fn unsubscribe(&self, object_key: &[u8], session_id: SessionId) -> bool {
+    pub fn unsubscribe(&self, namespace: usize, object_key: &[u8], session_id: SessionId) -> bool {
+        let key = (namespace, object_key.to_vec());
         let key_unsubbed = self
             .0
             .keys
-            .get(object_key)
+            .get(&key)
             .and_then(|sessions| sessions.remove(&session_id))
             .is_some();
         let session_unsubbed = self
             .0
             .sessions
             .get(&session_id)
-            .and_then(|keys| keys.remove(object_key))
+            .and_then(|keys| keys.remove(&key))
             .is_some();
 
         // Really either both of these should be true or both false, but we'll
@@ -82,20 +142,121 @@ impl PubSubManager {
             .sessions
             .remove(&session_id)
             .map(|(_, subscriptions)| {
-                for (_, sub) in subscriptions.into_iter() {
+                for (key, sub) in subscriptions.into_iter() {
+                    if let Some(sessions) = self.0.keys.get(&key) {
+                        sessions.remove(&session_id);
+                    }
+
                     sub.close();
                 }
             })
             .is_some()
     }
+
+    /// Returns the number of keys a session is subscribed to.
+    pub fn subscriptions(&self, session_id: SessionId) -> usize {
+        self.0
+            .sessions
+            .get(&session_id)
+            .map_or(0, |subscriptions| subscriptions.len())
+    }
+
+    /// Unsubscribes every session from all of its subscriptions, such as when
+    /// the server is shutting down.
+    ///
+    /// Returns the number of sessions that were subscribed to any keys.
+    pub fn remove_all(&self) -> usize {
+        let session_ids = self
+            .0
+            .sessions
+            .iter()
+            .map(|session| *session.key())
+            .collect::<Vec<_>>();
+        self.0.keys.clear();
+
+        session_ids
+            .into_iter()
+            .filter(|session_id| self.remove_session(*session_id))
+            .count()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{PubSubManager, PubSubManagerRef};
+    use crate::{pubsub::KeyUpdate, session::SessionId, state::Value};
     use core::fmt::Debug;
     use static_assertions::assert_impl_all;
 
     assert_impl_all!(PubSubManagerRef: Debug, Default);
     assert_impl_all!(PubSubManager: Clone, Debug, Default);
+
+    #[tokio::test]
+    async fn test_publish() {
+        let manager = PubSubManager::default();
+        let sub = manager
+            .subscribe(0, b"foo".to_vec(), SessionId::new(1))
+            .unwrap();
+        let update = || KeyUpdate::Deleted(Value::Integer(1));
+
+        assert_eq!(1, manager.publish(0, b"foo", update));
+        assert_eq!(0, manager.publish(0, b"bar", update));
+        assert!(matches!(
+            sub.upgrade().unwrap().receiver().receive().await,
+            Some(KeyUpdate::Deleted(Value::Integer(1)))
+        ));
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let manager = PubSubManager::default();
+        let session_id = SessionId::new(1);
+        assert!(manager.subscribe(1, b"foo".to_vec(), session_id).is_some());
+        let update = || KeyUpdate::Deleted(Value::Integer(1));
+
+        assert_eq!(0, manager.publish(0, b"foo", update));
+        assert_eq!(1, manager.publish(1, b"foo", update));
+        assert!(manager.get(0, b"foo", session_id).is_none());
+        assert!(!manager.unsubscribe(0, b"foo", session_id));
+        assert!(manager.unsubscribe(1, b"foo", session_id));
+    }
+
+    #[test]
+    fn test_remove_session() {
+        let manager = PubSubManager::default();
+        let session_id = SessionId::new(1);
+        assert!(manager.subscribe(0, b"foo".to_vec(), session_id).is_some());
+        assert_eq!(1, manager.subscriptions(session_id));
+
+        assert!(manager.remove_session(session_id));
+        assert!(!manager.remove_session(session_id));
+        assert_eq!(0, manager.subscriptions(session_id));
+        assert!(manager
+            .0
+            .keys
+            .get(&(0, b"foo".to_vec()))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_remove_all() {
+        let manager = PubSubManager::default();
+        let foo = manager
+            .subscribe(0, b"foo".to_vec(), SessionId::new(1))
+            .unwrap();
+        assert!(manager
+            .subscribe(0, b"bar".to_vec(), SessionId::new(2))
+            .is_some());
+
+        assert_eq!(2, manager.remove_all());
+        assert_eq!(0, manager.remove_all());
+        assert_eq!(
+            0,
+            manager.publish(0, b"foo", || KeyUpdate::Deleted(Value::Integer(1)))
+        );
+
+        // Subscriptions are closed and dropped.
+        assert!(foo.upgrade().is_none());
+    }
 }
@@ -12,6 +12,13 @@ pub struct Subscription {
 }
 
 impl Subscription {
+    /// The number of updates buffered for a subscription.
+    ///
+    /// Updates are published from within command dispatching, which can't wait
+    /// for subscribers, so updates are dropped if a subscriber falls this far
+    /// behind.
+    pub const BUFFER: usize = 64;
+
     pub fn new() -> Self {
         Default::default()
     }
@@ -31,7 +38,7 @@ impl Subscription {
 
 impl Default for Subscription {
     fn default() -> Self {
-        let (tx, rx) = shared::unbuffered_channel();
+        let (tx, rx) = shared::channel(Self::BUFFER);
 
         Self { rx, tx }
     }
@@ -52,4 +59,15 @@ mod tests {
         sub.close();
         assert!(sub.sender().send(update).await.is_err());
     }
+
+    #[tokio::test]
+    async fn test_sub_buffered() {
+        let sub = Subscription::new();
+        let update = KeyUpdate::Renamed { to: b"b".to_vec() };
+        assert!(sub.sender().try_send(update).is_ok());
+        assert!(matches!(
+            sub.receiver().receive().await,
+            Some(KeyUpdate::Renamed { to }) if to == b"b"
+        ));
+    }
 }
@@ -1,17 +1,17 @@
-use super::Session;
+use super::{Session, SessionId, SessionLimitReached};
 use crate::metrics::{Metric, Writer};
-use alloc::sync::Arc;
-use core::sync::atomic::{AtomicU32, Ordering};
-use dashmap::{
-    mapref::{entry::Entry, one::RefMut},
-    DashMap,
-};
+use alloc::{string::String, sync::Arc};
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use dashmap::{mapref::one::Ref, DashMap};
 
 #[derive(Debug)]
 struct SessionManagerRef {
+    /// The number of sessions, counted separately from the map so that
+    /// checking the limit and adding a session can't race.
+    active: AtomicUsize,
     metrics_writer: Writer,
     next_id: AtomicU32,
-    sessions: DashMap<u32, Session>,
+    sessions: DashMap<SessionId, Session>,
 }
 
 #[derive(Clone, Debug)]
@@ -20,46 +20,185 @@ pub struct SessionManager(Arc<SessionManagerRef>);
 impl SessionManager {
     pub fn new(metrics_writer: Writer) -> Self {
         Self(Arc::new(SessionManagerRef {
+            active: AtomicUsize::new(0),
             metrics_writer,
             next_id: AtomicU32::new(0),
             sessions: DashMap::new(),
         }))
     }
 
-    pub fn add(&mut self, client_token: [u8; 32]) -> RefMut<'_, u32, Session> {
-        let session = Session::new(client_token, self.next_id());
+    /// Add a session for a new connection, returning its ID.
+    ///
+    /// Returns [`SessionLimitReached`] if there are already the maximum
+    /// number of sessions.
+    ///
+    /// [`SessionLimitReached`]: struct.SessionLimitReached.html
+    pub fn add(
+        &self,
+        peer: Option<String>,
+        connected_at: u64,
+        max: usize,
+    ) -> Result<SessionId, SessionLimitReached> {
+        self.0
+            .active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| {
+                Some(active + 1).filter(|_| active < max)
+            })
+            .map_err(|_| SessionLimitReached)?;
 
-        let value = match self.0.sessions.entry(self.next_id()) {
-            Entry::Occupied(_) => {
-                unreachable!();
-            }
-            Entry::Vacant(v) => v.insert(session),
-        };
+        let id = SessionId::new(self.0.next_id.fetch_add(1, Ordering::SeqCst));
 
+        self.0
+            .sessions
+            .insert(id, Session::new(id, peer, connected_at));
         self.0.metrics_writer.increment(Metric::SessionsStarted);
 
-        self.0
-            .next_id
-            .store(self.next_id().wrapping_add(1), Ordering::SeqCst);
+        Ok(id)
+    }
+
+    /// Call a function with every session, in no particular order.
+    pub fn for_each(&self, mut f: impl FnMut(&Session)) {
+        for session in self.0.sessions.iter() {
+            f(session.value());
+        }
+    }
+
+    pub fn get(&self, id: SessionId) -> Option<Ref<'_, SessionId, Session>> {
+        self.0.sessions.get(&id)
+    }
 
-        value
+    pub fn is_empty(&self) -> bool {
+        self.0.sessions.is_empty()
     }
 
-    pub fn remove(&self, id: u32) -> bool {
-        self.0.sessions.remove(&id).is_some()
+    /// Kill and remove a session, so that its connection is closed.
+    ///
+    /// Returns whether the session existed.
+    pub fn kill(&self, id: SessionId) -> bool {
+        match self.0.sessions.remove(&id) {
+            Some((_, session)) => {
+                session.kill();
+                self.0.active.fetch_sub(1, Ordering::SeqCst);
+                self.0.metrics_writer.increment(Metric::SessionsEnded);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.sessions.len()
+    }
+
+    /// Remove a session, such as once its connection has closed.
+    ///
+    /// Returns whether the session existed.
+    pub fn remove(&self, id: SessionId) -> bool {
+        let removed = self.0.sessions.remove(&id).is_some();
+
+        if removed {
+            self.0.active.fetch_sub(1, Ordering::SeqCst);
+            self.0.metrics_writer.increment(Metric::SessionsEnded);
+        }
+
+        removed
     }
 
-    fn next_id(&self) -> u32 {
-        self.0.next_id.load(Ordering::SeqCst)
+    /// Remove a session whose connection was closed for being idle for too
+    /// long.
+    ///
+    /// Returns whether the session existed.
+    pub fn time_out(&self, id: SessionId) -> bool {
+        let removed = self.remove(id);
+
+        if removed {
+            self.0.metrics_writer.increment(Metric::SessionsTimedOut);
+        }
+
+        removed
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{SessionManager, SessionManagerRef};
+    use super::{super::SessionLimitReached, SessionManager, SessionManagerRef};
+    use crate::metrics::{Metric, Metrics};
+    use alloc::borrow::ToOwned;
     use core::fmt::Debug;
+    use futures_intrusive::channel::TryReceiveError;
     use static_assertions::assert_impl_all;
 
     assert_impl_all!(SessionManagerRef: Debug);
     assert_impl_all!(SessionManager: Clone, Debug);
+
+    #[test]
+    fn test_add_remove() {
+        let metrics = Metrics::default();
+        let sessions = SessionManager::new(metrics.writer());
+        let first = sessions
+            .add(Some("127.0.0.1:1".to_owned()), 5, usize::MAX)
+            .unwrap();
+        let second = sessions.add(None, 10, usize::MAX).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(2, sessions.len());
+        assert_eq!(Some("127.0.0.1:1"), sessions.get(first).unwrap().peer());
+
+        assert!(sessions.remove(first));
+        assert!(!sessions.remove(first));
+        assert_eq!(1, sessions.len());
+
+        let reader = metrics.reader();
+        assert_eq!(Some(2), reader.counter(&Metric::SessionsStarted));
+        assert_eq!(Some(1), reader.counter(&Metric::SessionsEnded));
+    }
+
+    #[test]
+    fn test_limit() {
+        let metrics = Metrics::default();
+        let sessions = SessionManager::new(metrics.writer());
+        let first = sessions.add(None, 5, 2).unwrap();
+        let second = sessions.add(None, 5, 2).unwrap();
+        assert_eq!(Err(SessionLimitReached), sessions.add(None, 5, 2));
+        assert_eq!(2, sessions.len());
+
+        // Ending a session in any way makes room for another.
+        assert!(sessions.remove(first));
+        let third = sessions.add(None, 5, 2).unwrap();
+        assert_eq!(Err(SessionLimitReached), sessions.add(None, 5, 2));
+        assert!(sessions.kill(second));
+        assert!(sessions.add(None, 5, 2).is_ok());
+        assert!(sessions.time_out(third));
+        assert!(sessions.add(None, 5, 2).is_ok());
+
+        let reader = metrics.reader();
+        assert_eq!(Some(5), reader.counter(&Metric::SessionsStarted));
+    }
+
+    #[test]
+    fn test_time_out() {
+        let metrics = Metrics::default();
+        let sessions = SessionManager::new(metrics.writer());
+        let id = sessions.add(None, 5, usize::MAX).unwrap();
+
+        assert!(sessions.time_out(id));
+        assert!(!sessions.time_out(id));
+        assert!(sessions.is_empty());
+
+        let reader = metrics.reader();
+        assert_eq!(Some(1), reader.counter(&Metric::SessionsEnded));
+        assert_eq!(Some(1), reader.counter(&Metric::SessionsTimedOut));
+    }
+
+    #[test]
+    fn test_kill() {
+        let sessions = SessionManager::new(Metrics::default().writer());
+        let id = sessions.add(None, 5, usize::MAX).unwrap();
+        let killed = sessions.get(id).unwrap().killed();
+
+        assert!(sessions.kill(id));
+        assert!(sessions.is_empty());
+        assert_eq!(Err(TryReceiveError::Closed), killed.try_receive());
+        assert!(!sessions.kill(id));
+    }
 }
@@ -2,25 +2,102 @@ mod manager;
 
 pub use manager::SessionManager;
 
+use alloc::string::String;
+use core::sync::atomic::{AtomicU64, Ordering};
+use futures_intrusive::channel::shared::{self, Receiver, Sender};
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct SessionId(u32);
 
+/// A session couldn't be started because the maximum number of sessions are
+/// already active.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SessionLimitReached;
+
+impl SessionId {
+    pub fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+/// A connection to the engine, tracked so that operators can list who is
+/// connected and kill connections.
 #[derive(Debug)]
 pub struct Session {
-    pub client_token: [u8; 32],
-    pub id: u32,
+    connected_at: u64,
+    id: SessionId,
+    last_active: AtomicU64,
+    peer: Option<String>,
+    rx: Receiver<()>,
+    tx: Sender<()>,
 }
 
 impl Session {
-    pub fn new(client_token: [u8; 32], id: u32) -> Self {
-        Self { client_token, id }
+    pub fn new(id: SessionId, peer: Option<String>, connected_at: u64) -> Self {
+        let (tx, rx) = shared::channel(1);
+
+        Self {
+            connected_at,
+            id,
+            last_active: AtomicU64::new(connected_at),
+            peer,
+            rx,
+            tx,
+        }
+    }
+
+    /// Retrieve the Unix timestamp in milliseconds of when the session
+    /// connected.
+    pub fn connected_at(&self) -> u64 {
+        self.connected_at
+    }
+
+    pub fn id(&self) -> SessionId {
+        self.id
+    }
+
+    /// Kill the session, which closes the receiver returned by [`killed`].
+    ///
+    /// [`killed`]: #method.killed
+    pub fn kill(&self) {
+        self.tx.close();
+    }
+
+    /// Return a receiver that's closed once the session is killed.
+    ///
+    /// Connections should stop once receiving from it returns `None`.
+    pub fn killed(&self) -> Receiver<()> {
+        self.rx.clone()
+    }
+
+    /// Retrieve the Unix timestamp in milliseconds of when the session last
+    /// ran a command, or connected if it hasn't run any.
+    pub fn last_active(&self) -> u64 {
+        self.last_active.load(Ordering::Relaxed)
+    }
+
+    /// Retrieve the address of the peer, if it has one.
+    pub fn peer(&self) -> Option<&str> {
+        self.peer.as_deref()
+    }
+
+    /// Record that the session ran a command at a Unix timestamp in
+    /// milliseconds.
+    pub fn touch(&self, now: u64) {
+        self.last_active.store(now, Ordering::Relaxed);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{Session, SessionId};
+    use alloc::borrow::ToOwned;
     use core::{fmt::Debug, hash::Hash};
+    use futures_intrusive::channel::TryReceiveError;
     use static_assertions::assert_impl_all;
 
     assert_impl_all!(
@@ -34,4 +111,26 @@ mod tests {
         PartialOrd
     );
     assert_impl_all!(Session: Debug);
+
+    #[tokio::test]
+    async fn test_kill() {
+        let session = Session::new(SessionId::new(1), None, 5);
+        let killed = session.killed();
+        assert_eq!(Err(TryReceiveError::Empty), killed.try_receive());
+
+        session.kill();
+        assert!(killed.receive().await.is_none());
+    }
+
+    #[test]
+    fn test_touch() {
+        let session = Session::new(SessionId::new(1), Some("127.0.0.1:1".to_owned()), 5);
+        assert_eq!(5, session.connected_at());
+        assert_eq!(5, session.last_active());
+        assert_eq!(Some("127.0.0.1:1"), session.peer());
+
+        session.touch(10);
+        assert_eq!(5, session.connected_at());
+        assert_eq!(10, session.last_active());
+    }
 }
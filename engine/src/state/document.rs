@@ -0,0 +1,926 @@
+//! Nested documents of objects, arrays and scalars, along with paths into
+//! them.
+//!
+//! Refer to [`Document`] for more information.
+//!
+//! [`Document`]: enum.Document.html
+
+use super::value::sampled;
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    char,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    mem,
+    str::{self, FromStr},
+};
+
+/// The maximum depth of nesting allowed in a document, and the maximum number
+/// of segments in a path.
+///
+/// This keeps parsing untrusted input, and the recursive operations on the
+/// result, from overflowing the stack.
+pub const DEPTH_MAX: usize = 128;
+
+/// A document of nested objects, arrays and scalars, modelled after JSON.
+///
+/// Documents are parsed from and displayed as JSON text. Integers that fit in
+/// an `i64` are kept separate from floats so that they can be incremented
+/// without losing precision.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::state::{Document, DocumentPath};
+///
+/// let mut document = r#"{"user":{"name":"alice","visits":[1,2]}}"#
+///     .parse::<Document>()
+///     .unwrap();
+/// let path = "user.visits[-1]".parse::<DocumentPath>().unwrap();
+///
+/// assert_eq!(Some(&Document::Integer(2)), document.get(&path));
+/// assert!(document.set(&"user.name".parse().unwrap(), Document::from("bob")));
+/// assert_eq!(
+///     r#"{"user":{"name":"bob","visits":[1,2]}}"#,
+///     document.to_string(),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Document {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Document>),
+    Object(BTreeMap<String, Document>),
+}
+
+impl Document {
+    /// Deserialize a document from its JSON text.
+    ///
+    /// Returns `None` if the bytes aren't valid UTF-8 or valid JSON.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        str::from_utf8(bytes).ok()?.parse().ok()
+    }
+
+    /// Serialize the document to its JSON text.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        self.to_string().into_bytes()
+    }
+
+    /// Retrieve an immutable reference to the document at a path.
+    pub fn get(&self, path: &DocumentPath) -> Option<&Self> {
+        path.segments()
+            .iter()
+            .try_fold(self, |document, segment| document.child(segment))
+    }
+
+    /// Retrieve a mutable reference to the document at a path.
+    pub fn get_mut(&mut self, path: &DocumentPath) -> Option<&mut Self> {
+        path.segments()
+            .iter()
+            .try_fold(self, |document, segment| document.child_mut(segment))
+    }
+
+    /// Set the document at a path, replacing any existing document there.
+    ///
+    /// Object fields are created if they don't exist, but the parent of the
+    /// path must exist. Array elements must already exist to be replaced; use
+    /// [`as_array_mut`] to push to arrays.
+    ///
+    /// Returns `false` if the parent doesn't exist or can't hold the value,
+    /// or if the value would be nested deeper than [`DEPTH_MAX`].
+    ///
+    /// [`as_array_mut`]: #method.as_array_mut
+    /// [`DEPTH_MAX`]: constant.DEPTH_MAX.html
+    pub fn set(&mut self, path: &DocumentPath, value: Self) -> bool {
+        if path.segments().len().saturating_add(value.depth()) > DEPTH_MAX {
+            return false;
+        }
+
+        let (last, parent) = match path.segments().split_last() {
+            Some(split) => split,
+            None => {
+                *self = value;
+
+                return true;
+            }
+        };
+
+        let parent = match parent
+            .iter()
+            .try_fold(self, |document, segment| document.child_mut(segment))
+        {
+            Some(parent) => parent,
+            None => return false,
+        };
+
+        match (parent, last) {
+            (Self::Object(object), Segment::Field(field)) => {
+                object.insert(field.clone(), value);
+
+                true
+            }
+            (parent, Segment::Index(_)) => match parent.child_mut(last) {
+                Some(element) => {
+                    *element = value;
+
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Remove the document at a path, returning it if it existed.
+    ///
+    /// The root of a document can't be removed, so an empty path always
+    /// returns `None`.
+    pub fn remove(&mut self, path: &DocumentPath) -> Option<Self> {
+        let (last, parent) = path.segments().split_last()?;
+        let parent = parent
+            .iter()
+            .try_fold(self, |document, segment| document.child_mut(segment))?;
+
+        match (parent, last) {
+            (Self::Object(object), Segment::Field(field)) => object.remove(field),
+            (Self::Array(array), Segment::Index(idx)) => {
+                let idx = resolve_index(array.len(), *idx)?;
+
+                Some(array.remove(idx))
+            }
+            _ => None,
+        }
+    }
+
+    /// Estimate the number of bytes of memory used by the document, including
+    /// its nested values.
+    ///
+    /// Only some elements of arrays and objects are measured, so refer to
+    /// [`MEMORY_SAMPLES`] for how the estimate is made.
+    ///
+    /// [`MEMORY_SAMPLES`]: ../value/constant.MEMORY_SAMPLES.html
+    pub fn memory_usage(&self) -> usize {
+        let owned = match self {
+            Self::Null | Self::Boolean(_) | Self::Integer(_) | Self::Float(_) => 0,
+            Self::String(string) => string.capacity(),
+            Self::Array(array) => {
+                // The size of each element is included in its own measurement.
+                (array.capacity() - array.len()) * mem::size_of::<Self>()
+                    + sampled(array.len(), array.iter().map(Self::memory_usage))
+            }
+            Self::Object(object) => sampled(
+                object.len(),
+                object.iter().map(|(key, value)| {
+                    mem::size_of::<String>() + key.capacity() + value.memory_usage()
+                }),
+            ),
+        };
+
+        mem::size_of::<Self>() + owned
+    }
+
+    /// Retrieve how many levels of arrays and objects the document's values
+    /// are nested within.
+    ///
+    /// Scalars and empty arrays and objects have a depth of 0, and an array of
+    /// scalars has a depth of 1.
+    pub fn depth(&self) -> usize {
+        let children = match self {
+            Self::Array(array) => array.iter().map(Self::depth).max(),
+            Self::Object(object) => object.values().map(Self::depth).max(),
+            _ => None,
+        };
+
+        children.map_or(0, |depth| depth + 1)
+    }
+
+    /// Retrieve the number of elements of an array or fields of an object.
+    ///
+    /// Returns `None` if the document is a scalar.
+    pub fn element_count(&self) -> Option<usize> {
+        match self {
+            Self::Array(array) => Some(array.len()),
+            Self::Object(object) => Some(object.len()),
+            _ => None,
+        }
+    }
+
+    pub fn as_array_mut(&mut self) -> Option<&mut Vec<Self>> {
+        match self {
+            Self::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    fn child(&self, segment: &Segment) -> Option<&Self> {
+        match (self, segment) {
+            (Self::Object(object), Segment::Field(field)) => object.get(field),
+            (Self::Array(array), Segment::Index(idx)) => {
+                array.get(resolve_index(array.len(), *idx)?)
+            }
+            _ => None,
+        }
+    }
+
+    fn child_mut(&mut self, segment: &Segment) -> Option<&mut Self> {
+        match (self, segment) {
+            (Self::Object(object), Segment::Field(field)) => object.get_mut(field),
+            (Self::Array(array), Segment::Index(idx)) => {
+                let idx = resolve_index(array.len(), *idx)?;
+
+                array.get_mut(idx)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Boolean(boolean) => Display::fmt(boolean, f),
+            Self::Integer(int) => Display::fmt(int, f),
+            // JSON can't represent NaN or infinity.
+            Self::Float(float) if !float.is_finite() => f.write_str("null"),
+            Self::Float(float) => {
+                let text = float.to_string();
+                f.write_str(&text)?;
+
+                // Keep floats with no fraction from being read back as
+                // integers.
+                if text.contains(['.', 'e']) {
+                    Ok(())
+                } else {
+                    f.write_str(".0")
+                }
+            }
+            Self::String(string) => write_string(f, string),
+            Self::Array(array) => {
+                f.write_char('[')?;
+
+                for (idx, element) in array.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+
+                    Display::fmt(element, f)?;
+                }
+
+                f.write_char(']')
+            }
+            Self::Object(object) => {
+                f.write_char('{')?;
+
+                for (idx, (field, value)) in object.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+
+                    write_string(f, field)?;
+                    f.write_char(':')?;
+                    Display::fmt(value, f)?;
+                }
+
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+impl FromStr for Document {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            bytes: s.as_bytes(),
+            idx: 0,
+        };
+        let document = parser.value(0)?;
+        parser.whitespace();
+
+        if parser.idx == parser.bytes.len() {
+            Ok(document)
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl From<bool> for Document {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<f64> for Document {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<i64> for Document {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<&str> for Document {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Document {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// A segment of a path into a document.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Segment {
+    /// The field of an object.
+    Field(String),
+    /// The index of an array element. Negative indexes count backwards from
+    /// the end of the array.
+    Index(i64),
+}
+
+/// A path to a document nested within another document.
+///
+/// Paths are written as fields separated by periods, with array indexes in
+/// brackets, such as `user.visits[0]`. They may start with `$` to refer to the
+/// root, and fields containing periods or brackets can be quoted, such as
+/// `$["user.name"]`. The empty path and `$` refer to the root document.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::state::{document::Segment, DocumentPath};
+///
+/// let path = "$.user.visits[-1]".parse::<DocumentPath>().unwrap();
+/// assert_eq!(
+///     &[
+///         Segment::Field("user".to_owned()),
+///         Segment::Field("visits".to_owned()),
+///         Segment::Index(-1),
+///     ],
+///     path.segments(),
+/// );
+/// assert!("".parse::<DocumentPath>().unwrap().is_root());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DocumentPath {
+    segments: Vec<Segment>,
+}
+
+impl DocumentPath {
+    /// Create a path to the root of a document.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Create a path to the parent of the document at this path.
+    ///
+    /// Returns `None` if this is the root.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.segments.split_last()?;
+
+        Some(Self {
+            segments: parent.to_vec(),
+        })
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+}
+
+impl FromStr for DocumentPath {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rooted, s) = match s.strip_prefix('$') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let mut segments = Vec::new();
+        let mut parser = Parser {
+            bytes: s.as_bytes(),
+            idx: 0,
+        };
+
+        // The first field may be written without a leading period, unless the
+        // path starts with the root.
+        if !rooted && !matches!(parser.peek(), None | Some(b'.') | Some(b'[')) {
+            segments.push(Segment::Field(parser.path_field()?));
+        }
+
+        while let Some(byte) = parser.peek() {
+            parser.idx += 1;
+
+            let segment = match byte {
+                b'.' => Segment::Field(parser.path_field()?),
+                b'[' if parser.peek() == Some(b'"') => {
+                    let field = parser.string()?;
+                    parser.expect(b']')?;
+
+                    Segment::Field(field)
+                }
+                b'[' => {
+                    let start = parser.idx;
+
+                    while !matches!(parser.peek(), None | Some(b']')) {
+                        parser.idx += 1;
+                    }
+
+                    let idx = str::from_utf8(&parser.bytes[start..parser.idx])
+                        .map_err(|_| ())?
+                        .parse()
+                        .map_err(|_| ())?;
+                    parser.expect(b']')?;
+
+                    Segment::Index(idx)
+                }
+                _ => return Err(()),
+            };
+
+            if segments.len() == DEPTH_MAX {
+                return Err(());
+            }
+
+            segments.push(segment);
+        }
+
+        Ok(Self { segments })
+    }
+}
+
+/// Resolve a possibly negative index into an index of an array with a length.
+fn resolve_index(len: usize, idx: i64) -> Option<usize> {
+    let idx = if idx < 0 {
+        (len as i64).checked_add(idx)?
+    } else {
+        idx
+    };
+
+    if idx >= 0 && (idx as u64) < len as u64 {
+        Some(idx as usize)
+    } else {
+        None
+    }
+}
+
+fn write_string(f: &mut Formatter<'_>, string: &str) -> FmtResult {
+    f.write_char('"')?;
+
+    for c in string.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => f.write_fmt(format_args!("\\u{:04x}", c as u32))?,
+            c => f.write_char(c)?,
+        }
+    }
+
+    f.write_char('"')
+}
+
+/// Recursive descent parser over JSON text and document paths.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    idx: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.idx).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), ()> {
+        if self.peek() == Some(byte) {
+            self.idx += 1;
+
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    fn keyword(&mut self, keyword: &[u8], document: Document) -> Result<Document, ()> {
+        if self.bytes[self.idx..].starts_with(keyword) {
+            self.idx += keyword.len();
+
+            Ok(document)
+        } else {
+            Err(())
+        }
+    }
+
+    fn whitespace(&mut self) {
+        while matches!(
+            self.peek(),
+            Some(b' ') | Some(b'\n') | Some(b'\r') | Some(b'\t')
+        ) {
+            self.idx += 1;
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Document, ()> {
+        if depth > DEPTH_MAX {
+            return Err(());
+        }
+
+        self.whitespace();
+
+        let document = match self.peek().ok_or(())? {
+            b'n' => self.keyword(b"null", Document::Null)?,
+            b't' => self.keyword(b"true", Document::Boolean(true))?,
+            b'f' => self.keyword(b"false", Document::Boolean(false))?,
+            b'"' => Document::String(self.string()?),
+            b'[' => {
+                self.idx += 1;
+                let mut array = Vec::new();
+                self.whitespace();
+
+                if self.peek() == Some(b']') {
+                    self.idx += 1;
+                } else {
+                    loop {
+                        array.push(self.value(depth + 1)?);
+                        self.whitespace();
+
+                        match self.peek() {
+                            Some(b',') => self.idx += 1,
+                            Some(b']') => {
+                                self.idx += 1;
+
+                                break;
+                            }
+                            _ => return Err(()),
+                        }
+                    }
+                }
+
+                Document::Array(array)
+            }
+            b'{' => {
+                self.idx += 1;
+                let mut object = BTreeMap::new();
+                self.whitespace();
+
+                if self.peek() == Some(b'}') {
+                    self.idx += 1;
+                } else {
+                    loop {
+                        self.whitespace();
+                        let field = self.string()?;
+                        self.whitespace();
+                        self.expect(b':')?;
+                        object.insert(field, self.value(depth + 1)?);
+                        self.whitespace();
+
+                        match self.peek() {
+                            Some(b',') => self.idx += 1,
+                            Some(b'}') => {
+                                self.idx += 1;
+
+                                break;
+                            }
+                            _ => return Err(()),
+                        }
+                    }
+                }
+
+                Document::Object(object)
+            }
+            b'-' | b'0'..=b'9' => self.number()?,
+            _ => return Err(()),
+        };
+
+        Ok(document)
+    }
+
+    fn number(&mut self) -> Result<Document, ()> {
+        let start = self.idx;
+        let mut float = false;
+
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'0'..=b'9' | b'-' | b'+' => {}
+                b'.' | b'e' | b'E' => float = true,
+                _ => break,
+            }
+
+            self.idx += 1;
+        }
+
+        let text = str::from_utf8(&self.bytes[start..self.idx]).map_err(|_| ())?;
+
+        // Reject forms that Rust accepts but JSON doesn't, such as "+1", ".5"
+        // and "1.".
+        let digits = text.strip_prefix('-').unwrap_or(text);
+
+        if !digits.starts_with(|c: char| c.is_ascii_digit())
+            || digits.ends_with('.')
+            || digits.contains(".e")
+            || digits.contains(".E")
+            || (digits.starts_with('0') && digits.as_bytes().get(1).is_some_and(u8::is_ascii_digit))
+        {
+            return Err(());
+        }
+
+        if !float {
+            if let Ok(int) = text.parse() {
+                return Ok(Document::Integer(int));
+            }
+        }
+
+        match text.parse::<f64>() {
+            Ok(float) if float.is_finite() => Ok(Document::Float(float)),
+            _ => Err(()),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ()> {
+        self.expect(b'"')?;
+        let mut string = String::new();
+
+        loop {
+            let start = self.idx;
+
+            while !matches!(self.peek(), None | Some(b'"') | Some(b'\\')) {
+                if self.peek() < Some(0x20) {
+                    return Err(());
+                }
+
+                self.idx += 1;
+            }
+
+            string.push_str(str::from_utf8(&self.bytes[start..self.idx]).map_err(|_| ())?);
+
+            match self.peek().ok_or(())? {
+                b'"' => {
+                    self.idx += 1;
+
+                    return Ok(string);
+                }
+                _ => {
+                    self.idx += 1;
+
+                    let c = match self.peek().ok_or(())? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            self.idx += 1;
+                            let high = self.hex()?;
+
+                            let code = if (0xD800..0xDC00).contains(&high) {
+                                self.expect(b'\\')?;
+                                self.expect(b'u')?;
+                                let low = self.hex()?;
+
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(());
+                                }
+
+                                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                            } else {
+                                high
+                            };
+
+                            string.push(char::from_u32(code).ok_or(())?);
+
+                            continue;
+                        }
+                        _ => return Err(()),
+                    };
+
+                    self.idx += 1;
+                    string.push(c);
+                }
+            }
+        }
+    }
+
+    /// Parse the 4 hexadecimal digits of a unicode escape.
+    fn hex(&mut self) -> Result<u32, ()> {
+        let digits = self.bytes.get(self.idx..self.idx + 4).ok_or(())?;
+        let digits = str::from_utf8(digits).map_err(|_| ())?;
+        let code = u32::from_str_radix(digits, 16).map_err(|_| ())?;
+        self.idx += 4;
+
+        Ok(code)
+    }
+
+    /// Parse an unquoted field of a path, which ends at the next period or
+    /// bracket.
+    fn path_field(&mut self) -> Result<String, ()> {
+        let start = self.idx;
+
+        while !matches!(self.peek(), None | Some(b'.') | Some(b'[')) {
+            self.idx += 1;
+        }
+
+        if start == self.idx {
+            return Err(());
+        }
+
+        str::from_utf8(&self.bytes[start..self.idx])
+            .map(ToString::to_string)
+            .map_err(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Document, DocumentPath, Segment, DEPTH_MAX};
+    use alloc::{borrow::ToOwned, collections::BTreeMap, string::ToString, vec::Vec};
+    use core::{fmt::Debug, hash::Hash, str::FromStr};
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(Document: Clone, Debug, FromStr, PartialEq, ToString);
+    assert_impl_all!(DocumentPath: Clone, Debug, Default, Eq, FromStr, Hash, PartialEq);
+    assert_impl_all!(Segment: Clone, Debug, Eq, Hash, PartialEq);
+
+    fn path(path: &str) -> DocumentPath {
+        path.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_scalars() {
+        assert_eq!(Ok(Document::Null), "null".parse());
+        assert_eq!(Ok(Document::Boolean(true)), " true ".parse());
+        assert_eq!(Ok(Document::Boolean(false)), "false".parse());
+        assert_eq!(Ok(Document::Integer(-12)), "-12".parse());
+        assert_eq!(Ok(Document::Float(1.5)), "1.5".parse());
+        assert_eq!(Ok(Document::Float(100.0)), "1e2".parse());
+        assert_eq!(Ok(Document::Float(0.0)), "0e1".parse());
+        assert_eq!(
+            Ok(Document::Float(1e19)),
+            "10000000000000000000".parse::<Document>()
+        );
+        assert_eq!(
+            Ok(Document::String("a\"\n\u{e9}\u{1f600}".to_owned())),
+            r#""a\"\n\u00e9\ud83d\ude00""#.parse()
+        );
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let document = r#"{ "a": [1, {"b": null}], "c": {} }"#.parse::<Document>().unwrap();
+        let mut b = BTreeMap::new();
+        b.insert("b".to_owned(), Document::Null);
+        let mut expected = BTreeMap::new();
+        expected.insert(
+            "a".to_owned(),
+            Document::Array([Document::Integer(1), Document::Object(b)].to_vec()),
+        );
+        expected.insert("c".to_owned(), Document::Object(BTreeMap::new()));
+
+        assert_eq!(Document::Object(expected), document);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for text in [
+            "",
+            "-",
+            "nul",
+            "[1,]",
+            "{\"a\"}",
+            "{a:1}",
+            "01",
+            "+1",
+            ".5",
+            "1.",
+            "1.e5",
+            "\"\\x\"",
+            "\"\n\"",
+            "[] []",
+            "\"\\ud83d\"",
+        ]
+        .iter()
+        {
+            assert!(text.parse::<Document>().is_err(), "{}", text);
+        }
+
+        let deep = "[".repeat(200) + &"]".repeat(200);
+        assert!(deep.parse::<Document>().is_err());
+    }
+
+    #[test]
+    fn test_depth() {
+        assert_eq!(0, Document::Null.depth());
+        assert_eq!(0, Document::Array(Vec::new()).depth());
+        assert_eq!(2, r#"{"a":[1],"b":2}"#.parse::<Document>().unwrap().depth());
+
+        // Documents can be parsed up to the maximum depth.
+        let nested = |depth: usize| "[".repeat(depth + 1) + &"]".repeat(depth + 1);
+        let document = nested(DEPTH_MAX).parse::<Document>().unwrap();
+        assert_eq!(DEPTH_MAX, document.depth());
+        assert!(nested(DEPTH_MAX + 1).parse::<Document>().is_err());
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let text = r#"{"a":[1,2.0,-0.5,"x\\y\u0001"],"b":{"c":true,"d":null}}"#;
+        let document = text.parse::<Document>().unwrap();
+
+        assert_eq!(text, document.to_string());
+        assert_eq!(
+            Some(document.clone()),
+            Document::from_bytes(&document.as_bytes())
+        );
+        assert_eq!("null", Document::Float(f64::NAN).to_string());
+    }
+
+    #[test]
+    fn test_path_parse() {
+        assert!(path("").is_root());
+        assert!(path("$").is_root());
+        assert_eq!(
+            &[
+                Segment::Field("a".to_owned()),
+                Segment::Field("b.c".to_owned()),
+                Segment::Index(2),
+                Segment::Index(-1),
+            ],
+            path(r#"a["b.c"][2][-1]"#).segments()
+        );
+        assert_eq!(path("a.b"), path("$.a.b"));
+
+        for text in ["a..b", "a.", "a[", "a[x]", "a[1", "$a"].iter() {
+            assert!(text.parse::<DocumentPath>().is_err(), "{}", text);
+        }
+
+        let long = |len: usize| "$".to_owned() + &"[0]".repeat(len);
+        assert_eq!(DEPTH_MAX, path(&long(DEPTH_MAX)).segments().len());
+        assert!(long(DEPTH_MAX + 1).parse::<DocumentPath>().is_err());
+    }
+
+    #[test]
+    fn test_get_set_remove() {
+        let mut document = r#"{"a":{"b":[1,2,3]}}"#.parse::<Document>().unwrap();
+
+        assert_eq!(Some(&Document::Integer(3)), document.get(&path("a.b[-1]")));
+        assert!(document.get(&path("a.b[3]")).is_none());
+        assert!(document.get(&path("a.c")).is_none());
+        assert!(document.get(&path("a[0]")).is_none());
+
+        assert!(document.set(&path("a.c"), Document::from("x")));
+        assert!(document.set(&path("a.b[0]"), Document::Null));
+        assert!(!document.set(&path("a.b[5]"), Document::Null));
+        assert!(!document.set(&path("x.y"), Document::Null));
+        assert!(!document.set(&path("a.b.c"), Document::Null));
+        assert_eq!(r#"{"a":{"b":[null,2,3],"c":"x"}}"#, document.to_string());
+
+        assert_eq!(Some(Document::Integer(2)), document.remove(&path("a.b[1]")));
+        assert_eq!(Some(Document::from("x")), document.remove(&path("a.c")));
+        assert!(document.remove(&path("a.c")).is_none());
+        assert!(document.remove(&path("")).is_none());
+        assert_eq!(r#"{"a":{"b":[null,3]}}"#, document.to_string());
+
+        assert!(document.set(&DocumentPath::root(), Document::Array(Vec::new())));
+        assert_eq!("[]", document.to_string());
+    }
+
+    #[test]
+    fn test_set_depth() {
+        let mut document = "[[0]]".parse::<Document>().unwrap();
+        let deep = |depth: usize| {
+            (0..depth).fold(Document::Null, |document, _| {
+                Document::Array([document].to_vec())
+            })
+        };
+
+        // The value would be nested one level too deep.
+        assert!(!document.set(&path("[0][0]"), deep(DEPTH_MAX - 1)));
+        assert_eq!("[[0]]", document.to_string());
+
+        assert!(document.set(&path("[0][0]"), deep(DEPTH_MAX - 2)));
+        assert_eq!(DEPTH_MAX, document.depth());
+        assert!(Document::from_bytes(&document.as_bytes()).is_some());
+    }
+}
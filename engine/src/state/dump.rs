@@ -0,0 +1,339 @@
+//! A portable serialized form of values, used to move keys between instances.
+//!
+//! Refer to [`dump`] for more information.
+//!
+//! [`dump`]: fn.dump.html
+
+use super::{Document, HyperLogLog, KeyType, Stream, Value};
+use alloc::{string::String, vec::Vec};
+use core::convert::{TryFrom, TryInto};
+use dashmap::{DashMap, DashSet};
+
+/// Magic bytes at the start of the serialized form.
+const MAGIC: &[u8; 3] = b"HOP";
+
+/// The version of the serialized form.
+///
+/// This must be incremented whenever the serialized form of any value changes.
+pub const VERSION: u8 = 1;
+
+/// The length of the header of the serialized form: the magic bytes, the
+/// version, and the key type.
+const HEADER_LEN: usize = MAGIC.len() + 2;
+
+/// The length of the checksum at the end of the serialized form.
+const CHECKSUM_LEN: usize = 4;
+
+/// Serialize a value into a portable form that can be restored with
+/// [`restore`], possibly by another instance.
+///
+/// The serialized form starts with a header made of the magic bytes `HOP`, the
+/// [`VERSION`] of the form, and the value's key type. It's followed by the
+/// value itself and ends with a big-endian CRC-32 checksum of everything
+/// before it.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::state::{dump, Value};
+///
+/// let bytes = dump::dump(&Value::Integer(7));
+/// assert!(matches!(dump::restore(&bytes), Some(Value::Integer(7))));
+/// ```
+///
+/// [`VERSION`]: constant.VERSION.html
+/// [`restore`]: fn.restore.html
+pub fn dump(value: &Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(VERSION);
+    bytes.push(value.kind() as u8);
+
+    match value {
+        Value::Boolean(boolean) => bytes.push(*boolean as u8),
+        Value::Bytes(inner) => bytes.extend_from_slice(inner),
+        Value::Document(document) => bytes.extend_from_slice(&document.as_bytes()),
+        Value::Float(float) => bytes.extend_from_slice(&float.to_be_bytes()),
+        Value::HyperLogLog(hll) => bytes.extend_from_slice(&hll.as_bytes()),
+        Value::Integer(int) => bytes.extend_from_slice(&int.to_be_bytes()),
+        Value::List(list) => {
+            write_len(&mut bytes, list.len());
+
+            for item in list {
+                write_sized(&mut bytes, item);
+            }
+        }
+        Value::Map(map) => {
+            write_len(&mut bytes, map.len());
+
+            for entry in map.iter() {
+                write_sized(&mut bytes, entry.key());
+                write_sized(&mut bytes, entry.value());
+            }
+        }
+        Value::Set(set) => {
+            write_len(&mut bytes, set.len());
+
+            for item in set.iter() {
+                write_sized(&mut bytes, item.key());
+            }
+        }
+        Value::Stream(stream) => bytes.extend_from_slice(&stream.as_bytes()),
+        Value::String(string) => bytes.extend_from_slice(string.as_bytes()),
+    }
+
+    let checksum = checksum(&bytes);
+    bytes.extend_from_slice(&checksum.to_be_bytes());
+
+    bytes
+}
+
+/// Deserialize a value from the form created by [`dump`].
+///
+/// Returns `None` if the bytes aren't a valid serialized value, were created
+/// by a different version, or don't match their checksum.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::state::{dump, Value};
+///
+/// let mut bytes = dump::dump(&Value::Bytes(b"foo".to_vec()));
+/// assert!(dump::restore(&bytes).is_some());
+///
+/// // Corrupt the value.
+/// bytes[5] = b'g';
+/// assert!(dump::restore(&bytes).is_none());
+/// ```
+///
+/// [`dump`]: fn.dump.html
+pub fn restore(bytes: &[u8]) -> Option<Value> {
+    let split = bytes.len().checked_sub(CHECKSUM_LEN)?;
+    let (bytes, expected) = bytes.split_at(split);
+
+    if bytes.len() < HEADER_LEN
+        || bytes[..MAGIC.len()] != MAGIC[..]
+        || bytes[MAGIC.len()] != VERSION
+        || checksum(bytes) != u32::from_be_bytes(expected.try_into().ok()?)
+    {
+        return None;
+    }
+
+    let key_type = KeyType::try_from(bytes[HEADER_LEN - 1]).ok()?;
+    let body = &bytes[HEADER_LEN..];
+
+    Some(match key_type {
+        KeyType::Boolean => match body {
+            [0] => Value::Boolean(false),
+            [1] => Value::Boolean(true),
+            _ => return None,
+        },
+        KeyType::Bytes => Value::Bytes(body.to_vec()),
+        KeyType::Document => Value::Document(Document::from_bytes(body)?),
+        KeyType::Float => Value::Float(f64::from_be_bytes(body.try_into().ok()?)),
+        KeyType::HyperLogLog => Value::HyperLogLog(HyperLogLog::from_bytes(body)?),
+        KeyType::Integer => Value::Integer(i64::from_be_bytes(body.try_into().ok()?)),
+        KeyType::List => {
+            let mut idx = 0;
+            let len = read_len(body, &mut idx)?;
+            let mut list = Vec::new();
+
+            for _ in 0..len {
+                list.push(read_sized(body, &mut idx)?);
+            }
+
+            finish(body, idx, Value::List(list))?
+        }
+        KeyType::Map => {
+            let mut idx = 0;
+            let len = read_len(body, &mut idx)?;
+            let map = DashMap::new();
+
+            for _ in 0..len {
+                let key = read_sized(body, &mut idx)?;
+                let value = read_sized(body, &mut idx)?;
+
+                map.insert(key, value);
+            }
+
+            finish(body, idx, Value::Map(map))?
+        }
+        KeyType::Set => {
+            let mut idx = 0;
+            let len = read_len(body, &mut idx)?;
+            let set = DashSet::new();
+
+            for _ in 0..len {
+                set.insert(read_sized(body, &mut idx)?);
+            }
+
+            finish(body, idx, Value::Set(set))?
+        }
+        KeyType::Stream => Value::Stream(Stream::from_bytes(body)?),
+        KeyType::String => Value::String(String::from_utf8(body.to_vec()).ok()?),
+    })
+}
+
+/// Calculate the CRC-32 (IEEE) checksum of some bytes.
+fn checksum(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+/// Return the value if the entire body was read.
+fn finish(body: &[u8], idx: usize, value: Value) -> Option<Value> {
+    if idx == body.len() {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+fn read_len(bytes: &[u8], idx: &mut usize) -> Option<usize> {
+    let len = u32::from_be_bytes(bytes.get(*idx..*idx + 4)?.try_into().ok()?) as usize;
+    *idx += 4;
+
+    Some(len)
+}
+
+fn read_sized(bytes: &[u8], idx: &mut usize) -> Option<Vec<u8>> {
+    let len = read_len(bytes, idx)?;
+    let value = bytes.get(*idx..idx.checked_add(len)?)?.to_vec();
+    *idx += len;
+
+    Some(value)
+}
+
+fn write_len(to: &mut Vec<u8>, len: usize) {
+    to.extend_from_slice(&(len as u32).to_be_bytes());
+}
+
+fn write_sized(to: &mut Vec<u8>, bytes: &[u8]) {
+    write_len(to, bytes.len());
+    to.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Document, HyperLogLog, Stream, Value, VERSION};
+    use crate::state::{document::DEPTH_MAX, StreamId};
+    use alloc::{string::String, vec::Vec};
+    use dashmap::{DashMap, DashSet};
+
+    fn round_trip(value: &Value) -> Value {
+        super::restore(&super::dump(value)).unwrap()
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(0xCBF4_3926, super::checksum(b"123456789"));
+    }
+
+    #[test]
+    fn test_round_trip_scalars() {
+        assert!(matches!(
+            round_trip(&Value::Boolean(true)),
+            Value::Boolean(true)
+        ));
+        assert!(matches!(
+            round_trip(&Value::Bytes(b"foo".to_vec())),
+            Value::Bytes(bytes) if bytes == b"foo"
+        ));
+        assert!(matches!(
+            round_trip(&Value::Float(1.5)),
+            Value::Float(float) if float == 1.5
+        ));
+        assert!(matches!(
+            round_trip(&Value::Integer(-7)),
+            Value::Integer(-7)
+        ));
+        assert!(matches!(
+            round_trip(&Value::String(String::from("foo"))),
+            Value::String(string) if string == "foo"
+        ));
+
+        let document = Document::from_bytes(br#"{"a":[1,2]}"#).unwrap();
+        assert!(matches!(
+            round_trip(&Value::Document(document.clone())),
+            Value::Document(restored) if restored == document
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_deepest_document() {
+        let depth = DEPTH_MAX + 1;
+        let text = "[".repeat(depth) + &"]".repeat(depth);
+        let document = Document::from_bytes(text.as_bytes()).unwrap();
+        assert_eq!(DEPTH_MAX, document.depth());
+
+        assert!(matches!(
+            round_trip(&Value::Document(document.clone())),
+            Value::Document(restored) if restored == document
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_collections() {
+        let list = Value::List(vec![b"a".to_vec(), Vec::new(), b"c".to_vec()]);
+        assert!(matches!(
+            round_trip(&list),
+            Value::List(list) if list == [b"a".to_vec(), Vec::new(), b"c".to_vec()]
+        ));
+
+        let map = DashMap::new();
+        map.insert(b"a".to_vec(), b"1".to_vec());
+        map.insert(b"b".to_vec(), b"2".to_vec());
+        let restored = round_trip(&Value::Map(map));
+        let restored = restored.as_map_ref().unwrap();
+        assert_eq!(2, restored.len());
+        assert_eq!(b"2", restored.get(b"b".as_ref()).unwrap().as_slice());
+
+        let set = DashSet::new();
+        set.insert(b"a".to_vec());
+        let restored = round_trip(&Value::Set(set));
+        assert!(restored.as_set_ref().unwrap().contains(b"a".as_ref()));
+
+        let mut hll = HyperLogLog::new();
+        hll.add(b"foo");
+        assert!(matches!(
+            round_trip(&Value::HyperLogLog(hll.clone())),
+            Value::HyperLogLog(restored) if restored == hll
+        ));
+
+        let mut stream = Stream::new();
+        assert!(stream.append(StreamId::new(1, 0), vec![(b"a".to_vec(), b"1".to_vec())]));
+        let restored = round_trip(&Value::Stream(stream));
+        assert_eq!(1, restored.as_stream_ref().unwrap().len());
+    }
+
+    #[test]
+    fn test_restore_invalid() {
+        let bytes = super::dump(&Value::Integer(1));
+
+        // Truncated.
+        assert!(super::restore(&bytes[..bytes.len() - 1]).is_none());
+        assert!(super::restore(&[]).is_none());
+
+        // Corrupted checksum.
+        let mut corrupted = bytes.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert!(super::restore(&corrupted).is_none());
+
+        // Different version, even with a valid checksum.
+        let mut versioned = bytes[..bytes.len() - 4].to_vec();
+        versioned[3] = VERSION + 1;
+        let checksum = super::checksum(&versioned);
+        versioned.extend_from_slice(&checksum.to_be_bytes());
+        assert!(super::restore(&versioned).is_none());
+    }
+}
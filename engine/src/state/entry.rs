@@ -0,0 +1,91 @@
+use super::Value;
+use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// A value in the state along with metadata about how it's accessed.
+///
+/// The metadata is atomic so that it can be updated while only holding a
+/// shared reference to the entry, such as when a value is only read.
+#[derive(Debug)]
+pub(crate) struct Entry {
+    /// The tick of the state's access clock when the entry was last accessed.
+    accessed: AtomicUsize,
+    /// The Unix timestamp in milliseconds of when the entry was last accessed.
+    accessed_at: AtomicU64,
+    /// The Unix timestamp in milliseconds of when the entry was created.
+    created_at: u64,
+    /// The number of times the entry has been accessed.
+    frequency: AtomicUsize,
+    value: Value,
+}
+
+impl Entry {
+    pub fn new(value: Value, tick: usize, now: u64) -> Self {
+        Self {
+            accessed: AtomicUsize::new(tick),
+            accessed_at: AtomicU64::new(now),
+            created_at: now,
+            frequency: AtomicUsize::new(1),
+            value,
+        }
+    }
+
+    pub fn accessed(&self) -> usize {
+        self.accessed.load(Ordering::Relaxed)
+    }
+
+    pub fn accessed_at(&self) -> u64 {
+        self.accessed_at.load(Ordering::Relaxed)
+    }
+
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    pub fn frequency(&self) -> usize {
+        self.frequency.load(Ordering::Relaxed)
+    }
+
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+
+    /// Record an access of the entry at a tick of the state's access clock and
+    /// a Unix timestamp in milliseconds.
+    pub fn touch(&self, tick: usize, now: u64) {
+        self.accessed.store(tick, Ordering::Relaxed);
+        self.accessed_at.store(now, Ordering::Relaxed);
+        let _ = self
+            .frequency
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |frequency| {
+                frequency.checked_add(1)
+            });
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn value_mut(&mut self) -> &mut Value {
+        &mut self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entry, Value};
+
+    #[test]
+    fn test_touch() {
+        let entry = Entry::new(Value::Integer(1), 3, 1000);
+        assert_eq!(3, entry.accessed());
+        assert_eq!(1000, entry.accessed_at());
+        assert_eq!(1, entry.frequency());
+
+        entry.touch(7, 1500);
+        entry.touch(9, 2000);
+        assert_eq!(9, entry.accessed());
+        assert_eq!(2000, entry.accessed_at());
+        assert_eq!(1000, entry.created_at());
+        assert_eq!(3, entry.frequency());
+    }
+}
@@ -0,0 +1,167 @@
+//! Matching of keys against glob patterns.
+//!
+//! Patterns support the following syntax:
+//!
+//! - `*` matches any number of bytes, including none
+//! - `?` matches exactly one byte
+//! - `[abc]` matches one of the bytes in the brackets, and `[a-z]` matches a
+//!   range of bytes; `[!abc]` or `[^abc]` matches any byte not in the brackets
+//! - `\` escapes the following byte so that it's matched literally
+//!
+//! # Examples
+//!
+//! ```
+//! use hop_engine::state::glob;
+//!
+//! assert!(glob::matches(b"user:*", b"user:alice"));
+//! assert!(glob::matches(b"h?p", b"hop"));
+//! assert!(glob::matches(b"log:[0-9]", b"log:7"));
+//! assert!(!glob::matches(b"log:[!0-9]", b"log:7"));
+//! ```
+
+/// Check whether some bytes match a glob pattern.
+pub fn matches(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // Where to resume if the current attempt fails: the pattern index after
+    // the last star and the text index that star is currently matched up to.
+    let mut backtrack = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                p += 1;
+                backtrack = Some((p, t));
+
+                continue;
+            }
+            Some(_) => {
+                if let Some((len, true)) = match_one(&pattern[p..], text[t]) {
+                    p += len;
+                    t += 1;
+
+                    continue;
+                }
+            }
+            None => {}
+        }
+
+        // The current byte doesn't match, so let the last star consume one
+        // more byte and try again.
+        match backtrack {
+            Some((star_p, star_t)) => {
+                p = star_p;
+                t = star_t + 1;
+                backtrack = Some((star_p, t));
+            }
+            None => return false,
+        }
+    }
+
+    pattern[p..].iter().all(|&byte| byte == b'*')
+}
+
+/// Match the single-byte element at the start of a pattern against a byte.
+///
+/// Returns the length of the element in the pattern and whether it matched,
+/// or `None` if the element is malformed.
+fn match_one(pattern: &[u8], byte: u8) -> Option<(usize, bool)> {
+    match *pattern.first()? {
+        b'?' => Some((1, true)),
+        b'\\' => Some((2, *pattern.get(1)? == byte)),
+        b'[' => {
+            let mut idx = 1;
+            let negated = matches!(pattern.get(idx), Some(b'!') | Some(b'^'));
+
+            if negated {
+                idx += 1;
+            }
+
+            let mut matched = false;
+            let mut first = true;
+
+            loop {
+                let mut start = *pattern.get(idx)?;
+
+                // A closing bracket right after the opening one is literal.
+                if start == b']' && !first {
+                    return Some((idx + 1, matched != negated));
+                }
+
+                first = false;
+
+                if start == b'\\' {
+                    idx += 1;
+                    start = *pattern.get(idx)?;
+                }
+
+                idx += 1;
+
+                let end = match (pattern.get(idx), pattern.get(idx + 1)) {
+                    (Some(b'-'), Some(&end)) if end != b']' => {
+                        idx += 2;
+
+                        end
+                    }
+                    _ => start,
+                };
+
+                matched |= start <= byte && byte <= end;
+            }
+        }
+        literal => Some((1, literal == byte)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matches;
+
+    #[test]
+    fn test_literal() {
+        assert!(matches(b"foo", b"foo"));
+        assert!(!matches(b"foo", b"fo"));
+        assert!(!matches(b"foo", b"fooo"));
+        assert!(matches(b"", b""));
+        assert!(!matches(b"", b"a"));
+    }
+
+    #[test]
+    fn test_star() {
+        assert!(matches(b"*", b""));
+        assert!(matches(b"*", b"anything"));
+        assert!(matches(b"a*c", b"abbbc"));
+        assert!(matches(b"a*c", b"ac"));
+        assert!(!matches(b"a*c", b"abcd"));
+        assert!(matches(b"*:*:*", b"a:b:c"));
+        assert!(matches(b"a**b", b"a-b"));
+    }
+
+    #[test]
+    fn test_question() {
+        assert!(matches(b"?", b"a"));
+        assert!(!matches(b"?", b""));
+        assert!(matches(b"a?c*", b"abcdef"));
+    }
+
+    #[test]
+    fn test_class() {
+        assert!(matches(b"[abc]", b"b"));
+        assert!(!matches(b"[abc]", b"d"));
+        assert!(matches(b"[a-c]x", b"bx"));
+        assert!(matches(b"[!a-c]", b"d"));
+        assert!(matches(b"[^a-c]", b"d"));
+        assert!(!matches(b"[^a-c]", b"a"));
+        assert!(matches(b"[]]", b"]"));
+        assert!(matches(b"[a-]", b"-"));
+        assert!(matches(b"[\\]]", b"]"));
+        assert!(!matches(b"[abc", b"a"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert!(matches(b"\\*", b"*"));
+        assert!(!matches(b"\\*", b"a"));
+        assert!(matches(b"a\\?*", b"a?b"));
+        assert!(!matches(b"\\", b"\\"));
+    }
+}
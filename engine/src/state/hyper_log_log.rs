@@ -0,0 +1,507 @@
+//! A probabilistic cardinality estimator.
+//!
+//! Refer to [`HyperLogLog`] for more information.
+//!
+//! [`HyperLogLog`]: struct.HyperLogLog.html
+
+use alloc::vec::Vec;
+use core::{convert::TryInto, mem};
+
+/// The number of bits of an item's hash used to select its register.
+pub const PRECISION: u32 = 14;
+
+/// The number of registers in a HyperLogLog.
+pub const REGISTERS: usize = 1 << PRECISION;
+
+/// The highest rank that a register can hold.
+const RANK_MAX: u8 = (64 - PRECISION + 1) as u8;
+
+/// The number of bits used by each register in the dense serialized form.
+const REGISTER_BITS: usize = 6;
+
+/// The number of entries at which the sparse representation is converted to
+/// the dense representation.
+///
+/// Each sparse entry takes 3 bytes when serialized, so past this point the
+/// dense form is smaller.
+const SPARSE_MAX: usize = REGISTERS * REGISTER_BITS / 8 / 3;
+
+/// Magic bytes at the start of the serialized form.
+const MAGIC: &[u8; 3] = b"HLL";
+
+/// The version of the serialized form.
+const VERSION: u8 = 1;
+
+const ENCODING_SPARSE: u8 = 0;
+const ENCODING_DENSE: u8 = 1;
+
+/// The length of the header of the serialized form: the magic bytes, the
+/// version, and the encoding.
+const HEADER_LEN: usize = MAGIC.len() + 2;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Registers {
+    /// Register indexes and their ranks, sorted by index. Registers not in the
+    /// list have a rank of 0.
+    Sparse(Vec<(u16, u8)>),
+    /// The rank of every register.
+    Dense(Vec<u8>),
+}
+
+/// A HyperLogLog, used to estimate the number of unique items that have been
+/// added to it using a small, fixed amount of memory.
+///
+/// Small HyperLogLogs are stored sparsely and are converted to a dense
+/// representation of 16 KiB once enough registers are in use. Estimates have a
+/// standard error of about 0.81%.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::state::HyperLogLog;
+///
+/// let mut hll = HyperLogLog::new();
+/// hll.add(b"foo");
+/// hll.add(b"bar");
+/// hll.add(b"foo");
+///
+/// assert_eq!(2, hll.count());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HyperLogLog {
+    registers: Registers,
+}
+
+impl HyperLogLog {
+    /// Estimate the number of bytes of memory owned by the HyperLogLog's
+    /// registers.
+    pub fn memory_usage(&self) -> usize {
+        match &self.registers {
+            Registers::Sparse(registers) => registers.capacity() * mem::size_of::<(u16, u8)>(),
+            Registers::Dense(registers) => registers.capacity(),
+        }
+    }
+
+    pub fn new() -> Self {
+        Self {
+            registers: Registers::Sparse(Vec::new()),
+        }
+    }
+
+    /// Deserialize a HyperLogLog from its serialized form.
+    ///
+    /// Returns `None` if the bytes aren't a valid serialized HyperLogLog.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::state::HyperLogLog;
+    ///
+    /// let mut hll = HyperLogLog::new();
+    /// hll.add(b"foo");
+    ///
+    /// let bytes = hll.as_bytes();
+    /// assert_eq!(Some(hll), HyperLogLog::from_bytes(&bytes));
+    /// assert!(HyperLogLog::from_bytes(b"foo").is_none());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.get(..MAGIC.len())? != MAGIC || *bytes.get(MAGIC.len())? != VERSION {
+            return None;
+        }
+
+        let body = bytes.get(HEADER_LEN..)?;
+
+        let registers = match *bytes.get(HEADER_LEN - 1)? {
+            ENCODING_SPARSE => {
+                let len = u16::from_be_bytes(body.get(..2)?.try_into().ok()?) as usize;
+                let entries = &body[2..];
+
+                if entries.len() != len * 3 || len > SPARSE_MAX {
+                    return None;
+                }
+
+                let mut sparse = Vec::with_capacity(len);
+
+                for entry in entries.chunks(3) {
+                    let idx = u16::from_be_bytes([entry[0], entry[1]]);
+                    let rank = entry[2];
+
+                    if idx as usize >= REGISTERS || rank == 0 || rank > RANK_MAX {
+                        return None;
+                    }
+
+                    // Entries must be strictly sorted by index.
+                    if let Some((last, _)) = sparse.last() {
+                        if *last >= idx {
+                            return None;
+                        }
+                    }
+
+                    sparse.push((idx, rank));
+                }
+
+                Registers::Sparse(sparse)
+            }
+            ENCODING_DENSE => {
+                if body.len() != REGISTERS * REGISTER_BITS / 8 {
+                    return None;
+                }
+
+                let mut dense = Vec::with_capacity(REGISTERS);
+
+                for chunk in body.chunks(3) {
+                    let packed = u32::from_be_bytes([0, chunk[0], chunk[1], chunk[2]]);
+
+                    for shift in [18, 12, 6, 0].iter() {
+                        let rank = (packed >> shift) as u8 & 0b11_1111;
+
+                        if rank > RANK_MAX {
+                            return None;
+                        }
+
+                        dense.push(rank);
+                    }
+                }
+
+                Registers::Dense(dense)
+            }
+            _ => return None,
+        };
+
+        Some(Self { registers })
+    }
+
+    /// Add an item, returning whether the estimated cardinality may have
+    /// changed.
+    pub fn add(&mut self, item: &[u8]) -> bool {
+        let hash = hash(item);
+        let idx = (hash >> (64 - PRECISION)) as u16;
+        // Set a sentinel bit so that the rank is capped if the remaining bits
+        // are all zero.
+        let remaining = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+
+        self.update(idx, rank)
+    }
+
+    /// Estimate the number of unique items that have been added.
+    pub fn count(&self) -> u64 {
+        let m = REGISTERS as f64;
+        let mut sum = 0.0;
+        let mut zeros = 0;
+
+        match &self.registers {
+            Registers::Sparse(sparse) => {
+                zeros = REGISTERS - sparse.len();
+                sum += zeros as f64;
+
+                for (_, rank) in sparse {
+                    sum += inverse_power_of_two(*rank);
+                }
+            }
+            Registers::Dense(dense) => {
+                for rank in dense {
+                    if *rank == 0 {
+                        zeros += 1;
+                    }
+
+                    sum += inverse_power_of_two(*rank);
+                }
+            }
+        }
+
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / sum;
+
+        // Use linear counting for small cardinalities, where the raw estimate
+        // is heavily biased.
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * ln(m / zeros as f64)
+        } else {
+            estimate
+        };
+
+        (estimate + 0.5) as u64
+    }
+
+    /// Merge another HyperLogLog into this one, so that this one estimates the
+    /// cardinality of the union of the two.
+    pub fn merge(&mut self, other: &Self) {
+        match &other.registers {
+            Registers::Sparse(sparse) => {
+                for (idx, rank) in sparse {
+                    self.update(*idx, *rank);
+                }
+            }
+            Registers::Dense(dense) => {
+                let own = self.dense_mut();
+
+                for (own, rank) in own.iter_mut().zip(dense) {
+                    if *rank > *own {
+                        *own = *rank;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Serialize the HyperLogLog into its compact form.
+    ///
+    /// The form starts with the bytes `HLL`, a version byte, and an encoding
+    /// byte. Sparse HyperLogLogs follow this with a big-endian `u16` number of
+    /// entries and then each entry as a big-endian `u16` register index and a
+    /// `u8` rank. Dense HyperLogLogs follow it with every register packed into
+    /// 6 bits.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+
+        match &self.registers {
+            Registers::Sparse(sparse) => {
+                bytes.push(ENCODING_SPARSE);
+                bytes.extend_from_slice(&(sparse.len() as u16).to_be_bytes());
+
+                for (idx, rank) in sparse {
+                    bytes.extend_from_slice(&idx.to_be_bytes());
+                    bytes.push(*rank);
+                }
+            }
+            Registers::Dense(dense) => {
+                bytes.push(ENCODING_DENSE);
+                bytes.reserve(REGISTERS * REGISTER_BITS / 8);
+
+                for chunk in dense.chunks(4) {
+                    let packed = chunk
+                        .iter()
+                        .fold(0u32, |packed, rank| (packed << 6) | u32::from(*rank));
+
+                    bytes.extend_from_slice(&packed.to_be_bytes()[1..]);
+                }
+            }
+        }
+
+        bytes
+    }
+
+    fn dense_mut(&mut self) -> &mut Vec<u8> {
+        if let Registers::Sparse(sparse) = &self.registers {
+            let mut dense = alloc::vec![0; REGISTERS];
+
+            for (idx, rank) in sparse {
+                dense[*idx as usize] = *rank;
+            }
+
+            self.registers = Registers::Dense(dense);
+        }
+
+        match &mut self.registers {
+            Registers::Dense(dense) => dense,
+            Registers::Sparse(_) => unreachable!(),
+        }
+    }
+
+    /// Raise a register's rank if the new rank is higher, returning whether it
+    /// was raised.
+    fn update(&mut self, idx: u16, rank: u8) -> bool {
+        if let Registers::Sparse(sparse) = &mut self.registers {
+            match sparse.binary_search_by_key(&idx, |(idx, _)| *idx) {
+                Ok(pos) => {
+                    let existing = &mut sparse[pos].1;
+
+                    if rank <= *existing {
+                        return false;
+                    }
+
+                    *existing = rank;
+
+                    return true;
+                }
+                Err(pos) if sparse.len() < SPARSE_MAX => {
+                    sparse.insert(pos, (idx, rank));
+
+                    return true;
+                }
+                Err(_) => {}
+            }
+        }
+
+        let existing = &mut self.dense_mut()[idx as usize];
+
+        if rank <= *existing {
+            return false;
+        }
+
+        *existing = rank;
+
+        true
+    }
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hash an item with 64-bit FNV-1a followed by the MurmurHash3 finalizer.
+///
+/// The hash needs to be stable across processes and platforms, since
+/// serialized HyperLogLogs can be moved between them and merged.
+fn hash(item: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+
+    for byte in item {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
+
+/// Calculate `2^-rank` exactly by building the float from its exponent.
+fn inverse_power_of_two(rank: u8) -> f64 {
+    f64::from_bits((1023 - u64::from(rank)) << 52)
+}
+
+/// Calculate the natural logarithm of a positive, normal float.
+///
+/// The float is split into `mantissa * 2^exponent` with the mantissa in
+/// `[1, 2)`, and the logarithm of the mantissa is calculated with the series
+/// for `2 * atanh(z)`, which converges quickly for the mantissa's range.
+fn ln(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | (1023 << 52));
+
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = 0.0;
+    let mut n = 1.0;
+
+    while n < 40.0 {
+        sum += term / n;
+        term *= z2;
+        n += 2.0;
+    }
+
+    exponent as f64 * core::f64::consts::LN_2 + 2.0 * sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HyperLogLog, Registers, REGISTERS};
+    use alloc::vec::Vec;
+    use core::fmt::Debug;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(HyperLogLog: Clone, Debug, Default, Eq, PartialEq);
+
+    fn assert_close(expected: u64, actual: u64) {
+        let error = (expected as f64 - actual as f64).abs() / expected as f64;
+
+        assert!(error < 0.03, "expected ~{}, got {}", expected, actual);
+    }
+
+    #[test]
+    fn test_empty() {
+        let hll = HyperLogLog::new();
+
+        assert_eq!(0, hll.count());
+        assert_eq!(b"HLL\x01\x00\x00\x00".as_ref(), hll.as_bytes().as_slice());
+    }
+
+    #[test]
+    fn test_add_duplicates() {
+        let mut hll = HyperLogLog::new();
+
+        assert!(hll.add(b"foo"));
+        assert!(!hll.add(b"foo"));
+        assert_eq!(1, hll.count());
+    }
+
+    #[test]
+    fn test_count_small_and_large() {
+        let mut hll = HyperLogLog::new();
+
+        for i in 0..1000u32 {
+            hll.add(&i.to_be_bytes());
+        }
+
+        assert!(matches!(hll.registers, Registers::Sparse(_)));
+        assert_close(1000, hll.count());
+
+        for i in 1000..100_000u32 {
+            hll.add(&i.to_be_bytes());
+        }
+
+        assert!(matches!(hll.registers, Registers::Dense(_)));
+        assert_close(100_000, hll.count());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a = HyperLogLog::new();
+        let mut b = HyperLogLog::new();
+
+        for i in 0..20_000u32 {
+            a.add(&i.to_be_bytes());
+        }
+
+        for i in 10_000..30_000u32 {
+            b.add(&i.to_be_bytes());
+        }
+
+        a.merge(&b);
+        assert_close(30_000, a.count());
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let mut hll = HyperLogLog::new();
+
+        for i in 0..100u32 {
+            hll.add(&i.to_be_bytes());
+        }
+
+        let sparse = hll.as_bytes();
+        assert_eq!(7 + hll_len(&hll) * 3, sparse.len());
+        assert_eq!(Some(&hll), HyperLogLog::from_bytes(&sparse).as_ref());
+
+        for i in 100..50_000u32 {
+            hll.add(&i.to_be_bytes());
+        }
+
+        let dense = hll.as_bytes();
+        assert_eq!(5 + REGISTERS * 6 / 8, dense.len());
+        assert_eq!(Some(&hll), HyperLogLog::from_bytes(&dense).as_ref());
+    }
+
+    #[test]
+    fn test_from_bytes_invalid() {
+        assert!(HyperLogLog::from_bytes(b"").is_none());
+        // the header is cut off before the encoding
+        assert!(HyperLogLog::from_bytes(b"HLL\x01").is_none());
+        assert!(HyperLogLog::from_bytes(b"HLL\x02\x00\x00\x00").is_none());
+        assert!(HyperLogLog::from_bytes(b"HLL\x01\x02\x00\x00").is_none());
+        // one entry is declared, but none are present
+        assert!(HyperLogLog::from_bytes(b"HLL\x01\x00\x00\x01").is_none());
+        // the rank is higher than possible
+        assert!(HyperLogLog::from_bytes(b"HLL\x01\x00\x00\x01\x00\x00\x40").is_none());
+        // the entries are not sorted
+        let unsorted: Vec<u8> = b"HLL\x01\x00\x00\x02\x00\x02\x01\x00\x01\x01".to_vec();
+        assert!(HyperLogLog::from_bytes(&unsorted).is_none());
+    }
+
+    fn hll_len(hll: &HyperLogLog) -> usize {
+        match &hll.registers {
+            Registers::Sparse(sparse) => sparse.len(),
+            Registers::Dense(_) => REGISTERS,
+        }
+    }
+}
@@ -1,12 +1,31 @@
+pub mod document;
+pub mod dump;
+pub mod glob;
+pub mod hyper_log_log;
+pub mod stream;
 pub mod value;
 
-pub use self::value::Value;
+mod entry;
 
-use alloc::{borrow::ToOwned, string::String, sync::Arc, vec::Vec};
-use core::convert::TryFrom;
+pub use self::{
+    document::{Document, DocumentPath},
+    hyper_log_log::HyperLogLog,
+    stream::{Stream, StreamEntry, StreamId},
+    value::Value,
+};
+
+use self::entry::Entry;
+
+use alloc::{borrow::ToOwned, collections::BinaryHeap, string::String, sync::Arc, vec::Vec};
+use core::{
+    convert::TryFrom,
+    mem,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicUsize, Ordering},
+};
 use dashmap::{
     mapref::one::{Ref, RefMut},
-    DashMap,
+    DashMap, SharedValue,
 };
 
 pub type Key = Vec<u8>;
@@ -22,6 +41,9 @@ pub enum KeyType {
     List = 5,
     Map = 6,
     Set = 7,
+    HyperLogLog = 8,
+    Stream = 9,
+    Document = 10,
 }
 
 impl TryFrom<u8> for KeyType {
@@ -39,20 +61,258 @@ impl TryFrom<u8> for KeyType {
             5 => List,
             6 => Map,
             7 => Set,
+            8 => HyperLogLog,
+            9 => Stream,
+            10 => Document,
             _ => return Err(()),
         })
     }
 }
 
+/// An immutable reference to a key's value.
+pub struct ValueRef<'a> {
+    inner: Ref<'a, Key, Entry>,
+}
+
+impl ValueRef<'_> {
+    pub fn key(&self) -> &Key {
+        self.inner.key()
+    }
+
+    pub fn value(&self) -> &Value {
+        self.inner.value().value()
+    }
+}
+
+impl Deref for ValueRef<'_> {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        self.value()
+    }
+}
+
+/// A mutable reference to a key's value.
+///
+/// The memory usage of the value is measured again when the reference is
+/// dropped, so that the state's memory usage reflects any changes.
+pub struct ValueMut<'a> {
+    inner: RefMut<'a, Key, Entry>,
+    usage: usize,
+    used: &'a AtomicUsize,
+}
+
+impl<'a> ValueMut<'a> {
+    fn new(inner: RefMut<'a, Key, Entry>, used: &'a AtomicUsize) -> Self {
+        let usage = inner.value().value().memory_usage();
+
+        Self { inner, usage, used }
+    }
+
+    pub fn key(&self) -> &Key {
+        self.inner.key()
+    }
+
+    pub fn value(&self) -> &Value {
+        self.inner.value().value()
+    }
+
+    pub fn value_mut(&mut self) -> &mut Value {
+        self.inner.value_mut().value_mut()
+    }
+}
+
+impl Deref for ValueMut<'_> {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        self.value()
+    }
+}
+
+impl DerefMut for ValueMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.value_mut()
+    }
+}
+
+impl Drop for ValueMut<'_> {
+    fn drop(&mut self) {
+        State::adjust(self.used, self.usage, self.value().memory_usage());
+    }
+}
+
+/// A key sampled for eviction, along with how it has been accessed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Sample {
+    /// The tick of the state's access clock when the key was last accessed.
+    pub accessed: usize,
+    /// The number of times the key has been accessed.
+    pub frequency: usize,
+    pub key: Key,
+}
+
+/// A position in the shards of a state to continue a [`State::scan`] from.
+///
+/// The default cursor starts a new scan.
+///
+/// [`State::scan`]: struct.State.html#method.scan
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ScanCursor {
+    /// The index of the shard to continue from.
+    pub shard: usize,
+    /// The last key returned from the shard, if it has more keys to return.
+    pub after: Option<Key>,
+}
+
+/// Information about a key's value and how it has been accessed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ObjectInfo {
+    /// The Unix timestamp in milliseconds of when the key was last accessed.
+    pub accessed_at: u64,
+    /// The Unix timestamp in milliseconds of when the key was created.
+    pub created_at: u64,
+    /// The number of times the key has been accessed, including its creation.
+    pub frequency: usize,
+    pub key_type: KeyType,
+    /// The estimated number of bytes of memory used by the key and its value.
+    pub memory_usage: usize,
+}
+
+/// Data shared by every handle to a state.
+#[derive(Debug)]
+struct Shared {
+    /// The function used to get the current Unix timestamp in milliseconds.
+    clock: fn() -> u64,
+    /// A logical clock that ticks on every access of a key, used to order
+    /// accesses even when they happen within the same millisecond.
+    ticks: AtomicUsize,
+    /// The estimated number of bytes used by the keys and values.
+    used: AtomicUsize,
+}
+
+impl Default for Shared {
+    fn default() -> Self {
+        Self {
+            clock: || 0,
+            ticks: AtomicUsize::new(0),
+            used: AtomicUsize::new(0),
+        }
+    }
+}
+
 // The inner map is public to the crate solely for testing purposes.
 #[derive(Clone, Debug, Default)]
-pub struct State(pub(crate) Arc<DashMap<Key, Value>>);
+pub struct State(pub(crate) Arc<DashMap<Key, Entry>>, Arc<Shared>);
 
 impl State {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create a new state using a function to get the current Unix timestamp
+    /// in milliseconds, which is used to record when keys are created and
+    /// accessed.
+    ///
+    /// By default the time is always 0.
+    pub fn with_clock(clock: fn() -> u64) -> Self {
+        let shared = Shared {
+            clock,
+            ..Shared::default()
+        };
+
+        Self(Arc::default(), Arc::new(shared))
+    }
+
+    /// Retrieve the current Unix timestamp in milliseconds.
+    fn now(&self) -> u64 {
+        (self.1.clock)()
+    }
+
+    /// Advance the access clock, returning the new tick.
+    fn tick(&self) -> usize {
+        self.1.ticks.fetch_add(1, Ordering::Relaxed).wrapping_add(1)
+    }
+
+    /// Estimate the number of bytes of memory used by a key and its value.
+    fn entry_usage(key: &[u8], value: &Value) -> usize {
+        mem::size_of::<Key>() + key.len() + value.memory_usage()
+    }
+
+    /// Adjust a count of used memory after an entry changed from using one
+    /// number of bytes to another.
+    fn adjust(used: &AtomicUsize, before: usize, after: usize) {
+        // Estimates of sampled collections can drift, so make sure that the
+        // count never underflows.
+        let _ = used.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+            Some(used.saturating_sub(before).saturating_add(after))
+        });
+    }
+
+    /// Retrieve the estimated number of bytes of memory used by every key and
+    /// value.
+    ///
+    /// This is updated as keys are inserted, mutated and removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::state::{State, Value};
+    ///
+    /// let state = State::new();
+    /// assert_eq!(0, state.memory_used());
+    ///
+    /// state.insert(b"foo".to_vec(), Value::Bytes(vec![0; 1024]));
+    /// assert!(state.memory_used() > 1024);
+    ///
+    /// state.remove(b"foo");
+    /// assert_eq!(0, state.memory_used());
+    /// ```
+    pub fn memory_used(&self) -> usize {
+        self.1.used.load(Ordering::Relaxed)
+    }
+
+    /// Estimate the number of bytes of memory used by a key and its value.
+    ///
+    /// Returns `None` if the key does not exist.
+    pub fn memory_usage(&self, key: &[u8]) -> Option<usize> {
+        self.0
+            .get(key)
+            .map(|entry| Self::entry_usage(entry.key(), entry.value().value()))
+    }
+
+    /// Retrieve information about a key's value and how it has been accessed.
+    ///
+    /// Unlike retrieving the value itself, this doesn't count as an access.
+    ///
+    /// Returns `None` if the key does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::state::{KeyType, State, Value};
+    ///
+    /// let state = State::with_clock(|| 1000);
+    /// state.insert(b"foo".to_vec(), Value::Integer(1));
+    /// state.key_ref(b"foo");
+    ///
+    /// let info = state.object_info(b"foo").unwrap();
+    /// assert_eq!(KeyType::Integer, info.key_type);
+    /// assert_eq!(1000, info.created_at);
+    /// assert_eq!(2, info.frequency);
+    /// ```
+    pub fn object_info(&self, key: &[u8]) -> Option<ObjectInfo> {
+        let entry = self.0.get(key)?;
+
+        Some(ObjectInfo {
+            accessed_at: entry.value().accessed_at(),
+            created_at: entry.value().created_at(),
+            frequency: entry.value().frequency(),
+            key_type: entry.value().value().kind(),
+            memory_usage: Self::entry_usage(entry.key(), entry.value().value()),
+        })
+    }
+
     /// Check if a key exists.
     ///
     /// # Examples
@@ -84,7 +344,19 @@ impl State {
     /// assert!(state.insert(b"foo".to_vec(), Value::boolean()).is_some());
     /// ```
     pub fn insert(&self, key: Vec<u8>, value: Value) -> Option<Value> {
-        self.0.insert(key, value)
+        let after = Self::entry_usage(&key, &value);
+        let key_len = key.len();
+        let previous = self
+            .0
+            .insert(key, Entry::new(value, self.tick(), self.now()))
+            .map(Entry::into_value);
+        let before = previous.as_ref().map_or(0, |previous| {
+            mem::size_of::<Key>() + key_len + previous.memory_usage()
+        });
+
+        Self::adjust(&self.1.used, before, after);
+
+        previous
     }
 
     /// Remove a value by key, returning both the owned key and value if
@@ -104,7 +376,46 @@ impl State {
     /// assert!(!state.contains_key(b"foo"));
     /// ```
     pub fn remove(&self, key: &[u8]) -> Option<(Vec<u8>, Value)> {
-        self.0.remove(key)
+        let (key, entry) = self.0.remove(key)?;
+        let value = entry.into_value();
+
+        Self::adjust(&self.1.used, Self::entry_usage(&key, &value), 0);
+
+        Some((key, value))
+    }
+
+    /// Remove every key starting with a prefix, returning the owned keys and
+    /// values that were removed.
+    ///
+    /// An empty prefix removes every key. Keys are removed one at a time, so
+    /// writers are never blocked for long, but keys inserted while the removal
+    /// is in progress may be kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::state::{State, Value};
+    ///
+    /// let state = State::new();
+    /// state.insert(b"user:1".to_vec(), Value::Integer(1));
+    /// state.insert(b"user:2".to_vec(), Value::Integer(2));
+    /// state.insert(b"post:1".to_vec(), Value::Integer(3));
+    ///
+    /// assert_eq!(2, state.remove_prefix(b"user:").len());
+    /// assert!(state.contains_key(b"post:1"));
+    ///
+    /// assert_eq!(1, state.remove_prefix(b"").len());
+    /// assert!(!state.contains_key(b"post:1"));
+    /// ```
+    pub fn remove_prefix(&self, prefix: &[u8]) -> Vec<(Key, Value)> {
+        let keys = self
+            .0
+            .iter()
+            .filter(|entry| entry.key().starts_with(prefix))
+            .map(|entry| entry.key().clone())
+            .collect::<Vec<_>>();
+
+        keys.iter().filter_map(|key| self.remove(key)).collect()
     }
 
     /// Retrieve an immutable reference to a key-value pair by key.
@@ -125,28 +436,34 @@ impl State {
     /// state.insert(b"foo".to_vec(), Value::string());
     /// assert!(state.key_ref(b"foo").is_some());
     /// ```
-    pub fn key_ref<'a>(&'a self, key: &[u8]) -> Option<Ref<'a, Key, Value>> {
+    pub fn key_ref<'a>(&'a self, key: &[u8]) -> Option<ValueRef<'a>> {
         if key.starts_with(b"__hop__:") {
             panic!("Accessed internal key: {}", String::from_utf8_lossy(key));
         }
 
         debug_assert!(!key.is_empty());
 
-        self.0.get(key)
+        let inner = self.0.get(key)?;
+        inner.value().touch(self.tick(), self.now());
+
+        Some(ValueRef { inner })
     }
 
     /// Retrieve a mutable reference to a key-value pair by key.
     ///
     /// Returns `None` if the key does not exist.
     /// ```
-    pub fn key_mut<'a>(&'a self, key: &[u8]) -> Option<RefMut<'a, Key, Value>> {
+    pub fn key_mut<'a>(&'a self, key: &[u8]) -> Option<ValueMut<'a>> {
         if key.starts_with(b"__hop__:") {
             panic!("Accessed internal key: {}", String::from_utf8_lossy(key));
         }
 
         debug_assert!(!key.is_empty());
 
-        self.0.get_mut(key)
+        let inner = self.0.get_mut(key)?;
+        inner.value().touch(self.tick(), self.now());
+
+        Some(ValueMut::new(inner, &self.1.used))
     }
 
     /// Retrieve a key's value, providing a function returning the value to
@@ -166,24 +483,28 @@ impl State {
     ///     _ => println!("it's something else"),
     /// }
     /// ```
-    pub fn key_or_insert_with<'a>(
-        &'a self,
-        key: &[u8],
-        f: impl Fn() -> Value,
-    ) -> RefMut<'a, Key, Value> {
+    pub fn key_or_insert_with<'a>(&'a self, key: &[u8], f: impl Fn() -> Value) -> ValueMut<'a> {
         if key.starts_with(b"__hop__:") {
             panic!("Accessed internal key: {}", String::from_utf8_lossy(key));
         }
 
         debug_assert!(!key.is_empty());
 
+        // Inserting the key already counts as accessing it.
+        let mut inserted = false;
+
         loop {
             match self.0.get_mut(key) {
                 Some(v) => {
-                    break v;
+                    if !inserted {
+                        v.value().touch(self.tick(), self.now());
+                    }
+
+                    break ValueMut::new(v, &self.1.used);
                 }
                 None => {
-                    self.0.insert(key.to_owned(), f());
+                    self.insert(key.to_owned(), f());
+                    inserted = true;
 
                     continue;
                 }
@@ -205,13 +526,260 @@ impl State {
     /// assert_eq!(Some(KeyType::Boolean), state.key_type(b"foo"));
     /// ```
     pub fn key_type(&self, key: &[u8]) -> Option<KeyType> {
-        self.0.get(key).map(|r| r.value().kind())
+        self.0.get(key).map(|r| r.value().value().kind())
+    }
+
+    /// Scan for up to a number of keys matching a filter, continuing from a
+    /// cursor.
+    ///
+    /// Keys are visited one shard of the map at a time, in sorted order
+    /// within each shard. The cursor to pass into the next call is returned
+    /// alongside the keys, or `None` once every shard has been visited. Every
+    /// key that exists for the whole scan is returned exactly once, even while
+    /// other keys are inserted and removed.
+    ///
+    /// Each call only visits the shards that it returns keys from and locks
+    /// one of them at a time, so a full scan visits each key about once and
+    /// writers are never blocked for long.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use hop_engine::state::{glob, ScanCursor, State, Value};
+    ///
+    /// let state = State::new();
+    /// state.insert(b"user:1".to_vec(), Value::Integer(1));
+    /// state.insert(b"user:2".to_vec(), Value::Integer(2));
+    /// state.insert(b"user:3".to_vec(), Value::Boolean(true));
+    /// state.insert(b"post:1".to_vec(), Value::Integer(3));
+    ///
+    /// let mut cursor = Some(ScanCursor::default());
+    /// let mut keys = Vec::new();
+    ///
+    /// while let Some(current) = cursor {
+    ///     let (batch, next) = state.scan(&current, 2, |key, _| glob::matches(b"user:*", key));
+    ///     keys.extend(batch);
+    ///     cursor = next;
+    /// }
+    ///
+    /// keys.sort();
+    /// assert_eq!(
+    ///     vec![b"user:1".to_vec(), b"user:2".to_vec(), b"user:3".to_vec()],
+    ///     keys,
+    /// );
+    /// ```
+    pub fn scan(
+        &self,
+        cursor: &ScanCursor,
+        count: usize,
+        mut filter: impl FnMut(&[u8], &Value) -> bool,
+    ) -> (Vec<Key>, Option<ScanCursor>) {
+        if count == 0 {
+            return (Vec::new(), Some(cursor.clone()));
+        }
+
+        let shards = self.0.shards();
+        let mut keys = Vec::new();
+        let mut after = cursor.after.as_deref();
+
+        for (idx, shard) in shards.iter().enumerate().skip(cursor.shard) {
+            let room = count - keys.len();
+
+            // Keep the lowest matching keys in a max-heap so that the highest
+            // of them can be swapped out when a lower one is found.
+            let mut lowest = BinaryHeap::with_capacity(room);
+            let mut remaining = false;
+
+            for (key, entry) in shard.read().iter() {
+                if after.is_some_and(|after| key.as_slice() <= after)
+                    || !filter(key, entry.get().value())
+                {
+                    continue;
+                }
+
+                if lowest.len() < room {
+                    lowest.push(key.clone());
+                } else {
+                    remaining = true;
+
+                    if lowest.peek().is_some_and(|highest| key < highest) {
+                        lowest.pop();
+                        lowest.push(key.clone());
+                    }
+                }
+            }
+
+            keys.extend(lowest.into_sorted_vec());
+
+            // Continue from within the shard if it has more keys, otherwise
+            // from the start of the next one.
+            if remaining {
+                let cursor = ScanCursor {
+                    shard: idx,
+                    after: keys.last().cloned(),
+                };
+
+                return (keys, Some(cursor));
+            }
+
+            after = None;
+
+            if keys.len() == count && idx + 1 < shards.len() {
+                let cursor = ScanCursor {
+                    shard: idx + 1,
+                    after: None,
+                };
+
+                return (keys, Some(cursor));
+            }
+        }
+
+        (keys, None)
+    }
+
+    /// Call a function with every key and its value, such as to save a
+    /// snapshot of the state.
+    ///
+    /// Unlike retrieving keys, this doesn't count as accessing them, so it
+    /// doesn't affect which keys are evicted. Only one shard of the map is
+    /// locked at a time, so keys changed while iterating may or may not be
+    /// seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::state::{State, Value};
+    ///
+    /// let state = State::new();
+    /// state.insert(b"foo".to_vec(), Value::Integer(1));
+    /// state.insert(b"bar".to_vec(), Value::Integer(2));
+    ///
+    /// let mut sum = 0;
+    /// state.for_each(|_, value| {
+    ///     if let Value::Integer(int) = value {
+    ///         sum += int;
+    ///     }
+    /// });
+    /// assert_eq!(3, sum);
+    /// ```
+    pub fn for_each(&self, mut f: impl FnMut(&[u8], &Value)) {
+        for shard in 0..self.shard_count() {
+            self.for_each_in_shard(shard, &mut f);
+        }
+    }
+
+    /// The number of shards the keys are split between.
+    pub fn shard_count(&self) -> usize {
+        self.0.shards().len()
+    }
+
+    /// Call a function with every key and its value in one shard, while the
+    /// shard is locked.
+    ///
+    /// Other keys in the shard can't be written until this returns, so slow
+    /// work like IO should be done after it instead. Nothing is called if
+    /// there's no shard at the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::state::{State, Value};
+    ///
+    /// let state = State::new();
+    /// state.insert(b"foo".to_vec(), Value::Integer(1));
+    /// state.insert(b"bar".to_vec(), Value::Integer(2));
+    ///
+    /// let mut count = 0;
+    ///
+    /// for shard in 0..state.shard_count() {
+    ///     state.for_each_in_shard(shard, |_, _| count += 1);
+    /// }
+    ///
+    /// assert_eq!(2, count);
+    /// ```
+    pub fn for_each_in_shard(&self, shard: usize, mut f: impl FnMut(&[u8], &Value)) {
+        let shard = match self.0.shards().get(shard) {
+            Some(shard) => shard.read(),
+            None => return,
+        };
+
+        for (key, entry) in shard.iter() {
+            f(key, entry.get().value());
+        }
+    }
+
+    /// Sample up to a number of consecutive keys for eviction, starting from
+    /// a position in the map.
+    ///
+    /// The position picks both the shard to start in and where to start in
+    /// it, wrapping around the number of each, so a random number can be given
+    /// to sample random keys. Samples continue into the following shards if
+    /// the first doesn't have enough keys after the starting point, so this
+    /// takes time proportional to the size of one shard rather than the whole
+    /// map. Only one shard of the map is locked at a time.
+    pub(crate) fn sample(&self, position: usize, count: usize) -> Vec<Sample> {
+        let shards = self.0.shards();
+        let count = count.min(self.0.len());
+        let start = position % shards.len();
+        let mut samples = Vec::with_capacity(count);
+        // The first non-empty shard and how many of its keys were skipped.
+        let mut first = None;
+
+        for idx in (start..shards.len()).chain(0..start) {
+            if samples.len() >= count {
+                break;
+            }
+
+            let shard = shards[idx].read();
+
+            let skip = match first {
+                None if !shard.is_empty() => {
+                    let skip = position / shards.len() % shard.len();
+                    first = Some((idx, skip));
+
+                    skip
+                }
+                _ => 0,
+            };
+
+            samples.extend(
+                shard
+                    .iter()
+                    .skip(skip)
+                    .take(count - samples.len())
+                    .map(Self::sample_entry),
+            );
+        }
+
+        // Wrap around to the keys skipped in the first shard.
+        if let Some((idx, skip)) = first {
+            let remaining = count.saturating_sub(samples.len()).min(skip);
+
+            samples.extend(
+                shards[idx]
+                    .read()
+                    .iter()
+                    .take(remaining)
+                    .map(Self::sample_entry),
+            );
+        }
+
+        samples
+    }
+
+    fn sample_entry((key, entry): (&Key, &SharedValue<Entry>)) -> Sample {
+        Sample {
+            accessed: entry.get().accessed(),
+            frequency: entry.get().frequency(),
+            key: key.clone(),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{KeyType, State, Value};
+    use super::{KeyType, ScanCursor, State, Value};
+    use alloc::vec::Vec;
     use core::{convert::TryFrom, fmt::Debug, hash::Hash};
     use static_assertions::assert_impl_all;
 
@@ -226,6 +794,75 @@ mod tests {
     );
     assert_impl_all!(State: Clone, Debug, Default);
 
+    #[test]
+    fn test_scan_all() {
+        let state = State::new();
+
+        for idx in 0..100u8 {
+            state.insert([idx].to_vec(), Value::Integer(idx.into()));
+        }
+
+        let mut cursor = Some(ScanCursor::default());
+        let mut keys = Vec::new();
+
+        while let Some(current) = cursor {
+            let (batch, next) = state.scan(&current, 7, |_, _| true);
+            assert!(batch.len() <= 7);
+            keys.extend(batch);
+            cursor = next;
+        }
+
+        keys.sort();
+        assert_eq!(
+            (0..100u8).map(|idx| [idx].to_vec()).collect::<Vec<_>>(),
+            keys
+        );
+    }
+
+    #[test]
+    fn test_scan_filter() {
+        let state = State::new();
+        state.insert(b"a:1".to_vec(), Value::Integer(1));
+        state.insert(b"a:2".to_vec(), Value::Boolean(true));
+        state.insert(b"b:1".to_vec(), Value::Integer(2));
+
+        let (mut keys, cursor) = state.scan(&ScanCursor::default(), 10, |key, value| {
+            key.starts_with(b"a:") && value.kind() == KeyType::Integer
+        });
+        keys.sort();
+        assert_eq!([b"a:1".to_vec()].to_vec(), keys);
+        assert!(cursor.is_none());
+
+        let (keys, cursor) = state.scan(&ScanCursor::default(), 0, |_, _| true);
+        assert!(keys.is_empty());
+        assert_eq!(Some(ScanCursor::default()), cursor);
+    }
+
+    #[test]
+    fn test_sample() {
+        let state = State::new();
+        assert!(state.sample(0, 5).is_empty());
+
+        for idx in 0..100u8 {
+            state.insert([idx].to_vec(), Value::Integer(idx.into()));
+        }
+
+        for position in 0..50 {
+            let samples = state.sample(position * 7919, 5);
+            assert!(!samples.is_empty() && samples.len() <= 5);
+            assert!(samples
+                .iter()
+                .all(|sample| sample.key.len() == 1 && sample.key[0] < 100));
+        }
+
+        for idx in 0..100u8 {
+            state.remove(&[idx]);
+        }
+
+        state.insert(b"foo".to_vec(), Value::Integer(1));
+        assert_eq!(b"foo".to_vec(), state.sample(3, 5)[0].key);
+    }
+
     #[test]
     fn test_key_type_nonexistent_key() {
         let state = State::new();
@@ -0,0 +1,461 @@
+//! An append-only log of entries.
+//!
+//! Refer to [`Stream`] for more information.
+//!
+//! [`Stream`]: struct.Stream.html
+
+use super::value::sampled;
+use alloc::{collections::VecDeque, vec::Vec};
+use core::{
+    convert::TryInto,
+    fmt::{Display, Formatter, Result as FmtResult},
+    mem,
+    str::FromStr,
+};
+
+/// The ID of an entry in a stream.
+///
+/// IDs are made of the Unix timestamp in milliseconds at which the entry was
+/// appended and a sequence number to order entries appended in the same
+/// millisecond. They're displayed and parsed as `millis-sequence`, where the
+/// sequence may be omitted when parsing to mean 0.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::state::StreamId;
+///
+/// let id = "1500-2".parse::<StreamId>().unwrap();
+/// assert_eq!(StreamId::new(1500, 2), id);
+/// assert_eq!("1500-2", id.to_string());
+/// assert_eq!(Ok(StreamId::new(1500, 0)), "1500".parse());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StreamId {
+    pub millis: u64,
+    pub sequence: u64,
+}
+
+impl StreamId {
+    /// The lowest possible ID.
+    pub const MIN: Self = Self::new(0, 0);
+
+    /// The highest possible ID.
+    pub const MAX: Self = Self::new(u64::MAX, u64::MAX);
+
+    pub const fn new(millis: u64, sequence: u64) -> Self {
+        Self { millis, sequence }
+    }
+
+    /// Retrieve the ID directly after this one, if this isn't the highest ID.
+    pub fn next(self) -> Option<Self> {
+        match self.sequence.checked_add(1) {
+            Some(sequence) => Some(Self::new(self.millis, sequence)),
+            None => Some(Self::new(self.millis.checked_add(1)?, 0)),
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let millis = u64::from_be_bytes(bytes.get(..8)?.try_into().ok()?);
+        let sequence = u64::from_be_bytes(bytes.get(8..16)?.try_into().ok()?);
+
+        Some(Self::new(millis, sequence))
+    }
+
+    fn write(self, to: &mut Vec<u8>) {
+        to.extend_from_slice(&self.millis.to_be_bytes());
+        to.extend_from_slice(&self.sequence.to_be_bytes());
+    }
+}
+
+impl Display for StreamId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("{}-{}", self.millis, self.sequence))
+    }
+}
+
+impl FromStr for StreamId {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '-');
+        let millis = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+        let sequence = match parts.next() {
+            Some(sequence) => sequence.parse().map_err(|_| ())?,
+            None => 0,
+        };
+
+        Ok(Self::new(millis, sequence))
+    }
+}
+
+/// An entry in a stream, made of its ID and its field-value pairs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamEntry {
+    id: StreamId,
+    fields: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl StreamEntry {
+    pub fn new(id: StreamId, fields: Vec<(Vec<u8>, Vec<u8>)>) -> Self {
+        Self { id, fields }
+    }
+
+    pub fn id(&self) -> StreamId {
+        self.id
+    }
+
+    pub fn fields(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.fields
+    }
+}
+
+/// An ordered, append-only log of entries with increasing IDs.
+///
+/// Entries can only be removed from the start of the stream by trimming it.
+/// The stream remembers the highest ID ever appended, so trimmed IDs are never
+/// reused.
+///
+/// # Examples
+///
+/// ```
+/// use hop_engine::state::{Stream, StreamId};
+///
+/// let mut stream = Stream::new();
+/// let id = stream.next_id(1500).unwrap();
+/// assert!(stream.append(id, vec![(b"foo".to_vec(), b"bar".to_vec())]));
+///
+/// assert_eq!(StreamId::new(1500, 0), id);
+/// assert_eq!(StreamId::new(1500, 1), stream.next_id(1500).unwrap());
+/// assert_eq!(StreamId::new(1501, 0), stream.next_id(1501).unwrap());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stream {
+    entries: VecDeque<StreamEntry>,
+    last_id: StreamId,
+}
+
+impl Stream {
+    /// Estimate the number of bytes of memory owned by the stream's entries.
+    pub fn memory_usage(&self) -> usize {
+        let fields = self.entries.iter().map(|entry| {
+            entry.fields.capacity() * mem::size_of::<(Vec<u8>, Vec<u8>)>()
+                + entry
+                    .fields
+                    .iter()
+                    .map(|(field, value)| field.capacity() + value.capacity())
+                    .sum::<usize>()
+        });
+
+        self.entries.capacity() * mem::size_of::<StreamEntry>()
+            + sampled(self.entries.len(), fields)
+    }
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deserialize a stream from the form created by [`as_bytes`].
+    ///
+    /// Returns `None` if the bytes aren't a valid serialized stream.
+    ///
+    /// [`as_bytes`]: #method.as_bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let last_id = StreamId::from_bytes(bytes)?;
+        let len = u32::from_be_bytes(bytes.get(16..20)?.try_into().ok()?);
+        let mut idx = 20;
+        let mut stream = Self::new();
+
+        for _ in 0..len {
+            let id = StreamId::from_bytes(bytes.get(idx..)?)?;
+            let field_count = u16::from_be_bytes(bytes.get(idx + 16..idx + 18)?.try_into().ok()?);
+            idx += 18;
+
+            let mut fields = Vec::with_capacity(field_count as usize);
+
+            for _ in 0..field_count {
+                let field = read_sized(bytes, &mut idx)?;
+                let value = read_sized(bytes, &mut idx)?;
+
+                fields.push((field, value));
+            }
+
+            if !stream.append(id, fields) {
+                return None;
+            }
+        }
+
+        if idx != bytes.len() || stream.last_id > last_id {
+            return None;
+        }
+
+        stream.last_id = last_id;
+
+        Some(stream)
+    }
+
+    /// Append an entry to the end of the stream.
+    ///
+    /// Returns `false` if the ID isn't higher than every ID previously
+    /// appended, in which case the entry isn't appended.
+    pub fn append(&mut self, id: StreamId, fields: Vec<(Vec<u8>, Vec<u8>)>) -> bool {
+        if id <= self.last_id {
+            return false;
+        }
+
+        self.last_id = id;
+        self.entries.push_back(StreamEntry::new(id, fields));
+
+        true
+    }
+
+    /// Retrieve an iterator over the entries of the stream, from oldest to
+    /// newest.
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &StreamEntry> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Retrieve the highest ID ever appended to the stream, even if its entry
+    /// has since been trimmed.
+    ///
+    /// This is 0-0 if nothing has been appended.
+    pub fn last_id(&self) -> StreamId {
+        self.last_id
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Generate an ID for a new entry appended at the given Unix timestamp in
+    /// milliseconds.
+    ///
+    /// The ID uses the timestamp if it's later than the last ID's, or else
+    /// increments the last ID's sequence so that IDs always increase even if
+    /// the clock goes backwards.
+    ///
+    /// Returns `None` if the highest possible ID has already been used.
+    pub fn next_id(&self, millis: u64) -> Option<StreamId> {
+        if millis > self.last_id.millis {
+            Some(StreamId::new(millis, 0))
+        } else {
+            self.last_id.next()
+        }
+    }
+
+    /// Create a new stream of up to `count` entries with IDs between the start
+    /// and end, inclusive.
+    ///
+    /// The new stream has the same last ID as this one.
+    pub fn range(&self, start: StreamId, end: StreamId, count: usize) -> Self {
+        let from = self.entries.partition_point(|entry| entry.id < start);
+        let entries = self
+            .entries
+            .iter()
+            .skip(from)
+            .take_while(|entry| entry.id <= end)
+            .take(count)
+            .cloned()
+            .collect();
+
+        Self {
+            entries,
+            last_id: self.last_id,
+        }
+    }
+
+    /// Remove the oldest entries so that the stream has at most `max` entries,
+    /// returning the number of entries removed.
+    pub fn trim_length(&mut self, max: usize) -> usize {
+        let removed = self.entries.len().saturating_sub(max);
+        self.entries.drain(..removed);
+
+        removed
+    }
+
+    /// Remove the entries appended before a Unix timestamp in milliseconds,
+    /// returning the number of entries removed.
+    pub fn trim_before(&mut self, millis: u64) -> usize {
+        let removed = self
+            .entries
+            .partition_point(|entry| entry.id.millis < millis);
+        self.entries.drain(..removed);
+
+        removed
+    }
+
+    /// Serialize the stream.
+    ///
+    /// The form is the last ID followed by a big-endian `u32` number of
+    /// entries. Each entry is its ID, a big-endian `u16` number of fields, and
+    /// then each field and value prefixed by its big-endian `u32` length. IDs
+    /// are their milliseconds and sequence as big-endian `u64`s.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.last_id.write(&mut bytes);
+        bytes.extend_from_slice(&(self.entries.len() as u32).to_be_bytes());
+
+        for entry in &self.entries {
+            entry.id.write(&mut bytes);
+            bytes.extend_from_slice(&(entry.fields.len() as u16).to_be_bytes());
+
+            for (field, value) in &entry.fields {
+                bytes.extend_from_slice(&(field.len() as u32).to_be_bytes());
+                bytes.extend_from_slice(field);
+                bytes.extend_from_slice(&(value.len() as u32).to_be_bytes());
+                bytes.extend_from_slice(value);
+            }
+        }
+
+        bytes
+    }
+}
+
+fn read_sized(bytes: &[u8], idx: &mut usize) -> Option<Vec<u8>> {
+    let len = u32::from_be_bytes(bytes.get(*idx..*idx + 4)?.try_into().ok()?) as usize;
+    let start = *idx + 4;
+    let value = bytes.get(start..start.checked_add(len)?)?.to_vec();
+    *idx = start + len;
+
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Stream, StreamEntry, StreamId};
+    use alloc::{string::ToString, vec::Vec};
+    use core::{fmt::Debug, hash::Hash, str::FromStr};
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(
+        StreamId: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        FromStr,
+        Hash,
+        Ord,
+        PartialEq,
+        PartialOrd,
+        ToString
+    );
+    assert_impl_all!(StreamEntry: Clone, Debug, Eq, PartialEq);
+    assert_impl_all!(Stream: Clone, Debug, Default, Eq, PartialEq);
+
+    fn stream(millis: &[u64]) -> Stream {
+        let mut stream = Stream::new();
+
+        for millis in millis {
+            let id = stream.next_id(*millis).unwrap();
+            assert!(stream.append(
+                id,
+                [(b"n".to_vec(), millis.to_be_bytes().to_vec())].to_vec()
+            ));
+        }
+
+        stream
+    }
+
+    fn ids(stream: &Stream) -> Vec<StreamId> {
+        stream.entries().map(StreamEntry::id).collect()
+    }
+
+    #[test]
+    fn test_id_parse() {
+        assert_eq!(Ok(StreamId::new(1, 2)), "1-2".parse());
+        assert_eq!(Ok(StreamId::new(1, 0)), "1".parse());
+        assert!("1-".parse::<StreamId>().is_err());
+        assert!("foo".parse::<StreamId>().is_err());
+        assert!("-1".parse::<StreamId>().is_err());
+    }
+
+    #[test]
+    fn test_id_next() {
+        assert_eq!(Some(StreamId::new(1, 3)), StreamId::new(1, 2).next());
+        assert_eq!(Some(StreamId::new(2, 0)), StreamId::new(1, u64::MAX).next());
+        assert!(StreamId::MAX.next().is_none());
+    }
+
+    #[test]
+    fn test_append_monotonic() {
+        let mut stream = stream(&[5, 5, 3]);
+
+        assert_eq!(
+            [
+                StreamId::new(5, 0),
+                StreamId::new(5, 1),
+                StreamId::new(5, 2)
+            ]
+            .as_ref(),
+            ids(&stream).as_slice()
+        );
+        assert!(!stream.append(StreamId::new(5, 2), Vec::new()));
+        assert!(!stream.append(StreamId::MIN, Vec::new()));
+        assert!(stream.append(StreamId::new(6, 0), Vec::new()));
+    }
+
+    #[test]
+    fn test_range() {
+        let stream = stream(&[1, 2, 3, 4]);
+
+        let range = stream.range(StreamId::new(2, 0), StreamId::new(3, 0), usize::MAX);
+        assert_eq!(
+            [StreamId::new(2, 0), StreamId::new(3, 0)].as_ref(),
+            ids(&range).as_slice()
+        );
+        assert_eq!(StreamId::new(4, 0), range.last_id());
+
+        let range = stream.range(StreamId::MIN, StreamId::MAX, 1);
+        assert_eq!([StreamId::new(1, 0)].as_ref(), ids(&range).as_slice());
+    }
+
+    #[test]
+    fn test_trim() {
+        let mut stream = stream(&[1, 2, 3, 4]);
+
+        assert_eq!(1, stream.trim_length(3));
+        assert_eq!(0, stream.trim_length(3));
+        assert_eq!(1, stream.trim_before(3));
+        assert_eq!(
+            [StreamId::new(3, 0), StreamId::new(4, 0)].as_ref(),
+            ids(&stream).as_slice()
+        );
+
+        // trimmed IDs aren't reused
+        assert_eq!(2, stream.trim_length(0));
+        assert_eq!(Some(StreamId::new(4, 1)), stream.next_id(1));
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let mut stream = stream(&[1, 2, 3]);
+        stream.trim_length(2);
+
+        assert_eq!(
+            Some(&stream),
+            Stream::from_bytes(&stream.as_bytes()).as_ref()
+        );
+        assert_eq!(
+            Some(Stream::new()),
+            Stream::from_bytes(&Stream::new().as_bytes())
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_invalid() {
+        let bytes = stream(&[1, 2]).as_bytes();
+
+        assert!(Stream::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        assert!(Stream::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_none());
+
+        // the last ID is lower than the entries' IDs
+        let mut lower = bytes.clone();
+        lower[7] = 0;
+        assert!(Stream::from_bytes(&lower).is_none());
+    }
+}
@@ -1,29 +1,104 @@
-use super::KeyType;
+use super::{Document, HyperLogLog, KeyType, Stream};
 use alloc::{string::String, vec::Vec};
+use core::mem;
 use dashmap::{DashMap, DashSet};
 
-#[derive(Debug)]
+/// The number of elements of a collection that are measured when estimating
+/// its memory usage.
+///
+/// Measuring every element would make estimating the memory usage of large
+/// collections slow, so only the first few elements are measured and their
+/// average size is assumed for the rest.
+pub const MEMORY_SAMPLES: usize = 5;
+
+/// Estimate the total size of a collection's elements from the sizes of some
+/// of them.
+pub(crate) fn sampled(len: usize, sizes: impl Iterator<Item = usize>) -> usize {
+    let (count, total) = sizes
+        .take(MEMORY_SAMPLES)
+        .fold((0, 0), |(count, total), size| (count + 1, total + size));
+
+    match count {
+        0 => 0,
+        count => total.saturating_mul(len) / count,
+    }
+}
+
+#[derive(Clone, Debug)]
 pub enum Value {
     Boolean(bool),
     Bytes(Vec<u8>),
+    Document(Document),
     Float(f64),
+    HyperLogLog(HyperLogLog),
     Integer(i64),
     List(Vec<Vec<u8>>),
     Map(DashMap<Vec<u8>, Vec<u8>>),
     Set(DashSet<Vec<u8>>),
+    Stream(Stream),
     String(String),
 }
 
 impl Value {
+    /// Estimate the number of bytes of memory used by the value.
+    ///
+    /// The estimate includes the value itself and the memory it owns. The
+    /// elements of collections are sampled, so refer to [`MEMORY_SAMPLES`] for
+    /// how the estimate is made.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hop_engine::state::Value;
+    ///
+    /// let small = Value::Bytes(b"foo".to_vec());
+    /// let large = Value::Bytes(vec![0; 1024]);
+    ///
+    /// assert!(large.memory_usage() > small.memory_usage() + 1000);
+    /// ```
+    ///
+    /// [`MEMORY_SAMPLES`]: constant.MEMORY_SAMPLES.html
+    pub fn memory_usage(&self) -> usize {
+        let owned = match self {
+            Self::Boolean(_) | Self::Float(_) | Self::Integer(_) => 0,
+            Self::Bytes(bytes) => bytes.capacity(),
+            Self::Document(document) => document.memory_usage() - mem::size_of::<Document>(),
+            Self::HyperLogLog(hll) => hll.memory_usage(),
+            Self::List(list) => {
+                list.capacity() * mem::size_of::<Vec<u8>>()
+                    + sampled(list.len(), list.iter().map(Vec::capacity))
+            }
+            Self::Map(map) => {
+                map.len() * mem::size_of::<(Vec<u8>, Vec<u8>)>()
+                    + sampled(
+                        map.len(),
+                        map.iter()
+                            .map(|entry| entry.key().capacity() + entry.value().capacity()),
+                    )
+            }
+            Self::Set(set) => {
+                set.len() * mem::size_of::<Vec<u8>>()
+                    + sampled(set.len(), set.iter().map(|item| item.capacity()))
+            }
+            Self::Stream(stream) => stream.memory_usage(),
+            Self::String(string) => string.capacity(),
+        };
+
+        mem::size_of::<Self>() + owned
+    }
+
     pub fn kind(&self) -> KeyType {
         match self {
             Self::Boolean(_) => KeyType::Boolean,
             Self::Bytes(_) => KeyType::Bytes,
+            Self::Document(_) => KeyType::Document,
             Self::Float(_) => KeyType::Float,
+            Self::HyperLogLog(_) => KeyType::HyperLogLog,
             Self::Integer(_) => KeyType::Integer,
             Self::List(_) => KeyType::List,
             Self::Map(_) => KeyType::Map,
             Self::Set(_) => KeyType::Set,
+            Self::Stream(_) => KeyType::Stream,
             Self::String(_) => KeyType::String,
         }
     }
@@ -94,6 +169,28 @@ impl Value {
         matches!(self, Value::Float(_))
     }
 
+    pub fn hyper_log_log() -> Self {
+        Self::HyperLogLog(HyperLogLog::new())
+    }
+
+    pub fn as_hyper_log_log_ref(&self) -> Option<&HyperLogLog> {
+        match self {
+            Self::HyperLogLog(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_hyper_log_log_mut(&mut self) -> Option<&mut HyperLogLog> {
+        match self {
+            Self::HyperLogLog(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn is_hyper_log_log(&self) -> bool {
+        matches!(self, Value::HyperLogLog(_))
+    }
+
     pub fn integer() -> Self {
         Self::Integer(0)
     }
@@ -182,6 +279,50 @@ impl Value {
         matches!(self, Value::Set(_))
     }
 
+    pub fn document() -> Self {
+        Self::Document(Document::Null)
+    }
+
+    pub fn as_document_ref(&self) -> Option<&Document> {
+        match self {
+            Self::Document(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_document_mut(&mut self) -> Option<&mut Document> {
+        match self {
+            Self::Document(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn is_document(&self) -> bool {
+        matches!(self, Value::Document(_))
+    }
+
+    pub fn stream() -> Self {
+        Self::Stream(Stream::new())
+    }
+
+    pub fn as_stream_ref(&self) -> Option<&Stream> {
+        match self {
+            Self::Stream(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_stream_mut(&mut self) -> Option<&mut Stream> {
+        match self {
+            Self::Stream(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn is_stream(&self) -> bool {
+        matches!(self, Value::Stream(_))
+    }
+
     pub fn string() -> Self {
         Self::String(String::new())
     }
@@ -223,6 +364,12 @@ impl From<f64> for Value {
     }
 }
 
+impl From<HyperLogLog> for Value {
+    fn from(value: HyperLogLog) -> Self {
+        Self::HyperLogLog(value)
+    }
+}
+
 impl From<i64> for Value {
     fn from(value: i64) -> Self {
         Self::Integer(value)
@@ -247,6 +394,18 @@ impl From<DashSet<Vec<u8>>> for Value {
     }
 }
 
+impl From<Document> for Value {
+    fn from(value: Document) -> Self {
+        Self::Document(value)
+    }
+}
+
+impl From<Stream> for Value {
+    fn from(value: Stream) -> Self {
+        Self::Stream(value)
+    }
+}
+
 impl From<String> for Value {
     fn from(value: String) -> Self {
         Self::String(value)
@@ -255,22 +414,26 @@ impl From<String> for Value {
 
 #[cfg(test)]
 mod tests {
-    use super::Value;
+    use super::{Document, HyperLogLog, Stream, Value};
     use alloc::{string::String, vec::Vec};
     use core::fmt::Debug;
     use dashmap::{DashMap, DashSet};
     use static_assertions::assert_impl_all;
 
     assert_impl_all!(
-        Value: Debug,
+        Value: Clone,
+        Debug,
         From<bool>,
         From<Vec<u8>>,
         From<f64>,
+        From<HyperLogLog>,
         From<i64>,
         From<Vec<Vec<u8>>>,
         From<DashMap<Vec<u8>, Vec<u8>>>,
         From<Vec<u8>>,
         From<DashSet<Vec<u8>>>,
+        From<Document>,
+        From<Stream>,
         From<String>,
     );
 }
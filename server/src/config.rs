@@ -0,0 +1,813 @@
+//! Configuration of the server from a TOML file, environment variables and
+//! command-line flags.
+//!
+//! Every setting can be given as a command-line flag, which falls back to an
+//! environment variable, then to the configuration file, and finally to a
+//! default. Run `hop-server --help` for the flags and their environment
+//! variables.
+//!
+//! The password is the exception, since the arguments of a process can be
+//! seen by other users of the machine. It's read from the `PASSWORD`
+//! environment variable or the configuration file, or from a file given with
+//! `--password-file`, which takes precedence.
+//!
+//! The configuration file groups the same settings into tables:
+//!
+//! ```toml
+//! [listen]
+//! host = "127.0.0.1"
+//! port = 46733
+//! tcp_enabled = true
+//! unix_socket = "/run/hop/hop.sock"
+//! idle_timeout = 300
+//! tcp_keepalive = 300
+//!
+//! [tls]
+//! certificate = "certificate.pem"
+//! key = "key.pem"
+//! client_ca = "client_ca.pem"
+//!
+//! [engine]
+//! eviction_policy = "lru"
+//! namespaces = 16
+//! pubsub_enabled = true
+//! sessions_active_max = 10000
+//! slowlog_threshold = 100
+//!
+//! [limits]
+//! max_collection_elements = 1000000
+//! max_key_length = 1024
+//! max_memory = 1073741824
+//! max_request_size = 67108864
+//! max_value_size = 16777216
+//! session_rate_limit = 1000
+//! ip_rate_limit = 5000
+//!
+//! [auth]
+//! password = "hunter2"
+//! acl_file = "users.acl"
+//!
+//! [persistence]
+//! path = "hop.snapshot"
+//! interval = 60
+//!
+//! [log]
+//! level = "info"
+//!
+//! [shutdown]
+//! timeout = 30
+//! ```
+
+use hop_engine::hop::EvictionPolicy;
+use log::LevelFilter;
+use serde::Deserialize;
+use std::{
+    env,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs, io,
+    net::{IpAddr, Ipv4Addr},
+    num::NonZeroU32,
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+use structopt::StructOpt;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The configuration file couldn't be read.
+    FileUnreadable { path: PathBuf, source: io::Error },
+    /// The configuration file isn't valid TOML or has unknown or mistyped
+    /// settings.
+    FileInvalid {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// The password file couldn't be read.
+    PasswordFileUnreadable { path: PathBuf, source: io::Error },
+    /// Neither TCP nor a Unix socket is enabled, so there's nothing to listen
+    /// on.
+    NoListeners,
+    /// A setting requires another setting that isn't configured.
+    Requires {
+        name: &'static str,
+        requires: &'static str,
+    },
+    /// A setting has a value that isn't valid.
+    ValueInvalid {
+        name: &'static str,
+        value: String,
+        expected: &'static str,
+    },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::FileUnreadable { path, source } => f.write_fmt(format_args!(
+                "configuration file {} couldn't be read: {}",
+                path.display(),
+                source
+            )),
+            Self::FileInvalid { path, source } => f.write_fmt(format_args!(
+                "configuration file {} is invalid: {}",
+                path.display(),
+                source
+            )),
+            Self::PasswordFileUnreadable { path, source } => f.write_fmt(format_args!(
+                "password file {} couldn't be read: {}",
+                path.display(),
+                source
+            )),
+            Self::NoListeners => f.write_str("TCP must be enabled if there's no Unix socket"),
+            Self::Requires { name, requires } => {
+                f.write_fmt(format_args!("{} requires {} to be set", name, requires))
+            }
+            Self::ValueInvalid {
+                name,
+                value,
+                expected,
+            } => f.write_fmt(format_args!(
+                "{} is '{}' but must be {}",
+                name, value, expected
+            )),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::FileUnreadable { source, .. } => Some(source),
+            Self::FileInvalid { source, .. } => Some(source),
+            Self::PasswordFileUnreadable { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Command-line flags, each of which falls back to an environment variable.
+#[derive(Debug, Default, StructOpt)]
+#[structopt(
+    name = "hop-server",
+    about = "Run a server instance of the Hop database."
+)]
+pub struct Options {
+    /// Path to a TOML configuration file
+    #[structopt(short, long, env = "CONFIG_FILE", parse(from_os_str))]
+    config: Option<PathBuf>,
+
+    /// Address to listen for TCP connections on [default: 0.0.0.0]
+    #[structopt(long, env = "HOST")]
+    host: Option<IpAddr>,
+    /// Port to listen for TCP connections on [default: 46733]
+    #[structopt(long, env = "PORT")]
+    port: Option<u16>,
+    /// Whether to listen for TCP connections [default: true]
+    #[structopt(long, env = "TCP_ENABLED")]
+    tcp_enabled: Option<bool>,
+    /// Path to a Unix socket to listen for connections on
+    #[cfg(unix)]
+    #[structopt(long, env = "UNIX_SOCKET", parse(from_os_str))]
+    unix_socket: Option<PathBuf>,
+    /// Number of seconds a connection can go without sending anything before
+    /// it's closed, or 0 to keep idle connections open. Connections subscribed
+    /// to keys are kept open [default: 0]
+    #[structopt(long, env = "IDLE_TIMEOUT")]
+    idle_timeout: Option<u64>,
+    /// Number of seconds between TCP keepalive probes sent to detect dead
+    /// peers, or 0 to disable keepalive [default: 300]
+    #[structopt(long, env = "TCP_KEEPALIVE")]
+    tcp_keepalive: Option<u64>,
+
+    /// Path to a PEM-encoded certificate chain to encrypt TCP connections with
+    #[cfg(feature = "tls")]
+    #[structopt(long, env = "TLS_CERTIFICATE", parse(from_os_str))]
+    tls_certificate: Option<PathBuf>,
+    /// Path to the PEM-encoded private key of the certificate
+    #[cfg(feature = "tls")]
+    #[structopt(long, env = "TLS_KEY", parse(from_os_str))]
+    tls_key: Option<PathBuf>,
+    /// Path to PEM-encoded CA certificates that clients must present a
+    /// certificate signed by
+    #[cfg(feature = "tls")]
+    #[structopt(long, env = "TLS_CLIENT_CA", parse(from_os_str))]
+    tls_client_ca: Option<PathBuf>,
+
+    /// Policy for evicting keys once the memory limit is reached: lfu, lru,
+    /// no-eviction or random [default: no-eviction]
+    #[structopt(long, env = "EVICTION_POLICY")]
+    eviction_policy: Option<String>,
+    /// Number of namespaces that sessions can select [default: 16]
+    #[structopt(long, env = "NAMESPACES")]
+    namespaces: Option<usize>,
+    /// Whether pubsub commands are enabled [default: true]
+    #[structopt(long, env = "PUBSUB_ENABLED")]
+    pubsub_enabled: Option<bool>,
+    /// Maximum number of sessions that can be active at once
+    #[structopt(long, env = "SESSIONS_ACTIVE_MAX")]
+    sessions_active_max: Option<usize>,
+    /// Number of milliseconds a command must take for it to be logged as slow
+    #[structopt(long, env = "SLOWLOG_THRESHOLD")]
+    slowlog_threshold: Option<u64>,
+
+    /// Maximum number of elements in a collection
+    #[structopt(long, env = "MAX_COLLECTION_ELEMENTS")]
+    max_collection_elements: Option<usize>,
+    /// Maximum length of a key in bytes
+    #[structopt(long, env = "MAX_KEY_LENGTH")]
+    max_key_length: Option<usize>,
+    /// Maximum estimated number of bytes of memory used by keys and values
+    #[structopt(long, env = "MAX_MEMORY")]
+    max_memory: Option<usize>,
+    /// Maximum size of a request in bytes, or 0 to accept requests of any
+    /// size [default: 67108864]
+    #[structopt(long, env = "MAX_REQUEST_SIZE")]
+    max_request_size: Option<usize>,
+    /// Maximum size of a value in bytes
+    #[structopt(long, env = "MAX_VALUE_SIZE")]
+    max_value_size: Option<usize>,
+    /// Number of requests per second a connection can send, or 0 for no limit
+    /// [default: 0]
+    #[structopt(long, env = "SESSION_RATE_LIMIT")]
+    session_rate_limit: Option<u32>,
+    /// Number of requests per second the connections from an IP address can
+    /// send together, or 0 for no limit [default: 0]
+    #[structopt(long, env = "IP_RATE_LIMIT")]
+    ip_rate_limit: Option<u32>,
+
+    /// Password that sessions must authenticate with, read from the PASSWORD
+    /// environment variable rather than a flag so it isn't visible in the
+    /// process's arguments.
+    #[structopt(skip)]
+    password: Option<String>,
+    /// Path to a file containing the password that sessions must
+    /// authenticate with
+    #[structopt(long, env = "PASSWORD_FILE", parse(from_os_str))]
+    password_file: Option<PathBuf>,
+    /// Path to an ACL file of users
+    #[structopt(long, env = "ACL_FILE", parse(from_os_str))]
+    acl_file: Option<PathBuf>,
+
+    /// Path to a snapshot of the keys that's loaded on startup and saved
+    /// periodically
+    #[structopt(long, env = "PERSISTENCE_PATH", parse(from_os_str))]
+    persistence_path: Option<PathBuf>,
+    /// Number of seconds between saving snapshots, or 0 to only load the
+    /// snapshot [default: 60]
+    #[structopt(long, env = "PERSISTENCE_INTERVAL")]
+    persistence_interval: Option<u64>,
+
+    /// Level of messages to log: off, error, warn, info, debug or trace
+    /// [default: error]
+    #[structopt(long, env = "LOG_LEVEL")]
+    log_level: Option<String>,
+
+    /// Number of seconds to wait for connections to finish their requests
+    /// when shutting down [default: 30]
+    #[structopt(long, env = "SHUTDOWN_TIMEOUT")]
+    shutdown_timeout: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct File {
+    auth: AuthTable,
+    engine: EngineTable,
+    limits: LimitsTable,
+    listen: ListenTable,
+    log: LogTable,
+    persistence: PersistenceTable,
+    shutdown: ShutdownTable,
+    #[cfg(feature = "tls")]
+    tls: TlsTable,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct AuthTable {
+    acl_file: Option<PathBuf>,
+    password: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct EngineTable {
+    eviction_policy: Option<String>,
+    namespaces: Option<usize>,
+    pubsub_enabled: Option<bool>,
+    sessions_active_max: Option<usize>,
+    slowlog_threshold: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct LimitsTable {
+    ip_rate_limit: Option<u32>,
+    max_collection_elements: Option<usize>,
+    max_key_length: Option<usize>,
+    max_memory: Option<usize>,
+    max_request_size: Option<usize>,
+    max_value_size: Option<usize>,
+    session_rate_limit: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ListenTable {
+    host: Option<IpAddr>,
+    idle_timeout: Option<u64>,
+    port: Option<u16>,
+    tcp_enabled: Option<bool>,
+    tcp_keepalive: Option<u64>,
+    #[cfg(unix)]
+    unix_socket: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct LogTable {
+    level: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PersistenceTable {
+    interval: Option<u64>,
+    path: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ShutdownTable {
+    timeout: Option<u64>,
+}
+
+#[cfg(feature = "tls")]
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct TlsTable {
+    certificate: Option<PathBuf>,
+    client_ca: Option<PathBuf>,
+    key: Option<PathBuf>,
+}
+
+/// The validated configuration of the server.
+#[derive(Debug)]
+pub struct Config {
+    pub acl_file: Option<PathBuf>,
+    pub eviction_policy: EvictionPolicy,
+    pub host: IpAddr,
+    /// How long a connection can go without sending anything before it's
+    /// closed, if idle connections are closed.
+    pub idle_timeout: Option<Duration>,
+    /// Number of requests per second the connections from an IP address can
+    /// send together, if they're limited.
+    pub ip_rate_limit: Option<NonZeroU32>,
+    pub log_level: LevelFilter,
+    pub max_collection_elements: Option<usize>,
+    pub max_key_length: Option<usize>,
+    pub max_memory: Option<usize>,
+    /// Maximum size of a request in bytes, if the size is limited.
+    pub max_request_size: Option<usize>,
+    pub max_value_size: Option<usize>,
+    pub namespaces: Option<usize>,
+    pub password: Option<String>,
+    /// How often to save snapshots, if periodically saving them is enabled.
+    pub persistence_interval: Option<Duration>,
+    pub persistence_path: Option<PathBuf>,
+    pub port: u16,
+    pub pubsub_enabled: Option<bool>,
+    /// Number of requests per second a connection can send, if they're
+    /// limited.
+    pub session_rate_limit: Option<NonZeroU32>,
+    pub sessions_active_max: Option<usize>,
+    /// How long to wait for connections to finish when shutting down.
+    pub shutdown_timeout: Duration,
+    /// Number of milliseconds a command must take for it to be logged as
+    /// slow, if slow commands are logged.
+    pub slowlog_threshold: Option<u64>,
+    pub tcp_enabled: bool,
+    /// How often to send TCP keepalive probes, if keepalive is enabled.
+    pub tcp_keepalive: Option<Duration>,
+    #[cfg(feature = "tls")]
+    pub tls_certificate: Option<PathBuf>,
+    #[cfg(feature = "tls")]
+    pub tls_client_ca: Option<PathBuf>,
+    #[cfg(feature = "tls")]
+    pub tls_key: Option<PathBuf>,
+    #[cfg(unix)]
+    pub unix_socket: Option<PathBuf>,
+}
+
+impl Config {
+    const HOST_DEFAULT: IpAddr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
+    const MAX_REQUEST_SIZE_DEFAULT: usize = 64 * 1024 * 1024;
+    const PERSISTENCE_INTERVAL_DEFAULT: u64 = 60;
+    const PORT_DEFAULT: u16 = 46733;
+    const SHUTDOWN_TIMEOUT_DEFAULT: u64 = 30;
+    const TCP_KEEPALIVE_DEFAULT: u64 = 300;
+
+    /// Load the configuration from the command-line flags, environment
+    /// variables and the configuration file, if there is one.
+    ///
+    /// Invalid flags exit the process with a usage message.
+    pub fn load() -> Result<Self, ConfigError> {
+        let mut options = Options::from_args();
+        options.password = env::var("PASSWORD").ok();
+
+        Self::from_options(options)
+    }
+
+    /// Resolve the configuration from flags, reading the configuration file
+    /// they point to.
+    pub fn from_options(options: Options) -> Result<Self, ConfigError> {
+        let file = match options.config.as_ref() {
+            Some(path) => {
+                let contents =
+                    fs::read_to_string(path).map_err(|source| ConfigError::FileUnreadable {
+                        path: path.clone(),
+                        source,
+                    })?;
+
+                toml::from_str(&contents).map_err(|source| ConfigError::FileInvalid {
+                    path: path.clone(),
+                    source,
+                })?
+            }
+            None => File::default(),
+        };
+
+        Self::resolve(options, file)
+    }
+
+    /// Merge flags over a configuration file and validate the result.
+    fn resolve(options: Options, file: File) -> Result<Self, ConfigError> {
+        let eviction_policy = match options.eviction_policy.or(file.engine.eviction_policy) {
+            Some(policy) => {
+                EvictionPolicy::from_str(&policy).map_err(|_| ConfigError::ValueInvalid {
+                    name: "eviction_policy",
+                    value: policy,
+                    expected: "one of lfu, lru, no-eviction or random",
+                })?
+            }
+            None => EvictionPolicy::default(),
+        };
+
+        let log_level = match options.log_level.or(file.log.level) {
+            Some(level) => {
+                LevelFilter::from_str(&level).map_err(|_| ConfigError::ValueInvalid {
+                    name: "log_level",
+                    value: level,
+                    expected: "one of off, error, warn, info, debug or trace",
+                })?
+            }
+            None => LevelFilter::Error,
+        };
+
+        let namespaces = options.namespaces.or(file.engine.namespaces);
+        at_least_one("namespaces", namespaces)?;
+        let sessions_active_max = options
+            .sessions_active_max
+            .or(file.engine.sessions_active_max);
+        at_least_one("sessions_active_max", sessions_active_max)?;
+
+        let persistence_path = non_empty_path(options.persistence_path.or(file.persistence.path));
+        let persistence_interval = options.persistence_interval.or(file.persistence.interval);
+
+        if persistence_path.is_none() && persistence_interval.is_some() {
+            return Err(ConfigError::Requires {
+                name: "persistence_interval",
+                requires: "persistence_path",
+            });
+        }
+
+        let persistence_interval = persistence_path
+            .as_ref()
+            .map(|_| persistence_interval.unwrap_or(Self::PERSISTENCE_INTERVAL_DEFAULT))
+            .and_then(non_zero_seconds);
+
+        let password = match non_empty_path(options.password_file) {
+            Some(path) => {
+                let contents = fs::read_to_string(&path)
+                    .map_err(|source| ConfigError::PasswordFileUnreadable { path, source })?;
+
+                // Editors usually end files with a newline, which isn't part
+                // of the password.
+                Some(contents.trim_end_matches(&['\r', '\n'][..]).to_owned())
+            }
+            None => options.password.or(file.auth.password),
+        };
+
+        #[cfg(feature = "tls")]
+        let (tls_certificate, tls_client_ca, tls_key) = {
+            let certificate = non_empty_path(options.tls_certificate.or(file.tls.certificate));
+            let client_ca = non_empty_path(options.tls_client_ca.or(file.tls.client_ca));
+            let key = non_empty_path(options.tls_key.or(file.tls.key));
+
+            if certificate.is_some() && key.is_none() {
+                return Err(ConfigError::Requires {
+                    name: "tls_certificate",
+                    requires: "tls_key",
+                });
+            }
+
+            if certificate.is_none() && (key.is_some() || client_ca.is_some()) {
+                return Err(ConfigError::Requires {
+                    name: if key.is_some() {
+                        "tls_key"
+                    } else {
+                        "tls_client_ca"
+                    },
+                    requires: "tls_certificate",
+                });
+            }
+
+            (certificate, client_ca, key)
+        };
+
+        let tcp_enabled = options
+            .tcp_enabled
+            .or(file.listen.tcp_enabled)
+            .unwrap_or(true);
+        #[cfg(unix)]
+        let unix_socket = non_empty_path(options.unix_socket.or(file.listen.unix_socket));
+        #[cfg(unix)]
+        let has_unix_socket = unix_socket.is_some();
+        #[cfg(not(unix))]
+        let has_unix_socket = false;
+
+        if !tcp_enabled && !has_unix_socket {
+            return Err(ConfigError::NoListeners);
+        }
+
+        Ok(Self {
+            acl_file: non_empty_path(options.acl_file.or(file.auth.acl_file)),
+            eviction_policy,
+            host: options
+                .host
+                .or(file.listen.host)
+                .unwrap_or(Self::HOST_DEFAULT),
+            idle_timeout: non_zero_seconds(
+                options
+                    .idle_timeout
+                    .or(file.listen.idle_timeout)
+                    .unwrap_or(0),
+            ),
+            ip_rate_limit: NonZeroU32::new(
+                options
+                    .ip_rate_limit
+                    .or(file.limits.ip_rate_limit)
+                    .unwrap_or(0),
+            ),
+            log_level,
+            max_collection_elements: options
+                .max_collection_elements
+                .or(file.limits.max_collection_elements),
+            max_key_length: options.max_key_length.or(file.limits.max_key_length),
+            max_memory: options.max_memory.or(file.limits.max_memory),
+            max_request_size: Some(
+                options
+                    .max_request_size
+                    .or(file.limits.max_request_size)
+                    .unwrap_or(Self::MAX_REQUEST_SIZE_DEFAULT),
+            )
+            .filter(|size| *size > 0),
+            max_value_size: options.max_value_size.or(file.limits.max_value_size),
+            namespaces,
+            password: password.filter(|password| !password.is_empty()),
+            persistence_interval,
+            persistence_path,
+            port: options
+                .port
+                .or(file.listen.port)
+                .unwrap_or(Self::PORT_DEFAULT),
+            pubsub_enabled: options.pubsub_enabled.or(file.engine.pubsub_enabled),
+            session_rate_limit: NonZeroU32::new(
+                options
+                    .session_rate_limit
+                    .or(file.limits.session_rate_limit)
+                    .unwrap_or(0),
+            ),
+            sessions_active_max,
+            shutdown_timeout: Duration::from_secs(
+                options
+                    .shutdown_timeout
+                    .or(file.shutdown.timeout)
+                    .unwrap_or(Self::SHUTDOWN_TIMEOUT_DEFAULT),
+            ),
+            slowlog_threshold: options.slowlog_threshold.or(file.engine.slowlog_threshold),
+            tcp_enabled,
+            tcp_keepalive: non_zero_seconds(
+                options
+                    .tcp_keepalive
+                    .or(file.listen.tcp_keepalive)
+                    .unwrap_or(Self::TCP_KEEPALIVE_DEFAULT),
+            ),
+            #[cfg(feature = "tls")]
+            tls_certificate,
+            #[cfg(feature = "tls")]
+            tls_client_ca,
+            #[cfg(feature = "tls")]
+            tls_key,
+            #[cfg(unix)]
+            unix_socket,
+        })
+    }
+}
+
+/// Ensure that a count, if configured, is at least one.
+fn at_least_one(name: &'static str, value: Option<usize>) -> Result<(), ConfigError> {
+    match value {
+        Some(0) => Err(ConfigError::ValueInvalid {
+            name,
+            value: "0".to_owned(),
+            expected: "at least 1",
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Treat a number of seconds of 0 as disabling a setting.
+fn non_zero_seconds(seconds: u64) -> Option<Duration> {
+    Some(seconds)
+        .filter(|seconds| *seconds > 0)
+        .map(Duration::from_secs)
+}
+
+/// Treat empty paths, such as from an environment variable set to nothing, as
+/// not being configured.
+fn non_empty_path(path: Option<PathBuf>) -> Option<PathBuf> {
+    path.filter(|path| !path.as_os_str().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, ConfigError, File, Options};
+    use hop_engine::hop::EvictionPolicy;
+    use log::LevelFilter;
+    use std::{env, fs, num::NonZeroU32, path::PathBuf, process, time::Duration};
+
+    fn file(contents: &str) -> File {
+        toml::from_str(contents).unwrap()
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config = Config::resolve(Options::default(), File::default()).unwrap();
+
+        assert_eq!(Config::HOST_DEFAULT, config.host);
+        assert_eq!(Config::PORT_DEFAULT, config.port);
+        assert_eq!(EvictionPolicy::NoEviction, config.eviction_policy);
+        assert_eq!(LevelFilter::Error, config.log_level);
+        assert!(config.tcp_enabled);
+        assert!(config.persistence_path.is_none());
+        assert!(config.persistence_interval.is_none());
+        assert_eq!(Duration::from_secs(30), config.shutdown_timeout);
+        assert!(config.idle_timeout.is_none());
+        assert_eq!(Some(Duration::from_secs(300)), config.tcp_keepalive);
+        assert_eq!(Some(64 * 1024 * 1024), config.max_request_size);
+        assert!(config.session_rate_limit.is_none());
+        assert!(config.ip_rate_limit.is_none());
+    }
+
+    #[test]
+    fn test_file() {
+        let file = file(
+            r#"
+            [listen]
+            host = "127.0.0.1"
+            port = 1234
+
+            [engine]
+            eviction_policy = "lru"
+            namespaces = 4
+            pubsub_enabled = false
+            slowlog_threshold = 50
+
+            [limits]
+            max_memory = 1024
+            max_request_size = 0
+            session_rate_limit = 10
+            ip_rate_limit = 20
+
+            [persistence]
+            path = "hop.snapshot"
+
+            [log]
+            level = "debug"
+            "#,
+        );
+        let config = Config::resolve(Options::default(), file).unwrap();
+
+        assert_eq!("127.0.0.1", config.host.to_string());
+        assert_eq!(1234, config.port);
+        assert_eq!(EvictionPolicy::LeastRecentlyUsed, config.eviction_policy);
+        assert_eq!(Some(4), config.namespaces);
+        assert_eq!(Some(false), config.pubsub_enabled);
+        assert_eq!(Some(50), config.slowlog_threshold);
+        assert_eq!(Some(1024), config.max_memory);
+        assert!(config.max_request_size.is_none());
+        assert_eq!(NonZeroU32::new(10), config.session_rate_limit);
+        assert_eq!(NonZeroU32::new(20), config.ip_rate_limit);
+        assert_eq!(Some(PathBuf::from("hop.snapshot")), config.persistence_path);
+        assert_eq!(Some(Duration::from_secs(60)), config.persistence_interval);
+        assert_eq!(LevelFilter::Debug, config.log_level);
+    }
+
+    #[test]
+    fn test_options_override_file() {
+        let options = Options {
+            port: Some(4321),
+            namespaces: Some(2),
+            tcp_keepalive: Some(0),
+            ..Options::default()
+        };
+        let file = file(
+            "[listen]\nport = 1234\nidle_timeout = 60\ntcp_keepalive = 10\n\n[engine]\nnamespaces = 4\n",
+        );
+        let config = Config::resolve(options, file).unwrap();
+
+        assert_eq!(4321, config.port);
+        assert_eq!(Some(2), config.namespaces);
+        assert_eq!(Some(Duration::from_secs(60)), config.idle_timeout);
+        assert!(config.tcp_keepalive.is_none());
+    }
+
+    #[test]
+    fn test_password() {
+        let options = Options {
+            password: Some("hunter2".to_owned()),
+            ..Options::default()
+        };
+        let file = file("[auth]\npassword = \"swordfish\"\n");
+        let config = Config::resolve(options, file).unwrap();
+        assert_eq!(Some("hunter2"), config.password.as_deref());
+
+        let path = env::temp_dir().join(format!("hop-{}-password", process::id()));
+        fs::write(&path, "correct horse\n").unwrap();
+        let options = Options {
+            password: Some("hunter2".to_owned()),
+            password_file: Some(path.clone()),
+            ..Options::default()
+        };
+        let config = Config::resolve(options, File::default());
+        let _ = fs::remove_file(&path);
+        assert_eq!(Some("correct horse"), config.unwrap().password.as_deref());
+
+        let options = Options {
+            password_file: Some(path),
+            ..Options::default()
+        };
+        assert!(matches!(
+            Config::resolve(options, File::default()),
+            Err(ConfigError::PasswordFileUnreadable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_file_invalid() {
+        assert!(toml::from_str::<File>("[listen]\nprot = 1").is_err());
+        assert!(toml::from_str::<File>("[listen]\nport = \"foo\"").is_err());
+        assert!(toml::from_str::<File>("[foo]").is_err());
+    }
+
+    #[test]
+    fn test_values_invalid() {
+        let invalid = |contents: &str| Config::resolve(Options::default(), file(contents));
+
+        assert!(matches!(
+            invalid("[engine]\neviction_policy = \"oldest\""),
+            Err(ConfigError::ValueInvalid {
+                name: "eviction_policy",
+                ..
+            })
+        ));
+        assert!(matches!(
+            invalid("[engine]\nnamespaces = 0"),
+            Err(ConfigError::ValueInvalid {
+                name: "namespaces",
+                ..
+            })
+        ));
+        assert!(matches!(
+            invalid("[log]\nlevel = \"loud\""),
+            Err(ConfigError::ValueInvalid {
+                name: "log_level",
+                ..
+            })
+        ));
+        assert!(matches!(
+            invalid("[persistence]\ninterval = 10"),
+            Err(ConfigError::Requires {
+                name: "persistence_interval",
+                ..
+            })
+        ));
+        assert!(matches!(
+            invalid("[listen]\ntcp_enabled = false"),
+            Err(ConfigError::NoListeners)
+        ));
+    }
+}
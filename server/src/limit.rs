@@ -0,0 +1,279 @@
+//! Limits on how quickly and how much clients can send, so that a single
+//! misbehaving client can't flood the server.
+//!
+//! Requests are rate limited with token buckets, one for each connection and
+//! one shared by every connection from the same IP address. A bucket holds up
+//! to a second's worth of requests and refills continuously, so clients can
+//! send short bursts as long as they keep to the rate on average.
+
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    num::NonZeroU32,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+/// A token bucket that allows a number of requests per second.
+#[derive(Debug)]
+struct Bucket {
+    rate: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(rate: NonZeroU32, now: Instant) -> Self {
+        let rate = f64::from(rate.get());
+
+        Self {
+            rate,
+            tokens: rate,
+            updated: now,
+        }
+    }
+
+    /// Refill the bucket for the time passed since it was last updated.
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.updated = now;
+    }
+
+    /// Check whether there's a token for a request, without taking it.
+    fn has_token(&mut self, now: Instant) -> bool {
+        self.refill(now);
+
+        self.tokens >= 1.
+    }
+
+    /// Take a token for a request, returning whether there was one to take.
+    fn take(&mut self, now: Instant) -> bool {
+        if !self.has_token(now) {
+            return false;
+        }
+
+        self.tokens -= 1.;
+
+        true
+    }
+
+    fn is_full(&mut self, now: Instant) -> bool {
+        self.refill(now);
+
+        self.tokens >= self.rate
+    }
+}
+
+/// The buckets of each IP address, along with the number of connections from
+/// it.
+type IpBuckets = HashMap<IpAddr, (usize, Bucket)>;
+
+/// The limits configured for every connection, which each connection gets its
+/// own [`Limiter`] from.
+///
+/// [`Limiter`]: struct.Limiter.html
+#[derive(Clone, Debug, Default)]
+pub struct Limits {
+    ip_buckets: Arc<Mutex<IpBuckets>>,
+    ip_rate: Option<NonZeroU32>,
+    max_request_size: Option<usize>,
+    session_rate: Option<NonZeroU32>,
+}
+
+impl Limits {
+    pub fn new(
+        max_request_size: Option<usize>,
+        session_rate: Option<NonZeroU32>,
+        ip_rate: Option<NonZeroU32>,
+    ) -> Self {
+        Self {
+            ip_buckets: Arc::default(),
+            ip_rate,
+            max_request_size,
+            session_rate,
+        }
+    }
+
+    /// Create the limiter of a new connection, from an IP address if it's
+    /// over TCP.
+    pub fn connect(&self, ip: Option<IpAddr>) -> Limiter {
+        let now = Instant::now();
+
+        let ip = match (ip, self.ip_rate) {
+            (Some(ip), Some(rate)) => {
+                let mut buckets = self.ip_buckets.lock().unwrap();
+
+                // Buckets of addresses without connections are kept until
+                // they refill, so that reconnecting doesn't reset the limit.
+                buckets.retain(|_, (connections, bucket)| *connections > 0 || !bucket.is_full(now));
+
+                buckets
+                    .entry(ip)
+                    .or_insert_with(|| (0, Bucket::new(rate, now)))
+                    .0 += 1;
+
+                Some(IpGuard {
+                    buckets: Arc::clone(&self.ip_buckets),
+                    ip,
+                })
+            }
+            _ => None,
+        };
+
+        Limiter {
+            ip,
+            max_request_size: self.max_request_size.unwrap_or(usize::MAX),
+            session: self.session_rate.map(|rate| Bucket::new(rate, now)),
+        }
+    }
+}
+
+/// A connection's share of the bucket of its IP address.
+#[derive(Debug)]
+struct IpGuard {
+    buckets: Arc<Mutex<IpBuckets>>,
+    ip: IpAddr,
+}
+
+impl IpGuard {
+    fn take(&self, now: Instant) -> bool {
+        self.buckets
+            .lock()
+            .unwrap()
+            .get_mut(&self.ip)
+            .is_none_or(|(_, bucket)| bucket.take(now))
+    }
+}
+
+impl Drop for IpGuard {
+    fn drop(&mut self) {
+        if let Some((connections, _)) = self.buckets.lock().unwrap().get_mut(&self.ip) {
+            *connections -= 1;
+        }
+    }
+}
+
+/// The limits of a single connection.
+#[derive(Debug)]
+pub struct Limiter {
+    ip: Option<IpGuard>,
+    max_request_size: usize,
+    session: Option<Bucket>,
+}
+
+impl Limiter {
+    /// Maximum size of a request in bytes.
+    pub fn max_request_size(&self) -> usize {
+        self.max_request_size
+    }
+
+    /// Take a token for a request from both the connection's bucket and its
+    /// IP address's bucket, returning whether the request can be run.
+    ///
+    /// Tokens are only taken if both buckets have one, so a request denied
+    /// by one limit doesn't count against the other.
+    pub fn take(&mut self) -> bool {
+        let now = Instant::now();
+
+        if !self
+            .session
+            .as_mut()
+            .is_none_or(|bucket| bucket.has_token(now))
+        {
+            return false;
+        }
+
+        // The connection's bucket is only used by this connection, so it
+        // still has the token once the shared bucket has given one up.
+        if !self.ip.as_ref().is_none_or(|ip| ip.take(now)) {
+            return false;
+        }
+
+        if let Some(bucket) = self.session.as_mut() {
+            bucket.tokens -= 1.;
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bucket, Limits};
+    use std::{
+        net::{IpAddr, Ipv4Addr},
+        num::NonZeroU32,
+        time::{Duration, Instant},
+    };
+
+    const IP: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    #[test]
+    fn test_bucket() {
+        let now = Instant::now();
+        let mut bucket = Bucket::new(NonZeroU32::new(2).unwrap(), now);
+
+        assert!(bucket.take(now));
+        assert!(bucket.take(now));
+        assert!(!bucket.take(now));
+
+        // Half a second refills one token at two per second.
+        let later = now + Duration::from_millis(500);
+        assert!(bucket.take(later));
+        assert!(!bucket.take(later));
+        assert!(!bucket.is_full(later));
+        assert!(bucket.is_full(later + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_session_limit() {
+        let limits = Limits::new(None, NonZeroU32::new(1), None);
+        let mut first = limits.connect(Some(IP));
+        let mut second = limits.connect(Some(IP));
+
+        assert!(first.take());
+        assert!(!first.take());
+        assert!(second.take());
+        assert_eq!(usize::MAX, first.max_request_size());
+    }
+
+    #[test]
+    fn test_ip_limit() {
+        let limits = Limits::new(Some(64), None, NonZeroU32::new(1));
+        let mut first = limits.connect(Some(IP));
+        let mut second = limits.connect(Some(IP));
+        let mut other = limits.connect(Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)));
+        let mut unix = limits.connect(None);
+
+        assert!(first.take());
+        assert!(!second.take());
+        assert!(other.take());
+        assert!(unix.take());
+        assert!(unix.take());
+        assert_eq!(64, first.max_request_size());
+
+        // Reconnecting keeps the address's drained bucket.
+        drop(first);
+        drop(second);
+        assert!(!limits.connect(Some(IP)).take());
+    }
+
+    #[test]
+    fn test_session_and_ip_limit() {
+        let limits = Limits::new(None, NonZeroU32::new(1), NonZeroU32::new(1));
+        let mut first = limits.connect(Some(IP));
+        let mut second = limits.connect(Some(IP));
+
+        assert!(first.take());
+        assert!(!second.take());
+
+        // The request denied by the address's limit didn't use up the
+        // connection's token, but the allowed one used up both.
+        let now = Instant::now();
+        assert!(second.session.as_mut().unwrap().has_token(now));
+        assert!(!first.session.as_mut().unwrap().has_token(now));
+        assert!(!first.take());
+    }
+}
@@ -2,117 +2,584 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::multiple_crate_versions)]
 
+mod config;
+mod limit;
+mod persistence;
+mod shutdown;
+#[cfg(feature = "tls")]
+mod tls;
+
+use config::Config;
+use futures_intrusive::channel::{shared::Receiver, StateId};
 use hop_engine::{
-    command::{request::Context, Response},
+    command::{
+        request::{Context, ParseError},
+        DispatchError, Request, Response,
+    },
+    hop::Builder,
+    state::{Key, Value},
     Hop,
 };
-use log::{debug, warn};
+use limit::{Limiter, Limits};
+use log::{debug, info, warn, LevelFilter};
+use shutdown::Shutdown;
 use std::{
     env,
     error::Error,
-    net::{IpAddr, Ipv4Addr, SocketAddr},
-    str::FromStr as _,
+    fs,
+    net::SocketAddr,
+    process, thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tokio::{
-    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
     net::{TcpListener, TcpStream},
     stream::StreamExt,
-    task,
+    task, time,
 };
 
-struct Config {
-    host: IpAddr,
-    port: u16,
+#[cfg(unix)]
+use std::path::Path;
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+
+#[cfg(feature = "tls")]
+use tokio_rustls::TlsAcceptor;
+
+/// Longest a client may take to finish the TLS handshake, or less if the idle
+/// timeout is shorter.
+#[cfg(feature = "tls")]
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(source) => {
+            eprintln!("Invalid configuration: {}", source);
+            process::exit(1);
+        }
+    };
+
+    // The logger lets everything through and the level is enforced by the
+    // global maximum instead, so that it can be changed while running.
+    let mut logger = env_logger::Builder::new();
+    logger.filter_level(LevelFilter::Trace);
+    let mut log_level = config.log_level;
+
+    // Finer filters, such as for a single module, can still be set with the
+    // usual environment variable.
+    if let Ok(filters) = env::var("RUST_LOG") {
+        logger.parse_filters(&filters);
+        log_level = log_level.max(
+            env_logger::filter::Builder::new()
+                .parse(&filters)
+                .build()
+                .filter(),
+        );
+    }
+
+    logger.init();
+    log::set_max_level(log_level);
+
+    let mut builder = Builder::new();
+    builder
+        .clock(clock)
+        .drop_background(drop_background)
+        .eviction_policy(config.eviction_policy);
+
+    if let Some(max_memory) = config.max_memory {
+        builder.max_memory(max_memory);
+    }
+
+    if let Some(max_key_length) = config.max_key_length {
+        builder.max_key_length(max_key_length);
+    }
+
+    if let Some(max_value_size) = config.max_value_size {
+        builder.max_value_size(max_value_size);
+    }
+
+    if let Some(max_elements) = config.max_collection_elements {
+        builder.max_collection_elements(max_elements);
+    }
+
+    if let Some(namespaces) = config.namespaces {
+        builder.namespaces(namespaces);
+    }
+
+    if let Some(password) = config.password {
+        builder.password(password);
+    }
+
+    if let Some(pubsub_enabled) = config.pubsub_enabled {
+        builder.pubsub_enabled(pubsub_enabled);
+    }
+
+    if let Some(sessions_active_max) = config.sessions_active_max {
+        builder.sessions_active_max(sessions_active_max);
+    }
+
+    if let Some(slowlog_threshold) = config.slowlog_threshold {
+        builder.slowlog_threshold(slowlog_threshold);
+    }
+
+    let hop = builder.build();
+
+    if let Some(acl_file) = config.acl_file {
+        debug!("Loading users from {}", acl_file.display());
+        let contents = fs::read_to_string(&acl_file)?;
+
+        hop.acl()
+            .load(&contents)
+            .map_err(|source| format!("ACL file {} is invalid: {}", acl_file.display(), source))?;
+    }
+
+    let (controller, shutdown) = shutdown::channel();
+    let limits = Limits::new(
+        config.max_request_size,
+        config.session_rate_limit,
+        config.ip_rate_limit,
+    );
+
+    if let Some(path) = config.persistence_path.as_ref() {
+        debug!("Loading snapshot from {}", path.display());
+        let count = persistence::load(&hop, path)?;
+        debug!("Loaded {} keys", count);
+
+        if let Some(interval) = config.persistence_interval {
+            task::spawn(persistence::save_periodically(
+                hop.clone(),
+                path.clone(),
+                interval,
+                shutdown.clone(),
+            ));
+        }
+    }
+
+    #[cfg(feature = "tls")]
+    let acceptor = match (config.tls_certificate, config.tls_key) {
+        (Some(certificate), Some(key)) => {
+            debug!("Encrypting connections with TLS");
+
+            Some(tls::acceptor(
+                &certificate,
+                &key,
+                config.tls_client_ca.as_deref(),
+            )?)
+        }
+        _ => None,
+    };
+
+    #[cfg(unix)]
+    {
+        if let Some(path) = config.unix_socket.as_ref() {
+            task::spawn(listen_unix(
+                bind_unix(path)?,
+                hop.clone(),
+                shutdown.clone(),
+                config.idle_timeout,
+                limits.clone(),
+            ));
+        }
+    }
+
+    if config.tcp_enabled {
+        let addr = SocketAddr::new(config.host, config.port);
+
+        debug!("Binding to {}", addr);
+        let listener = TcpListener::bind(&addr).await?;
+
+        task::spawn(listen_tcp(
+            listener,
+            hop.clone(),
+            shutdown.clone(),
+            config.tcp_keepalive,
+            config.idle_timeout,
+            limits,
+            #[cfg(feature = "tls")]
+            acceptor,
+        ));
+    }
+
+    // Only the tasks' handles should keep the controller waiting.
+    drop(shutdown);
+
+    shutdown::signal().await?;
+    info!("Shutting down");
+
+    if !controller.shutdown(config.shutdown_timeout).await {
+        warn!("Closing connections that didn't finish within the shutdown timeout");
+    }
+
+    hop.pubsub().remove_all();
+
+    if let Some(path) = config.persistence_path {
+        debug!("Saving snapshot to {}", path.display());
+        let snapshot = hop.clone();
+        let count = task::spawn_blocking(move || persistence::save(&snapshot, &path)).await??;
+        debug!("Saved snapshot of {} keys", count);
+    }
+
+    #[cfg(unix)]
+    {
+        if let Some(path) = config.unix_socket {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    Ok(())
 }
 
-impl Config {
-    const HOST_DEFAULT: IpAddr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
-    const PORT_DEFAULT: u16 = 46733;
+async fn listen_tcp(
+    mut listener: TcpListener,
+    hop: Hop,
+    mut shutdown: Shutdown,
+    keepalive: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    limits: Limits,
+    #[cfg(feature = "tls")] acceptor: Option<TlsAcceptor>,
+) {
+    let mut incoming = listener.incoming();
+
+    debug!("Listening for new TCP connections");
 
-    fn new() -> Self {
-        let host = match env::var("HOST") {
-            Ok(host) => IpAddr::from_str(&host).unwrap_or(Self::HOST_DEFAULT),
-            Err(_) => Self::HOST_DEFAULT,
-        };
-        let port = match env::var("PORT") {
-            Ok(port) => port.parse().unwrap_or(Self::PORT_DEFAULT),
-            Err(_) => Self::PORT_DEFAULT,
+    loop {
+        let socket = tokio::select! {
+            socket = incoming.next() => socket,
+            _ = shutdown.wait() => break,
         };
 
-        Self { host, port }
+        match socket {
+            // Each connection gets its own handle so that it can select its
+            // own namespace.
+            Some(Ok(socket)) => {
+                // Keepalive probes detect peers that went away without
+                // closing the connection, such as when their machine died.
+                if let Err(why) = socket.set_keepalive(keepalive) {
+                    warn!("Failed to set TCP keepalive: {:?}", why);
+                }
+
+                task::spawn(handle_socket(
+                    socket,
+                    hop.clone(),
+                    shutdown.clone(),
+                    idle_timeout,
+                    limits.clone(),
+                    #[cfg(feature = "tls")]
+                    acceptor.clone(),
+                ));
+            }
+            Some(Err(why)) => warn!("Failed to accept TCP connection: {:?}", why),
+            None => break,
+        }
     }
-}
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    env_logger::init();
+    debug!("Stopped listening for new TCP connections");
+}
 
-    let config = Config::new();
+/// Bind to a Unix socket, replacing a socket left behind by a previous run.
+#[cfg(unix)]
+fn bind_unix(path: &Path) -> Result<UnixListener, Box<dyn Error>> {
+    use std::os::unix::fs::FileTypeExt as _;
 
-    debug!("Binding socket");
-    let addr = SocketAddr::new(config.host, config.port);
+    if fs::metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+        fs::remove_file(path)?;
+    }
 
-    debug!("Binding to {}", addr);
-    let mut listener = TcpListener::bind(&addr).await?;
+    debug!("Binding to Unix socket {}", path.display());
 
-    let hop = Hop::new();
+    Ok(UnixListener::bind(path)?)
+}
 
+#[cfg(unix)]
+async fn listen_unix(
+    mut listener: UnixListener,
+    hop: Hop,
+    mut shutdown: Shutdown,
+    idle_timeout: Option<Duration>,
+    limits: Limits,
+) {
     let mut incoming = listener.incoming();
 
-    debug!("Listening for new connections on {}", addr);
+    debug!("Listening for new Unix socket connections");
+
+    loop {
+        let socket = tokio::select! {
+            socket = incoming.next() => socket,
+            _ = shutdown.wait() => break,
+        };
 
-    while let Some(Ok(socket)) = incoming.next().await {
-        task::spawn(handle_socket(socket, hop.clone()));
+        match socket {
+            Some(Ok(socket)) => {
+                task::spawn(handle_unix_socket(
+                    socket,
+                    hop.clone(),
+                    shutdown.clone(),
+                    idle_timeout,
+                    limits.connect(None),
+                ));
+            }
+            Some(Err(why)) => warn!("Failed to accept Unix socket connection: {:?}", why),
+            None => break,
+        }
     }
 
-    Ok(())
+    debug!("Stopped listening for new Unix socket connections");
+}
+
+#[cfg(unix)]
+async fn handle_unix_socket(
+    socket: UnixStream,
+    hop: Hop,
+    shutdown: Shutdown,
+    idle_timeout: Option<Duration>,
+    limiter: Limiter,
+) {
+    debug!("Connected to Unix socket peer");
+
+    // Connections over Unix sockets stay on the machine, so they're never
+    // encrypted.
+    match handle_socket_inner(socket, hop, None, shutdown, idle_timeout, limiter).await {
+        Ok(()) => debug!("Dropping Unix socket peer"),
+        Err(why) => warn!("Erroring Unix socket peer: {:?}", why),
+    }
+}
+
+fn clock() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or(0)
 }
 
-async fn handle_socket(socket: TcpStream, hop: Hop) {
+/// Drop the keys removed by an asynchronous flush on a separate thread.
+fn drop_background(removed: Vec<(Key, Value)>) {
+    thread::spawn(move || drop(removed));
+}
+
+/// Wait until a blocking stream read has entries to read or its timeout has
+/// elapsed.
+///
+/// Blocking reads stop waiting once the server starts shutting down, so that
+/// they're answered with whatever there is to read.
+async fn wait_for_stream(hop: &Hop, req: &Request<'_>, shutdown: &mut Shutdown) {
+    let block = match hop.stream_read_block(req) {
+        Some(block) => block,
+        None => return,
+    };
+
+    let deadline = Instant::now() + Duration::from_millis(block);
+    let updates = hop.stream_updates();
+    let mut state = match updates.try_receive(StateId::new()) {
+        Some((state, ())) => state,
+        None => return,
+    };
+
+    while hop.stream_read_block(req).is_some() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+
+        tokio::select! {
+            result = time::timeout(remaining, updates.receive(state)) => match result {
+                Ok(Some((new_state, ()))) => state = new_state,
+                Ok(None) | Err(_) => break,
+            },
+            _ = shutdown.wait() => break,
+        }
+    }
+}
+
+async fn handle_socket(
+    socket: TcpStream,
+    hop: Hop,
+    #[cfg_attr(not(feature = "tls"), allow(unused_mut))] mut shutdown: Shutdown,
+    idle_timeout: Option<Duration>,
+    limits: Limits,
+    #[cfg(feature = "tls")] acceptor: Option<TlsAcceptor>,
+) {
     let addr = socket.peer_addr().unwrap();
+    let limiter = limits.connect(Some(addr.ip()));
 
     log::debug!("Connected to peer {}", addr);
 
-    match handle_socket_inner(socket, hop).await {
+    #[cfg(feature = "tls")]
+    let result = match acceptor {
+        Some(acceptor) => {
+            // A client stalling the handshake would otherwise hold its
+            // connection open forever, since it doesn't have a session to
+            // time out or drain yet.
+            let timeout = idle_timeout.map_or(TLS_HANDSHAKE_TIMEOUT, |idle_timeout| {
+                idle_timeout.min(TLS_HANDSHAKE_TIMEOUT)
+            });
+
+            let accepted = tokio::select! {
+                accepted = time::timeout(timeout, acceptor.accept(socket)) => accepted,
+                _ = shutdown.wait() => {
+                    debug!("Dropping {} mid-handshake to shut down", addr);
+
+                    return;
+                }
+            };
+
+            match accepted {
+                Ok(Ok(stream)) => {
+                    handle_socket_inner(stream, hop, Some(addr), shutdown, idle_timeout, limiter)
+                        .await
+                }
+                Ok(Err(source)) => Err(source.into()),
+                Err(source) => Err(source.into()),
+            }
+        }
+        None => handle_socket_inner(socket, hop, Some(addr), shutdown, idle_timeout, limiter).await,
+    };
+
+    #[cfg(not(feature = "tls"))]
+    let result =
+        handle_socket_inner(socket, hop, Some(addr), shutdown, idle_timeout, limiter).await;
+
+    match result {
         Ok(()) => debug!("Dropping {}", addr),
         Err(why) => warn!("Erroring {}: {:?}", addr, why),
     }
 }
 
-async fn handle_socket_inner(socket: TcpStream, hop: Hop) -> Result<(), Box<dyn Error>> {
+/// Serve requests from a connection as a new session until it's closed, the
+/// session is killed or times out, or the server shuts down.
+async fn handle_socket_inner(
+    socket: impl AsyncRead + AsyncWrite,
+    hop: Hop,
+    peer: Option<SocketAddr>,
+    shutdown: Shutdown,
+    idle_timeout: Option<Duration>,
+    limiter: Limiter,
+) -> Result<(), Box<dyn Error>> {
+    // Connections over the limit are closed before reading any requests.
+    let session = hop
+        .start_session(peer.map(|peer| peer.to_string()))
+        .map_err(|_| "the maximum number of sessions are already active")?;
+    let killed = match hop.sessions().get(session) {
+        Some(session) => session.killed(),
+        None => return Ok(()),
+    };
+
+    let result = serve(socket, &hop, killed, shutdown, idle_timeout, limiter).await;
+    hop.end_session();
+
+    result
+}
+
+async fn serve(
+    socket: impl AsyncRead + AsyncWrite,
+    hop: &Hop,
+    killed: Receiver<()>,
+    mut shutdown: Shutdown,
+    idle_timeout: Option<Duration>,
+    mut limiter: Limiter,
+) -> Result<(), Box<dyn Error>> {
     let mut input = Vec::new();
-    let mut ctx = Context::new();
+    let mut ctx = Context::with_max_size(limiter.max_request_size());
 
-    let (reader, mut writer) = socket.into_split();
-    let mut reader = BufReader::new(reader);
+    let (mut reader, mut writer) = io::split(socket);
+    let mut buf = [0; 4096];
     let mut resp = Vec::new();
+    let mut output = Vec::new();
 
-    while let Ok(size) = reader.read_until(b'\n', &mut input).await {
-        // If we get no bytes then we're EOF.
-        if size == 0 {
+    // Requests aren't delimited, so feed the context everything read so far
+    // until it has a complete request.
+    loop {
+        // Once shutting down, requests that have started to be received are
+        // finished, but the connection is closed before any more.
+        if shutdown.is_signalled() && input.is_empty() {
             break;
         }
 
-        match ctx.feed(&input) {
-            Ok(Some(req)) => match hop.dispatch(&req, &mut resp) {
-                Ok(()) => {}
-                Err(why) => {
-                    let res = Response::DispatchError(why);
+        let size = tokio::select! {
+            size = reader.read(&mut buf) => size,
+            _ = shutdown.wait(), if input.is_empty() => break,
+            // Killed sessions are closed straight away, even mid-request.
+            _ = killed.receive() => break,
+            _ = time::delay_for(idle_timeout.unwrap_or_default()), if idle_timeout.is_some() => {
+                let session = hop.session();
 
-                    res.copy_to(&mut resp);
+                // Subscribed connections are expected to wait quietly for
+                // updates.
+                if session.is_some_and(|id| hop.pubsub().subscriptions(id) > 0) {
+                    continue;
                 }
-            },
-            Ok(None) => continue,
-            Err(why) => {
-                let res = Response::ParseError(why);
 
-                res.copy_to(&mut resp);
+                if let Some(id) = session {
+                    debug!("Closing session {} after being idle", id.get());
+                    hop.sessions().time_out(id);
+                }
+
+                break;
             }
         };
 
-        writer.write_all(&resp).await?;
-        resp.clear();
-        input.clear();
+        // If we get no bytes then we're EOF.
+        let size = match size {
+            Ok(0) | Err(_) => break,
+            Ok(size) => size,
+        };
+
+        input.extend_from_slice(&buf[..size]);
+
+        let mut close = false;
+
+        // A read may contain several requests, so handle every complete one
+        // before reading more, keeping the start of any incomplete one.
+        loop {
+            let consumed = match ctx.feed(&input) {
+                Ok(Some(req)) => {
+                    let consumed = req.as_bytes().len();
+
+                    if limiter.take() {
+                        wait_for_stream(hop, &req, &mut shutdown).await;
+
+                        if let Err(why) = hop.dispatch(&req, &mut resp) {
+                            // Commands may have written part of a response
+                            // before failing.
+                            resp.clear();
+                            Response::DispatchError(why).copy_to(&mut resp);
+                        }
+                    } else {
+                        Response::DispatchError(DispatchError::RateLimited).copy_to(&mut resp);
+                    }
+
+                    consumed
+                }
+                Ok(None) => break,
+                Err(why) => {
+                    // The rest of a request that's too large is never read, so
+                    // there's no telling where the next request would start.
+                    close = why == ParseError::RequestTooLarge;
+
+                    Response::ParseError(why).copy_to(&mut resp);
+
+                    // Nor is there for a request that can't be parsed, so
+                    // anything else read is dropped.
+                    input.len()
+                }
+            };
+
+            input.drain(..consumed);
+            output.append(&mut resp);
+
+            if close || input.is_empty() {
+                break;
+            }
+        }
+
+        if !output.is_empty() {
+            writer.write_all(&output).await?;
+            output.clear();
+        }
+
+        if close {
+            debug!("Closing connection after a request that's too large");
+
+            break;
+        }
     }
 
     Ok(())
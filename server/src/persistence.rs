@@ -0,0 +1,190 @@
+//! Snapshots of the keys of every namespace, loaded from a file on startup and
+//! saved to it periodically.
+//!
+//! A snapshot starts with the magic bytes `HOPSNAP` and a version, followed by
+//! a record for each key: the number of its namespace, the key, and its value
+//! in the [dump] form. Numbers and lengths are big-endian 32-bit integers.
+//!
+//! [dump]: ../../hop_engine/state/dump/index.html
+
+use crate::shutdown::Shutdown;
+use hop_engine::{state::dump, Hop};
+use log::{debug, warn};
+use std::{
+    convert::{TryFrom, TryInto},
+    error::Error,
+    ffi::OsString,
+    fs::{self, File},
+    io::{self, BufWriter, ErrorKind, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use tokio::{task, time};
+
+/// Magic bytes at the start of a snapshot.
+const MAGIC: &[u8; 7] = b"HOPSNAP";
+
+/// The version of the snapshot format.
+const VERSION: u8 = 1;
+
+/// Load a snapshot into the namespaces of an instance, returning the number of
+/// keys loaded.
+///
+/// Nothing is loaded if there's no snapshot at the path yet.
+pub fn load(hop: &Hop, path: &Path) -> Result<usize, Box<dyn Error>> {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(source) if source.kind() == ErrorKind::NotFound => return Ok(0),
+        Err(source) => return Err(source.into()),
+    };
+
+    let invalid = || format!("snapshot {} is invalid", path.display());
+
+    let mut rest = bytes
+        .strip_prefix(&MAGIC[..])
+        .and_then(|rest| rest.split_first())
+        .filter(|(version, _)| **version == VERSION)
+        .map(|(_, rest)| rest)
+        .ok_or_else(invalid)?;
+
+    let namespaces = hop.namespaces().collect::<Vec<_>>();
+    let mut count = 0;
+
+    while !rest.is_empty() {
+        let namespace = read_u32(&mut rest).ok_or_else(invalid)? as usize;
+        let key = read_bytes(&mut rest).ok_or_else(invalid)?;
+        let value = read_bytes(&mut rest)
+            .and_then(dump::restore)
+            .ok_or_else(invalid)?;
+
+        let state = namespaces.get(namespace).ok_or_else(|| {
+            format!(
+                "snapshot {} has keys in namespace {} but there are only {}",
+                path.display(),
+                namespace,
+                namespaces.len()
+            )
+        })?;
+
+        state.insert(key.to_vec(), value);
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Save a snapshot of the namespaces of an instance, returning the number of
+/// keys saved.
+///
+/// The snapshot is written to a temporary file next to the path and then
+/// renamed over it, so an existing snapshot is only replaced by a complete
+/// one.
+pub fn save(hop: &Hop, path: &Path) -> io::Result<usize> {
+    let mut temporary = OsString::from(path.as_os_str());
+    temporary.push(".tmp");
+    let temporary = PathBuf::from(temporary);
+
+    let mut writer = BufWriter::new(File::create(&temporary)?);
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION])?;
+
+    let mut count = 0;
+    let mut records = Vec::new();
+
+    for (namespace, state) in hop.namespaces().enumerate() {
+        let namespace = u32::try_from(namespace).unwrap_or(u32::MAX);
+
+        // Each shard is encoded while it's locked and only written to the
+        // file afterwards, so writes to its keys don't wait on the disk.
+        for shard in 0..state.shard_count() {
+            let mut result = Ok(());
+            records.clear();
+
+            state.for_each_in_shard(shard, |key, value| {
+                if result.is_err() {
+                    return;
+                }
+
+                result = write_record(&mut records, namespace, key, &dump::dump(value));
+                count += 1;
+            });
+
+            result?;
+            writer.write_all(&records)?;
+        }
+    }
+
+    writer.flush()?;
+    writer.get_ref().sync_all()?;
+    fs::rename(&temporary, path)?;
+
+    Ok(count)
+}
+
+/// Save a snapshot every interval until the server starts shutting down.
+///
+/// A snapshot being saved when the server starts shutting down is finished
+/// first.
+pub async fn save_periodically(
+    hop: Hop,
+    path: PathBuf,
+    interval: Duration,
+    mut shutdown: Shutdown,
+) {
+    let mut interval = time::interval_at(time::Instant::now() + interval, interval);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.wait() => break,
+        }
+
+        let hop = hop.clone();
+        let snapshot = path.clone();
+
+        // Writing the snapshot blocks, so it's moved off of the runtime.
+        match task::spawn_blocking(move || save(&hop, &snapshot)).await {
+            Ok(Ok(count)) => debug!("Saved snapshot of {} keys", count),
+            Ok(Err(source)) => warn!("Failed to save snapshot to {}: {}", path.display(), source),
+            Err(source) => warn!("Failed to save snapshot: {}", source),
+        }
+    }
+}
+
+fn write_record(
+    writer: &mut impl Write,
+    namespace: u32,
+    key: &[u8],
+    value: &[u8],
+) -> io::Result<()> {
+    let len = |bytes: &[u8]| {
+        u32::try_from(bytes.len())
+            .map_err(|_| io::Error::new(ErrorKind::InvalidData, "record is too long"))
+    };
+
+    writer.write_all(&namespace.to_be_bytes())?;
+    writer.write_all(&len(key)?.to_be_bytes())?;
+    writer.write_all(key)?;
+    writer.write_all(&len(value)?.to_be_bytes())?;
+    writer.write_all(value)
+}
+
+fn read_u32(bytes: &mut &[u8]) -> Option<u32> {
+    let int = u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?);
+    *bytes = &bytes[4..];
+
+    Some(int)
+}
+
+fn read_bytes<'a>(bytes: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let len = read_u32(bytes)? as usize;
+
+    if bytes.len() < len {
+        return None;
+    }
+
+    let (read, rest) = bytes.split_at(len);
+    *bytes = rest;
+
+    Some(read)
+}
@@ -0,0 +1,93 @@
+//! Graceful shutdown of the server when it receives a termination signal.
+
+use std::{io, time::Duration};
+use tokio::{
+    signal,
+    sync::{mpsc, watch},
+    time,
+};
+
+/// A handle held by every task that should finish before the server exits,
+/// which tells the task when the server is shutting down.
+#[derive(Clone, Debug)]
+pub struct Shutdown {
+    /// Never sent on; the controller knows every task has finished once all
+    /// of the senders have been dropped.
+    _running: mpsc::Sender<()>,
+    signalled: watch::Receiver<bool>,
+}
+
+impl Shutdown {
+    /// Return whether the server has started shutting down.
+    pub fn is_signalled(&self) -> bool {
+        *self.signalled.borrow()
+    }
+
+    /// Wait until the server starts shutting down.
+    pub async fn wait(&mut self) {
+        while !self.is_signalled() {
+            if self.signalled.recv().await.is_none() {
+                return;
+            }
+        }
+    }
+}
+
+/// Tells tasks to shut down and waits for them to finish.
+#[derive(Debug)]
+pub struct Controller {
+    running: mpsc::Receiver<()>,
+    signal: watch::Sender<bool>,
+}
+
+impl Controller {
+    /// Tell every task holding a [`Shutdown`] handle to shut down and wait for
+    /// them to finish, for up to a deadline.
+    ///
+    /// Returns whether every task finished in time.
+    ///
+    /// [`Shutdown`]: struct.Shutdown.html
+    pub async fn shutdown(mut self, deadline: Duration) -> bool {
+        let _ = self.signal.broadcast(true);
+
+        time::timeout(deadline, self.running.recv()).await.is_ok()
+    }
+}
+
+/// Create a controller and the first handle to give to tasks, which can be
+/// cloned for more tasks.
+///
+/// The handle must be dropped once it's no longer needed to clone from, or
+/// the controller will wait for it.
+pub fn channel() -> (Controller, Shutdown) {
+    let (running_tx, running_rx) = mpsc::channel(1);
+    let (signal_tx, signal_rx) = watch::channel(false);
+
+    let controller = Controller {
+        running: running_rx,
+        signal: signal_tx,
+    };
+    let shutdown = Shutdown {
+        _running: running_tx,
+        signalled: signal_rx,
+    };
+
+    (controller, shutdown)
+}
+
+/// Wait until the process is asked to terminate by an interrupt, such as from
+/// Ctrl+C, or on Unix by `SIGTERM`.
+pub async fn signal() -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        let mut terminate = signal::unix::signal(signal::unix::SignalKind::terminate())?;
+
+        tokio::select! {
+            result = signal::ctrl_c() => result,
+            _ = terminate.recv() => Ok(()),
+        }
+    }
+
+    #[cfg(not(unix))]
+    signal::ctrl_c().await
+}
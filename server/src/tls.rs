@@ -0,0 +1,53 @@
+use std::{error::Error, fs, path::Path, sync::Arc};
+use tokio_rustls::{
+    rustls::{
+        internal::pemfile, AllowAnyAuthenticatedClient, NoClientAuth, PrivateKey, RootCertStore,
+        ServerConfig,
+    },
+    TlsAcceptor,
+};
+
+/// Create an acceptor encrypting connections with TLS from the paths to a
+/// PEM-encoded certificate chain and private key.
+///
+/// If the path to PEM-encoded client CA certificates is provided then clients
+/// must present a certificate signed by one of them.
+pub fn acceptor(
+    certificate: &Path,
+    key: &Path,
+    client_ca: Option<&Path>,
+) -> Result<TlsAcceptor, Box<dyn Error>> {
+    let certificates = pemfile::certs(&mut fs::read(certificate)?.as_slice())
+        .ok()
+        .filter(|certificates| !certificates.is_empty())
+        .ok_or_else(|| format!("no certificates in {}", certificate.display()))?;
+    let private_key =
+        private_key(&fs::read(key)?).ok_or_else(|| format!("no key in {}", key.display()))?;
+
+    let verifier = match client_ca {
+        Some(client_ca) => {
+            let mut roots = RootCertStore::empty();
+
+            match roots.add_pem_file(&mut fs::read(client_ca)?.as_slice()) {
+                Ok((valid, _)) if valid > 0 => AllowAnyAuthenticatedClient::new(roots),
+                _ => return Err(format!("no certificates in {}", client_ca.display()).into()),
+            }
+        }
+        None => NoClientAuth::new(),
+    };
+
+    let mut config = ServerConfig::new(verifier);
+    config.set_single_cert(certificates, private_key)?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Parse a private key, which may either be in the PKCS #8 or the older RSA
+/// format.
+fn private_key(pem: &[u8]) -> Option<PrivateKey> {
+    pemfile::pkcs8_private_keys(&mut &*pem)
+        .ok()
+        .filter(|keys| !keys.is_empty())
+        .or_else(|| pemfile::rsa_private_keys(&mut &*pem).ok())
+        .and_then(|mut keys| keys.pop())
+}
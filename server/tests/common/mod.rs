@@ -0,0 +1,76 @@
+use std::{
+    fs,
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    process::{self, Child, Command, ExitStatus},
+    thread,
+    time::Duration,
+};
+
+/// A server process that's killed when dropped, along with a directory for
+/// its files.
+pub struct Server {
+    child: Child,
+    pub dir: PathBuf,
+    pub port: u16,
+}
+
+impl Server {
+    /// Start a server listening on a free port once the command has been
+    /// configured, which is passed the server's directory.
+    pub fn start(name: &str, configure: impl FnOnce(&mut Command, &PathBuf)) -> Self {
+        let dir = std::env::temp_dir().join(format!("hop-{}-{}", process::id(), name));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Find a free port by binding to any port and releasing it.
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        let mut command = Command::new(env!("CARGO_BIN_EXE_hop-server"));
+        command
+            .env("HOST", "127.0.0.1")
+            .env("PORT", port.to_string());
+        configure(&mut command, &dir);
+
+        let server = Self {
+            child: command.spawn().unwrap(),
+            dir,
+            port,
+        };
+
+        // Every other listener is bound before the TCP listener.
+        for _ in 0..100 {
+            if TcpStream::connect(("127.0.0.1", port)).is_ok() {
+                return server;
+            }
+
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        panic!("server didn't start listening");
+    }
+
+    /// Ask the server to shut down with `SIGTERM` and wait for it to exit.
+    #[allow(dead_code)]
+    #[cfg(unix)]
+    pub fn terminate(&mut self) -> ExitStatus {
+        Command::new("kill")
+            .arg("-TERM")
+            .arg(self.child.id().to_string())
+            .status()
+            .unwrap();
+
+        self.child.wait().unwrap()
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::Server;
+use hop::{backend::ConnectOptions, Client};
+use std::{
+    env, fs,
+    path::Path,
+    process::{self, Command},
+    thread,
+    time::Duration,
+};
+
+/// Start a server configured by a file, keeping its snapshot at a path.
+fn start(name: &str, snapshot: &Path) -> Server {
+    let config = format!(
+        "[auth]\npassword = \"hunter2\"\n\n[persistence]\npath = {:?}\ninterval = 1\n",
+        snapshot
+    );
+
+    Server::start(name, |command, dir| {
+        fs::write(dir.join("hop.toml"), config).unwrap();
+        command.arg("--config").arg(dir.join("hop.toml"));
+    })
+}
+
+#[tokio::test]
+async fn test_config_file_and_persistence() {
+    let snapshot = env::temp_dir().join(format!("hop-{}-config.snapshot", process::id()));
+    let mut options = ConnectOptions::new();
+    options.password("hunter2");
+
+    {
+        let server = start("config-first", &snapshot);
+        let client = Client::connect_with_options(("127.0.0.1", server.port), &options)
+            .await
+            .unwrap();
+        client.set("foo").int(5).await.unwrap();
+
+        // Wait for a snapshot to be saved before the server is killed.
+        thread::sleep(Duration::from_millis(1500));
+    }
+
+    let server = start("config-second", &snapshot);
+    let unauthenticated = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    assert!(unauthenticated.get("foo").int().await.is_err());
+
+    let client = Client::connect_with_options(("127.0.0.1", server.port), &options)
+        .await
+        .unwrap();
+    assert_eq!(5, client.get("foo").int().await.unwrap());
+
+    let _ = fs::remove_file(&snapshot);
+}
+
+#[tokio::test]
+async fn test_config_get_set() {
+    let server = Server::start("config-runtime", |command, _| {
+        command.env("MAX_VALUE_SIZE", "4");
+    });
+    let client = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    assert!(client.set("foo").bytes(b"hello".to_vec()).await.is_err());
+
+    assert_eq!("8", client.config_set("max_value_size", "8").await.unwrap());
+    let config = client.config_get(["max_value_size"]).await.unwrap();
+    assert_eq!(
+        Some(b"8".as_ref()),
+        config
+            .get(b"max_value_size".as_ref())
+            .as_deref()
+            .map(Vec::as_slice)
+    );
+    assert!(client.set("foo").bytes(b"hello".to_vec()).await.is_ok());
+
+    assert!(client.config_set("namespaces", "4").await.is_err());
+}
+
+#[test]
+fn test_config_invalid() {
+    let output = Command::new(env!("CARGO_BIN_EXE_hop-server"))
+        .env("NAMESPACES", "0")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("namespaces"));
+}
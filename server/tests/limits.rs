@@ -0,0 +1,56 @@
+mod common;
+
+use common::Server;
+use hop::{backend::server::Error, Client};
+
+#[tokio::test]
+async fn test_request_too_large() {
+    let server = Server::start("limits-size", |command, _| {
+        command.env("MAX_REQUEST_SIZE", "64");
+    });
+    let client = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    client.set("foo").bytes(b"bar".to_vec()).await.unwrap();
+
+    assert!(matches!(
+        client.set("foo").bytes(vec![0; 128]).await,
+        Err(Error::RequestTooLarge)
+    ));
+
+    // The rest of the request was never read, so the connection is closed.
+    assert!(client.get("foo").bytes().await.is_err());
+}
+
+#[tokio::test]
+async fn test_session_rate_limit() {
+    let server = Server::start("limits-session", |command, _| {
+        command.env("SESSION_RATE_LIMIT", "2");
+    });
+    let client = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    client.set("foo").int(1).await.unwrap();
+    client.get("foo").int().await.unwrap();
+
+    assert!(matches!(
+        client.get("foo").int().await,
+        Err(Error::RateLimited)
+    ));
+
+    // Other connections have their own limit.
+    let other = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    assert_eq!(1, other.get("foo").int().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_ip_rate_limit() {
+    let server = Server::start("limits-ip", |command, _| {
+        command.env("IP_RATE_LIMIT", "2");
+    });
+    let first = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    let second = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    first.set("foo").int(1).await.unwrap();
+    assert_eq!(1, second.get("foo").int().await.unwrap());
+
+    assert!(matches!(
+        first.get("foo").int().await,
+        Err(Error::RateLimited)
+    ));
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::Server;
+use hop_engine::{
+    command::{request::RequestBuilder, CommandId},
+    Hop,
+};
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    time::Duration,
+};
+
+#[test]
+fn test_requests_in_one_write() {
+    let server = Server::start("pipelining", |_, _| {});
+
+    // The responses are the same as from an engine of our own.
+    let hop = Hop::new();
+    let mut input = Vec::new();
+    let mut expected = Vec::new();
+
+    for value in &[b"foo".as_ref(), b"bar"] {
+        let mut builder = RequestBuilder::new(CommandId::Echo);
+        assert!(builder.bytes(*value).is_ok());
+        let req = builder.into_request();
+
+        assert!(hop.dispatch(&req, &mut expected).is_ok());
+        input.extend_from_slice(req.as_bytes());
+    }
+
+    let mut stream = TcpStream::connect(("127.0.0.1", server.port)).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    stream.write_all(&input).unwrap();
+
+    let mut output = vec![0; expected.len()];
+    stream.read_exact(&mut output).unwrap();
+    assert_eq!(expected, output);
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use common::Server;
+use hop::Client;
+use std::time::Duration;
+use tokio::time;
+
+#[tokio::test]
+async fn test_list_and_kill() {
+    let server = Server::start("sessions", |_, _| {});
+    let admin = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    admin.set("foo").int(1).await.unwrap();
+    let other = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    assert_eq!(1, other.get("foo").int().await.unwrap());
+
+    let sessions = admin.client_list().await.unwrap();
+    assert_eq!(2, sessions.len());
+    assert!(sessions.iter().all(|session| session
+        .peer()
+        .is_some_and(|peer| peer.starts_with("127.0.0.1:"))));
+    assert!(sessions[0].id() < sessions[1].id());
+
+    // The admin connected first, so the other session is the second one.
+    assert!(admin.client_kill(sessions[1].id()).await.unwrap());
+    assert!(!admin.client_kill(sessions[1].id()).await.unwrap());
+    assert!(other.get("foo").int().await.is_err());
+
+    let sessions = admin.client_list().await.unwrap();
+    assert_eq!(1, sessions.len());
+    assert_eq!(1, admin.get("foo").int().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_idle_timeout() {
+    let server = Server::start("sessions-idle", |command, _| {
+        command.env("IDLE_TIMEOUT", "1");
+    });
+    let idle = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    idle.set("foo").int(1).await.unwrap();
+
+    time::delay_for(Duration::from_millis(1500)).await;
+    assert!(idle.get("foo").int().await.is_err());
+
+    let client = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    let stats = client.stats().await.unwrap();
+    assert_eq!(1, stats.sessions_timed_out());
+}
+
+#[tokio::test]
+async fn test_sessions_active_max() {
+    let server = Server::start("sessions-max", |command, _| {
+        command.env("SESSIONS_ACTIVE_MAX", "1");
+    });
+    let first = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    first.set("foo").int(1).await.unwrap();
+
+    // Connections over the limit are closed.
+    let second = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    assert!(second.get("foo").int().await.is_err());
+
+    // Once a session ends there's room for another.
+    drop(first);
+    time::delay_for(Duration::from_millis(200)).await;
+    let third = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    assert_eq!(1, third.get("foo").int().await.unwrap());
+}
@@ -0,0 +1,43 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::Server;
+use hop::Client;
+use std::{
+    env, fs,
+    path::Path,
+    process,
+    time::{Duration, Instant},
+};
+
+fn start(name: &str, snapshot: &Path) -> Server {
+    Server::start(name, |command, _| {
+        command
+            .env("PERSISTENCE_PATH", snapshot)
+            .env("PERSISTENCE_INTERVAL", "0")
+            .env("SHUTDOWN_TIMEOUT", "10");
+    })
+}
+
+#[tokio::test]
+async fn test_graceful_shutdown() {
+    let snapshot = env::temp_dir().join(format!("hop-{}-shutdown.snapshot", process::id()));
+
+    let mut server = start("shutdown-first", &snapshot);
+    let client = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    client.set("foo").int(9).await.unwrap();
+
+    // The idle connection is closed rather than waited on until the timeout.
+    let started = Instant::now();
+    assert!(server.terminate().success());
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(client.get("foo").int().await.is_err());
+
+    // Keys are saved on shutdown even without periodic snapshots.
+    let server = start("shutdown-second", &snapshot);
+    let client = Client::connect(("127.0.0.1", server.port)).await.unwrap();
+    assert_eq!(9, client.get("foo").int().await.unwrap());
+
+    let _ = fs::remove_file(&snapshot);
+}
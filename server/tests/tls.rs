@@ -0,0 +1,138 @@
+#![cfg(feature = "tls")]
+
+mod common;
+
+use common::Server;
+use hop::{
+    backend::{server::Error, ConnectOptions, ServerBackend, TlsOptions},
+    Client,
+};
+use std::{
+    fs,
+    io::{ErrorKind, Read},
+    net::TcpStream,
+    time::Duration,
+};
+
+/// A certificate for `localhost` signed by itself.
+struct SelfSigned {
+    certificate: String,
+    key: String,
+}
+
+impl SelfSigned {
+    fn generate() -> Self {
+        let certificate = rcgen::generate_simple_self_signed(vec!["localhost".to_owned()])
+            .expect("certificate is valid");
+
+        Self {
+            certificate: certificate.serialize_pem().expect("certificate serializes"),
+            key: certificate.serialize_private_key_pem(),
+        }
+    }
+}
+
+/// Start a server encrypting connections with a certificate, optionally
+/// requiring clients to present a certificate signed by a client CA.
+fn start(name: &str, certificate: &SelfSigned, client_ca: Option<&SelfSigned>) -> Server {
+    Server::start(name, |command, dir| {
+        fs::write(dir.join("certificate.pem"), &certificate.certificate).unwrap();
+        fs::write(dir.join("key.pem"), &certificate.key).unwrap();
+        command
+            .env("TLS_CERTIFICATE", dir.join("certificate.pem"))
+            .env("TLS_KEY", dir.join("key.pem"));
+
+        if let Some(client_ca) = client_ca {
+            fs::write(dir.join("client_ca.pem"), &client_ca.certificate).unwrap();
+            command.env("TLS_CLIENT_CA", dir.join("client_ca.pem"));
+        }
+    })
+}
+
+async fn connect(server: &Server, tls: TlsOptions) -> Result<Client<ServerBackend>, Error> {
+    let mut options = ConnectOptions::new();
+    options.tls(tls);
+
+    Client::connect_with_options(("127.0.0.1", server.port), &options).await
+}
+
+#[tokio::test]
+async fn test_self_signed() {
+    let certificate = SelfSigned::generate();
+    let server = start("tls-self-signed", &certificate, None);
+
+    let mut tls = TlsOptions::new("localhost").unwrap();
+    tls.ca_certificates(certificate.certificate.as_bytes())
+        .unwrap();
+    let client = connect(&server, tls).await.unwrap();
+
+    client.set("foo").int(7).await.unwrap();
+    assert_eq!(7, client.get("foo").int().await.unwrap());
+
+    // Clients that don't trust the certificate refuse to connect.
+    let untrusted = TlsOptions::new("localhost").unwrap();
+    assert!(connect(&server, untrusted).await.is_err());
+
+    // Neither do clients expecting a certificate for another domain.
+    let mut other = TlsOptions::new("example.com").unwrap();
+    other
+        .ca_certificates(certificate.certificate.as_bytes())
+        .unwrap();
+    assert!(connect(&server, other).await.is_err());
+}
+
+#[tokio::test]
+async fn test_client_certificate() {
+    let certificate = SelfSigned::generate();
+    let client_certificate = SelfSigned::generate();
+    let server = start("tls-client-ca", &certificate, Some(&client_certificate));
+
+    let mut tls = TlsOptions::new("localhost").unwrap();
+    tls.ca_certificates(certificate.certificate.as_bytes())
+        .unwrap();
+
+    // The server rejects clients without a certificate, possibly only after
+    // the client has finished its side of the handshake.
+    let rejected = match connect(&server, tls.clone()).await {
+        Ok(client) => client.set("foo").int(1).await.is_err(),
+        Err(_) => true,
+    };
+    assert!(rejected);
+
+    tls.client_certificate(
+        client_certificate.certificate.as_bytes(),
+        client_certificate.key.as_bytes(),
+    )
+    .unwrap();
+    let client = connect(&server, tls).await.unwrap();
+
+    client.set("foo").int(1).await.unwrap();
+    assert_eq!(1, client.get("foo").int().await.unwrap());
+}
+
+#[test]
+fn test_handshake_timeout() {
+    let certificate = SelfSigned::generate();
+    let server = Server::start("tls-handshake-timeout", |command, dir| {
+        fs::write(dir.join("certificate.pem"), &certificate.certificate).unwrap();
+        fs::write(dir.join("key.pem"), &certificate.key).unwrap();
+        command
+            .env("IDLE_TIMEOUT", "1")
+            .env("TLS_CERTIFICATE", dir.join("certificate.pem"))
+            .env("TLS_KEY", dir.join("key.pem"));
+    });
+
+    // A client that never starts the handshake is disconnected once the idle
+    // timeout passes.
+    let mut stream = TcpStream::connect(("127.0.0.1", server.port)).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+
+    let mut buf = [0; 1];
+    match stream.read(&mut buf) {
+        Ok(0) => {}
+        Err(why) if why.kind() == ErrorKind::ConnectionReset => {}
+        other => panic!("connection wasn't closed: {:?}", other),
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(unix)]
+
+mod common;
+
+use common::Server;
+use hop::{backend::ConnectOptions, Client};
+
+#[tokio::test]
+async fn test_unix_socket() {
+    let server = Server::start("unix", |command, dir| {
+        command
+            .env("PASSWORD", "hunter2")
+            .env("UNIX_SOCKET", dir.join("hop.sock"));
+    });
+    let path = server.dir.join("hop.sock");
+
+    let mut options = ConnectOptions::new();
+    options.password("hunter2");
+    let client = Client::connect_unix_with_options(&path, &options)
+        .await
+        .unwrap();
+    client.set("foo").int(3).await.unwrap();
+
+    // Both listeners share the same instance.
+    let tcp = Client::connect_with_options(("127.0.0.1", server.port), &options)
+        .await
+        .unwrap();
+    assert_eq!(3, tcp.get("foo").int().await.unwrap());
+
+    // Connections still need to authenticate.
+    let unauthenticated = Client::connect_unix(&path).await.unwrap();
+    assert!(unauthenticated.get("foo").int().await.is_err());
+}